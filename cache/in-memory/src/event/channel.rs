@@ -16,7 +16,7 @@ impl InMemoryCache {
         }
     }
 
-    fn cache_guild_channel(&self, guild_id: GuildId, mut channel: GuildChannel) {
+    pub(crate) fn cache_guild_channel(&self, guild_id: GuildId, mut channel: GuildChannel) {
         match channel {
             GuildChannel::Category(ref mut c) => {
                 c.guild_id.replace(guild_id);
@@ -30,6 +30,11 @@ impl InMemoryCache {
             GuildChannel::Stage(ref mut c) => {
                 c.guild_id.replace(guild_id);
             }
+            GuildChannel::NewsThread(ref mut c)
+            | GuildChannel::PrivateThread(ref mut c)
+            | GuildChannel::PublicThread(ref mut c) => {
+                c.guild_id.replace(guild_id);
+            }
         }
 
         let id = channel.id();
@@ -56,7 +61,7 @@ impl InMemoryCache {
     ///
     /// The guild channel data itself and the channel entry in its guild's list
     /// of channels will be deleted.
-    fn delete_guild_channel(&self, channel_id: ChannelId) {
+    pub(crate) fn delete_guild_channel(&self, channel_id: ChannelId) {
         if let Some((_, item)) = self.0.channels_guild.remove(&channel_id) {
             if let Some(mut guild_channels) = self.0.guild_channels.get_mut(&item.guild_id) {
                 guild_channels.remove(&channel_id);
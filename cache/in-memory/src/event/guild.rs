@@ -20,6 +20,8 @@ impl InMemoryCache {
             self.cache_guild_channels(guild.id, guild.channels);
         }
 
+        self.cache_threads(guild.id, guild.threads);
+
         if self.wants(ResourceType::EMOJI) {
             self.0.guild_emojis.insert(guild.id, HashSet::new());
             self.cache_emojis(guild.id, guild.emojis);
@@ -129,6 +131,14 @@ impl UpdateCache for GuildDelete {
 
         cache.0.guilds.remove(&id);
 
+        if cache.wants(ResourceType::THREAD_MEMBER) {
+            if let Some(channel_ids) = cache.0.guild_channels.get(&id).map(|r| r.clone()) {
+                for channel_id in channel_ids {
+                    cache.delete_thread_members(channel_id);
+                }
+            }
+        }
+
         if cache.wants(ResourceType::CHANNEL) {
             remove_ids(&cache.0.guild_channels, &cache.0.channels_guild, id);
         }
@@ -264,6 +274,7 @@ mod tests {
             splash: None,
             stage_instances: Vec::new(),
             system_channel_id: None,
+            threads: Vec::new(),
             system_channel_flags: SystemChannelFlags::SUPPRESS_JOIN_NOTIFICATIONS,
             rules_channel_id: None,
             unavailable: false,
@@ -336,6 +347,7 @@ mod tests {
             stage_instances: Vec::new(),
             system_channel_flags: SystemChannelFlags::empty(),
             system_channel_id: None,
+            threads: Vec::new(),
             unavailable: false,
             vanity_url_code: None,
             verification_level: VerificationLevel::VeryHigh,
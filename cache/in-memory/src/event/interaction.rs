@@ -88,6 +88,7 @@ mod tests {
                             premium_since: None,
                             roles: vec![RoleId(8)],
                         }],
+                        messages: Vec::new(),
                         roles: vec![Role {
                             color: 0u32,
                             hoist: false,
@@ -115,6 +116,7 @@ mod tests {
                             verified: None,
                         }],
                     }),
+                    target_id: None,
                 },
                 guild_id: Some(GuildId(3)),
                 id: InteractionId(4),
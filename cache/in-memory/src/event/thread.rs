@@ -0,0 +1,363 @@
+use crate::{config::ResourceType, InMemoryCache, UpdateCache};
+use std::collections::HashSet;
+use twilight_model::{
+    channel::{thread::ThreadMember, Channel, GuildChannel},
+    gateway::payload::{
+        ThreadCreate, ThreadDelete, ThreadListSync, ThreadMemberUpdate, ThreadMembersUpdate,
+        ThreadUpdate,
+    },
+    id::{ChannelId, GuildId, UserId},
+};
+
+impl InMemoryCache {
+    pub(crate) fn cache_threads(
+        &self,
+        guild_id: GuildId,
+        threads: impl IntoIterator<Item = GuildChannel>,
+    ) {
+        for thread in threads {
+            self.cache_thread(guild_id, thread);
+        }
+    }
+
+    /// Cache a thread, along with the thread member of the current user if it
+    /// has joined the thread.
+    fn cache_thread(&self, guild_id: GuildId, thread: GuildChannel) {
+        if self.wants(ResourceType::THREAD_MEMBER) {
+            if let Some(member) = thread.thread().and_then(|t| t.member.clone()) {
+                self.cache_thread_member(thread.id(), member);
+            }
+        }
+
+        if self.wants(ResourceType::CHANNEL) {
+            self.cache_guild_channel(guild_id, thread);
+        }
+    }
+
+    /// Cache a member of a thread.
+    ///
+    /// Thread members included in a thread channel don't have a user ID, as
+    /// they are always the current user's. These are only cached if the
+    /// current user is.
+    fn cache_thread_member(&self, channel_id: ChannelId, member: ThreadMember) {
+        let user_id = match member.user_id.or_else(|| self.current_user_id()) {
+            Some(user_id) => user_id,
+            None => return,
+        };
+
+        self.0
+            .channel_thread_members
+            .entry(channel_id)
+            .or_default()
+            .insert(user_id);
+
+        crate::upsert_item(&self.0.thread_members, (channel_id, user_id), member);
+    }
+
+    fn current_user_id(&self) -> Option<UserId> {
+        self.0
+            .current_user
+            .lock()
+            .expect("current user poisoned")
+            .as_ref()
+            .map(|user| user.id)
+    }
+
+    fn delete_thread_member(&self, channel_id: ChannelId, user_id: UserId) {
+        self.0.thread_members.remove(&(channel_id, user_id));
+
+        if let Some(mut members) = self.0.channel_thread_members.get_mut(&channel_id) {
+            members.remove(&user_id);
+        }
+    }
+
+    /// Delete the cached threads of the synced parent channels that are
+    /// missing from a thread list sync, since they're no longer active.
+    fn delete_unsynced_threads(&self, sync: &ThreadListSync) {
+        let channel_ids = match self.0.guild_channels.get(&sync.guild_id) {
+            Some(channel_ids) => channel_ids.clone(),
+            None => return,
+        };
+
+        let synced = sync.threads.iter().map(Channel::id).collect::<HashSet<_>>();
+
+        for channel_id in channel_ids {
+            if synced.contains(&channel_id) {
+                continue;
+            }
+
+            let unsynced = self
+                .0
+                .channels_guild
+                .get(&channel_id)
+                .map_or(false, |item| {
+                    item.data.thread().map_or(false, |thread| {
+                        sync.channel_ids.is_empty()
+                            || thread
+                                .parent_id
+                                .map_or(false, |parent_id| sync.channel_ids.contains(&parent_id))
+                    })
+                });
+
+            if unsynced {
+                self.delete_guild_channel(channel_id);
+                self.delete_thread_members(channel_id);
+            }
+        }
+    }
+
+    /// Delete all of the cached members of a thread.
+    pub(crate) fn delete_thread_members(&self, channel_id: ChannelId) {
+        if let Some((_, user_ids)) = self.0.channel_thread_members.remove(&channel_id) {
+            for user_id in user_ids {
+                self.0.thread_members.remove(&(channel_id, user_id));
+            }
+        }
+    }
+}
+
+/// Cache a thread from a thread event, if it's a guild channel.
+fn cache_thread_channel(cache: &InMemoryCache, channel: &Channel) {
+    if let Channel::Guild(thread) = channel {
+        if let Some(guild_id) = thread.guild_id() {
+            cache.cache_thread(guild_id, thread.clone());
+        }
+    }
+}
+
+impl UpdateCache for ThreadCreate {
+    fn update(&self, cache: &InMemoryCache) {
+        cache_thread_channel(cache, &self.0);
+    }
+}
+
+impl UpdateCache for ThreadDelete {
+    fn update(&self, cache: &InMemoryCache) {
+        if cache.wants(ResourceType::CHANNEL) {
+            cache.delete_guild_channel(self.id);
+        }
+
+        if cache.wants(ResourceType::THREAD_MEMBER) {
+            cache.delete_thread_members(self.id);
+        }
+    }
+}
+
+impl UpdateCache for ThreadListSync {
+    fn update(&self, cache: &InMemoryCache) {
+        if cache.wants(ResourceType::CHANNEL) {
+            cache.delete_unsynced_threads(self);
+        }
+
+        for thread in &self.threads {
+            if let Channel::Guild(thread) = thread {
+                cache.cache_thread(self.guild_id, thread.clone());
+            }
+        }
+
+        if !cache.wants(ResourceType::THREAD_MEMBER) {
+            return;
+        }
+
+        for member in &self.members {
+            if let Some(channel_id) = member.id {
+                cache.cache_thread_member(channel_id, member.clone());
+            }
+        }
+    }
+}
+
+impl UpdateCache for ThreadMemberUpdate {
+    fn update(&self, cache: &InMemoryCache) {
+        if !cache.wants(ResourceType::THREAD_MEMBER) {
+            return;
+        }
+
+        if let Some(channel_id) = self.0.id {
+            cache.cache_thread_member(channel_id, self.0.clone());
+        }
+    }
+}
+
+impl UpdateCache for ThreadMembersUpdate {
+    fn update(&self, cache: &InMemoryCache) {
+        if cache.wants(ResourceType::CHANNEL) {
+            if let Some(mut item) = cache.0.channels_guild.get_mut(&self.id) {
+                if let Some(thread) = item.data.thread_mut() {
+                    thread.member_count = self.member_count;
+                }
+            }
+        }
+
+        if !cache.wants(ResourceType::THREAD_MEMBER) {
+            return;
+        }
+
+        for member in &self.added_members {
+            cache.cache_thread_member(self.id, member.clone());
+        }
+
+        for user_id in &self.removed_member_ids {
+            cache.delete_thread_member(self.id, *user_id);
+        }
+    }
+}
+
+impl UpdateCache for ThreadUpdate {
+    fn update(&self, cache: &InMemoryCache) {
+        cache_thread_channel(cache, &self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{test, InMemoryCache};
+    use twilight_model::{
+        channel::{
+            thread::{AutoArchiveDuration, ThreadMember, ThreadMetadata},
+            Channel, ChannelType, GuildChannel, ThreadChannel,
+        },
+        gateway::payload::{
+            ThreadCreate, ThreadDelete, ThreadListSync, ThreadMemberUpdate, ThreadMembersUpdate,
+            UserUpdate,
+        },
+        id::{ChannelId, GuildId, UserId},
+    };
+
+    fn thread(member: Option<ThreadMember>) -> GuildChannel {
+        GuildChannel::PublicThread(ThreadChannel {
+            guild_id: Some(GuildId(1)),
+            id: ChannelId(2),
+            kind: ChannelType::GuildPublicThread,
+            last_message_id: None,
+            member,
+            member_count: 1,
+            message_count: 0,
+            name: "thread".to_owned(),
+            owner_id: Some(UserId(3)),
+            parent_id: Some(ChannelId(4)),
+            rate_limit_per_user: None,
+            thread_metadata: ThreadMetadata {
+                archive_timestamp: "2021-09-19T14:17:32.000000+00:00".to_owned(),
+                archived: false,
+                auto_archive_duration: AutoArchiveDuration::Day,
+                invitable: None,
+                locked: false,
+            },
+        })
+    }
+
+    fn thread_member(user_id: Option<UserId>) -> ThreadMember {
+        ThreadMember {
+            flags: 0,
+            id: user_id.map(|_| ChannelId(2)),
+            join_timestamp: "2021-09-19T14:17:32.000000+00:00".to_owned(),
+            user_id,
+        }
+    }
+
+    #[test]
+    fn test_thread_create_delete() {
+        let cache = InMemoryCache::new();
+        cache.update(&UserUpdate(test::current_user(3)));
+        cache.update(&ThreadCreate(Channel::Guild(thread(Some(thread_member(
+            None,
+        ))))));
+
+        assert_eq!(
+            Some(thread(Some(thread_member(None)))),
+            cache.guild_channel(ChannelId(2))
+        );
+        assert!(cache
+            .guild_channels(GuildId(1))
+            .unwrap()
+            .contains(&ChannelId(2)));
+        assert_eq!(
+            Some(thread_member(None)),
+            cache.thread_member(ChannelId(2), UserId(3))
+        );
+
+        cache.update(&ThreadDelete {
+            guild_id: GuildId(1),
+            id: ChannelId(2),
+            kind: ChannelType::GuildPublicThread,
+            parent_id: ChannelId(4),
+        });
+
+        assert!(cache.guild_channel(ChannelId(2)).is_none());
+        assert!(cache.thread_members(ChannelId(2)).is_none());
+        assert!(cache.thread_member(ChannelId(2), UserId(3)).is_none());
+    }
+
+    #[test]
+    fn test_thread_members_update() {
+        let cache = InMemoryCache::new();
+        cache.update(&ThreadCreate(Channel::Guild(thread(None))));
+        cache.update(&ThreadMemberUpdate(thread_member(Some(UserId(5)))));
+
+        cache.update(&ThreadMembersUpdate {
+            added_members: vec![thread_member(Some(UserId(6)))],
+            guild_id: GuildId(1),
+            id: ChannelId(2),
+            member_count: 2,
+            removed_member_ids: vec![UserId(5)],
+        });
+
+        let members = cache.thread_members(ChannelId(2)).unwrap();
+        assert_eq!(1, members.len());
+        assert!(members.contains(&UserId(6)));
+        assert!(cache.thread_member(ChannelId(2), UserId(5)).is_none());
+
+        let channel = cache.guild_channel(ChannelId(2)).unwrap();
+        assert_eq!(2, channel.thread().unwrap().member_count);
+    }
+
+    #[test]
+    fn test_thread_list_sync() {
+        let mut other = thread(None);
+        if let GuildChannel::PublicThread(ref mut thread) = other {
+            thread.id = ChannelId(5);
+            thread.parent_id = Some(ChannelId(6));
+        }
+
+        let cache = InMemoryCache::new();
+        cache.update(&ThreadCreate(Channel::Guild(thread(None))));
+        cache.update(&ThreadCreate(Channel::Guild(other.clone())));
+        cache.update(&ThreadMemberUpdate(thread_member(Some(UserId(7)))));
+
+        // Only the threads of the synced parent channel are evicted.
+        cache.update(&ThreadListSync {
+            channel_ids: vec![ChannelId(4)],
+            guild_id: GuildId(1),
+            members: Vec::new(),
+            threads: Vec::new(),
+        });
+
+        assert!(cache.guild_channel(ChannelId(2)).is_none());
+        assert!(cache.thread_member(ChannelId(2), UserId(7)).is_none());
+        assert!(cache.guild_channel(ChannelId(5)).is_some());
+
+        // Syncing all of the guild's threads keeps the threads in the payload.
+        cache.update(&ThreadListSync {
+            channel_ids: Vec::new(),
+            guild_id: GuildId(1),
+            members: Vec::new(),
+            threads: vec![Channel::Guild(other)],
+        });
+
+        assert!(cache.guild_channel(ChannelId(5)).is_some());
+
+        cache.update(&ThreadListSync {
+            channel_ids: Vec::new(),
+            guild_id: GuildId(1),
+            members: Vec::new(),
+            threads: Vec::new(),
+        });
+
+        assert!(cache.guild_channel(ChannelId(5)).is_none());
+        assert!(!cache
+            .guild_channels(GuildId(1))
+            .unwrap()
+            .contains(&ChannelId(5)));
+    }
+}
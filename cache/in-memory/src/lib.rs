@@ -105,7 +105,7 @@ use std::{
     sync::{Arc, Mutex},
 };
 use twilight_model::{
-    channel::{Group, GuildChannel, PrivateChannel, StageInstance},
+    channel::{thread::ThreadMember, Group, GuildChannel, PrivateChannel, StageInstance},
     gateway::event::Event,
     guild::{GuildIntegration, Role},
     id::{ChannelId, EmojiId, GuildId, IntegrationId, MessageId, RoleId, StageId, UserId},
@@ -151,6 +151,7 @@ fn upsert_item<K: Eq + Hash, V: PartialEq>(map: &DashMap<K, V>, k: K, v: V) {
 #[derive(Debug, Default)]
 struct InMemoryCacheRef {
     config: Config,
+    channel_thread_members: DashMap<ChannelId, HashSet<UserId>>,
     channels_guild: DashMap<ChannelId, GuildItem<GuildChannel>>,
     channels_private: DashMap<ChannelId, PrivateChannel>,
     // So long as the lock isn't held across await or panic points this is fine.
@@ -171,6 +172,7 @@ struct InMemoryCacheRef {
     presences: DashMap<(GuildId, UserId), CachedPresence>,
     roles: DashMap<RoleId, GuildItem<Role>>,
     stage_instances: DashMap<StageId, GuildItem<StageInstance>>,
+    thread_members: DashMap<(ChannelId, UserId), ThreadMember>,
     unavailable_guilds: DashSet<GuildId>,
     users: DashMap<UserId, (User, BTreeSet<GuildId>)>,
     /// Mapping of channels and the users currently connected.
@@ -249,6 +251,7 @@ impl InMemoryCache {
     ///
     /// This is equal to creating a new empty cache.
     pub fn clear(&self) {
+        self.0.channel_thread_members.clear();
         self.0.channels_guild.clear();
         self.0.channels_private.clear();
         self.0
@@ -271,6 +274,7 @@ impl InMemoryCache {
         self.0.messages.clear();
         self.0.presences.clear();
         self.0.roles.clear();
+        self.0.thread_members.clear();
         self.0.unavailable_guilds.clear();
         self.0.users.clear();
         self.0.voice_state_channels.clear();
@@ -524,6 +528,37 @@ impl InMemoryCache {
             .map(|role| role.data.clone())
     }
 
+    /// Gets a member of a thread by thread ID and user ID.
+    ///
+    /// Members of threads other than the current user are only cached if the
+    /// [`GUILD_MEMBERS`] intent is enabled.
+    ///
+    /// This is an O(1) operation. This requires the [`GUILDS`] intent.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    /// [`GUILD_MEMBERS`]: ::twilight_model::gateway::Intents::GUILD_MEMBERS
+    pub fn thread_member(&self, channel_id: ChannelId, user_id: UserId) -> Option<ThreadMember> {
+        self.0
+            .thread_members
+            .get(&(channel_id, user_id))
+            .map(|r| r.clone())
+    }
+
+    /// Gets the set of members of a thread.
+    ///
+    /// This list may be incomplete if not all members have been cached.
+    ///
+    /// This is a O(m) operation, where m is the amount of members in the
+    /// thread. This requires the [`GUILDS`] intent.
+    ///
+    /// [`GUILDS`]: ::twilight_model::gateway::Intents::GUILDS
+    pub fn thread_members(&self, channel_id: ChannelId) -> Option<HashSet<UserId>> {
+        self.0
+            .channel_thread_members
+            .get(&channel_id)
+            .map(|r| r.clone())
+    }
+
     /// Gets a user by ID.
     ///
     /// This is an O(1) operation. This requires the [`GUILD_MEMBERS`] intent.
@@ -655,6 +690,12 @@ impl UpdateCache for Event {
             StageInstanceCreate(v) => c.update(v),
             StageInstanceDelete(v) => c.update(v),
             StageInstanceUpdate(v) => c.update(v),
+            ThreadCreate(v) => c.update(v),
+            ThreadDelete(v) => c.update(v),
+            ThreadListSync(v) => c.update(v),
+            ThreadMemberUpdate(v) => c.update(v),
+            ThreadMembersUpdate(v) => c.update(v),
+            ThreadUpdate(v) => c.update(v),
             TypingStart(_) => {}
             UnavailableGuild(v) => c.update(v),
             UserUpdate(v) => c.update(v),
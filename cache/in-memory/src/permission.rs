@@ -29,7 +29,7 @@ use std::{
     fmt::{Display, Formatter, Result as FmtResult},
 };
 use twilight_model::{
-    channel::GuildChannel,
+    channel::{permission_overwrite::PermissionOverwrite, GuildChannel},
     guild::Permissions,
     id::{ChannelId, GuildId, RoleId, UserId},
 };
@@ -333,11 +333,24 @@ impl<'a> InMemoryCachePermissions<'a> {
             .member_roles(user_id, guild_id)
             .map_err(ChannelError::from_member_roles)?;
 
-        let overwrites = match &channel.data {
-            GuildChannel::Category(c) => &c.permission_overwrites,
-            GuildChannel::Stage(c) => &c.permission_overwrites,
-            GuildChannel::Text(c) => &c.permission_overwrites,
-            GuildChannel::Voice(c) => &c.permission_overwrites,
+        // Threads don't have permission overwrites of their own, and instead
+        // use those of their parent channel.
+        let parent;
+        let overwrites = match channel_overwrites(&channel.data) {
+            Some(overwrites) => overwrites,
+            None => {
+                parent = channel
+                    .data
+                    .thread()
+                    .and_then(|thread| thread.parent_id)
+                    .and_then(|parent_id| (self.0).0.channels_guild.get(&parent_id))
+                    .ok_or(ChannelError {
+                        kind: ChannelErrorType::ChannelUnavailable { channel_id },
+                        source: None,
+                    })?;
+
+                channel_overwrites(&parent.data).unwrap_or_default()
+            }
         };
 
         let calculator =
@@ -467,6 +480,19 @@ impl<'a> InMemoryCachePermissions<'a> {
     }
 }
 
+/// Permission overwrites of a channel, or `None` if the channel is a thread.
+fn channel_overwrites(channel: &GuildChannel) -> Option<&[PermissionOverwrite]> {
+    match channel {
+        GuildChannel::Category(c) => Some(&c.permission_overwrites),
+        GuildChannel::NewsThread(_)
+        | GuildChannel::PrivateThread(_)
+        | GuildChannel::PublicThread(_) => None,
+        GuildChannel::Stage(c) => Some(&c.permission_overwrites),
+        GuildChannel::Text(c) => Some(&c.permission_overwrites),
+        GuildChannel::Voice(c) => Some(&c.permission_overwrites),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{
@@ -478,9 +504,12 @@ mod tests {
     use twilight_model::{
         channel::{
             permission_overwrite::{PermissionOverwrite, PermissionOverwriteType},
-            Channel, ChannelType, GuildChannel, TextChannel,
+            thread::{AutoArchiveDuration, ThreadMetadata},
+            Channel, ChannelType, GuildChannel, TextChannel, ThreadChannel,
+        },
+        gateway::payload::{
+            ChannelCreate, GuildCreate, MemberAdd, MemberUpdate, RoleCreate, ThreadCreate,
         },
-        gateway::payload::{ChannelCreate, GuildCreate, MemberAdd, MemberUpdate, RoleCreate},
         guild::{
             DefaultMessageNotificationLevel, ExplicitContentFilter, Guild, MfaLevel, NSFWLevel,
             Permissions, PremiumTier, Role, SystemChannelFlags, VerificationLevel,
@@ -519,6 +548,9 @@ mod tests {
     /// This has the same ID as the [`GUILD_ID`].
     const CHANNEL_ID: ChannelId = ChannelId(GUILD_ID.0);
 
+    /// ID of a thread in the #general channel.
+    const THREAD_ID: ChannelId = ChannelId(5);
+
     fn base_guild() -> Guild {
         Guild {
             id: GUILD_ID,
@@ -561,6 +593,7 @@ mod tests {
             splash: None,
             stage_instances: Vec::new(),
             system_channel_id: None,
+            threads: Vec::new(),
             system_channel_flags: SystemChannelFlags::SUPPRESS_JOIN_NOTIFICATIONS,
             rules_channel_id: None,
             unavailable: false,
@@ -603,6 +636,29 @@ mod tests {
         }))
     }
 
+    fn thread() -> Channel {
+        Channel::Guild(GuildChannel::PublicThread(ThreadChannel {
+            guild_id: Some(GUILD_ID),
+            id: THREAD_ID,
+            kind: ChannelType::GuildPublicThread,
+            last_message_id: None,
+            member: None,
+            member_count: 1,
+            message_count: 0,
+            name: "thread".to_owned(),
+            owner_id: Some(USER_ID),
+            parent_id: Some(CHANNEL_ID),
+            rate_limit_per_user: None,
+            thread_metadata: ThreadMetadata {
+                archive_timestamp: "2021-09-19T14:17:32.000000+00:00".to_owned(),
+                archived: false,
+                auto_archive_duration: AutoArchiveDuration::Day,
+                invitable: None,
+                locked: false,
+            },
+        }))
+    }
+
     fn role_with_permissions(id: RoleId, permissions: Permissions) -> Role {
         let mut role = test::role(id);
         role.permissions = permissions;
@@ -732,6 +788,33 @@ mod tests {
         Ok(())
     }
 
+    /// Test that [`in_channel`] uses the permission overwrites of the parent
+    /// channel of threads.
+    ///
+    /// [`in_channel`]: super::InMemoryCachePermissions::in_channel
+    #[test]
+    fn test_in_channel_thread() -> Result<(), Box<dyn Error>> {
+        let cache = InMemoryCache::new();
+        let permissions = cache.permissions();
+
+        cache.update(&GuildCreate(base_guild()));
+        cache.update(&MemberAdd(test::member(USER_ID, GUILD_ID)));
+        cache.update(&ThreadCreate(thread()));
+        assert!(matches!(
+            permissions.in_channel(USER_ID, THREAD_ID).unwrap_err().kind(),
+            ChannelErrorType::ChannelUnavailable { channel_id }
+            if *channel_id == THREAD_ID
+        ));
+
+        cache.update(&ChannelCreate(channel()));
+        assert_eq!(
+            Permissions::EMBED_LINKS,
+            permissions.in_channel(USER_ID, THREAD_ID)?,
+        );
+
+        Ok(())
+    }
+
     /// Test that [`in_channel`] and [`root`] both return [`Permissions::all`]
     /// if the user is also the owner of the guild.
     ///
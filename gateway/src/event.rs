@@ -5,7 +5,7 @@ use twilight_model::gateway::event::EventType;
 bitflags! {
     /// Bitflags representing all of the possible types of events.
     #[non_exhaustive]
-    pub struct EventTypeFlags: u64 {
+    pub struct EventTypeFlags: u128 {
         /// User has been banned from a guild.
         const BAN_ADD = 1;
         /// User has been unbanned from a guild.
@@ -119,6 +119,19 @@ bitflags! {
         const STAGE_INSTANCE_DELETE = 1 << 58;
         /// Stage instance was updated in a stage channel.
         const STAGE_INSTANCE_UPDATE = 1 << 59;
+        /// Thread has been created, or the current user has been added to a
+        /// private thread.
+        const THREAD_CREATE = 1 << 63;
+        /// Thread has been deleted.
+        const THREAD_DELETE = 1 << 64;
+        /// Current user has gained access to a channel's threads.
+        const THREAD_LIST_SYNC = 1 << 65;
+        /// Current user's thread member has been updated.
+        const THREAD_MEMBER_UPDATE = 1 << 66;
+        /// Users have been added to or removed from a thread.
+        const THREAD_MEMBERS_UPDATE = 1 << 67;
+        /// Thread has been updated.
+        const THREAD_UPDATE = 1 << 68;
         /// User has begun typing in a channel.
         const TYPING_START = 1 << 39;
         /// Guild is unavailable, potentially due to an outage.
@@ -189,6 +202,12 @@ impl From<EventType> for EventTypeFlags {
             EventType::StageInstanceCreate => EventTypeFlags::STAGE_INSTANCE_CREATE,
             EventType::StageInstanceDelete => EventTypeFlags::STAGE_INSTANCE_DELETE,
             EventType::StageInstanceUpdate => EventTypeFlags::STAGE_INSTANCE_UPDATE,
+            EventType::ThreadCreate => EventTypeFlags::THREAD_CREATE,
+            EventType::ThreadDelete => EventTypeFlags::THREAD_DELETE,
+            EventType::ThreadListSync => EventTypeFlags::THREAD_LIST_SYNC,
+            EventType::ThreadMemberUpdate => EventTypeFlags::THREAD_MEMBER_UPDATE,
+            EventType::ThreadMembersUpdate => EventTypeFlags::THREAD_MEMBERS_UPDATE,
+            EventType::ThreadUpdate => EventTypeFlags::THREAD_UPDATE,
             EventType::TypingStart => EventTypeFlags::TYPING_START,
             EventType::UnavailableGuild => EventTypeFlags::UNAVAILABLE_GUILD,
             EventType::UserUpdate => EventTypeFlags::USER_UPDATE,
@@ -294,7 +294,10 @@ impl ShardProcessor {
 
         let properties = IdentifyProperties::new("twilight.rs", "twilight.rs", OS, "", "");
 
-        url.push_str("?v=8");
+        // Use the API version of the HTTP client, so that events and requests
+        // share the same models.
+        url.push_str("?v=");
+        url.push_str(&twilight_http::API_VERSION.to_string());
         compression::add_url_feature(&mut url);
 
         emitter.event(Event::ShardConnecting(Connecting {
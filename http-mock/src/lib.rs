@@ -0,0 +1,438 @@
+//! # twilight-http-mock
+//!
+//! Mock Discord REST server for testing code using `twilight-http` without a
+//! token or network access.
+//!
+//! A [`MockServer`] listens on a local port and responds to requests with
+//! canned [`MockResponse`]s registered per [`Route`]. Every request it
+//! receives is recorded, so tests can assert on the method, path, JSON body
+//! and audit log reason of the requests made by the code under test.
+//! Ratelimits can be simulated via [`MockResponse::ratelimited`].
+//!
+//! Clients connect to the server via [`ClientBuilder::proxy`], which
+//! [`MockServer::client_builder`] configures.
+//!
+//! # Examples
+//!
+//! Respond to a request to delete a message and assert on its audit log
+//! reason:
+//!
+//! ```rust
+//! use twilight_http::{request::AuditLogReason, routing::Route};
+//! use twilight_http_mock::{MockResponse, MockServer};
+//! use twilight_model::id::{ChannelId, MessageId};
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let server = MockServer::start().await;
+//! let route = Route::DeleteMessage {
+//!     channel_id: 1,
+//!     message_id: 2,
+//! };
+//! server.mock(route.clone(), MockResponse::no_content());
+//!
+//! let client = server.client_builder().build();
+//! client
+//!     .delete_message(ChannelId(1), MessageId(2))
+//!     .reason("spam")?
+//!     .await?;
+//!
+//! let requests = server.requests();
+//! assert!(requests[0].matches(&route));
+//! assert_eq!(Some("spam".to_owned()), requests[0].audit_log_reason());
+//! # Ok(()) }
+//! ```
+//!
+//! [`ClientBuilder::proxy`]: twilight_http::client::ClientBuilder::proxy
+
+#![deny(
+    broken_intra_doc_links,
+    clippy::missing_const_for_fn,
+    missing_docs,
+    rust_2018_idioms,
+    unused,
+    warnings
+)]
+
+mod request;
+mod response;
+
+pub use self::{request::ReceivedRequest, response::MockResponse};
+
+use hyper::{
+    body,
+    service::{make_service_fn, service_fn},
+    Body, Method as HyperMethod, Request, Response, Server, StatusCode,
+};
+use std::{
+    collections::VecDeque,
+    convert::Infallible,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+};
+use tokio::sync::oneshot::{self, Sender};
+use twilight_http::{
+    client::ClientBuilder,
+    request::Method,
+    routing::{self, Route},
+    Client,
+};
+
+/// Canned responses of a route.
+#[derive(Debug)]
+struct Mock {
+    method: HyperMethod,
+    path: String,
+    responses: VecDeque<MockResponse>,
+}
+
+/// State shared by the server and its handle.
+#[derive(Debug, Default)]
+struct State {
+    mocks: Mutex<Vec<Mock>>,
+    requests: Mutex<Vec<ReceivedRequest>>,
+}
+
+impl State {
+    /// Take the next canned response of the route matching a request.
+    ///
+    /// Routes with a query take precedence over routes without one.
+    fn respond(&self, method: &HyperMethod, path: &str) -> Response<Body> {
+        let mut mocks = self.mocks.lock().expect("mocks poisoned");
+
+        let position = mocks
+            .iter()
+            .position(|mock| mock.method == *method && mock.path == path)
+            .or_else(|| {
+                mocks
+                    .iter()
+                    .position(|mock| mock.method == *method && path_matches(&mock.path, path))
+            });
+
+        let mock = match position {
+            Some(position) => &mut mocks[position],
+            None => {
+                return MockResponse::error(StatusCode::NOT_FOUND, 0, "404: Not Found").to_hyper()
+            }
+        };
+
+        let response = if mock.responses.len() > 1 {
+            mock.responses.pop_front()
+        } else {
+            mock.responses.front().cloned()
+        };
+
+        response.map_or_else(
+            || MockResponse::new(StatusCode::INTERNAL_SERVER_ERROR).to_hyper(),
+            |response| response.to_hyper(),
+        )
+    }
+}
+
+/// Local server responding to requests with canned responses.
+///
+/// Responses are registered per route via [`mock`]. Requests to routes
+/// without a registered response receive a 404 API error response, and
+/// requests with a method the API doesn't use receive a 405 response. The
+/// server shuts down when dropped.
+///
+/// [`mock`]: Self::mock
+pub struct MockServer {
+    address: SocketAddr,
+    shutdown: Option<Sender<()>>,
+    state: Arc<State>,
+}
+
+impl MockServer {
+    /// Start a server listening on a random local port.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a local port couldn't be bound.
+    pub async fn start() -> Self {
+        let state = Arc::new(State::default());
+        let service_state = Arc::clone(&state);
+
+        let service = make_service_fn(move |_| {
+            let state = Arc::clone(&service_state);
+
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request| {
+                    handle(Arc::clone(&state), request)
+                }))
+            }
+        });
+
+        let (shutdown, rx) = oneshot::channel();
+        let server = Server::bind(&([127, 0, 0, 1], 0).into()).serve(service);
+        let address = server.local_addr();
+
+        tokio::spawn(server.with_graceful_shutdown(async {
+            let _ = rx.await;
+        }));
+
+        Self {
+            address,
+            shutdown: Some(shutdown),
+            state,
+        }
+    }
+
+    /// Address the server is listening on.
+    pub const fn address(&self) -> SocketAddr {
+        self.address
+    }
+
+    /// Create a client builder configured to connect to the server.
+    pub fn client_builder(&self) -> ClientBuilder {
+        Client::builder()
+            .proxy(self.address.to_string(), true)
+            .token("mock token")
+    }
+
+    /// Register a canned response for a route.
+    ///
+    /// Registering multiple responses for the same route queues them, with
+    /// each request receiving the next response and the last response being
+    /// repeated. For example, register a [`MockResponse::ratelimited`]
+    /// response followed by a successful response to simulate a ratelimit.
+    ///
+    /// The query of requests is only compared if the route has one.
+    pub fn mock(&self, route: Route, response: MockResponse) {
+        let method = route.method().into_hyper();
+        let path = route.display().to_string();
+
+        let mut mocks = self.state.mocks.lock().expect("mocks poisoned");
+
+        if let Some(mock) = mocks
+            .iter_mut()
+            .find(|mock| mock.method == method && mock.path == path)
+        {
+            mock.responses.push_back(response);
+
+            return;
+        }
+
+        mocks.push(Mock {
+            method,
+            path,
+            responses: VecDeque::from(vec![response]),
+        });
+    }
+
+    /// Requests received by the server, in the order they were received.
+    pub fn requests(&self) -> Vec<ReceivedRequest> {
+        self.state
+            .requests
+            .lock()
+            .expect("requests poisoned")
+            .clone()
+    }
+
+    /// Remove all registered responses and received requests.
+    pub fn reset(&self) {
+        self.state.mocks.lock().expect("mocks poisoned").clear();
+        self.state
+            .requests
+            .lock()
+            .expect("requests poisoned")
+            .clear();
+    }
+}
+
+impl Debug for MockServer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("MockServer")
+            .field("address", &self.address)
+            .field("state", &self.state)
+            .finish()
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            let _ = shutdown.send(());
+        }
+    }
+}
+
+async fn handle(state: Arc<State>, request: Request<Body>) -> Result<Response<Body>, hyper::Error> {
+    let (parts, body) = request.into_parts();
+    let body = body::to_bytes(body).await?;
+
+    let uri_path = parts
+        .uri
+        .path_and_query()
+        .map_or_else(|| parts.uri.path(), |path| path.as_str());
+    let path = api_path(uri_path).to_owned();
+
+    let response = if Method::from_hyper(&parts.method).is_some() {
+        state.respond(&parts.method, &path)
+    } else {
+        MockResponse::error(StatusCode::METHOD_NOT_ALLOWED, 0, "405: Method Not Allowed").to_hyper()
+    };
+
+    state
+        .requests
+        .lock()
+        .expect("requests poisoned")
+        .push(ReceivedRequest {
+            body: body.to_vec(),
+            headers: parts.headers,
+            method: parts.method,
+            path,
+        });
+
+    Ok(response)
+}
+
+/// Strip the API prefix and version from the path of a request URI, such as
+/// `/api/v8/channels/1` to `channels/1`.
+fn api_path(uri_path: &str) -> &str {
+    routing::api_relative_path(uri_path).unwrap_or_else(|| uri_path.trim_start_matches('/'))
+}
+
+/// Whether the path of a request matches the path of a route, only
+/// comparing the query if the route has one.
+fn path_matches(route_path: &str, path: &str) -> bool {
+    if route_path.contains('?') {
+        return route_path == path;
+    }
+
+    path.split('?').next() == Some(route_path)
+}
+
+/// Whether a request was made to a route.
+fn route_matches(route: &Route, method: &HyperMethod, path: &str) -> bool {
+    route.method().into_hyper() == *method && path_matches(&route.display().to_string(), path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{api_path, path_matches, MockResponse, MockServer};
+    use hyper::{Body, Client as HyperClient, Method as HyperMethod, Request, StatusCode};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug, time::Duration};
+    use twilight_http::{client::RetryPolicy, error::ErrorType, routing::Route};
+    use twilight_model::{channel::Message, id::ChannelId};
+
+    assert_impl_all!(MockServer: Debug, Send, Sync);
+
+    #[test]
+    fn test_api_path() {
+        assert_eq!("channels/1", api_path("/api/v8/channels/1"));
+        assert_eq!("channels/1?a=b", api_path("/api/channels/1?a=b"));
+        assert_eq!("health", api_path("/health"));
+    }
+
+    #[test]
+    fn test_path_matches() {
+        assert!(path_matches("channels/1/messages", "channels/1/messages"));
+        assert!(path_matches(
+            "channels/1/messages",
+            "channels/1/messages?limit=5"
+        ));
+        assert!(path_matches(
+            "channels/1/messages?limit=5",
+            "channels/1/messages?limit=5"
+        ));
+        assert!(!path_matches(
+            "channels/1/messages?limit=5",
+            "channels/1/messages"
+        ));
+        assert!(!path_matches("channels/1", "channels/1/messages"));
+    }
+
+    #[tokio::test]
+    async fn test_canned_response() -> Result<(), Box<dyn Error>> {
+        let server = MockServer::start().await;
+        server.mock(
+            Route::GetPins { channel_id: 1 },
+            MockResponse::json(&Vec::<Message>::new()),
+        );
+
+        let client = server.client_builder().build();
+        assert!(client.pins(ChannelId(1)).await?.is_empty());
+
+        // Unregistered routes respond with a 404.
+        let result = client.pins(ChannelId(2)).await;
+        assert!(matches!(
+            result.map_err(twilight_http::Error::into_parts),
+            Err((ErrorType::Response { status, .. }, _)) if status == StatusCode::NOT_FOUND
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_unknown_method() -> Result<(), Box<dyn Error>> {
+        let server = MockServer::start().await;
+        server.mock(
+            Route::GetPins { channel_id: 1 },
+            MockResponse::json(&Vec::<Message>::new()),
+        );
+
+        let request = Request::builder()
+            .method(HyperMethod::OPTIONS)
+            .uri(format!(
+                "http://{}/api/v9/channels/1/pins",
+                server.address()
+            ))
+            .body(Body::empty())?;
+        let response = HyperClient::new().request(request).await?;
+
+        assert_eq!(StatusCode::METHOD_NOT_ALLOWED, response.status());
+        assert_eq!(&HyperMethod::OPTIONS, server.requests()[0].method());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_received_request() -> Result<(), Box<dyn Error>> {
+        let server = MockServer::start().await;
+        let client = server.client_builder().build();
+
+        // The response isn't a valid message, only the request is checked.
+        let _ = client.create_message(ChannelId(1)).content("hello")?.await;
+
+        let requests = server.requests();
+        assert_eq!(1, requests.len());
+        assert_eq!(&HyperMethod::POST, requests[0].method());
+        assert_eq!("channels/1/messages", requests[0].path());
+        assert!(requests[0].matches(&Route::CreateMessage { channel_id: 1 }));
+        assert!(!requests[0].matches(&Route::GetPins { channel_id: 1 }));
+        assert_eq!("hello", requests[0].json::<serde_json::Value>()?["content"]);
+        assert_eq!(
+            Some("Bot mock token"),
+            requests[0]
+                .headers()
+                .get("authorization")
+                .and_then(|value| value.to_str().ok())
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_ratelimited() -> Result<(), Box<dyn Error>> {
+        let server = MockServer::start().await;
+        let route = Route::GetPins { channel_id: 1 };
+        server.mock(
+            route.clone(),
+            MockResponse::ratelimited(Duration::from_millis(50), false),
+        );
+        server.mock(route, MockResponse::json(&Vec::<Message>::new()));
+
+        let client = server
+            .client_builder()
+            .retry_policy(RetryPolicy::new())
+            .build();
+        assert!(client.pins(ChannelId(1)).await?.is_empty());
+
+        assert_eq!(2, server.requests().len());
+
+        Ok(())
+    }
+}
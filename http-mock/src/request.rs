@@ -0,0 +1,67 @@
+use hyper::{header::HeaderMap, Method as HyperMethod};
+use percent_encoding::percent_decode_str;
+use serde::de::DeserializeOwned;
+use twilight_http::routing::Route;
+
+/// Request received by the [`MockServer`].
+///
+/// [`MockServer`]: crate::MockServer
+#[derive(Clone, Debug)]
+pub struct ReceivedRequest {
+    pub(crate) body: Vec<u8>,
+    pub(crate) headers: HeaderMap,
+    pub(crate) method: HyperMethod,
+    pub(crate) path: String,
+}
+
+impl ReceivedRequest {
+    /// Method of the request.
+    pub const fn method(&self) -> &HyperMethod {
+        &self.method
+    }
+
+    /// Path of the request relative to the API version, including the query,
+    /// such as `channels/1/messages?limit=5`.
+    ///
+    /// This is the format of the [`Route::display`] of the route the request
+    /// was made to.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Headers of the request.
+    pub const fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// Raw body of the request.
+    pub fn body(&self) -> &[u8] {
+        &self.body
+    }
+
+    /// Deserialize the JSON body of the request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the body isn't valid JSON for the type.
+    pub fn json<T: DeserializeOwned>(&self) -> serde_json::Result<T> {
+        serde_json::from_slice(&self.body)
+    }
+
+    /// Decoded audit log reason of the request, if one was provided.
+    pub fn audit_log_reason(&self) -> Option<String> {
+        let value = self.headers.get("x-audit-log-reason")?.to_str().ok()?;
+
+        percent_decode_str(value)
+            .decode_utf8()
+            .ok()
+            .map(|reason| reason.into_owned())
+    }
+
+    /// Whether the request was made to a route.
+    ///
+    /// The query of the request is only compared if the route has one.
+    pub fn matches(&self, route: &Route) -> bool {
+        crate::route_matches(route, &self.method, &self.path)
+    }
+}
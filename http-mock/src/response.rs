@@ -0,0 +1,176 @@
+use hyper::{
+    header::{HeaderName, HeaderValue, CONTENT_TYPE},
+    Body, Response, StatusCode,
+};
+use serde::Serialize;
+use std::{
+    convert::TryFrom,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Canned response returned by the [`MockServer`] for a route.
+///
+/// [`MockServer`]: crate::MockServer
+#[derive(Clone, Debug)]
+pub struct MockResponse {
+    body: Vec<u8>,
+    headers: Vec<(HeaderName, HeaderValue)>,
+    status: StatusCode,
+}
+
+impl MockResponse {
+    /// Create an empty response with a status code.
+    pub const fn new(status: StatusCode) -> Self {
+        Self {
+            body: Vec::new(),
+            headers: Vec::new(),
+            status,
+        }
+    }
+
+    /// Create a successful response with a JSON body, such as a model.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value fails to serialize.
+    pub fn json(value: &impl Serialize) -> Self {
+        let body = serde_json::to_vec(value).expect("value is serializable");
+
+        Self::new(StatusCode::OK)
+            .header(CONTENT_TYPE, HeaderValue::from_static("application/json"))
+            .body(body)
+    }
+
+    /// Create a successful response without a body, as returned by most
+    /// endpoints deleting or updating a resource.
+    pub const fn no_content() -> Self {
+        Self::new(StatusCode::NO_CONTENT)
+    }
+
+    /// Create an API error response with an error code and message.
+    pub fn error(status: StatusCode, code: u64, message: &str) -> Self {
+        let body = serde_json::json!({
+            "code": code,
+            "message": message,
+        });
+
+        Self {
+            status,
+            ..Self::json(&body)
+        }
+    }
+
+    /// Create a ratelimited response with a 429 status code, telling the
+    /// client to wait for an amount of time before retrying.
+    ///
+    /// Globally ratelimited responses apply to every route, while other
+    /// responses exhaust the ratelimit bucket of the route.
+    pub fn ratelimited(retry_after: Duration, global: bool) -> Self {
+        let secs = retry_after.as_secs_f64();
+        let body = serde_json::json!({
+            "global": global,
+            "message": "You are being rate limited.",
+            "retry_after": secs,
+        });
+
+        let response = Self {
+            status: StatusCode::TOO_MANY_REQUESTS,
+            ..Self::json(&body)
+        }
+        .header(
+            HeaderName::from_static("retry-after"),
+            HeaderValue::from(retry_after.as_secs() + u64::from(retry_after.subsec_nanos() > 0)),
+        );
+
+        if global {
+            return response.header(
+                HeaderName::from_static("x-ratelimit-global"),
+                HeaderValue::from_static("true"),
+            );
+        }
+
+        let reset = SystemTime::now()
+            .checked_add(retry_after)
+            .and_then(|reset| reset.duration_since(UNIX_EPOCH).ok())
+            .map_or(0., |reset| reset.as_secs_f64());
+
+        response
+            .header(
+                HeaderName::from_static("x-ratelimit-bucket"),
+                HeaderValue::from_static("mock"),
+            )
+            .header(
+                HeaderName::from_static("x-ratelimit-limit"),
+                HeaderValue::from(1),
+            )
+            .header(
+                HeaderName::from_static("x-ratelimit-remaining"),
+                HeaderValue::from(0),
+            )
+            .header(HeaderName::from_static("x-ratelimit-reset"), float(reset))
+            .header(
+                HeaderName::from_static("x-ratelimit-reset-after"),
+                float(secs),
+            )
+    }
+
+    /// Set the body of the response.
+    pub fn body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = body.into();
+
+        self
+    }
+
+    /// Add a header to the response.
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.push((name, value));
+
+        self
+    }
+
+    /// Set the status code of the response.
+    pub const fn status(mut self, status: StatusCode) -> Self {
+        self.status = status;
+
+        self
+    }
+
+    pub(crate) fn to_hyper(&self) -> Response<Body> {
+        let mut response = Response::new(Body::from(self.body.clone()));
+        *response.status_mut() = self.status;
+        response.headers_mut().extend(self.headers.iter().cloned());
+
+        response
+    }
+}
+
+/// Header value of a number of seconds with millisecond precision.
+fn float(secs: f64) -> HeaderValue {
+    HeaderValue::try_from(format!("{:.3}", secs)).expect("number is a valid header value")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MockResponse;
+    use hyper::StatusCode;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, time::Duration};
+
+    assert_impl_all!(MockResponse: Clone, Debug, Send, Sync);
+
+    #[test]
+    fn test_ratelimited() {
+        let response = MockResponse::ratelimited(Duration::from_millis(1500), false).to_hyper();
+        let headers = response.headers();
+
+        assert_eq!(StatusCode::TOO_MANY_REQUESTS, response.status());
+        assert_eq!("2", headers["retry-after"]);
+        assert_eq!("0", headers["x-ratelimit-remaining"]);
+        assert_eq!("1.500", headers["x-ratelimit-reset-after"]);
+        assert!(!headers.contains_key("x-ratelimit-global"));
+
+        let response = MockResponse::ratelimited(Duration::from_secs(1), true).to_hyper();
+        assert_eq!("true", response.headers()["x-ratelimit-global"]);
+        assert!(!response.headers().contains_key("x-ratelimit-bucket"));
+    }
+}
@@ -0,0 +1,174 @@
+use hyper::header::HeaderValue;
+use std::{
+    convert::TryFrom,
+    env,
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    net::{IpAddr, SocketAddr},
+};
+use twilight_http::ratelimiting::GlobalLimit;
+
+/// Address the proxy listens on if `HOST` isn't set.
+const DEFAULT_HOST: &str = "0.0.0.0";
+
+/// Port the proxy listens on if `PORT` isn't set.
+const DEFAULT_PORT: u16 = 80;
+
+/// URL requests are forwarded to if `UPSTREAM_URL` isn't set.
+const DEFAULT_UPSTREAM: &str = "https://discord.com";
+
+/// Configuration of the proxy could not be read from the environment.
+#[derive(Debug)]
+pub struct ConfigError {
+    kind: ConfigErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            ConfigErrorType::InvalidVariable { name } => {
+                f.write_str("environment variable ")?;
+                f.write_str(name)?;
+
+                f.write_str(" is invalid")
+            }
+        }
+    }
+}
+
+impl Error for ConfigError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`ConfigError`] that occurred.
+#[derive(Debug)]
+pub enum ConfigErrorType {
+    /// Value of an environment variable is invalid.
+    InvalidVariable {
+        /// Name of the variable.
+        name: &'static str,
+    },
+}
+
+/// Configuration of the proxy.
+#[derive(Debug)]
+pub struct Config {
+    /// Address to listen on.
+    pub address: SocketAddr,
+    /// Proactive global ratelimit, if requests are limited before the API
+    /// responds with a global ratelimit.
+    pub global_limit: Option<GlobalLimit>,
+    /// Authorization header to add to requests without one.
+    pub token: Option<HeaderValue>,
+    /// Scheme and authority of the API to forward requests to.
+    pub upstream: String,
+}
+
+impl Config {
+    /// Read the configuration from the environment.
+    ///
+    /// The following variables are read:
+    ///
+    /// - `HOST`: IP address to listen on, defaulting to `0.0.0.0`;
+    /// - `PORT`: port to listen on, defaulting to 80;
+    /// - `DISCORD_TOKEN`: token to authorize requests without an
+    ///   `Authorization` header with, prefixed with `Bot ` if it doesn't
+    ///   have a prefix, which must be a valid header value;
+    /// - `GLOBAL_LIMIT`: number of requests per second allowed by the
+    ///   proactive global ratelimit, or 0 to only globally limit requests
+    ///   once the API responds with a global ratelimit, defaulting to the
+    ///   [`GlobalLimit`] default;
+    /// - `UPSTREAM_URL`: URL of the API to forward requests to, defaulting to
+    ///   `https://discord.com`.
+    pub fn from_env() -> Result<Self, ConfigError> {
+        let host = parse_var::<IpAddr>("HOST")?
+            .unwrap_or_else(|| DEFAULT_HOST.parse().expect("default host is valid"));
+        let port = parse_var("PORT")?.unwrap_or(DEFAULT_PORT);
+
+        let global_limit = match parse_var::<u32>("GLOBAL_LIMIT")? {
+            Some(0) => None,
+            Some(requests_per_second) => Some(GlobalLimit::new(requests_per_second)),
+            None => Some(GlobalLimit::default()),
+        };
+
+        let token = env::var("DISCORD_TOKEN")
+            .ok()
+            .map(parse_token)
+            .transpose()?;
+
+        let upstream = env::var("UPSTREAM_URL").map_or_else(
+            |_| DEFAULT_UPSTREAM.to_owned(),
+            |url| url.trim_end_matches('/').to_owned(),
+        );
+
+        Ok(Self {
+            address: SocketAddr::new(host, port),
+            global_limit,
+            token,
+            upstream,
+        })
+    }
+}
+
+/// Parse a token into the value of an `Authorization` header.
+fn parse_token(mut token: String) -> Result<HeaderValue, ConfigError> {
+    if !token.starts_with("Bot ") && !token.starts_with("Bearer ") {
+        token.insert_str(0, "Bot ");
+    }
+
+    let mut value = HeaderValue::try_from(token).map_err(|source| ConfigError {
+        kind: ConfigErrorType::InvalidVariable {
+            name: "DISCORD_TOKEN",
+        },
+        source: Some(Box::new(source)),
+    })?;
+    value.set_sensitive(true);
+
+    Ok(value)
+}
+
+/// Parse an environment variable, returning `None` if it isn't set.
+fn parse_var<T>(name: &'static str) -> Result<Option<T>, ConfigError>
+where
+    T: std::str::FromStr,
+    T::Err: Error + Send + Sync + 'static,
+{
+    let value = match env::var(name) {
+        Ok(value) => value,
+        Err(_) => return Ok(None),
+    };
+
+    value.parse().map(Some).map_err(|source| ConfigError {
+        kind: ConfigErrorType::InvalidVariable { name },
+        source: Some(Box::new(source)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_token, ConfigErrorType};
+
+    #[test]
+    fn test_parse_token() {
+        let value = parse_token("token".to_owned()).unwrap();
+        assert_eq!("Bot token", value);
+        assert!(value.is_sensitive());
+        assert_eq!(
+            "Bearer token",
+            parse_token("Bearer token".to_owned()).unwrap()
+        );
+
+        let error = parse_token("token\n".to_owned()).unwrap_err();
+        assert!(matches!(
+            error.kind,
+            ConfigErrorType::InvalidVariable {
+                name: "DISCORD_TOKEN"
+            }
+        ));
+    }
+}
@@ -0,0 +1,82 @@
+//! # twilight-http-proxy
+//!
+//! Ratelimiting HTTP proxy for the Discord API.
+//!
+//! The proxy accepts API requests from any number of processes, such as the
+//! workers of a bot, and forwards them to the API through a single shared
+//! [`Ratelimiter`]. Processes using `twilight-http` can point their client at
+//! the proxy via `ClientBuilder::proxy`, disabling their own ratelimiter.
+//!
+//! Requests are mapped to their ratelimit path from their method and URI
+//! path, which must start with `/api`, optionally followed by the API
+//! version. Requests to unknown paths are rejected, as they can't be
+//! ratelimited.
+//!
+//! Besides proxied requests, the proxy serves:
+//!
+//! - `GET /health`: responds with a 200 status code while the proxy is
+//!   running;
+//! - `GET /metrics`: request counters in the Prometheus text format.
+//!
+//! Refer to [`Config::from_env`] for the environment variables used to
+//! configure the proxy.
+//!
+//! [`Ratelimiter`]: twilight_http::ratelimiting::Ratelimiter
+
+#![deny(
+    broken_intra_doc_links,
+    clippy::missing_const_for_fn,
+    rust_2018_idioms,
+    unused,
+    warnings
+)]
+
+mod config;
+mod metrics;
+mod proxy;
+
+use self::{config::Config, proxy::Proxy};
+use hyper::{
+    service::{make_service_fn, service_fn},
+    Server,
+};
+use std::{convert::Infallible, error::Error, sync::Arc};
+use tokio::signal;
+use twilight_http::ratelimiting::InMemoryRatelimiter;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error + Send + Sync>> {
+    tracing_subscriber::fmt::init();
+
+    let config = Config::from_env()?;
+
+    let ratelimiter = InMemoryRatelimiter::with_global_limit(config.global_limit);
+    let proxy = Arc::new(Proxy::new(
+        Box::new(ratelimiter),
+        config.token,
+        config.upstream,
+    ));
+
+    let service = make_service_fn(move |_| {
+        let proxy = Arc::clone(&proxy);
+
+        async move {
+            Ok::<_, Infallible>(service_fn(move |request| {
+                let proxy = Arc::clone(&proxy);
+
+                async move { proxy.handle(request).await }
+            }))
+        }
+    });
+
+    let server = Server::try_bind(&config.address)?.serve(service);
+    tracing::info!(address = %server.local_addr(), "listening");
+
+    server
+        .with_graceful_shutdown(async {
+            let _ = signal::ctrl_c().await;
+        })
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,175 @@
+use hyper::StatusCode;
+use std::{
+    fmt::Write,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+/// Prefix of the names of all metrics.
+const PREFIX: &str = "twilight_http_proxy";
+
+/// Counters of the requests handled by the proxy, rendered in the Prometheus
+/// text format.
+#[derive(Debug, Default)]
+pub struct Metrics {
+    /// Requests that failed to be forwarded, due to either the ratelimiter or
+    /// the upstream connection.
+    failed: AtomicU64,
+    /// Requests currently queued in the ratelimiter or being forwarded.
+    in_flight: AtomicU64,
+    /// Requests that couldn't be mapped to a ratelimit path.
+    rejected: AtomicU64,
+    /// Requests received, excluding those to the health and metrics
+    /// endpoints.
+    requests: AtomicU64,
+    /// Upstream responses by status class, from 1xx to 5xx.
+    responses: [AtomicU64; 5],
+    /// Upstream responses that were ratelimited.
+    ratelimited: AtomicU64,
+}
+
+impl Metrics {
+    /// Record that a request was received, returning a guard counting it as
+    /// in flight until dropped.
+    pub fn request(&self) -> InFlight<'_> {
+        self.requests.fetch_add(1, Ordering::Relaxed);
+        self.in_flight.fetch_add(1, Ordering::Relaxed);
+
+        InFlight(self)
+    }
+
+    /// Record that a request failed to be forwarded.
+    pub fn failed(&self) {
+        self.failed.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record that a request couldn't be mapped to a ratelimit path.
+    pub fn rejected(&self) {
+        self.rejected.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record the status of an upstream response.
+    pub fn response(&self, status: StatusCode) {
+        let class = usize::from(status.as_u16() / 100);
+
+        if let Some(counter) = class.checked_sub(1).and_then(|idx| self.responses.get(idx)) {
+            counter.fetch_add(1, Ordering::Relaxed);
+        }
+
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            self.ratelimited.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Render the metrics in the Prometheus text format.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+
+        render_counter(
+            &mut out,
+            "requests_total",
+            "Requests received by the proxy.",
+            &self.requests,
+        );
+        render_counter(
+            &mut out,
+            "requests_rejected_total",
+            "Requests that couldn't be mapped to a ratelimit path.",
+            &self.rejected,
+        );
+        render_counter(
+            &mut out,
+            "requests_failed_total",
+            "Requests that failed to be forwarded upstream.",
+            &self.failed,
+        );
+        render_counter(
+            &mut out,
+            "responses_ratelimited_total",
+            "Upstream responses with a 429 status code.",
+            &self.ratelimited,
+        );
+
+        let _ = writeln!(
+            out,
+            "# HELP {0}_requests_in_flight Requests queued or being forwarded.\n\
+             # TYPE {0}_requests_in_flight gauge\n\
+             {0}_requests_in_flight {1}",
+            PREFIX,
+            self.in_flight.load(Ordering::Relaxed),
+        );
+
+        let _ = writeln!(
+            out,
+            "# HELP {0}_responses_total Upstream responses by status class.\n\
+             # TYPE {0}_responses_total counter",
+            PREFIX,
+        );
+
+        for (idx, counter) in self.responses.iter().enumerate() {
+            let _ = writeln!(
+                out,
+                "{}_responses_total{{class=\"{}xx\"}} {}",
+                PREFIX,
+                idx + 1,
+                counter.load(Ordering::Relaxed),
+            );
+        }
+
+        out
+    }
+}
+
+/// Guard counting a request as in flight until dropped.
+#[derive(Debug)]
+pub struct InFlight<'a>(&'a Metrics);
+
+impl Drop for InFlight<'_> {
+    fn drop(&mut self) {
+        self.0.in_flight.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+fn render_counter(out: &mut String, name: &str, help: &str, counter: &AtomicU64) {
+    let _ = writeln!(
+        out,
+        "# HELP {0}_{1} {2}\n# TYPE {0}_{1} counter\n{0}_{1} {3}",
+        PREFIX,
+        name,
+        help,
+        counter.load(Ordering::Relaxed),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Metrics;
+    use hyper::StatusCode;
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+
+    assert_impl_all!(Metrics: Debug, Default, Send, Sync);
+
+    #[test]
+    fn test_render() {
+        let metrics = Metrics::default();
+
+        let in_flight = metrics.request();
+        metrics.response(StatusCode::TOO_MANY_REQUESTS);
+        assert!(metrics
+            .render()
+            .contains("twilight_http_proxy_requests_in_flight 1\n"));
+
+        drop(in_flight);
+        let _ = metrics.request();
+        metrics.response(StatusCode::OK);
+        metrics.rejected();
+
+        let rendered = metrics.render();
+        assert!(rendered.contains("twilight_http_proxy_requests_total 2\n"));
+        assert!(rendered.contains("twilight_http_proxy_requests_in_flight 0\n"));
+        assert!(rendered.contains("twilight_http_proxy_requests_rejected_total 1\n"));
+        assert!(rendered.contains("twilight_http_proxy_responses_ratelimited_total 1\n"));
+        assert!(rendered.contains("twilight_http_proxy_responses_total{class=\"2xx\"} 1\n"));
+        assert!(rendered.contains("twilight_http_proxy_responses_total{class=\"4xx\"} 1\n"));
+    }
+}
@@ -0,0 +1,349 @@
+use crate::metrics::Metrics;
+use hyper::{
+    client::{Client as HyperClient, HttpConnector},
+    header::{HeaderValue, AUTHORIZATION, HOST},
+    Body, Method as HyperMethod, Request, Response, StatusCode, Uri,
+};
+use hyper_rustls::HttpsConnector;
+use std::{
+    convert::{Infallible, TryFrom},
+    fmt::{Debug, Formatter, Result as FmtResult},
+};
+use twilight_http::{
+    ratelimiting::{RatelimitHeaders, Ratelimiter},
+    request::Method,
+    routing::{self, Path},
+};
+
+/// Path of the health endpoint.
+const HEALTH_PATH: &str = "/health";
+
+/// Path of the metrics endpoint.
+const METRICS_PATH: &str = "/metrics";
+
+/// Proxy forwarding API requests upstream through a shared ratelimiter.
+pub struct Proxy {
+    client: HyperClient<HttpsConnector<HttpConnector>, Body>,
+    metrics: Metrics,
+    ratelimiter: Box<dyn Ratelimiter>,
+    token: Option<HeaderValue>,
+    upstream: String,
+}
+
+impl Proxy {
+    /// Create a proxy forwarding requests to an upstream URL.
+    ///
+    /// Requests without an `Authorization` header are authorized with the
+    /// token, if one is provided.
+    pub fn new(
+        ratelimiter: Box<dyn Ratelimiter>,
+        token: Option<HeaderValue>,
+        upstream: impl Into<String>,
+    ) -> Self {
+        Self {
+            client: HyperClient::builder().build(HttpsConnector::with_native_roots()),
+            metrics: Metrics::default(),
+            ratelimiter,
+            token,
+            upstream: upstream.into(),
+        }
+    }
+
+    /// Handle a request, either to the health or metrics endpoint or to be
+    /// forwarded upstream.
+    pub async fn handle(&self, request: Request<Body>) -> Result<Response<Body>, Infallible> {
+        let response = match (request.method(), request.uri().path()) {
+            (&HyperMethod::GET, HEALTH_PATH) => Response::new(Body::from("OK")),
+            (&HyperMethod::GET, METRICS_PATH) => Response::new(Body::from(self.metrics.render())),
+            _ => self.forward(request).await,
+        };
+
+        Ok(response)
+    }
+
+    async fn forward(&self, mut request: Request<Body>) -> Response<Body> {
+        let _in_flight = self.metrics.request();
+
+        let path = match parse_path(request.method(), request.uri().path()) {
+            Ok(path) => path,
+            Err(status) => {
+                tracing::warn!(method = %request.method(), uri = %request.uri(), "rejecting request");
+                self.metrics.rejected();
+
+                return status_response(status);
+            }
+        };
+
+        let uri = format!(
+            "{}{}",
+            self.upstream,
+            request
+                .uri()
+                .path_and_query()
+                .map_or_else(|| request.uri().path(), |path| path.as_str()),
+        );
+
+        *request.uri_mut() = match Uri::try_from(uri) {
+            Ok(uri) => uri,
+            Err(source) => {
+                tracing::warn!(%source, "upstream uri is invalid");
+                self.metrics.failed();
+
+                return status_response(StatusCode::BAD_GATEWAY);
+            }
+        };
+
+        let headers = request.headers_mut();
+        headers.remove(HOST);
+
+        if let Some(token) = &self.token {
+            headers
+                .entry(AUTHORIZATION)
+                .or_insert_with(|| token.clone());
+        }
+
+        tracing::debug!(?path, uri = %request.uri(), "queueing request");
+
+        let ticket = match self.ratelimiter.ticket(path).await {
+            Ok(receiver) => receiver.await.map_err(|source| source.into()),
+            Err(source) => Err(source),
+        };
+
+        let sender = match ticket {
+            Ok(sender) => sender,
+            Err(source) => {
+                tracing::warn!(%source, "failed to get ratelimit ticket");
+                self.metrics.failed();
+
+                return status_response(StatusCode::SERVICE_UNAVAILABLE);
+            }
+        };
+
+        match self.client.request(request).await {
+            Ok(response) => {
+                let _ = sender.headers(RatelimitHeaders::try_from(response.headers()).ok());
+                self.metrics.response(response.status());
+
+                response
+            }
+            Err(source) => {
+                let _ = sender.headers(None);
+                tracing::warn!(%source, "failed to forward request");
+                self.metrics.failed();
+
+                status_response(StatusCode::BAD_GATEWAY)
+            }
+        }
+    }
+}
+
+impl Debug for Proxy {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("Proxy")
+            .field("client", &self.client)
+            .field("metrics", &self.metrics)
+            .field("ratelimiter", &self.ratelimiter)
+            .field("token", &"<redacted>")
+            .field("upstream", &self.upstream)
+            .finish()
+    }
+}
+
+/// Map the method and URI path of a request to its ratelimit path, or the
+/// status to reject it with.
+///
+/// The URI path must start with `/api`, optionally followed by an API
+/// version, such as in `/api/v8/channels/1`.
+fn parse_path(method: &HyperMethod, uri_path: &str) -> Result<Path, StatusCode> {
+    let method = Method::from_hyper(method).ok_or(StatusCode::METHOD_NOT_ALLOWED)?;
+
+    let path = routing::api_relative_path(uri_path).ok_or(StatusCode::NOT_FOUND)?;
+
+    Path::try_from((method, path)).map_err(|_| StatusCode::NOT_IMPLEMENTED)
+}
+
+fn status_response(status: StatusCode) -> Response<Body> {
+    let mut response = Response::new(Body::empty());
+    *response.status_mut() = status;
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_path, Proxy};
+    use hyper::{
+        body,
+        header::HeaderValue,
+        service::{make_service_fn, service_fn},
+        Body, Client, Method as HyperMethod, Request, Response, Server, StatusCode,
+    };
+    use static_assertions::assert_impl_all;
+    use std::{
+        convert::Infallible,
+        error::Error,
+        fmt::Debug,
+        net::SocketAddr,
+        sync::{Arc, Mutex},
+        time::{Duration, Instant},
+    };
+    use twilight_http::{ratelimiting::InMemoryRatelimiter, request::Method, routing::Path};
+
+    assert_impl_all!(Proxy: Debug, Send, Sync);
+
+    type Received = Arc<Mutex<Vec<(String, Option<String>)>>>;
+
+    /// Start a stub upstream recording the URI and authorization of each
+    /// request, responding with an exhausted ratelimit bucket that resets
+    /// after 250 milliseconds.
+    fn start_upstream() -> (SocketAddr, Received) {
+        let received = Received::default();
+        let recorded = Arc::clone(&received);
+
+        let service = make_service_fn(move |_| {
+            let recorded = Arc::clone(&recorded);
+
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request: Request<Body>| {
+                    let authorization = request
+                        .headers()
+                        .get("authorization")
+                        .map(|value| value.to_str().unwrap().to_owned());
+                    recorded
+                        .lock()
+                        .unwrap()
+                        .push((request.uri().to_string(), authorization));
+
+                    async {
+                        Response::builder()
+                            .header("x-ratelimit-bucket", "abcd")
+                            .header("x-ratelimit-limit", "1")
+                            .header("x-ratelimit-remaining", "0")
+                            .header("x-ratelimit-reset", "1")
+                            .header("x-ratelimit-reset-after", "0.25")
+                            .body(Body::from("[]"))
+                    }
+                }))
+            }
+        });
+
+        let server = Server::bind(&([127, 0, 0, 1], 0).into()).serve(service);
+        let addr = server.local_addr();
+        tokio::spawn(server);
+
+        (addr, received)
+    }
+
+    /// Start the proxy in front of an upstream.
+    fn start_proxy(upstream: SocketAddr) -> SocketAddr {
+        let proxy = Arc::new(Proxy::new(
+            Box::new(InMemoryRatelimiter::with_global_limit(None)),
+            Some(HeaderValue::from_static("Bot token")),
+            format!("http://{}", upstream),
+        ));
+
+        let service = make_service_fn(move |_| {
+            let proxy = Arc::clone(&proxy);
+
+            async move {
+                Ok::<_, Infallible>(service_fn(move |request| {
+                    let proxy = Arc::clone(&proxy);
+
+                    async move { proxy.handle(request).await }
+                }))
+            }
+        });
+
+        let server = Server::bind(&([127, 0, 0, 1], 0).into()).serve(service);
+        let addr = server.local_addr();
+        tokio::spawn(server);
+
+        addr
+    }
+
+    async fn get(addr: SocketAddr, path: &str) -> Result<(StatusCode, String), Box<dyn Error>> {
+        let response = Client::new()
+            .get(format!("http://{}{}", addr, path).parse()?)
+            .await?;
+        let status = response.status();
+        let body = body::to_bytes(response.into_body()).await?;
+
+        Ok((status, String::from_utf8(body.to_vec())?))
+    }
+
+    #[test]
+    fn test_parse_path() {
+        assert_eq!(
+            Ok(Path::ChannelsIdMessages(1)),
+            parse_path(&HyperMethod::GET, "/api/v8/channels/1/messages")
+        );
+        assert_eq!(
+            Ok(Path::ChannelsIdMessagesId(Method::Delete, 1)),
+            parse_path(&HyperMethod::DELETE, "/api/channels/1/messages/2")
+        );
+        assert_eq!(
+            Err(StatusCode::METHOD_NOT_ALLOWED),
+            parse_path(&HyperMethod::OPTIONS, "/api/v8/channels/1")
+        );
+        assert_eq!(
+            Err(StatusCode::NOT_FOUND),
+            parse_path(&HyperMethod::GET, "/channels/1")
+        );
+        assert_eq!(
+            Err(StatusCode::NOT_IMPLEMENTED),
+            parse_path(&HyperMethod::GET, "/api/v8/unknown")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_forward() -> Result<(), Box<dyn Error>> {
+        let (upstream, received) = start_upstream();
+        let proxy = start_proxy(upstream);
+
+        let (status, body) = get(proxy, "/api/v8/channels/1/messages?limit=5").await?;
+        assert_eq!(StatusCode::OK, status);
+        assert_eq!("[]", body);
+
+        let (status, _) = get(proxy, "/api/v8/unknown").await?;
+        assert_eq!(StatusCode::NOT_IMPLEMENTED, status);
+
+        assert_eq!(
+            vec![(
+                "/api/v8/channels/1/messages?limit=5".to_owned(),
+                Some("Bot token".to_owned())
+            )],
+            *received.lock().unwrap()
+        );
+
+        let (status, metrics) = get(proxy, "/metrics").await?;
+        assert_eq!(StatusCode::OK, status);
+        assert!(metrics.contains("twilight_http_proxy_requests_total 2\n"));
+        assert!(metrics.contains("twilight_http_proxy_requests_rejected_total 1\n"));
+        assert!(metrics.contains("twilight_http_proxy_responses_total{class=\"2xx\"} 1\n"));
+
+        assert_eq!(
+            (StatusCode::OK, "OK".to_owned()),
+            get(proxy, "/health").await?
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_ratelimited() -> Result<(), Box<dyn Error>> {
+        let (upstream, received) = start_upstream();
+        let proxy = start_proxy(upstream);
+
+        get(proxy, "/api/v8/channels/1/pins").await?;
+
+        // The bucket is exhausted, so the second request waits for it to
+        // reset.
+        let start = Instant::now();
+        get(proxy, "/api/v8/channels/1/pins").await?;
+
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        assert_eq!(2, received.lock().unwrap().len());
+
+        Ok(())
+    }
+}
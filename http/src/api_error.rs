@@ -2,7 +2,10 @@ use serde::{
     de::{Error as DeError, Visitor},
     Deserialize, Deserializer, Serialize, Serializer,
 };
-use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[non_exhaustive]
@@ -663,6 +666,20 @@ impl Display for ApiError {
 #[non_exhaustive]
 pub struct GeneralApiError {
     pub code: ErrorCode,
+    /// Errors of individual fields of the request body.
+    ///
+    /// The API provides these when the body is invalid, such as when an embed
+    /// field's value is too long, in which case the [`code`] is
+    /// [`ErrorCode::InvalidFormBodyOrContentType`].
+    ///
+    /// [`code`]: Self::code
+    #[serde(
+        default,
+        deserialize_with = "field_errors::deserialize",
+        serialize_with = "field_errors::serialize",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub errors: Vec<FieldError>,
     pub message: String,
 }
 
@@ -672,10 +689,149 @@ impl Display for GeneralApiError {
         Display::fmt(&self.code.num(), f)?;
         f.write_str(": ")?;
 
+        f.write_str(&self.message)?;
+
+        if let Some((first, rest)) = self.errors.split_first() {
+            f.write_str(" (")?;
+            Display::fmt(first, f)?;
+
+            for error in rest {
+                f.write_str(", ")?;
+                Display::fmt(error, f)?;
+            }
+
+            f.write_str(")")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Error of a single field of a request body.
+///
+/// The API responds to invalid request bodies with a tree of errors mirroring
+/// the structure of the body. Each field with errors is flattened into its
+/// path within the body and one error per problem with the field.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct FieldError {
+    /// Code of the error, such as `BASE_TYPE_MAX_LENGTH`.
+    pub code: String,
+    /// Human readable message provided by the API.
+    pub message: String,
+    /// Path to the field within the request body.
+    ///
+    /// For example, the value of the fourth field of an embed has the path
+    /// `["embed", "fields", "3", "value"]`. The path is empty if the error
+    /// applies to the body as a whole.
+    pub path: Vec<String>,
+}
+
+impl Display for FieldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if let Some((first, rest)) = self.path.split_first() {
+            f.write_str(first)?;
+
+            for segment in rest {
+                f.write_str(".")?;
+                f.write_str(segment)?;
+            }
+
+            f.write_str(": ")?;
+        }
+
         f.write_str(&self.message)
     }
 }
 
+/// (De)serialization of the tree of field errors into a list of
+/// [`FieldError`]s.
+mod field_errors {
+    use super::{BTreeMap, FieldError};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use serde_json::Value;
+
+    /// Key of the errors of a node, as opposed to the errors of its fields.
+    const ERRORS_KEY: &str = "_errors";
+
+    /// Node of the tree of field errors, keyed by field name or array index.
+    #[derive(Default, Serialize)]
+    struct ErrorTree {
+        #[serde(rename = "_errors", skip_serializing_if = "Vec::is_empty")]
+        errors: Vec<ErrorTreeLeaf>,
+        #[serde(flatten)]
+        fields: BTreeMap<String, ErrorTree>,
+    }
+
+    #[derive(Deserialize, Serialize)]
+    struct ErrorTreeLeaf {
+        code: String,
+        message: String,
+    }
+
+    /// Flatten a node of the tree into the list of errors.
+    ///
+    /// Anything not shaped like a tree of errors is skipped, so that an
+    /// unexpected tree doesn't prevent the rest of the API error from being
+    /// parsed.
+    fn flatten(node: Value, path: &mut Vec<String>, errors: &mut Vec<FieldError>) {
+        let mut fields = match node {
+            Value::Object(fields) => fields,
+            _ => return,
+        };
+
+        if let Some(Value::Array(leaves)) = fields.remove(ERRORS_KEY) {
+            errors.extend(
+                leaves
+                    .into_iter()
+                    .filter_map(|leaf| ErrorTreeLeaf::deserialize(leaf).ok())
+                    .map(|leaf| FieldError {
+                        code: leaf.code,
+                        message: leaf.message,
+                        path: path.clone(),
+                    }),
+            );
+        }
+
+        for (field, node) in fields {
+            path.push(field);
+            flatten(node, path, errors);
+            path.pop();
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<FieldError>, D::Error> {
+        let tree = Value::deserialize(deserializer)?;
+        let mut errors = Vec::new();
+        flatten(tree, &mut Vec::new(), &mut errors);
+
+        Ok(errors)
+    }
+
+    #[allow(clippy::ptr_arg)]
+    pub fn serialize<S: Serializer>(
+        errors: &Vec<FieldError>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let mut root = ErrorTree::default();
+
+        for error in errors {
+            let node = error.path.iter().fold(&mut root, |node, segment| {
+                node.fields.entry(segment.clone()).or_default()
+            });
+
+            node.errors.push(ErrorTreeLeaf {
+                code: error.code.clone(),
+                message: error.message.clone(),
+            });
+        }
+
+        root.serialize(serializer)
+    }
+}
+
 /// Sending a message failed because the provided fields contained invalid
 /// input.
 #[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -768,12 +924,13 @@ impl PartialEq for RatelimitedApiError {
 #[cfg(test)]
 mod tests {
     use super::{
-        ApiError, ErrorCode, GeneralApiError, MessageApiError, MessageApiErrorEmbedField,
-        RatelimitedApiError,
+        ApiError, ErrorCode, FieldError, GeneralApiError, MessageApiError,
+        MessageApiErrorEmbedField, RatelimitedApiError,
     };
+    use serde_json::Value;
     use serde_test::Token;
     use static_assertions::assert_impl_all;
-    use std::{convert::TryFrom, fmt::Debug};
+    use std::{convert::TryFrom, error::Error, fmt::Debug};
 
     assert_impl_all!(ErrorCode: Clone, Copy, Debug, Eq, PartialEq, Send, Sync);
 
@@ -798,6 +955,7 @@ mod tests {
     fn test_api_error_deser() {
         let expected = GeneralApiError {
             code: ErrorCode::UnknownAccount,
+            errors: Vec::new(),
             message: "Unknown account".to_owned(),
         };
 
@@ -817,6 +975,120 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_api_error_field_errors() -> Result<(), Box<dyn Error>> {
+        let body = r#"{
+            "code": 50035,
+            "errors": {
+                "embed": {
+                    "fields": {
+                        "3": {
+                            "value": {
+                                "_errors": [{
+                                    "code": "BASE_TYPE_MAX_LENGTH",
+                                    "message": "Must be 1024 or fewer in length."
+                                }]
+                            }
+                        }
+                    },
+                    "title": {
+                        "_errors": [{
+                            "code": "BASE_TYPE_REQUIRED",
+                            "message": "This field is required"
+                        }]
+                    }
+                }
+            },
+            "message": "Invalid Form Body"
+        }"#;
+
+        let error = serde_json::from_str::<ApiError>(body)?;
+        let general = match &error {
+            ApiError::General(general) => general,
+            other => panic!("not a general error: {:?}", other),
+        };
+
+        assert_eq!(ErrorCode::InvalidFormBodyOrContentType, general.code);
+        assert_eq!(
+            vec![
+                FieldError {
+                    code: "BASE_TYPE_MAX_LENGTH".to_owned(),
+                    message: "Must be 1024 or fewer in length.".to_owned(),
+                    path: vec![
+                        "embed".to_owned(),
+                        "fields".to_owned(),
+                        "3".to_owned(),
+                        "value".to_owned()
+                    ],
+                },
+                FieldError {
+                    code: "BASE_TYPE_REQUIRED".to_owned(),
+                    message: "This field is required".to_owned(),
+                    path: vec!["embed".to_owned(), "title".to_owned()],
+                },
+            ],
+            general.errors
+        );
+        assert_eq!(
+            "Error code 50035: Invalid Form Body (embed.fields.3.value: Must be 1024 or fewer \
+             in length., embed.title: This field is required)",
+            error.to_string()
+        );
+
+        // Serializing the errors restores the tree.
+        assert_eq!(
+            serde_json::from_str::<Value>(body)?,
+            serde_json::to_value(&error)?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_api_error_unexpected_field_errors() -> Result<(), Box<dyn Error>> {
+        let body = r#"{
+            "code": 50035,
+            "errors": {
+                "content": {
+                    "_errors": [
+                        {"code": "BASE_TYPE_REQUIRED"},
+                        {"code": "BASE_TYPE_REQUIRED", "message": "This field is required"}
+                    ]
+                },
+                "embed": {"_errors": "invalid"},
+                "nonce": 5
+            },
+            "message": "Invalid Form Body"
+        }"#;
+
+        let error = serde_json::from_str::<ApiError>(body)?;
+        let general = match &error {
+            ApiError::General(general) => general,
+            other => panic!("not a general error: {:?}", other),
+        };
+
+        assert_eq!(ErrorCode::InvalidFormBodyOrContentType, general.code);
+        assert_eq!("Invalid Form Body", general.message);
+        assert_eq!(
+            vec![FieldError {
+                code: "BASE_TYPE_REQUIRED".to_owned(),
+                message: "This field is required".to_owned(),
+                path: vec!["content".to_owned()],
+            }],
+            general.errors
+        );
+
+        let error = serde_json::from_str::<ApiError>(
+            r#"{"code": 50035, "errors": ["invalid"], "message": "Invalid Form Body"}"#,
+        )?;
+        assert!(matches!(
+            error,
+            ApiError::General(GeneralApiError { errors, .. }) if errors.is_empty()
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_api_error_message() {
         let expected = ApiError::Message(MessageApiError {
@@ -0,0 +1,789 @@
+//! Record and replay the requests made by the [`Client`].
+//!
+//! A [`Cassette`] is a [`Middleware`] with two modes:
+//!
+//! - in record mode, every request and its response are sent as normal and
+//!   kept in memory until they are written to a JSON cassette file with
+//!   [`Cassette::save`];
+//! - in replay mode, the responses are served from the cassette file and no
+//!   request is sent.
+//!
+//! This allows tests to capture real API responses once and then run
+//! deterministically without network access.
+//!
+//! Cassettes are meant to be committed, so secrets are redacted before
+//! they're recorded:
+//!
+//! - the `Authorization` header of requests;
+//! - the token in the path of webhook and interaction requests;
+//! - the body of requests that aren't [durable], such as `OAuth2` requests
+//!   carrying a client secret;
+//! - the values of `access_token`, `refresh_token` and `token` fields in
+//!   JSON response bodies.
+//!
+//! Requests are matched to recorded interactions by their method and path,
+//! including the query. Each interaction is played once, in the order in
+//! which it was recorded, so repeated requests to the same route receive
+//! their responses in order. Requests without an unplayed interaction fail
+//! with [`ErrorType::UnrecordedRequest`].
+//!
+//! Requests in replay mode skip the ratelimiter, so the cassette is best
+//! added as the last middleware. Cassettes are cheap to clone, so a clone can
+//! be kept to save the cassette once the client has been built.
+//!
+//! # Examples
+//!
+//! Record a cassette if the `RECORD` environment variable is set, and replay
+//! it otherwise:
+//!
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use std::env;
+//! use twilight_http::{cassette::Cassette, Client};
+//! use twilight_model::id::ChannelId;
+//!
+//! let path = "tests/cassettes/pins.json";
+//!
+//! let cassette = if env::var_os("RECORD").is_some() {
+//!     Cassette::record(path)
+//! } else {
+//!     Cassette::replay(path)?
+//! };
+//!
+//! let client = Client::builder()
+//!     .token(env::var("DISCORD_TOKEN").unwrap_or_default())
+//!     .middleware(Box::new(cassette.clone()))
+//!     .build();
+//!
+//! let pins = client.pins(ChannelId(1)).await?;
+//!
+//! cassette.save().await?;
+//! # Ok(()) }
+//! ```
+//!
+//! [`Client`]: crate::Client
+//! [`ErrorType::UnrecordedRequest`]: crate::error::ErrorType::UnrecordedRequest
+//! [durable]: crate::middleware::MiddlewareRequest::durable
+
+use crate::{
+    error::{Error, ErrorType},
+    middleware::{Middleware, MiddlewareFuture, MiddlewareRequest, Next},
+    routing,
+};
+use hyper::{
+    body,
+    header::{HeaderMap, HeaderName, HeaderValue, AUTHORIZATION},
+    Body, Method, Response, StatusCode, Uri,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    convert::TryFrom,
+    error::Error as StdError,
+    fmt::{Display, Formatter, Result as FmtResult},
+    fs, mem,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex},
+};
+
+/// Value secrets in recorded interactions are replaced with.
+const REDACTED: &str = "[redacted]";
+
+/// Fields of JSON response bodies whose values are redacted.
+const SECRET_FIELDS: &[&str] = &["access_token", "refresh_token", "token"];
+
+/// Loading a [`Cassette`] in replay mode or saving it in record mode failed.
+#[derive(Debug)]
+pub struct CassetteError {
+    kind: CassetteErrorType,
+    source: Option<Box<dyn StdError + Send + Sync>>,
+}
+
+impl CassetteError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &CassetteErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn StdError + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (CassetteErrorType, Option<Box<dyn StdError + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for CassetteError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            CassetteErrorType::Deserializing { path } => {
+                f.write_str("cassette file ")?;
+                Display::fmt(&path.display(), f)?;
+
+                f.write_str(" is not a valid cassette")
+            }
+            CassetteErrorType::Reading { path } => {
+                f.write_str("failed to read cassette file ")?;
+
+                Display::fmt(&path.display(), f)
+            }
+            CassetteErrorType::Writing { path } => {
+                f.write_str("failed to write cassette file ")?;
+
+                Display::fmt(&path.display(), f)
+            }
+        }
+    }
+}
+
+impl StdError for CassetteError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn StdError + 'static))
+    }
+}
+
+/// Type of [`CassetteError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum CassetteErrorType {
+    /// Contents of the cassette file are not a valid cassette.
+    Deserializing {
+        /// Path of the cassette file.
+        path: PathBuf,
+    },
+    /// Cassette file could not be read.
+    Reading {
+        /// Path of the cassette file.
+        path: PathBuf,
+    },
+    /// Cassette file could not be written.
+    Writing {
+        /// Path of the cassette file.
+        path: PathBuf,
+    },
+}
+
+/// Body of a recorded request or response.
+///
+/// Bodies are stored as text if they are valid UTF-8, such as JSON bodies,
+/// and as an array of bytes otherwise.
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(untagged)]
+pub(crate) enum RecordedBody {
+    Text(String),
+    Bytes(Vec<u8>),
+}
+
+impl RecordedBody {
+    pub(crate) fn new(bytes: Vec<u8>) -> Self {
+        String::from_utf8(bytes).map_or_else(|err| Self::Bytes(err.into_bytes()), Self::Text)
+    }
+
+    pub(crate) fn into_bytes(self) -> Vec<u8> {
+        match self {
+            Self::Text(text) => text.into_bytes(),
+            Self::Bytes(bytes) => bytes,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+struct RecordedRequest {
+    body: RecordedBody,
+    headers: Vec<(String, String)>,
+    method: String,
+    path: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+struct RecordedResponse {
+    body: RecordedBody,
+    headers: Vec<(String, String)>,
+    status: u16,
+}
+
+impl RecordedResponse {
+    fn to_hyper(&self) -> Result<Response<Body>, Error> {
+        let mut response = Response::new(Body::from(self.body.clone().into_bytes()));
+
+        *response.status_mut() = StatusCode::from_u16(self.status).map_err(|source| Error {
+            kind: ErrorType::BuildingRequest,
+            source: Some(Box::new(source)),
+        })?;
+
+        for (name, value) in &self.headers {
+            let header = HeaderName::try_from(name.as_str())
+                .map_err(|source| Box::new(source) as Box<dyn StdError + Send + Sync>)
+                .and_then(|name| {
+                    HeaderValue::try_from(value.as_str())
+                        .map(|value| (name, value))
+                        .map_err(|source| Box::new(source) as Box<dyn StdError + Send + Sync>)
+                });
+
+            let (name, value) = header.map_err(|source| Error {
+                kind: ErrorType::CreatingHeader { name: name.clone() },
+                source: Some(source),
+            })?;
+
+            response.headers_mut().append(name, value);
+        }
+
+        Ok(response)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+struct Interaction {
+    request: RecordedRequest,
+    response: RecordedResponse,
+}
+
+#[derive(Debug, Default, Deserialize, Serialize)]
+struct CassetteFile {
+    interactions: Vec<Interaction>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Mode {
+    Record,
+    Replay,
+}
+
+#[derive(Debug)]
+struct State {
+    file: CassetteFile,
+    /// Whether each interaction of the file has been played, in replay mode.
+    played: Vec<bool>,
+}
+
+/// Middleware recording requests to or replaying responses from a cassette
+/// file.
+///
+/// Refer to the [module-level documentation] for more information.
+///
+/// [module-level documentation]: self
+#[derive(Clone, Debug)]
+pub struct Cassette {
+    mode: Mode,
+    path: PathBuf,
+    state: Arc<Mutex<State>>,
+}
+
+impl Cassette {
+    /// Create a cassette in record mode, recording interactions to be written
+    /// to a file.
+    ///
+    /// Nothing is written until [`save`] is called.
+    ///
+    /// [`save`]: Self::save
+    pub fn record(path: impl Into<PathBuf>) -> Self {
+        Self {
+            mode: Mode::Record,
+            path: path.into(),
+            state: Arc::new(Mutex::new(State {
+                file: CassetteFile::default(),
+                played: Vec::new(),
+            })),
+        }
+    }
+
+    /// Create a cassette in replay mode, serving responses from a file.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CassetteErrorType::Reading`] error type if the file could
+    /// not be read.
+    ///
+    /// Returns a [`CassetteErrorType::Deserializing`] error type if the file
+    /// is not a valid cassette.
+    pub fn replay(path: impl Into<PathBuf>) -> Result<Self, CassetteError> {
+        let path = path.into();
+
+        let contents = fs::read(&path).map_err(|source| CassetteError {
+            kind: CassetteErrorType::Reading { path: path.clone() },
+            source: Some(Box::new(source)),
+        })?;
+
+        let file =
+            serde_json::from_slice::<CassetteFile>(&contents).map_err(|source| CassetteError {
+                kind: CassetteErrorType::Deserializing { path: path.clone() },
+                source: Some(Box::new(source)),
+            })?;
+
+        let played = vec![false; file.interactions.len()];
+
+        Ok(Self {
+            mode: Mode::Replay,
+            path,
+            state: Arc::new(Mutex::new(State { file, played })),
+        })
+    }
+
+    /// Path of the cassette file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Number of interactions recorded, or remaining to be played in replay
+    /// mode.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the state of the cassette was poisoned by a panic while it
+    /// was locked.
+    pub fn remaining(&self) -> usize {
+        let state = self.state.lock().expect("cassette state poisoned");
+
+        match self.mode {
+            Mode::Record => state.file.interactions.len(),
+            Mode::Replay => state.played.iter().filter(|played| !**played).count(),
+        }
+    }
+
+    /// Write the recorded interactions to the cassette file, overwriting it.
+    ///
+    /// Call this once all requests to record have completed. Cassettes in
+    /// replay mode are left untouched.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CassetteErrorType::Writing`] error type if the file could
+    /// not be written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the state of the cassette was poisoned by a panic while it
+    /// was locked.
+    pub async fn save(&self) -> Result<(), CassetteError> {
+        if self.mode == Mode::Replay {
+            return Ok(());
+        }
+
+        let contents = {
+            let state = self.state.lock().expect("cassette state poisoned");
+
+            serde_json::to_vec_pretty(&state.file)
+        };
+
+        let result = match contents {
+            Ok(contents) => tokio::fs::write(&self.path, contents)
+                .await
+                .map_err(|source| Box::new(source) as Box<dyn StdError + Send + Sync>),
+            Err(source) => Err(Box::new(source) as Box<dyn StdError + Send + Sync>),
+        };
+
+        result.map_err(|source| CassetteError {
+            kind: CassetteErrorType::Writing {
+                path: self.path.clone(),
+            },
+            source: Some(source),
+        })
+    }
+
+    /// Play the first unplayed interaction matching a request.
+    fn play(&self, method: &Method, path: &str) -> Result<Response<Body>, Error> {
+        let mut state = self.state.lock().expect("cassette state poisoned");
+        let State { file, played } = &mut *state;
+
+        let position =
+            file.interactions
+                .iter()
+                .zip(played.iter())
+                .position(|(interaction, played)| {
+                    !played
+                        && interaction.request.method == method.as_str()
+                        && interaction.request.path == path
+                });
+
+        let index = position.ok_or_else(|| Error {
+            kind: ErrorType::UnrecordedRequest {
+                method: method.clone(),
+                path: path.to_owned(),
+            },
+            source: None,
+        })?;
+
+        played[index] = true;
+
+        file.interactions[index].response.to_hyper()
+    }
+
+    /// Send a request and record it and its response.
+    async fn record_interaction(
+        &self,
+        mut request: MiddlewareRequest,
+        next: Next<'_>,
+    ) -> Result<Response<Body>, Error> {
+        let request_body = body::to_bytes(mem::take(request.body_mut()))
+            .await
+            .map_err(|source| Error {
+                kind: ErrorType::BuildingRequest,
+                source: Some(Box::new(source)),
+            })?;
+        *request.body_mut() = Body::from(request_body.clone());
+
+        let recorded_body = if request.durable() || request_body.is_empty() {
+            RecordedBody::new(request_body.to_vec())
+        } else {
+            RecordedBody::Text(REDACTED.to_owned())
+        };
+
+        let recorded_request = RecordedRequest {
+            body: recorded_body,
+            headers: record_headers(request.headers(), true),
+            method: request.method().as_str().to_owned(),
+            path: api_path(request.uri()),
+        };
+
+        let (parts, response_body) = next.run(request).await?.into_parts();
+
+        let response_body = body::to_bytes(response_body)
+            .await
+            .map_err(|source| Error {
+                kind: ErrorType::ChunkingResponse,
+                source: Some(Box::new(source)),
+            })?;
+
+        let recorded_response = RecordedResponse {
+            body: RecordedBody::new(redact_body(&response_body)),
+            headers: record_headers(&parts.headers, false),
+            status: parts.status.as_u16(),
+        };
+
+        self.state
+            .lock()
+            .expect("cassette state poisoned")
+            .file
+            .interactions
+            .push(Interaction {
+                request: recorded_request,
+                response: recorded_response,
+            });
+
+        Ok(Response::from_parts(parts, Body::from(response_body)))
+    }
+}
+
+impl Middleware for Cassette {
+    fn handle<'a>(&'a self, request: MiddlewareRequest, next: Next<'a>) -> MiddlewareFuture<'a> {
+        match self.mode {
+            Mode::Record => Box::pin(self.record_interaction(request, next)),
+            Mode::Replay => {
+                let result = self.play(request.method(), &api_path(request.uri()));
+
+                Box::pin(async move { result })
+            }
+        }
+    }
+}
+
+/// Path and query of a URI relative to the API version, such as
+/// `channels/1/messages?limit=5`, with any webhook or interaction token
+/// redacted.
+fn api_path(uri: &Uri) -> String {
+    let path = uri
+        .path_and_query()
+        .map_or_else(|| uri.path(), |path| path.as_str());
+
+    let path = routing::api_relative_path(path).unwrap_or_else(|| path.trim_start_matches('/'));
+
+    routing::redact_token(path).into_owned()
+}
+
+/// Redact the values of secret fields in a JSON body.
+///
+/// Bodies that aren't JSON or don't contain any secrets are returned
+/// unchanged.
+fn redact_body(body: &[u8]) -> Vec<u8> {
+    let mut value = match serde_json::from_slice::<Value>(body) {
+        Ok(value) => value,
+        Err(_) => return body.to_vec(),
+    };
+
+    if !redact_value(&mut value) {
+        return body.to_vec();
+    }
+
+    serde_json::to_vec(&value).unwrap_or_else(|_| body.to_vec())
+}
+
+/// Redact the values of secret fields in a JSON value and the values nested
+/// in it, returning whether any were redacted.
+fn redact_value(value: &mut Value) -> bool {
+    let mut redacted = false;
+
+    match value {
+        Value::Array(values) => {
+            for value in values {
+                redacted |= redact_value(value);
+            }
+        }
+        Value::Object(fields) => {
+            for (name, value) in fields {
+                if value.is_string() && SECRET_FIELDS.contains(&name.as_str()) {
+                    *value = Value::String(REDACTED.to_owned());
+                    redacted = true;
+                } else {
+                    redacted |= redact_value(value);
+                }
+            }
+        }
+        _ => {}
+    }
+
+    redacted
+}
+
+/// Convert headers into name and value pairs, optionally redacting the
+/// `Authorization` header.
+fn record_headers(headers: &HeaderMap, redact: bool) -> Vec<(String, String)> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let value = if redact && name == AUTHORIZATION {
+                REDACTED.to_owned()
+            } else {
+                String::from_utf8_lossy(value.as_bytes()).into_owned()
+            };
+
+            (name.as_str().to_owned(), value)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{api_path, redact_body, Cassette, CassetteError, CassetteFile, RecordedBody};
+    use crate::{error::ErrorType, test_server, Client};
+    use hyper::{Body, Request, Response, StatusCode};
+    use static_assertions::assert_impl_all;
+    use std::{
+        env,
+        error::Error,
+        fmt::Debug,
+        fs,
+        net::SocketAddr,
+        path::PathBuf,
+        sync::atomic::{AtomicU64, Ordering},
+    };
+    use twilight_model::id::{ApplicationId, ChannelId, MessageId, WebhookId};
+
+    assert_impl_all!(Cassette: Clone, Debug, Send, Sync);
+    assert_impl_all!(CassetteError: Debug, Send, Sync);
+
+    /// Start a server responding with the number of requests it has received.
+    fn start_server() -> SocketAddr {
+        static COUNT: AtomicU64 = AtomicU64::new(0);
+
+        test_server::start(|_| async {
+            let count = COUNT.fetch_add(1, Ordering::Relaxed);
+
+            Response::builder()
+                .header("content-type", "application/json")
+                .body(Body::from(format!(r#"{{"count":{}}}"#, count)))
+        })
+    }
+
+    fn cassette_path(name: &str) -> PathBuf {
+        env::temp_dir().join(format!(
+            "twilight-http-cassette-{}-{}.json",
+            std::process::id(),
+            name
+        ))
+    }
+
+    #[test]
+    fn test_api_path() {
+        assert_eq!(
+            "channels/1/messages?limit=5",
+            api_path(
+                &"http://localhost/api/v8/channels/1/messages?limit=5"
+                    .parse()
+                    .unwrap()
+            )
+        );
+        assert_eq!(
+            "channels/1",
+            api_path(&"https://discord.com/api/channels/1".parse().unwrap())
+        );
+        assert_eq!(
+            "webhooks/1/[redacted]?wait=true",
+            api_path(
+                &"https://discord.com/api/v9/webhooks/1/token?wait=true"
+                    .parse()
+                    .unwrap()
+            )
+        );
+    }
+
+    #[test]
+    fn test_redact_body() {
+        assert_eq!(
+            br#"[{"id":"1","token":"[redacted]","user":{"access_token":"[redacted]"}}]"#.to_vec(),
+            redact_body(br#"[{"id":"1","token":"secret","user":{"access_token":"secret"}}]"#)
+        );
+
+        let unchanged: &[&[u8]] = &[b"", br#"{"id": "1", "token": null}"#, b"\xff"];
+
+        for body in unchanged {
+            assert_eq!(body.to_vec(), redact_body(body));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_record_replay() -> Result<(), Box<dyn Error>> {
+        let addr = start_server();
+        let path = cassette_path("record-replay");
+        let cassette = Cassette::record(&path);
+
+        let client = Client::builder()
+            .proxy(addr.to_string(), true)
+            .token("secret token")
+            .middleware(Box::new(cassette.clone()))
+            .build();
+
+        let first = client.pins(ChannelId(1)).response().await?.text().await?;
+        let second = client.pins(ChannelId(1)).response().await?.text().await?;
+        client
+            .delete_message(ChannelId(1), MessageId(2))
+            .response()
+            .await?;
+
+        // Interactions are only written when saved.
+        assert_eq!(3, cassette.remaining());
+        assert!(!path.exists());
+        cassette.save().await?;
+
+        let contents = fs::read_to_string(&path)?;
+        assert!(!contents.contains("secret token"));
+        assert!(contents.contains("[redacted]"));
+
+        let file = serde_json::from_str::<CassetteFile>(&contents)?;
+        assert_eq!(3, file.interactions.len());
+        assert_eq!("channels/1/pins", file.interactions[0].request.path);
+        assert_eq!("DELETE", file.interactions[2].request.method);
+
+        // Nothing is listening on this address, so the request fails if it is
+        // sent.
+        let client = Client::builder()
+            .proxy("127.0.0.1:1", true)
+            .middleware(Box::new(Cassette::replay(&path)?))
+            .build();
+
+        let replayed = client.pins(ChannelId(1)).response().await?;
+        assert_eq!("application/json", replayed.headers()["content-type"]);
+        assert_eq!(first, replayed.text().await?);
+
+        let replayed = client.pins(ChannelId(1)).response().await?;
+        assert_eq!(second, replayed.text().await?);
+
+        // Every recorded request to the route has been played.
+        let err = client.pins(ChannelId(1)).response().await.unwrap_err();
+        assert!(matches!(
+            err.kind(),
+            ErrorType::UnrecordedRequest { path, .. } if path == "channels/1/pins"
+        ));
+
+        let err = client.pins(ChannelId(2)).response().await.unwrap_err();
+        assert_eq!(
+            "no unplayed interaction in the cassette matches the request GET channels/2/pins",
+            err.to_string()
+        );
+
+        fs::remove_file(&path)?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_record_secrets() -> Result<(), Box<dyn Error>> {
+        let addr = test_server::start(|request: Request<Body>| async move {
+            if request.uri().path().ends_with("oauth2/token") {
+                Response::builder().body(Body::from(
+                    r#"{
+                        "access_token": "access secret",
+                        "expires_in": 604800,
+                        "refresh_token": "refresh secret",
+                        "scope": "identify",
+                        "token_type": "Bearer"
+                    }"#,
+                ))
+            } else {
+                Response::builder()
+                    .status(StatusCode::NO_CONTENT)
+                    .body(Body::empty())
+            }
+        });
+        let path = cassette_path("secrets");
+        let cassette = Cassette::record(&path);
+
+        let client = Client::builder()
+            .proxy(addr.to_string(), true)
+            .middleware(Box::new(cassette.clone()))
+            .build();
+
+        client
+            .exchange_code(
+                ApplicationId(1),
+                "client secret",
+                "code secret",
+                "https://a.b/c",
+            )
+            .await?;
+        client
+            .execute_webhook(WebhookId(1), "webhook-secret")
+            .content("hello")
+            .await?;
+        cassette.save().await?;
+
+        let contents = fs::read_to_string(&path)?;
+        assert!(!contents.contains("secret"));
+
+        let file = serde_json::from_str::<CassetteFile>(&contents)?;
+        assert_eq!(
+            RecordedBody::Text("[redacted]".to_owned()),
+            file.interactions[0].request.body
+        );
+        assert_eq!("webhooks/1/[redacted]", file.interactions[1].request.path);
+
+        // Redacted interactions are still replayed.
+        let client = Client::builder()
+            .proxy("127.0.0.1:1", true)
+            .middleware(Box::new(Cassette::replay(&path)?))
+            .build();
+
+        let token = client
+            .exchange_code(
+                ApplicationId(1),
+                "client secret",
+                "code secret",
+                "https://a.b/c",
+            )
+            .await?;
+        assert_eq!("Bearer [redacted]", token.authorization());
+        client
+            .execute_webhook(WebhookId(1), "other-token")
+            .content("hello")
+            .await?;
+
+        fs::remove_file(&path)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_replay_missing_file() {
+        let path = cassette_path("missing");
+
+        assert!(Cassette::replay(&path).is_err());
+    }
+}
@@ -1,7 +1,23 @@
-use super::{Client, State};
-use crate::ratelimiting::Ratelimiter;
-use hyper::header::HeaderMap;
+use super::{
+    coalesce::RequestCoalescer,
+    invalid_request::InvalidRequestCounter,
+    transport::{BuildHttpClient, CustomConnector, Preconfigured, Resolve, TransportOptions},
+    Client, DryRun, InvalidRequestLimit, RetryPolicy, State,
+};
+use crate::{
+    middleware::Middleware,
+    outbox::{InFlight, OutboxStore},
+    ratelimiting::{GlobalLimit, InMemoryRatelimiter, Ratelimiter},
+};
+use hyper::{
+    client::{connect::Connect, Builder as HyperBuilder, Client as HyperClient},
+    header::HeaderMap,
+    Body,
+};
 use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+    net::IpAddr,
     sync::{
         atomic::{AtomicBool, AtomicU64, Ordering},
         Arc,
@@ -10,16 +26,88 @@ use std::{
 };
 use twilight_model::{channel::message::allowed_mentions::AllowedMentions, id::ApplicationId};
 
+/// Building a [`Client`] failed.
+#[derive(Debug)]
+pub struct ClientBuildError {
+    pub(super) kind: ClientBuildErrorType,
+    pub(super) source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl ClientBuildError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ClientBuildErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (ClientBuildErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for ClientBuildError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            ClientBuildErrorType::TlsRootCertificates => {
+                f.write_str("no root certificates could be loaded for TLS connections")
+            }
+        }
+    }
+}
+
+impl Error for ClientBuildError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`ClientBuildError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ClientBuildErrorType {
+    /// No root certificates could be loaded for the default TLS connector,
+    /// such as if the platform's certificate store couldn't be read.
+    TlsRootCertificates,
+}
+
 #[derive(Debug)]
 /// A builder for [`Client`].
 pub struct ClientBuilder {
     pub(crate) application_id: AtomicU64,
+    pub(crate) coalesce_requests: bool,
+    /// Custom connector or preconfigured HTTP client, or `None` if the
+    /// default connector should be created.
+    pub(crate) connector: Option<Box<dyn BuildHttpClient>>,
     pub(crate) default_allowed_mentions: Option<AllowedMentions>,
+    pub(crate) dry_run: Option<DryRun>,
+    pub(crate) global_limit: Option<GlobalLimit>,
+    pub(crate) http2_only: bool,
+    pub(crate) invalid_request_limit: Option<InvalidRequestLimit>,
+    pub(crate) max_background_queue_wait: Option<Duration>,
+    pub(crate) middleware: Vec<Box<dyn Middleware>>,
+    pub(crate) outbox: Option<Box<dyn OutboxStore>>,
+    pub(crate) pool_idle_timeout: Option<Duration>,
+    pub(crate) pool_max_idle_per_host: usize,
     pub(crate) proxy: Option<Box<str>>,
-    pub(crate) ratelimiter: Option<Ratelimiter>,
+    /// Configured ratelimiter, or `None` if an [`InMemoryRatelimiter`]
+    /// should be created.
+    #[allow(clippy::option_option)]
+    pub(crate) ratelimiter: Option<Option<Box<dyn Ratelimiter>>>,
+    pub(crate) retry_policy: Option<RetryPolicy>,
     pub(crate) default_headers: Option<HeaderMap>,
     pub(crate) timeout: Duration,
     pub(crate) token: Option<Box<str>>,
+    pub(crate) transport: TransportOptions,
     pub(crate) use_http: bool,
 }
 
@@ -30,26 +118,68 @@ impl ClientBuilder {
     }
 
     /// Build the [`Client`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the default TLS connector is used and no root certificates
+    /// could be loaded for it. Use [`try_build`] to handle this error.
+    ///
+    /// [`try_build`]: Self::try_build
     pub fn build(self) -> Client {
-        #[cfg(feature = "rustls-native-roots")]
-        let connector = hyper_rustls::HttpsConnector::with_native_roots();
-        #[cfg(all(feature = "rustls-webpki-roots", not(feature = "rustls-native-roots")))]
-        let connector = hyper_rustls::HttpsConnector::with_webpki_roots();
-        #[cfg(all(
-            feature = "hyper-tls",
-            not(feature = "rustls-native-roots"),
-            not(feature = "rustls-webpki-roots")
-        ))]
-        let connector = hyper_tls::HttpsConnector::new();
-
-        let http = hyper::client::Builder::default().build(connector);
-
-        Client {
+        match self.try_build() {
+            Ok(client) => client,
+            Err(source) => panic!("failed to build client: {}", source),
+        }
+    }
+
+    /// Build the [`Client`], returning an error if it can't be built.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ClientBuildErrorType::TlsRootCertificates`] error type if
+    /// the default TLS connector is used and no root certificates could be
+    /// loaded for it. The certificates are loaded by the first client built
+    /// successfully and shared by later clients.
+    pub fn try_build(self) -> Result<Client, ClientBuildError> {
+        let mut builder = HyperBuilder::default();
+        builder
+            .http2_only(self.http2_only)
+            .pool_idle_timeout(self.pool_idle_timeout)
+            .pool_max_idle_per_host(self.pool_max_idle_per_host);
+
+        let http = match self.connector {
+            Some(connector) => connector.build(&builder),
+            None => self.transport.build(&builder)?,
+        };
+
+        let global_limit = self.global_limit;
+        let ratelimiter = self.ratelimiter.unwrap_or_else(|| {
+            Some(Box::new(InMemoryRatelimiter::with_global_limit(
+                global_limit,
+            )))
+        });
+
+        Ok(Client {
+            priority: None,
+            ratelimiter: None,
             state: Arc::new(State {
                 http,
+                coalescer: if self.coalesce_requests {
+                    Some(RequestCoalescer::new())
+                } else {
+                    None
+                },
                 default_headers: self.default_headers,
+                dry_run: self.dry_run,
+                invalid_requests: InvalidRequestCounter::new(self.invalid_request_limit.as_ref()),
+                invalid_request_limit: self.invalid_request_limit,
+                max_background_queue_wait: self.max_background_queue_wait,
+                middleware: self.middleware,
+                outbox: self.outbox,
+                outbox_in_flight: InFlight::default(),
                 proxy: self.proxy,
-                ratelimiter: self.ratelimiter,
+                ratelimiter,
+                retry_policy: self.retry_policy,
                 timeout: self.timeout,
                 token_invalid: AtomicBool::new(false),
                 token: self.token,
@@ -57,7 +187,113 @@ impl ClientBuilder {
                 default_allowed_mentions: self.default_allowed_mentions,
                 use_http: self.use_http,
             }),
-        }
+        })
+    }
+
+    /// Set whether connections only use HTTP/2.
+    ///
+    /// The default is false, in which case HTTP/2 is used only if it is
+    /// negotiated when connecting via TLS.
+    pub const fn http2_only(mut self, http2_only: bool) -> Self {
+        self.http2_only = http2_only;
+
+        self
+    }
+
+    /// Set the connector used to make connections.
+    ///
+    /// The connector is responsible for TLS, so it must support HTTPS
+    /// unless [`proxy`] is configured to use HTTP. Connection pool options,
+    /// such as [`pool_idle_timeout`], are still applied, but options of the
+    /// default connector such as [`resolver`] aren't.
+    ///
+    /// [`pool_idle_timeout`]: Self::pool_idle_timeout
+    /// [`proxy`]: Self::proxy
+    /// [`resolver`]: Self::resolver
+    pub fn connector<C: Connect + Clone + Send + Sync + 'static>(mut self, connector: C) -> Self {
+        self.connector = Some(Box::new(CustomConnector(connector)));
+
+        self
+    }
+
+    /// Set a preconfigured hyper client to send requests with, such as one
+    /// with a custom TLS configuration.
+    ///
+    /// The client is used as is, so connection pool and connector options,
+    /// such as [`pool_idle_timeout`] and [`resolver`], aren't applied.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use twilight_http::Client;
+    ///
+    /// let hyper = hyper::Client::builder().build_http();
+    ///
+    /// let client = Client::builder()
+    ///     .hyper_client(hyper)
+    ///     .proxy("localhost:3000", true)
+    ///     .build();
+    /// ```
+    ///
+    /// [`pool_idle_timeout`]: Self::pool_idle_timeout
+    /// [`resolver`]: Self::resolver
+    pub fn hyper_client<C: Connect + Clone + Send + Sync + 'static>(
+        mut self,
+        client: HyperClient<C, Body>,
+    ) -> Self {
+        self.connector = Some(Box::new(Preconfigured(client)));
+
+        self
+    }
+
+    /// Set the local address connections are bound to.
+    ///
+    /// If the argument is `None` then the operating system chooses the
+    /// address, which is the default.
+    pub fn local_address(mut self, local_address: impl Into<Option<IpAddr>>) -> Self {
+        self.transport.local_address = local_address.into();
+
+        self
+    }
+
+    /// Set the duration idle connections are kept in the connection pool.
+    ///
+    /// If the argument is `None` then idle connections are kept until they
+    /// are closed by the server. The default is 90 seconds.
+    pub fn pool_idle_timeout(mut self, pool_idle_timeout: impl Into<Option<Duration>>) -> Self {
+        self.pool_idle_timeout = pool_idle_timeout.into();
+
+        self
+    }
+
+    /// Set the maximum number of idle connections kept in the connection pool
+    /// per host.
+    ///
+    /// The default is no limit.
+    pub const fn pool_max_idle_per_host(mut self, max_idle: usize) -> Self {
+        self.pool_max_idle_per_host = max_idle;
+
+        self
+    }
+
+    /// Set the DNS resolver used to resolve the addresses of hosts.
+    ///
+    /// By default addresses are resolved via `getaddrinfo` on a blocking
+    /// thread.
+    pub fn resolver(mut self, resolver: Box<dyn Resolve>) -> Self {
+        self.transport.resolver = Some(resolver.into());
+
+        self
+    }
+
+    /// Set the interval of TCP keepalive probes sent on connections.
+    ///
+    /// If the argument is `None` then keepalive probes aren't sent, which is
+    /// the default.
+    pub fn tcp_keepalive(mut self, interval: impl Into<Option<Duration>>) -> Self {
+        self.transport.tcp_keepalive = interval.into();
+
+        self
     }
 
     /// Set the [`ApplicationId`] used by interaction methods.
@@ -68,6 +304,27 @@ impl ClientBuilder {
         self
     }
 
+    /// Set whether identical `GET` requests made concurrently share a single
+    /// request.
+    ///
+    /// When enabled, a `GET` request to the same path and query as a request
+    /// that is already in flight isn't sent. Instead, it waits for the
+    /// in-flight request and receives a copy of its response body, saving a
+    /// request against the ratelimit. If the in-flight request fails then the
+    /// waiting requests fail with the same type of error, without its source
+    /// error. If the in-flight request is cancelled then the waiting requests
+    /// are sent themselves.
+    ///
+    /// The number of requests saved is available via
+    /// [`Client::coalesced_request_count`].
+    ///
+    /// The default is false.
+    pub const fn coalesce_requests(mut self, coalesce_requests: bool) -> Self {
+        self.coalesce_requests = coalesce_requests;
+
+        self
+    }
+
     /// Set the default allowed mentions setting to use on all messages sent through the HTTP
     /// client.
     pub fn default_allowed_mentions(mut self, allowed_mentions: AllowedMentions) -> Self {
@@ -76,6 +333,90 @@ impl ClientBuilder {
         self
     }
 
+    /// Set a dry run, in which requests other than `GET` requests aren't
+    /// sent.
+    ///
+    /// Refer to [`DryRun`] for more information.
+    pub fn dry_run(mut self, dry_run: DryRun) -> Self {
+        self.dry_run = Some(dry_run);
+
+        self
+    }
+
+    /// Set the limit to proactively limit requests across all routes by.
+    ///
+    /// If the argument is `None` then requests will only be globally limited
+    /// after the API responds with a global ratelimit.
+    ///
+    /// This only applies to the [`InMemoryRatelimiter`] created by
+    /// [`ClientBuilder::build`] and has no effect if a ratelimiter has been
+    /// set via [`ClientBuilder::ratelimiter`].
+    ///
+    /// The default is [`GlobalLimit::default`].
+    pub fn global_limit(mut self, global_limit: impl Into<Option<GlobalLimit>>) -> Self {
+        self.global_limit = global_limit.into();
+
+        self
+    }
+
+    /// Set the limit on the number of invalid requests made by the client.
+    ///
+    /// If the argument is `None` then invalid requests will still be counted
+    /// but requests will never be slowed down or refused.
+    ///
+    /// The default is [`InvalidRequestLimit::default`].
+    pub fn invalid_request_limit(
+        mut self,
+        invalid_request_limit: impl Into<Option<InvalidRequestLimit>>,
+    ) -> Self {
+        self.invalid_request_limit = invalid_request_limit.into();
+
+        self
+    }
+
+    /// Set the maximum duration [`Priority::Background`] requests wait in the
+    /// ratelimiter's queue.
+    ///
+    /// Background requests waiting for longer fail with an error of type
+    /// [`ErrorType::QueueWaitExceeded`] instead of being sent.
+    ///
+    /// If the argument is `None` then background requests wait for as long as
+    /// necessary, which is the default.
+    ///
+    /// [`ErrorType::QueueWaitExceeded`]: crate::error::ErrorType::QueueWaitExceeded
+    /// [`Priority::Background`]: crate::ratelimiting::Priority::Background
+    pub fn max_background_queue_wait(mut self, max_wait: impl Into<Option<Duration>>) -> Self {
+        self.max_background_queue_wait = max_wait.into();
+
+        self
+    }
+
+    /// Add a middleware to the end of the middleware chain.
+    ///
+    /// Middleware is called for every attempt of a request in the order it
+    /// was added, before the request is ratelimited and sent.
+    ///
+    /// Refer to the [`middleware`] module for more information.
+    ///
+    /// [`middleware`]: crate::middleware
+    pub fn middleware(mut self, middleware: Box<dyn Middleware>) -> Self {
+        self.middleware.push(middleware);
+
+        self
+    }
+
+    /// Set the store of the outbox persisting requests until they have been
+    /// delivered.
+    ///
+    /// Refer to the [`outbox`] module for more information.
+    ///
+    /// [`outbox`]: crate::outbox
+    pub fn outbox(mut self, store: Box<dyn OutboxStore>) -> Self {
+        self.outbox = Some(store);
+
+        self
+    }
+
     /// Set the proxy to use for all HTTP(S) requests.
     ///
     /// **Note** that this isn't currently a traditional proxy, but is for
@@ -108,10 +449,22 @@ impl ClientBuilder {
     /// If the argument is `None` then the client's ratelimiter will be skipped
     /// before making a request.
     ///
-    /// If this method is not called at all then a default ratelimiter will be
-    /// created by [`ClientBuilder::build`].
-    pub fn ratelimiter(mut self, ratelimiter: impl Into<Option<Ratelimiter>>) -> Self {
-        self.ratelimiter = ratelimiter.into();
+    /// If this method is not called at all then an [`InMemoryRatelimiter`]
+    /// will be created by [`ClientBuilder::build`].
+    pub fn ratelimiter(mut self, ratelimiter: Option<Box<dyn Ratelimiter>>) -> Self {
+        self.ratelimiter = Some(ratelimiter);
+
+        self
+    }
+
+    /// Set a policy for automatically retrying failed requests.
+    ///
+    /// If the argument is `None` then requests will not be retried, which is
+    /// the default.
+    ///
+    /// Refer to [`RetryPolicy`] for more information.
+    pub fn retry_policy(mut self, retry_policy: impl Into<Option<RetryPolicy>>) -> Self {
+        self.retry_policy = retry_policy.into();
 
         self
     }
@@ -155,12 +508,25 @@ impl Default for ClientBuilder {
     fn default() -> Self {
         Self {
             application_id: AtomicU64::default(),
+            coalesce_requests: false,
+            connector: None,
             default_allowed_mentions: None,
+            dry_run: None,
             default_headers: None,
+            global_limit: Some(GlobalLimit::default()),
+            http2_only: false,
+            invalid_request_limit: Some(InvalidRequestLimit::default()),
+            max_background_queue_wait: None,
+            middleware: Vec::new(),
+            outbox: None,
+            pool_idle_timeout: Some(Duration::from_secs(90)),
+            pool_max_idle_per_host: usize::MAX,
             proxy: None,
-            ratelimiter: Some(Ratelimiter::new()),
+            ratelimiter: None,
+            retry_policy: None,
             timeout: Duration::from_secs(10),
             token: None,
+            transport: TransportOptions::default(),
             use_http: false,
         }
     }
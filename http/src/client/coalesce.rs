@@ -0,0 +1,298 @@
+use crate::error::{Error, ErrorType};
+use hyper::{body::Bytes, Body, Response};
+use std::{
+    collections::HashMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, PoisonError,
+    },
+};
+use tokio::sync::oneshot::{self, Receiver, Sender};
+
+/// Key identifying identical requests: the path and query, and whether the
+/// request is authorized with the token of the client.
+type Key = (String, bool);
+
+/// Outcome of an in-flight request shared with its waiters: the response
+/// body, or the type of error the request failed with.
+pub(super) type Outcome = Result<Bytes, ErrorType>;
+
+/// Waiters of in-flight requests by key.
+type InFlight = Mutex<HashMap<Key, Vec<Sender<Option<Outcome>>>>>;
+
+/// Role of a request joining the coalescer.
+pub(super) enum Join {
+    /// No identical request is in flight, so the request must be sent. The
+    /// response body must be shared via the guard.
+    Leader(LeaderGuard),
+    /// An identical request is in flight, whose outcome is received once it
+    /// completes, or `None` if it was cancelled.
+    Follower(Receiver<Option<Outcome>>),
+}
+
+/// Coalescer sharing the outcome of in-flight `GET` requests with identical
+/// requests made concurrently.
+#[derive(Debug, Default)]
+pub(super) struct RequestCoalescer {
+    in_flight: Arc<InFlight>,
+    saved: AtomicU64,
+}
+
+impl RequestCoalescer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Join the in-flight request identical to a request, or become the
+    /// leader sending it.
+    pub fn join(&self, path: &str, use_authorization_token: bool) -> Join {
+        let key = (path.to_owned(), use_authorization_token);
+        let mut in_flight = self
+            .in_flight
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        if let Some(waiters) = in_flight.get_mut(&key) {
+            let (tx, rx) = oneshot::channel();
+            waiters.push(tx);
+
+            return Join::Follower(rx);
+        }
+
+        in_flight.insert(key.clone(), Vec::new());
+
+        Join::Leader(LeaderGuard {
+            in_flight: Arc::clone(&self.in_flight),
+            key: Some(key),
+        })
+    }
+
+    /// Record that a request was served by an identical in-flight request.
+    pub fn record_saved(&self) {
+        self.saved.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Number of requests that were served by an identical in-flight
+    /// request instead of being sent.
+    pub fn saved(&self) -> u64 {
+        self.saved.load(Ordering::Relaxed)
+    }
+}
+
+/// Guard of the leader of coalesced requests.
+///
+/// If the guard is dropped without completing, such as when the leading
+/// request is cancelled, the waiters are notified that no outcome is
+/// available so that they send their requests themselves.
+pub(super) struct LeaderGuard {
+    in_flight: Arc<InFlight>,
+    key: Option<Key>,
+}
+
+impl LeaderGuard {
+    /// Complete the request, sharing its response body or the type of error
+    /// it failed with with the waiters.
+    pub fn complete(mut self, result: &Result<Bytes, Error>) {
+        self.notify(Some(result));
+    }
+
+    fn notify(&mut self, result: Option<&Result<Bytes, Error>>) {
+        let key = match self.key.take() {
+            Some(key) => key,
+            None => return,
+        };
+
+        let waiters = self
+            .in_flight
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&key)
+            .unwrap_or_default();
+
+        for waiter in waiters {
+            let outcome = result.map(|result| match result {
+                Ok(bytes) => Ok(bytes.clone()),
+                Err(source) => Err(copy_error_type(source.kind())),
+            });
+
+            let _ = waiter.send(outcome);
+        }
+    }
+}
+
+/// Copy the type of error a leading request failed with for a waiter.
+///
+/// The source error can't be copied, so it's only available to the leader.
+/// The body of a [`ErrorType::ServiceUnavailable`] response isn't copied.
+fn copy_error_type(kind: &ErrorType) -> ErrorType {
+    match kind {
+        ErrorType::BuildingRequest => ErrorType::BuildingRequest,
+        ErrorType::ChunkingResponse => ErrorType::ChunkingResponse,
+        ErrorType::CreatingHeader { name } => ErrorType::CreatingHeader { name: name.clone() },
+        ErrorType::InvalidRequestLimitReached => ErrorType::InvalidRequestLimitReached,
+        ErrorType::Json => ErrorType::Json,
+        ErrorType::OutboxStore => ErrorType::OutboxStore,
+        ErrorType::Parsing { body } => ErrorType::Parsing { body: body.clone() },
+        ErrorType::QueueWaitExceeded => ErrorType::QueueWaitExceeded,
+        ErrorType::RatelimiterTicket => ErrorType::RatelimiterTicket,
+        ErrorType::RequestCanceled => ErrorType::RequestCanceled,
+        ErrorType::RequestError => ErrorType::RequestError,
+        ErrorType::RequestTimedOut => ErrorType::RequestTimedOut,
+        ErrorType::Response {
+            body,
+            error,
+            status,
+        } => ErrorType::Response {
+            body: body.clone(),
+            error: error.clone(),
+            status: *status,
+        },
+        ErrorType::ServiceUnavailable { response } => {
+            let mut copy = Response::new(Body::empty());
+            *copy.status_mut() = response.status();
+            *copy.version_mut() = response.version();
+            *copy.headers_mut() = response.headers().clone();
+
+            ErrorType::ServiceUnavailable { response: copy }
+        }
+        ErrorType::UnrecordedRequest { method, path } => ErrorType::UnrecordedRequest {
+            method: method.clone(),
+            path: path.clone(),
+        },
+        ErrorType::Unauthorized => ErrorType::Unauthorized,
+    }
+}
+
+impl Drop for LeaderGuard {
+    fn drop(&mut self) {
+        self.notify(None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Join, RequestCoalescer};
+    use crate::{error::ErrorType, test_server, Client};
+    use hyper::{body::Bytes, Body, Response};
+    use static_assertions::assert_impl_all;
+    use std::{
+        error::Error,
+        fmt::Debug,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+    use tokio::time;
+    use twilight_model::id::ChannelId;
+
+    assert_impl_all!(RequestCoalescer: Debug, Send, Sync);
+
+    #[tokio::test]
+    async fn test_dropped_leader() {
+        let coalescer = RequestCoalescer::new();
+
+        let leader = match coalescer.join("guilds/1", true) {
+            Join::Leader(guard) => guard,
+            Join::Follower(_) => panic!("first request must lead"),
+        };
+
+        let follower = match coalescer.join("guilds/1", true) {
+            Join::Follower(rx) => rx,
+            Join::Leader(_) => panic!("identical request must follow"),
+        };
+
+        assert!(matches!(coalescer.join("guilds/1", false), Join::Leader(_)));
+
+        drop(leader);
+        assert!(follower.await.unwrap().is_none());
+
+        let leader = match coalescer.join("guilds/1", true) {
+            Join::Leader(guard) => guard,
+            Join::Follower(_) => panic!("completed request must not be joined"),
+        };
+        let follower = match coalescer.join("guilds/1", true) {
+            Join::Follower(rx) => rx,
+            Join::Leader(_) => panic!("identical request must follow"),
+        };
+
+        leader.complete(&Ok(Bytes::from_static(b"{}")));
+        assert!(matches!(
+            follower.await.unwrap(),
+            Some(Ok(bytes)) if bytes == Bytes::from_static(b"{}")
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_coalesced_requests() -> Result<(), Box<dyn Error>> {
+        let received = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&received);
+
+        let addr = test_server::start(move |_| {
+            counter.fetch_add(1, Ordering::Relaxed);
+
+            async {
+                time::sleep(Duration::from_millis(100)).await;
+
+                Response::builder().status(200).body(Body::from("[]"))
+            }
+        });
+
+        let client = Client::builder()
+            .proxy(addr.to_string(), true)
+            .token("token")
+            .coalesce_requests(true)
+            .build();
+
+        let (first, second, third) = tokio::join!(
+            client.pins(ChannelId(1)),
+            client.pins(ChannelId(1)),
+            client.pins(ChannelId(2)),
+        );
+
+        assert!(first?.is_empty());
+        assert!(second?.is_empty());
+        assert!(third?.is_empty());
+        assert_eq!(2, received.load(Ordering::Relaxed));
+        assert_eq!(1, client.coalesced_request_count());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_failed_request_shared() {
+        let received = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&received);
+
+        let addr = test_server::start(move |_| {
+            counter.fetch_add(1, Ordering::Relaxed);
+
+            async {
+                time::sleep(Duration::from_millis(100)).await;
+
+                Response::builder()
+                    .status(404)
+                    .body(Body::from(r#"{"code":10003,"message":"Unknown Channel"}"#))
+            }
+        });
+
+        let client = Client::builder()
+            .proxy(addr.to_string(), true)
+            .token("token")
+            .coalesce_requests(true)
+            .build();
+
+        let (first, second) = tokio::join!(client.pins(ChannelId(1)), client.pins(ChannelId(1)));
+
+        for result in &[first, second] {
+            assert!(matches!(
+                result.as_ref().unwrap_err().kind(),
+                ErrorType::Response { status, .. } if status.as_u16() == 404
+            ));
+        }
+
+        assert_eq!(1, received.load(Ordering::Relaxed));
+        assert_eq!(1, client.coalesced_request_count());
+    }
+}
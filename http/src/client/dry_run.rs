@@ -0,0 +1,623 @@
+use crate::{
+    error::{Error, ErrorType},
+    request::Method,
+    routing::{self, Path},
+};
+use hyper::{
+    body::{self, Bytes},
+    header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE},
+    Body, Response, StatusCode,
+};
+use serde_json::{json, Value};
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, PoisonError,
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Milliseconds since the Unix epoch of the first second of 2015, the epoch of
+/// Discord snowflakes.
+const DISCORD_EPOCH: u64 = 1_420_070_400_000;
+
+/// Default number of requests kept in the journal.
+const JOURNAL_CAPACITY: usize = 1000;
+
+/// Fields of message request bodies copied into synthesized messages.
+const MESSAGE_FIELDS: &[&str] = &["components", "content", "embeds", "tts"];
+
+/// Fields of role request bodies copied into synthesized roles.
+const ROLE_FIELDS: &[&str] = &["color", "hoist", "mentionable", "name", "permissions"];
+
+/// Timestamp of synthesized messages.
+const SYNTHETIC_TIMESTAMP: &str = "2015-01-01T00:00:00+00:00";
+
+/// Response returned for intercepted requests to a route.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Fixture {
+    body: Vec<u8>,
+    status: StatusCode,
+}
+
+/// Request intercepted by a [`DryRun`] instead of being sent.
+#[derive(Clone, Debug)]
+pub struct DryRunEntry {
+    body: Option<Vec<u8>>,
+    headers: HeaderMap,
+    method: Method,
+    path: Path,
+    path_str: String,
+}
+
+impl DryRunEntry {
+    /// Body of the request, if any.
+    pub fn body(&self) -> Option<&[u8]> {
+        self.body.as_deref()
+    }
+
+    /// Headers of the request.
+    ///
+    /// The `Authorization` header is not included.
+    pub const fn headers(&self) -> &HeaderMap {
+        &self.headers
+    }
+
+    /// Method of the request.
+    pub const fn method(&self) -> Method {
+        self.method
+    }
+
+    /// Ratelimiting path of the request.
+    pub const fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Path of the request relative to the API version, including the query.
+    ///
+    /// The token in the path of webhook and interaction requests is replaced
+    /// with `[redacted]`.
+    pub fn path_str(&self) -> &str {
+        &self.path_str
+    }
+}
+
+/// Mode in which requests that would mutate anything aren't sent.
+///
+/// When configured via [`ClientBuilder::dry_run`], requests with a method
+/// other than [`Method::Get`] are intercepted before the middleware chain
+/// and the ratelimiter. They are logged via tracing and collected in a
+/// journal, and a synthetic response is returned instead. `GET` requests are
+/// still sent. The token in the path of webhook and interaction requests
+/// isn't logged or collected.
+///
+/// The journal keeps the last 1000 requests by default, dropping the oldest
+/// requests once it's full. Its size can be changed via
+/// [`journal_capacity`].
+///
+/// By default requests creating, crossposting or updating messages and
+/// creating or updating roles, such as [`CreateMessage`], receive a `200 OK`
+/// response with a synthesized object. Its fields set in the request, such as
+/// the content of a message, are taken from the request; its other fields,
+/// such as the author of a message, are placeholders. New objects are given
+/// a fresh ID, and updated objects keep the ID from the path. Other requests
+/// with a JSON body receive a `200 OK` response echoing their body, and
+/// requests without one receive a `204 No Content` response. A fixture can
+/// be set for a route via [`fixture`] to respond differently.
+///
+/// Dry runs are cheap to clone; clones share the same journal, so a clone
+/// can be kept to inspect the requests made by the client.
+///
+/// # Examples
+///
+/// Collect the requests made to delete messages, and inspect them:
+///
+/// ```rust,no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use std::env;
+/// use twilight_http::{client::DryRun, Client};
+/// use twilight_model::id::{ChannelId, MessageId};
+///
+/// let dry_run = DryRun::new();
+///
+/// let client = Client::builder()
+///     .token(env::var("DISCORD_TOKEN")?)
+///     .dry_run(dry_run.clone())
+///     .build();
+///
+/// client.delete_message(ChannelId(1), MessageId(2)).await?;
+///
+/// for entry in dry_run.journal() {
+///     println!("{:?} {}", entry.method(), entry.path_str());
+/// }
+/// # Ok(()) }
+/// ```
+///
+/// [`ClientBuilder::dry_run`]: super::ClientBuilder::dry_run
+/// [`CreateMessage`]: crate::request::channel::message::CreateMessage
+/// [`fixture`]: Self::fixture
+/// [`journal_capacity`]: Self::journal_capacity
+#[derive(Clone, Debug)]
+pub struct DryRun {
+    fixtures: HashMap<(Method, Path), Fixture>,
+    journal: Arc<Mutex<VecDeque<DryRunEntry>>>,
+    journal_capacity: usize,
+}
+
+impl DryRun {
+    /// Create a new dry run without any fixtures.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum number of requests kept in the journal.
+    ///
+    /// Once the journal is full the oldest requests are dropped. A capacity
+    /// of 0 disables the journal.
+    ///
+    /// The default value is 1000.
+    pub const fn journal_capacity(mut self, journal_capacity: usize) -> Self {
+        self.journal_capacity = journal_capacity;
+
+        self
+    }
+
+    /// Set the response returned for intercepted requests with a method to a
+    /// ratelimiting path.
+    ///
+    /// # Examples
+    ///
+    /// Respond to created messages in a channel with a message:
+    ///
+    /// ```
+    /// use hyper::StatusCode;
+    /// use twilight_http::{client::DryRun, request::Method, routing::Path};
+    /// use twilight_model::channel::Message;
+    ///
+    /// let message = r#"{
+    ///     "attachments": [],
+    ///     "author": {"avatar": null, "discriminator": "0001", "id": "3", "username": "bot"},
+    ///     "channel_id": "2",
+    ///     "content": "test",
+    ///     "edited_timestamp": null,
+    ///     "embeds": [],
+    ///     "id": "1",
+    ///     "mention_everyone": false,
+    ///     "mention_roles": [],
+    ///     "mentions": [],
+    ///     "pinned": false,
+    ///     "timestamp": "2021-01-01T00:00:00+00:00",
+    ///     "tts": false,
+    ///     "type": 0
+    /// }"#;
+    /// # serde_json::from_str::<Message>(message).unwrap();
+    ///
+    /// let dry_run = DryRun::new().fixture(
+    ///     Method::Post,
+    ///     Path::ChannelsIdMessages(2),
+    ///     StatusCode::OK,
+    ///     message,
+    /// );
+    /// ```
+    pub fn fixture(
+        mut self,
+        method: Method,
+        path: Path,
+        status: StatusCode,
+        body: impl Into<Vec<u8>>,
+    ) -> Self {
+        let body = body.into();
+
+        self.fixtures
+            .insert((method, path), Fixture { body, status });
+
+        self
+    }
+
+    /// Copy of the requests intercepted so far, in the order in which they
+    /// were made.
+    pub fn journal(&self) -> Vec<DryRunEntry> {
+        self.journal
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .cloned()
+            .collect()
+    }
+
+    /// Take the requests intercepted so far, clearing the journal.
+    pub fn take_journal(&self) -> Vec<DryRunEntry> {
+        self.journal
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .drain(..)
+            .collect()
+    }
+
+    /// Record a request in the journal, returning the synthetic response.
+    pub(crate) async fn intercept(
+        &self,
+        method: Method,
+        path: Path,
+        path_str: &str,
+        mut headers: HeaderMap,
+        body: Option<Bytes>,
+        streamed_body: Option<Body>,
+    ) -> Result<Response<Body>, Error> {
+        let body = match streamed_body {
+            Some(streamed) => Some(body::to_bytes(streamed).await.map_err(|source| Error {
+                kind: ErrorType::BuildingRequest,
+                source: Some(Box::new(source)),
+            })?),
+            None => body,
+        };
+
+        headers.remove(AUTHORIZATION);
+
+        let fixture = self.fixtures.get(&(method, path.clone()));
+        let json_body = body.clone().filter(|_| {
+            headers.get(CONTENT_TYPE) == Some(&HeaderValue::from_static("application/json"))
+        });
+        let synthesized = synthesize(method, &path, path_str, json_body.as_deref());
+
+        let path_str = routing::redact_token(path_str);
+
+        #[cfg(feature = "tracing")]
+        tracing::info!(?method, path = %path_str, "dry run: not sending request");
+
+        self.record(DryRunEntry {
+            body: body.map(|body| body.to_vec()),
+            headers,
+            method,
+            path,
+            path_str: path_str.into_owned(),
+        });
+
+        let response = match fixture {
+            Some(fixture) => Response::builder()
+                .status(fixture.status)
+                .body(Body::from(fixture.body.clone())),
+            None => match synthesized.map(Bytes::from).or(json_body) {
+                Some(json_body) => Response::builder()
+                    .status(StatusCode::OK)
+                    .header(CONTENT_TYPE, "application/json")
+                    .body(Body::from(json_body)),
+                None => Response::builder()
+                    .status(StatusCode::NO_CONTENT)
+                    .body(Body::empty()),
+            },
+        };
+
+        response.map_err(|source| Error {
+            kind: ErrorType::BuildingRequest,
+            source: Some(Box::new(source)),
+        })
+    }
+
+    /// Add a request to the journal, dropping the oldest request if it's full.
+    fn record(&self, entry: DryRunEntry) {
+        if self.journal_capacity == 0 {
+            return;
+        }
+
+        let mut journal = self.journal.lock().unwrap_or_else(PoisonError::into_inner);
+
+        if journal.len() >= self.journal_capacity {
+            journal.pop_front();
+        }
+
+        journal.push_back(entry);
+    }
+}
+
+/// Synthesize the object a route responds with, if it is a route creating or
+/// updating a message or role.
+///
+/// Fields of the object set in the request body are copied from it, and the
+/// other required fields are set to placeholders.
+fn synthesize(method: Method, path: &Path, path_str: &str, body: Option<&[u8]>) -> Option<Vec<u8>> {
+    let (mut object, fields) = match (method, path) {
+        (Method::Post, Path::ChannelsIdMessages(channel_id)) => {
+            (message(*channel_id, next_id()), MESSAGE_FIELDS)
+        }
+        (Method::Patch, Path::ChannelsIdMessagesId(_, channel_id))
+        | (Method::Post, Path::ChannelsIdMessagesIdCrosspost(channel_id)) => (
+            message(*channel_id, path_id(path_str, "messages")?),
+            MESSAGE_FIELDS,
+        ),
+        (Method::Post, Path::GuildsIdRoles(_)) => (role(next_id()), ROLE_FIELDS),
+        (Method::Patch, Path::GuildsIdRolesId(_)) => {
+            (role(path_id(path_str, "roles")?), ROLE_FIELDS)
+        }
+        _ => return None,
+    };
+
+    if let Some(Value::Object(request)) = body.and_then(|body| serde_json::from_slice(body).ok()) {
+        for (name, value) in request {
+            if fields.contains(&name.as_str()) && !value.is_null() {
+                object[name] = value;
+            }
+        }
+    }
+
+    serde_json::to_vec(&object).ok()
+}
+
+/// Message with placeholders for its required fields.
+fn message(channel_id: u64, id: u64) -> Value {
+    json!({
+        "attachments": [],
+        "author": {
+            "avatar": null,
+            "bot": true,
+            "discriminator": "0000",
+            "id": "1",
+            "username": "dry run",
+        },
+        "channel_id": channel_id.to_string(),
+        "content": "",
+        "edited_timestamp": null,
+        "embeds": [],
+        "id": id.to_string(),
+        "mention_everyone": false,
+        "mention_roles": [],
+        "mentions": [],
+        "pinned": false,
+        "timestamp": SYNTHETIC_TIMESTAMP,
+        "tts": false,
+        "type": 0,
+    })
+}
+
+/// Role with placeholders for its required fields.
+fn role(id: u64) -> Value {
+    json!({
+        "color": 0,
+        "hoist": false,
+        "id": id.to_string(),
+        "managed": false,
+        "mentionable": false,
+        "name": "new role",
+        "permissions": "0",
+        "position": 0,
+    })
+}
+
+/// ID following a segment in a path, such as the ID of the message in
+/// `channels/1/messages/2`.
+fn path_id(path_str: &str, segment: &str) -> Option<u64> {
+    let mut segments = path_str.split(&['/', '?'][..]);
+    segments.find(|current| *current == segment)?;
+
+    segments.next()?.parse().ok()
+}
+
+/// Generate a new snowflake ID from the current time.
+fn next_id() -> u64 {
+    static INCREMENT: AtomicU64 = AtomicU64::new(0);
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| {
+            elapsed.as_secs() * 1000 + u64::from(elapsed.subsec_millis())
+        })
+        .saturating_sub(DISCORD_EPOCH);
+
+    (timestamp << 22) | (INCREMENT.fetch_add(1, Ordering::Relaxed) & 0xfff)
+}
+
+impl Default for DryRun {
+    fn default() -> Self {
+        Self {
+            fixtures: HashMap::new(),
+            journal: Arc::default(),
+            journal_capacity: JOURNAL_CAPACITY,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{path_id, DryRun, DryRunEntry};
+    use crate::{request::Method, routing::Path, test_server, Client};
+    use hyper::{
+        body,
+        header::{HeaderMap, HeaderValue, CONTENT_TYPE},
+        Body, Response, StatusCode,
+    };
+    use static_assertions::assert_impl_all;
+    use std::{
+        error::Error,
+        fmt::Debug,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+    };
+    use twilight_model::id::{ChannelId, GuildId, MessageId, RoleId, WebhookId};
+
+    assert_impl_all!(DryRun: Clone, Debug, Default, Send, Sync);
+    assert_impl_all!(DryRunEntry: Clone, Debug, Send, Sync);
+
+    #[tokio::test]
+    async fn test_dry_run() -> Result<(), Box<dyn Error>> {
+        let received = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&received);
+
+        let addr = test_server::start(move |_| {
+            counter.fetch_add(1, Ordering::Relaxed);
+
+            async { Response::builder().status(200).body(Body::from("[]")) }
+        });
+
+        let message = br#"{
+            "attachments": [],
+            "author": {"avatar": null, "discriminator": "0001", "id": "3", "username": "bot"},
+            "channel_id": "1",
+            "content": "test",
+            "edited_timestamp": null,
+            "embeds": [],
+            "id": "2",
+            "mention_everyone": false,
+            "mention_roles": [],
+            "mentions": [],
+            "pinned": false,
+            "timestamp": "2021-01-01T00:00:00+00:00",
+            "tts": false,
+            "type": 0
+        }"#;
+        let dry_run = DryRun::new().fixture(
+            Method::Post,
+            Path::ChannelsIdMessages(1),
+            StatusCode::OK,
+            message.as_ref(),
+        );
+
+        let client = Client::builder()
+            .proxy(addr.to_string(), true)
+            .token("token")
+            .dry_run(dry_run.clone())
+            .build();
+
+        client.delete_message(ChannelId(1), MessageId(2)).await?;
+        let created = client.create_message(ChannelId(1)).content("test")?.await?;
+        assert_eq!(MessageId(2), created.id);
+        assert!(client.pins(ChannelId(1)).await?.is_empty());
+
+        // Webhook tokens are redacted from the journal.
+        client
+            .execute_webhook(WebhookId(1), "webhook-token")
+            .content("test")
+            .await?;
+
+        assert_eq!(1, received.load(Ordering::Relaxed));
+
+        let journal = dry_run.take_journal();
+        assert_eq!(3, journal.len());
+        assert_eq!(Method::Delete, journal[0].method());
+        assert_eq!("channels/1/messages/2", journal[0].path_str());
+        assert_eq!(Method::Post, journal[1].method());
+        assert_eq!(Some(br#"{"content":"test"}"#.as_ref()), journal[1].body());
+        assert!(journal[1].headers().get("authorization").is_none());
+        assert_eq!("webhooks/1/[redacted]", journal[2].path_str());
+        assert!(dry_run.journal().is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_default_response() -> Result<(), Box<dyn Error>> {
+        let dry_run = DryRun::new();
+        let json = br#"{"name":"test"}"#;
+
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+
+        let response = dry_run
+            .intercept(
+                Method::Patch,
+                Path::ChannelsId(1),
+                "channels/1",
+                headers,
+                Some(json.as_ref().into()),
+                None,
+            )
+            .await?;
+        assert_eq!(StatusCode::OK, response.status());
+        assert_eq!(json.as_ref(), body::to_bytes(response.into_body()).await?);
+
+        let response = dry_run
+            .intercept(
+                Method::Delete,
+                Path::ChannelsId(1),
+                "channels/1",
+                HeaderMap::new(),
+                None,
+                None,
+            )
+            .await?;
+        assert_eq!(StatusCode::NO_CONTENT, response.status());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_synthesized_response() -> Result<(), Box<dyn Error>> {
+        let client = Client::builder()
+            .proxy("127.0.0.1:1", true)
+            .token("token")
+            .dry_run(DryRun::new())
+            .build();
+
+        let created = client.create_message(ChannelId(1)).content("test")?.await?;
+        assert_eq!(ChannelId(1), created.channel_id);
+        assert_eq!("test", created.content);
+
+        let updated = client
+            .update_message(ChannelId(1), MessageId(2))
+            .content(Some("edited".to_owned()))?
+            .await?;
+        assert_eq!(MessageId(2), updated.id);
+        assert_eq!("edited", updated.content);
+
+        let role = client.create_role(GuildId(1)).name("mods").await?;
+        assert_eq!("mods", role.name);
+        assert_ne!(created.id.0, role.id.0);
+
+        let role = client
+            .update_role(GuildId(1), RoleId(3))
+            .name(Some("admins".to_owned()))
+            .await?;
+        assert_eq!(RoleId(3), role.id);
+        assert_eq!("admins", role.name);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_path_id() {
+        assert_eq!(Some(2), path_id("channels/1/messages/2", "messages"));
+        assert_eq!(Some(2), path_id("guilds/1/roles/2?reason=a", "roles"));
+        assert_eq!(None, path_id("channels/1/messages", "messages"));
+    }
+
+    #[tokio::test]
+    async fn test_journal_capacity() -> Result<(), Box<dyn Error>> {
+        let dry_run = DryRun::new().journal_capacity(2);
+
+        for id in 1..=3 {
+            dry_run
+                .intercept(
+                    Method::Delete,
+                    Path::ChannelsId(id),
+                    &format!("channels/{}", id),
+                    HeaderMap::new(),
+                    None,
+                    None,
+                )
+                .await?;
+        }
+
+        let journal = dry_run.journal();
+        assert_eq!(2, journal.len());
+        assert_eq!("channels/2", journal[0].path_str());
+        assert_eq!("channels/3", journal[1].path_str());
+
+        let disabled = DryRun::new().journal_capacity(0);
+        disabled
+            .intercept(
+                Method::Delete,
+                Path::ChannelsId(1),
+                "channels/1",
+                HeaderMap::new(),
+                None,
+                None,
+            )
+            .await?;
+        assert!(disabled.journal().is_empty());
+
+        Ok(())
+    }
+}
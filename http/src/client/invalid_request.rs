@@ -0,0 +1,262 @@
+use hyper::{header::HeaderMap, StatusCode};
+use std::{
+    collections::VecDeque,
+    convert::TryFrom,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+/// Limit on the number of invalid requests made by the client.
+///
+/// The API temporarily bans IP addresses that make too many invalid requests,
+/// which are requests resulting in a 401, 403, or 429 response, within a
+/// window of time. At the time of writing the API allows 10,000 invalid
+/// requests per 10 minutes. Responses with a 429 status code that were
+/// ratelimited by a shared resource aren't counted.
+///
+/// The client counts invalid responses within the window. Once the count
+/// reaches the [`slow_down_at`] threshold each request is delayed by the
+/// [`slow_down_delay`], and once it reaches the [`refuse_at`] threshold
+/// requests are refused with an [`ErrorType::InvalidRequestLimitReached`]
+/// error type until enough invalid requests have left the window.
+///
+/// # Examples
+///
+/// Refuse requests after 5,000 invalid requests in 10 minutes, slowing down
+/// after 2,500:
+///
+/// ```rust,no_run
+/// use twilight_http::{client::InvalidRequestLimit, Client};
+///
+/// let limit = InvalidRequestLimit::new()
+///     .refuse_at(5_000)
+///     .slow_down_at(2_500);
+///
+/// let client = Client::builder()
+///     .token("my token")
+///     .invalid_request_limit(limit)
+///     .build();
+/// ```
+///
+/// [`ErrorType::InvalidRequestLimitReached`]: crate::error::ErrorType::InvalidRequestLimitReached
+/// [`refuse_at`]: Self::refuse_at
+/// [`slow_down_at`]: Self::slow_down_at
+/// [`slow_down_delay`]: Self::slow_down_delay
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct InvalidRequestLimit {
+    refuse_at: u32,
+    slow_down_at: u32,
+    slow_down_delay: Duration,
+    window: Duration,
+}
+
+impl InvalidRequestLimit {
+    /// Create a new limit with the default settings.
+    ///
+    /// Requests are slowed down after 8,000 and refused after 9,500 invalid
+    /// requests within 10 minutes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the number of invalid requests after which requests are refused.
+    ///
+    /// The default is 9,500.
+    pub const fn refuse_at(mut self, refuse_at: u32) -> Self {
+        self.refuse_at = refuse_at;
+
+        self
+    }
+
+    /// Set the number of invalid requests after which requests are delayed.
+    ///
+    /// The default is 8,000.
+    pub const fn slow_down_at(mut self, slow_down_at: u32) -> Self {
+        self.slow_down_at = slow_down_at;
+
+        self
+    }
+
+    /// Set the delay of each request once the [`slow_down_at`] threshold has
+    /// been reached.
+    ///
+    /// The default is 1 second.
+    ///
+    /// [`slow_down_at`]: Self::slow_down_at
+    pub const fn slow_down_delay(mut self, slow_down_delay: Duration) -> Self {
+        self.slow_down_delay = slow_down_delay;
+
+        self
+    }
+
+    /// Set the window of time in which invalid requests are counted.
+    ///
+    /// The default is 10 minutes.
+    pub const fn window(mut self, window: Duration) -> Self {
+        self.window = window;
+
+        self
+    }
+
+    /// Action to take before making a request, given the current number of
+    /// invalid requests.
+    pub(super) const fn action(&self, count: u32) -> InvalidRequestAction {
+        if count >= self.refuse_at {
+            InvalidRequestAction::Refuse
+        } else if count >= self.slow_down_at {
+            InvalidRequestAction::SlowDown(self.slow_down_delay)
+        } else {
+            InvalidRequestAction::Proceed
+        }
+    }
+}
+
+impl Default for InvalidRequestLimit {
+    fn default() -> Self {
+        Self {
+            refuse_at: 9_500,
+            slow_down_at: 8_000,
+            slow_down_delay: Duration::from_secs(1),
+            window: InvalidRequestCounter::DEFAULT_WINDOW,
+        }
+    }
+}
+
+/// Action to take before making a request.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(super) enum InvalidRequestAction {
+    Proceed,
+    Refuse,
+    SlowDown(Duration),
+}
+
+/// Sliding window counter of invalid requests.
+#[derive(Debug)]
+pub(super) struct InvalidRequestCounter {
+    requests: Mutex<VecDeque<Instant>>,
+    window: Duration,
+}
+
+impl InvalidRequestCounter {
+    const DEFAULT_WINDOW: Duration = Duration::from_secs(10 * 60);
+
+    pub fn new(limit: Option<&InvalidRequestLimit>) -> Self {
+        Self {
+            requests: Mutex::new(VecDeque::new()),
+            window: limit.map_or(Self::DEFAULT_WINDOW, |limit| limit.window),
+        }
+    }
+
+    /// Number of invalid requests made within the window.
+    pub fn count(&self) -> u32 {
+        let mut requests = self.requests.lock().expect("counter poisoned");
+        self.prune(&mut requests, Instant::now());
+
+        u32::try_from(requests.len()).unwrap_or(u32::MAX)
+    }
+
+    /// Record a response if it counts as an invalid request.
+    pub fn record(&self, status: StatusCode, headers: &HeaderMap) {
+        if !is_invalid(status, headers) {
+            return;
+        }
+
+        let now = Instant::now();
+        let mut requests = self.requests.lock().expect("counter poisoned");
+        self.prune(&mut requests, now);
+        requests.push_back(now);
+    }
+
+    fn prune(&self, requests: &mut VecDeque<Instant>, now: Instant) {
+        while let Some(oldest) = requests.front() {
+            if now.duration_since(*oldest) < self.window {
+                break;
+            }
+
+            requests.pop_front();
+        }
+    }
+}
+
+/// Whether a response counts as an invalid request.
+fn is_invalid(status: StatusCode, headers: &HeaderMap) -> bool {
+    match status {
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN => true,
+        // Ratelimits of shared resources, such as emoji reactions in a
+        // channel, aren't counted.
+        StatusCode::TOO_MANY_REQUESTS => headers
+            .get("x-ratelimit-scope")
+            .map_or(true, |scope| scope != "shared"),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InvalidRequestAction, InvalidRequestCounter, InvalidRequestLimit};
+    use hyper::{
+        header::{HeaderMap, HeaderValue},
+        StatusCode,
+    };
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash, thread, time::Duration};
+
+    assert_impl_all!(
+        InvalidRequestLimit: Clone,
+        Copy,
+        Debug,
+        Default,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Sync
+    );
+
+    #[test]
+    fn test_action() {
+        let limit = InvalidRequestLimit::new()
+            .refuse_at(10)
+            .slow_down_at(5)
+            .slow_down_delay(Duration::from_millis(100));
+
+        assert_eq!(InvalidRequestAction::Proceed, limit.action(4));
+        assert_eq!(
+            InvalidRequestAction::SlowDown(Duration::from_millis(100)),
+            limit.action(5)
+        );
+        assert_eq!(InvalidRequestAction::Refuse, limit.action(10));
+    }
+
+    #[test]
+    fn test_record() {
+        let counter = InvalidRequestCounter::new(None);
+        let headers = HeaderMap::new();
+
+        counter.record(StatusCode::OK, &headers);
+        counter.record(StatusCode::NOT_FOUND, &headers);
+        assert_eq!(0, counter.count());
+
+        counter.record(StatusCode::UNAUTHORIZED, &headers);
+        counter.record(StatusCode::FORBIDDEN, &headers);
+        counter.record(StatusCode::TOO_MANY_REQUESTS, &headers);
+        assert_eq!(3, counter.count());
+
+        let mut shared = HeaderMap::new();
+        shared.insert("x-ratelimit-scope", HeaderValue::from_static("shared"));
+        counter.record(StatusCode::TOO_MANY_REQUESTS, &shared);
+        assert_eq!(3, counter.count());
+    }
+
+    #[test]
+    fn test_window() {
+        let limit = InvalidRequestLimit::new().window(Duration::from_millis(50));
+        let counter = InvalidRequestCounter::new(Some(&limit));
+
+        counter.record(StatusCode::FORBIDDEN, &HeaderMap::new());
+        assert_eq!(1, counter.count());
+
+        thread::sleep(Duration::from_millis(60));
+        assert_eq!(0, counter.count());
+    }
+}
@@ -1,11 +1,32 @@
 mod builder;
+mod coalesce;
+mod dry_run;
+mod invalid_request;
+mod retry;
+mod transport;
+mod webhook;
+
+pub use self::{
+    builder::{ClientBuildError, ClientBuildErrorType, ClientBuilder},
+    dry_run::{DryRun, DryRunEntry},
+    invalid_request::InvalidRequestLimit,
+    retry::RetryPolicy,
+    transport::{Resolve, ResolveFuture},
+    webhook::WebhookClient,
+};
 
-pub use self::builder::ClientBuilder;
+use self::{
+    coalesce::{Join, RequestCoalescer},
+    invalid_request::{InvalidRequestAction, InvalidRequestCounter},
+    transport::HttpClient,
+};
 
 use crate::{
     api_error::ApiError,
     error::{Error, ErrorType},
-    ratelimiting::{RatelimitHeaders, Ratelimiter},
+    middleware::{Middleware, MiddlewareRequest, Next},
+    outbox::{self, FailedEntry, OutboxEntry, OutboxReplay, OutboxStore},
+    ratelimiting::{InMemoryRatelimiter, Priority, RatelimitHeaders, Ratelimiter},
     request::{
         application::{
             CreateFollowupMessage, CreateGlobalCommand, CreateGuildCommand, DeleteFollowupMessage,
@@ -15,20 +36,30 @@ use crate::{
             SetGlobalCommands, SetGuildCommands, UpdateCommandPermissions, UpdateFollowupMessage,
             UpdateGlobalCommand, UpdateGuildCommand, UpdateOriginalResponse,
         },
-        channel::stage::create_stage_instance::CreateStageInstanceError,
+        channel::{
+            stage::create_stage_instance::CreateStageInstanceError,
+            thread::{
+                create_thread::CreateThreadError,
+                create_thread_from_message::CreateThreadFromMessageError,
+            },
+        },
         guild::{
             create_guild::CreateGuildError, create_guild_channel::CreateGuildChannelError,
             update_guild_channel_positions::Position,
         },
+        oauth::{
+            ClientCredentialsGrant, ExchangeCode, GetCurrentAuthorizationInformation,
+            RefreshAccessToken, RevokeAccessToken,
+        },
         prelude::*,
-        GetUserApplicationInfo, Method, Request,
+        FormBody, GetUserApplicationInfo, Method, Request,
     },
+    routing::Path,
     API_VERSION,
 };
 use hyper::body::Bytes;
 use hyper::{
     body,
-    client::{Client as HyperClient, HttpConnector},
     header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE, USER_AGENT},
     Body, Response, StatusCode,
 };
@@ -46,9 +77,9 @@ use tokio::time;
 use twilight_model::{
     application::{
         callback::InteractionResponse,
-        command::{permissions::CommandPermissions, Command},
+        command::{permissions::CommandPermissions, Command, CommandType},
     },
-    channel::message::allowed_mentions::AllowedMentions,
+    channel::{message::allowed_mentions::AllowedMentions, ChannelType},
     guild::Permissions,
     id::{
         ApplicationId, ChannelId, CommandId, EmojiId, GuildId, IntegrationId, InteractionId,
@@ -56,16 +87,20 @@ use twilight_model::{
     },
 };
 
-#[cfg(feature = "hyper-rustls")]
-type HttpsConnector<T> = hyper_rustls::HttpsConnector<T>;
-#[cfg(all(feature = "hyper-tls", not(feature = "hyper-rustls")))]
-type HttpsConnector<T> = hyper_tls::HttpsConnector<T>;
-
 struct State {
-    http: HyperClient<HttpsConnector<HttpConnector>, Body>,
+    http: Box<dyn HttpClient>,
+    coalescer: Option<RequestCoalescer>,
     default_headers: Option<HeaderMap>,
+    dry_run: Option<DryRun>,
+    invalid_request_limit: Option<InvalidRequestLimit>,
+    invalid_requests: InvalidRequestCounter,
+    max_background_queue_wait: Option<Duration>,
+    middleware: Vec<Box<dyn Middleware>>,
+    outbox: Option<Box<dyn OutboxStore>>,
+    outbox_in_flight: outbox::InFlight,
     proxy: Option<Box<str>>,
-    ratelimiter: Option<Ratelimiter>,
+    ratelimiter: Option<Box<dyn Ratelimiter>>,
+    retry_policy: Option<RetryPolicy>,
     timeout: Duration,
     token_invalid: AtomicBool,
     token: Option<Box<str>>,
@@ -78,9 +113,17 @@ impl Debug for State {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         f.debug_struct("State")
             .field("http", &self.http)
+            .field("coalescer", &self.coalescer)
             .field("default_headers", &self.default_headers)
+            .field("dry_run", &self.dry_run)
+            .field("invalid_request_limit", &self.invalid_request_limit)
+            .field("invalid_requests", &self.invalid_requests)
+            .field("max_background_queue_wait", &self.max_background_queue_wait)
+            .field("middleware", &self.middleware)
+            .field("outbox", &self.outbox)
             .field("proxy", &self.proxy)
             .field("ratelimiter", &self.ratelimiter)
+            .field("retry_policy", &self.retry_policy)
             .field("token", &self.token)
             .field("use_http", &self.use_http)
             .finish()
@@ -154,6 +197,11 @@ impl Debug for State {
 /// [here]: https://discord.com/developers/applications
 #[derive(Clone, Debug)]
 pub struct Client {
+    /// Priority of requests made with the client, unless set per request.
+    priority: Option<Priority>,
+    /// Ratelimiter used instead of the ratelimiter of the shared state, such
+    /// as by a [`WebhookClient`].
+    ratelimiter: Option<Arc<dyn Ratelimiter>>,
     state: Arc<State>,
 }
 
@@ -211,12 +259,106 @@ impl Client {
         self.state.default_allowed_mentions.clone()
     }
 
-    /// Get the Ratelimiter used by the client internally.
+    /// Get the number of invalid requests made within the window of the
+    /// [`InvalidRequestLimit`].
+    ///
+    /// Invalid requests are requests resulting in a 401, 403, or 429
+    /// response. Too many invalid requests result in the API temporarily
+    /// banning the IP address in use.
+    pub fn invalid_request_count(&self) -> u32 {
+        self.state.invalid_requests.count()
+    }
+
+    /// Get the number of `GET` requests that were served by an identical
+    /// request in flight at the same time instead of being sent.
+    ///
+    /// This is always 0 if request coalescing hasn't been enabled via
+    /// [`ClientBuilder::coalesce_requests`].
+    pub fn coalesced_request_count(&self) -> u64 {
+        self.state
+            .coalescer
+            .as_ref()
+            .map_or(0, RequestCoalescer::saved)
+    }
+
+    /// Create a client making requests with a [`Priority`] when waiting to
+    /// be ratelimited.
+    ///
+    /// The returned client shares its state, such as ratelimits, with this
+    /// client. Requests made with it have the priority unless a priority is
+    /// set via [`RequestBuilder::priority`].
+    ///
+    /// # Examples
+    ///
+    /// Fetch an audit log without delaying other requests, while responding
+    /// to an interaction as soon as possible:
+    ///
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_http::{ratelimiting::Priority, Client};
+    /// use twilight_model::id::{GuildId, WebhookId};
+    ///
+    /// let client = Client::new("my token");
+    ///
+    /// let audit_log = client
+    ///     .with_priority(Priority::Background)
+    ///     .audit_log(GuildId(1))
+    ///     .await?;
+    ///
+    /// client
+    ///     .with_priority(Priority::Urgent)
+    ///     .execute_webhook(WebhookId(2), "interaction token")
+    ///     .content("done")
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`RequestBuilder::priority`]: crate::request::RequestBuilder::priority
+    pub fn with_priority(&self, priority: Priority) -> Self {
+        Self {
+            priority: Some(priority),
+            ratelimiter: self.ratelimiter.clone(),
+            state: Arc::clone(&self.state),
+        }
+    }
+
+    /// Create a client sharing the state of this client, such as its
+    /// connection pool, but waiting for ratelimits with a new
+    /// [`InMemoryRatelimiter`].
+    ///
+    /// Ratelimit handling stays disabled if it has been disabled for this
+    /// client.
+    pub(crate) fn with_isolated_ratelimiter(&self) -> Self {
+        match self.ratelimiter() {
+            Some(_) => self.with_ratelimiter(Arc::new(InMemoryRatelimiter::new())),
+            None => self.clone(),
+        }
+    }
+
+    /// Create a client sharing the state of this client, but waiting for
+    /// ratelimits with another ratelimiter.
+    pub(crate) fn with_ratelimiter(&self, ratelimiter: Arc<dyn Ratelimiter>) -> Self {
+        Self {
+            priority: self.priority,
+            ratelimiter: Some(ratelimiter),
+            state: Arc::clone(&self.state),
+        }
+    }
+
+    /// Whether an outbox is configured.
+    pub(crate) fn has_outbox(&self) -> bool {
+        self.state.outbox.is_some()
+    }
+
+    /// Get the ratelimiter used by the client internally.
     ///
     /// This will return `None` only if ratelimit handling
     /// has been explicitly disabled in the [`ClientBuilder`].
-    pub fn ratelimiter(&self) -> Option<Ratelimiter> {
-        self.state.ratelimiter.clone()
+    pub fn ratelimiter(&self) -> Option<&dyn Ratelimiter> {
+        self.ratelimiter
+            .as_deref()
+            .or_else(|| self.state.ratelimiter.as_deref())
     }
 
     /// Get the audit log for a guild.
@@ -480,6 +622,107 @@ impl Client {
         GetUserApplicationInfo::new(self)
     }
 
+    /// Get information about the authorization of the current `OAuth2` access
+    /// token, such as its scopes and when it expires.
+    ///
+    /// The client must be authorized with a `Bearer` access token, such as
+    /// the [`AccessToken::authorization`] of a token.
+    ///
+    /// [`AccessToken::authorization`]: twilight_model::oauth::AccessToken::authorization
+    pub fn current_authorization(&self) -> GetCurrentAuthorizationInformation<'_> {
+        GetCurrentAuthorizationInformation::new(self)
+    }
+
+    /// Exchange the code of the `OAuth2` authorization code flow for an access
+    /// token.
+    ///
+    /// The user is redirected to the redirect URI with the code once they
+    /// authorize the application on the page of an [`AuthorizationUrl`]. The
+    /// redirect URI must be the same as the one of the authorization URL.
+    ///
+    /// The request is authorized with the client ID and secret of the
+    /// application rather than the token of the client.
+    ///
+    /// # Examples
+    ///
+    /// Exchange a code and add the user to a guild with the access token:
+    ///
+    /// ```rust,no_run
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// use twilight_http::Client;
+    /// use twilight_model::id::{ApplicationId, GuildId, UserId};
+    ///
+    /// let client = Client::new("my token");
+    ///
+    /// let token = client
+    ///     .exchange_code(
+    ///         ApplicationId(1),
+    ///         "client secret",
+    ///         "code",
+    ///         "https://example.com/callback",
+    ///     )
+    ///     .await?;
+    ///
+    /// let user_client = Client::new(token.authorization());
+    /// let user = user_client.current_user().await?;
+    ///
+    /// client
+    ///     .add_guild_member(GuildId(2), user.id, token.access_token)
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`AuthorizationUrl`]: crate::request::oauth::AuthorizationUrl
+    pub fn exchange_code(
+        &self,
+        client_id: ApplicationId,
+        client_secret: impl Into<String>,
+        code: impl Into<String>,
+        redirect_uri: impl Into<String>,
+    ) -> ExchangeCode<'_> {
+        ExchangeCode::new(self, client_id, client_secret, code, redirect_uri)
+    }
+
+    /// Exchange an `OAuth2` refresh token for a new access token.
+    ///
+    /// The request is authorized with the client ID and secret of the
+    /// application rather than the token of the client.
+    pub fn refresh_access_token(
+        &self,
+        client_id: ApplicationId,
+        client_secret: impl Into<String>,
+        refresh_token: impl Into<String>,
+    ) -> RefreshAccessToken<'_> {
+        RefreshAccessToken::new(self, client_id, client_secret, refresh_token)
+    }
+
+    /// Get an `OAuth2` access token for the owner of the application via the
+    /// client credentials grant.
+    ///
+    /// The request is authorized with the client ID and secret of the
+    /// application rather than the token of the client.
+    pub fn client_credentials_grant(
+        &self,
+        client_id: ApplicationId,
+        client_secret: impl Into<String>,
+    ) -> ClientCredentialsGrant<'_> {
+        ClientCredentialsGrant::new(self, client_id, client_secret)
+    }
+
+    /// Revoke an `OAuth2` access or refresh token.
+    ///
+    /// The request is authorized with the client ID and secret of the
+    /// application rather than the token of the client.
+    pub fn revoke_access_token(
+        &self,
+        client_id: ApplicationId,
+        client_secret: impl Into<String>,
+        token: impl Into<String>,
+    ) -> RevokeAccessToken<'_> {
+        RevokeAccessToken::new(self, client_id, client_secret, token)
+    }
+
     /// Update the current user.
     ///
     /// All paramaters are optional. If the username is changed, it may cause the discriminator to
@@ -861,9 +1104,10 @@ impl Client {
 
     /// Add a user to a guild.
     ///
-    /// An access token for the user with `guilds.join` scope is required. All
-    /// other fields are optional. Refer to [the discord docs] for more
-    /// information.
+    /// An access token for the user with `guilds.join` scope is required, such
+    /// as one obtained via [`exchange_code`]. The client must be authorized
+    /// with a bot token of the same application. All other fields are
+    /// optional. Refer to [the discord docs] for more information.
     ///
     /// # Errors
     ///
@@ -871,6 +1115,7 @@ impl Client {
     /// too short or too long.
     ///
     /// [`AddGuildMemberErrorType::NickNameInvalid`]: crate::request::guild::member::add_guild_member::AddGuildMemberErrorType::NicknameInvalid
+    /// [`exchange_code`]: Self::exchange_code
     ///
     /// [the discord docs]: https://discord.com/developers/docs/resources/guild#add-guild-member
     pub fn add_guild_member(
@@ -1225,7 +1470,8 @@ impl Client {
     /// Get a list of users that reacted to a message with an `emoji`.
     ///
     /// This endpoint is limited to 100 users maximum, so if a message has more than 100 reactions,
-    /// requests must be chained until all reactions are retireved.
+    /// requests must be chained until all reactions are retireved. Use [`GetReactions::stream`] to
+    /// page through all of them.
     pub fn reactions(
         &self,
         channel_id: ChannelId,
@@ -1406,6 +1652,104 @@ impl Client {
         DeleteStageInstance::new(self, channel_id)
     }
 
+    /// Create a thread in a channel that is not started from a message.
+    ///
+    /// The kind of the thread must be one of the thread channel types.
+    /// Private threads require the guild to have the `PRIVATE_THREADS`
+    /// feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CreateThreadErrorType::NameInvalid`] error type if the
+    /// name is invalid, or a [`CreateThreadErrorType::TypeInvalid`] error
+    /// type if the kind is not a thread type.
+    ///
+    /// [`CreateThreadErrorType::NameInvalid`]: crate::request::channel::thread::create_thread::CreateThreadErrorType::NameInvalid
+    /// [`CreateThreadErrorType::TypeInvalid`]: crate::request::channel::thread::create_thread::CreateThreadErrorType::TypeInvalid
+    pub fn create_thread(
+        &self,
+        channel_id: ChannelId,
+        name: impl Into<String>,
+        kind: ChannelType,
+    ) -> Result<CreateThread<'_>, CreateThreadError> {
+        CreateThread::new(self, channel_id, name, kind)
+    }
+
+    /// Create a thread from a message.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CreateThreadFromMessageErrorType::NameInvalid`] error type
+    /// if the name is invalid.
+    ///
+    /// [`CreateThreadFromMessageErrorType::NameInvalid`]: crate::request::channel::thread::create_thread_from_message::CreateThreadFromMessageErrorType::NameInvalid
+    pub fn create_thread_from_message(
+        &self,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        name: impl Into<String>,
+    ) -> Result<CreateThreadFromMessage<'_>, CreateThreadFromMessageError> {
+        CreateThreadFromMessage::new(self, channel_id, message_id, name)
+    }
+
+    /// Update a thread.
+    pub fn update_thread(&self, channel_id: ChannelId) -> UpdateThread<'_> {
+        UpdateThread::new(self, channel_id)
+    }
+
+    /// Add the current user to a thread.
+    pub fn join_thread(&self, channel_id: ChannelId) -> JoinThread<'_> {
+        JoinThread::new(self, channel_id)
+    }
+
+    /// Remove the current user from a thread.
+    pub fn leave_thread(&self, channel_id: ChannelId) -> LeaveThread<'_> {
+        LeaveThread::new(self, channel_id)
+    }
+
+    /// Add a member to a thread.
+    pub fn add_thread_member(&self, channel_id: ChannelId, user_id: UserId) -> AddThreadMember<'_> {
+        AddThreadMember::new(self, channel_id, user_id)
+    }
+
+    /// Remove a member from a thread.
+    pub fn remove_thread_member(
+        &self,
+        channel_id: ChannelId,
+        user_id: UserId,
+    ) -> RemoveThreadMember<'_> {
+        RemoveThreadMember::new(self, channel_id, user_id)
+    }
+
+    /// Get the members of a thread.
+    pub fn thread_members(&self, channel_id: ChannelId) -> GetThreadMembers<'_> {
+        GetThreadMembers::new(self, channel_id)
+    }
+
+    /// Get the active threads in a guild.
+    pub fn active_threads(&self, guild_id: GuildId) -> GetActiveThreads<'_> {
+        GetActiveThreads::new(self, guild_id)
+    }
+
+    /// Get the archived public threads in a channel.
+    pub fn public_archived_threads(&self, channel_id: ChannelId) -> GetPublicArchivedThreads<'_> {
+        GetPublicArchivedThreads::new(self, channel_id)
+    }
+
+    /// Get the archived private threads in a channel.
+    pub fn private_archived_threads(&self, channel_id: ChannelId) -> GetPrivateArchivedThreads<'_> {
+        GetPrivateArchivedThreads::new(self, channel_id)
+    }
+
+    /// Get the archived private threads in a channel that the current user
+    /// has joined.
+    pub fn joined_private_archived_threads(
+        &self,
+        channel_id: ChannelId,
+    ) -> GetJoinedPrivateArchivedThreads<'_> {
+        GetJoinedPrivateArchivedThreads::new(self, channel_id)
+    }
+
     /// Create a new guild based on a template.
     ///
     /// This endpoint can only be used by bots in less than 10 guilds.
@@ -1652,6 +1996,17 @@ impl Client {
     }
 
     /// Respond to an interaction, by ID and token.
+    ///
+    /// The response is validated when the request is awaited. If it's invalid
+    /// then an [`ErrorType::BuildingRequest`] error type is returned, whose
+    /// source is an [`InteractionError`]:
+    ///
+    /// - An [`InteractionErrorType::ComponentsValidationFailed`] error type
+    ///   if the components of the response are invalid.
+    /// - An [`InteractionErrorType::TooManyAutocompleteChoices`] error type if
+    ///   an autocomplete result has more than 25 choices.
+    /// - An [`InteractionErrorType::ModalValidationFailed`] error type if the
+    ///   modal of the response is invalid.
     pub fn interaction_callback(
         &self,
         interaction_id: InteractionId,
@@ -1674,6 +2029,7 @@ impl Client {
     ) -> Result<GetOriginalResponse<'_>, InteractionError> {
         let application_id = self.application_id().ok_or(InteractionError {
             kind: InteractionErrorType::ApplicationIdNotPresent,
+            source: None,
         })?;
 
         Ok(GetOriginalResponse::new(
@@ -1696,6 +2052,7 @@ impl Client {
     ) -> Result<UpdateOriginalResponse<'_>, InteractionError> {
         let application_id = self.application_id().ok_or(InteractionError {
             kind: InteractionErrorType::ApplicationIdNotPresent,
+            source: None,
         })?;
 
         Ok(UpdateOriginalResponse::new(
@@ -1718,6 +2075,7 @@ impl Client {
     ) -> Result<DeleteOriginalResponse<'_>, InteractionError> {
         let application_id = self.application_id().ok_or(InteractionError {
             kind: InteractionErrorType::ApplicationIdNotPresent,
+            source: None,
         })?;
 
         Ok(DeleteOriginalResponse::new(
@@ -1740,6 +2098,7 @@ impl Client {
     ) -> Result<CreateFollowupMessage<'_>, InteractionError> {
         let application_id = self.application_id().ok_or(InteractionError {
             kind: InteractionErrorType::ApplicationIdNotPresent,
+            source: None,
         })?;
 
         Ok(CreateFollowupMessage::new(
@@ -1763,6 +2122,7 @@ impl Client {
     ) -> Result<UpdateFollowupMessage<'_>, InteractionError> {
         let application_id = self.application_id().ok_or(InteractionError {
             kind: InteractionErrorType::ApplicationIdNotPresent,
+            source: None,
         })?;
 
         Ok(UpdateFollowupMessage::new(
@@ -1787,6 +2147,7 @@ impl Client {
     ) -> Result<DeleteFollowupMessage<'_>, InteractionError> {
         let application_id = self.application_id().ok_or(InteractionError {
             kind: InteractionErrorType::ApplicationIdNotPresent,
+            source: None,
         })?;
 
         Ok(DeleteFollowupMessage::new(
@@ -1827,11 +2188,47 @@ impl Client {
     ) -> Result<CreateGuildCommand<'_>, InteractionError> {
         let application_id = self.application_id().ok_or(InteractionError {
             kind: InteractionErrorType::ApplicationIdNotPresent,
+            source: None,
         })?;
 
         CreateGuildCommand::new(&self, application_id, guild_id, name, description)
     }
 
+    /// Create a new context menu command in a guild.
+    ///
+    /// Context menu commands are used by right clicking a user or message,
+    /// depending on their type, and have no description or options. Creating a
+    /// guild command with the same name and type as an already-existing guild
+    /// command in the same guild will overwrite the old command. See
+    /// [the discord docs] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InteractionErrorType::ApplicationIdNotPresent`]
+    /// error type if an application ID has not been configured via
+    /// [`Client::set_application_id`].
+    ///
+    /// Returns an [`InteractionErrorType::CommandNameValidationFailed`]
+    /// error type if the command name is not between 1 and 32 characters.
+    ///
+    /// Returns an [`InteractionErrorType::CommandTypeInvalid`] error type if
+    /// the command type is [`CommandType::ChatInput`].
+    ///
+    /// [the discord docs]: https://discord.com/developers/docs/interactions/application-commands#contextmenus
+    pub fn create_guild_context_menu_command(
+        &self,
+        guild_id: GuildId,
+        name: impl Into<String>,
+        kind: CommandType,
+    ) -> Result<CreateGuildCommand<'_>, InteractionError> {
+        let application_id = self.application_id().ok_or(InteractionError {
+            kind: InteractionErrorType::ApplicationIdNotPresent,
+            source: None,
+        })?;
+
+        CreateGuildCommand::context_menu(self, application_id, guild_id, name, kind)
+    }
+
     /// Fetch all commands for a guild, by ID.
     ///
     /// # Errors
@@ -1845,6 +2242,7 @@ impl Client {
     ) -> Result<GetGuildCommands<'_>, InteractionError> {
         let application_id = self.application_id().ok_or(InteractionError {
             kind: InteractionErrorType::ApplicationIdNotPresent,
+            source: None,
         })?;
 
         Ok(GetGuildCommands::new(self, application_id, guild_id))
@@ -1869,6 +2267,7 @@ impl Client {
     ) -> Result<UpdateGuildCommand<'_>, InteractionError> {
         let application_id = self.application_id().ok_or(InteractionError {
             kind: InteractionErrorType::ApplicationIdNotPresent,
+            source: None,
         })?;
 
         Ok(UpdateGuildCommand::new(
@@ -1893,6 +2292,7 @@ impl Client {
     ) -> Result<DeleteGuildCommand<'_>, InteractionError> {
         let application_id = self.application_id().ok_or(InteractionError {
             kind: InteractionErrorType::ApplicationIdNotPresent,
+            source: None,
         })?;
 
         Ok(DeleteGuildCommand::new(
@@ -1913,6 +2313,10 @@ impl Client {
     /// Returns an [`InteractionErrorType::ApplicationIdNotPresent`]
     /// error type if an application ID has not been configured via
     /// [`Client::set_application_id`].
+    ///
+    /// Returns an [`InteractionErrorType::CommandOptionAutocompleteChoices`]
+    /// error type if an option of a command, or an option nested under it, is
+    /// autocompleted and has choices.
     pub fn set_guild_commands(
         &self,
         guild_id: GuildId,
@@ -1920,14 +2324,10 @@ impl Client {
     ) -> Result<SetGuildCommands<'_>, InteractionError> {
         let application_id = self.application_id().ok_or(InteractionError {
             kind: InteractionErrorType::ApplicationIdNotPresent,
+            source: None,
         })?;
 
-        Ok(SetGuildCommands::new(
-            self,
-            application_id,
-            guild_id,
-            commands,
-        ))
+        SetGuildCommands::new(self, application_id, guild_id, commands)
     }
 
     /// Create a new global command.
@@ -1958,11 +2358,63 @@ impl Client {
     ) -> Result<CreateGlobalCommand<'_>, InteractionError> {
         let application_id = self.application_id().ok_or(InteractionError {
             kind: InteractionErrorType::ApplicationIdNotPresent,
+            source: None,
         })?;
 
         CreateGlobalCommand::new(self, application_id, name, description)
     }
 
+    /// Create a new global context menu command.
+    ///
+    /// Context menu commands are used by right clicking a user or message,
+    /// depending on their type, and have no description or options. Creating a
+    /// command with the same name and type as an already-existing global
+    /// command will overwrite the old command. See [the discord docs] for more
+    /// information.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use twilight_http::Client;
+    /// use twilight_model::{application::command::CommandType, id::ApplicationId};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new("token");
+    /// client.set_application_id(ApplicationId(1));
+    ///
+    /// client
+    ///     .create_global_context_menu_command("Report message", CommandType::Message)?
+    ///     .await?;
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InteractionErrorType::ApplicationIdNotPresent`]
+    /// error type if an application ID has not been configured via
+    /// [`Client::set_application_id`].
+    ///
+    /// Returns an [`InteractionErrorType::CommandNameValidationFailed`]
+    /// error type if the command name is not between 1 and 32 characters.
+    ///
+    /// Returns an [`InteractionErrorType::CommandTypeInvalid`] error type if
+    /// the command type is [`CommandType::ChatInput`].
+    ///
+    /// [the discord docs]: https://discord.com/developers/docs/interactions/application-commands#contextmenus
+    pub fn create_global_context_menu_command(
+        &self,
+        name: impl Into<String>,
+        kind: CommandType,
+    ) -> Result<CreateGlobalCommand<'_>, InteractionError> {
+        let application_id = self.application_id().ok_or(InteractionError {
+            kind: InteractionErrorType::ApplicationIdNotPresent,
+            source: None,
+        })?;
+
+        CreateGlobalCommand::context_menu(self, application_id, name, kind)
+    }
+
     /// Fetch all global commands for your application.
     ///
     /// # Errors
@@ -1973,6 +2425,7 @@ impl Client {
     pub fn get_global_commands(&self) -> Result<GetGlobalCommands<'_>, InteractionError> {
         let application_id = self.application_id().ok_or(InteractionError {
             kind: InteractionErrorType::ApplicationIdNotPresent,
+            source: None,
         })?;
 
         Ok(GetGlobalCommands::new(self, application_id))
@@ -1996,6 +2449,7 @@ impl Client {
     ) -> Result<UpdateGlobalCommand<'_>, InteractionError> {
         let application_id = self.application_id().ok_or(InteractionError {
             kind: InteractionErrorType::ApplicationIdNotPresent,
+            source: None,
         })?;
 
         Ok(UpdateGlobalCommand::new(self, application_id, command_id))
@@ -2014,6 +2468,7 @@ impl Client {
     ) -> Result<DeleteGlobalCommand<'_>, InteractionError> {
         let application_id = self.application_id().ok_or(InteractionError {
             kind: InteractionErrorType::ApplicationIdNotPresent,
+            source: None,
         })?;
 
         Ok(DeleteGlobalCommand::new(self, application_id, command_id))
@@ -2029,15 +2484,20 @@ impl Client {
     /// Returns an [`InteractionErrorType::ApplicationIdNotPresent`]
     /// error type if an application ID has not been configured via
     /// [`Client::set_application_id`].
+    ///
+    /// Returns an [`InteractionErrorType::CommandOptionAutocompleteChoices`]
+    /// error type if an option of a command, or an option nested under it, is
+    /// autocompleted and has choices.
     pub fn set_global_commands(
         &self,
         commands: Vec<Command>,
     ) -> Result<SetGlobalCommands<'_>, InteractionError> {
         let application_id = self.application_id().ok_or(InteractionError {
             kind: InteractionErrorType::ApplicationIdNotPresent,
+            source: None,
         })?;
 
-        Ok(SetGlobalCommands::new(self, application_id, commands))
+        SetGlobalCommands::new(self, application_id, commands)
     }
 
     /// Fetch command permissions for a command from the current application
@@ -2055,6 +2515,7 @@ impl Client {
     ) -> Result<GetCommandPermissions<'_>, InteractionError> {
         let application_id = self.application_id().ok_or(InteractionError {
             kind: InteractionErrorType::ApplicationIdNotPresent,
+            source: None,
         })?;
 
         Ok(GetCommandPermissions::new(
@@ -2079,6 +2540,7 @@ impl Client {
     ) -> Result<GetGuildCommandPermissions<'_>, InteractionError> {
         let application_id = self.application_id().ok_or(InteractionError {
             kind: InteractionErrorType::ApplicationIdNotPresent,
+            source: None,
         })?;
 
         Ok(GetGuildCommandPermissions::new(
@@ -2106,6 +2568,7 @@ impl Client {
     ) -> Result<UpdateCommandPermissions<'_>, InteractionError> {
         let application_id = self.application_id().ok_or(InteractionError {
             kind: InteractionErrorType::ApplicationIdNotPresent,
+            source: None,
         })?;
 
         UpdateCommandPermissions::new(self, application_id, guild_id, command_id, permissions)
@@ -2128,36 +2591,129 @@ impl Client {
     ) -> Result<SetCommandPermissions<'_>, InteractionError> {
         let application_id = self.application_id().ok_or(InteractionError {
             kind: InteractionErrorType::ApplicationIdNotPresent,
+            source: None,
         })?;
 
         SetCommandPermissions::new(self, application_id, guild_id, permissions)
     }
 
+    /// Send the requests left over in the outbox, such as from before a
+    /// restart.
+    ///
+    /// Entries are sent in the order in which they were created, applying
+    /// the configured [`RetryPolicy`]. Delivered entries and entries the API
+    /// permanently rejected are removed from the outbox, while entries that
+    /// still can't be delivered remain in it.
+    ///
+    /// Entries whose requests are still being sent, whether by a request
+    /// that is in flight or by a concurrent replay, are skipped and counted as
+    /// pending, so an entry is never sent twice at the same time.
+    ///
+    /// Returns an empty replay if no outbox has been configured.
+    ///
+    /// Refer to the [`outbox`] module for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ErrorType::OutboxStore`] error type if the entries
+    /// couldn't be loaded from the store.
+    ///
+    /// [`outbox`]: crate::outbox
+    pub async fn replay_outbox(&self) -> Result<OutboxReplay, Error> {
+        let mut replay = OutboxReplay::default();
+
+        let store = match &self.state.outbox {
+            Some(store) => store,
+            None => return Ok(replay),
+        };
+
+        let mut entries = store.load().await.map_err(|source| Error {
+            kind: ErrorType::OutboxStore,
+            source: Some(source),
+        })?;
+        entries.sort_by_key(OutboxEntry::id);
+
+        for entry in entries {
+            let _in_flight = if let Some(guard) = self.state.outbox_in_flight.claim(entry.id()) {
+                guard
+            } else {
+                replay.pending += 1;
+
+                continue;
+            };
+
+            let result = match entry.to_request() {
+                Ok(request) => self.raw_inner(request, Some(entry.id())).await,
+                Err(source) => {
+                    Self::remove_outbox_entry(store.as_ref(), entry.id()).await;
+
+                    Err(source)
+                }
+            };
+
+            if !outbox::is_settled(&result) {
+                replay.pending += 1;
+
+                continue;
+            }
+
+            let checked = match result {
+                Ok(response) => Self::check_response(response).await,
+                Err(source) => Err(source),
+            };
+
+            match checked {
+                Ok(_) => replay.delivered += 1,
+                Err(error) => replay.failed.push(FailedEntry { entry, error }),
+            }
+        }
+
+        Ok(replay)
+    }
+
     /// Execute a request, returning the response.
     ///
+    /// If a [`RetryPolicy`] is configured then failed attempts will be
+    /// retried according to it, and the response of the last attempt will be
+    /// returned.
+    ///
     /// # Errors
     ///
     /// Returns an [`ErrorType::Unauthorized`] error type if the configured
     /// token has become invalid due to expiration, revokation, etc.
-    #[allow(clippy::too_many_lines)]
+    ///
+    /// Returns an [`ErrorType::OutboxStore`] error type if an outbox is
+    /// configured and the request couldn't be saved to it.
     pub async fn raw(&self, request: Request) -> Result<Response<Body>, Error> {
-        if self.state.token_invalid.load(Ordering::Relaxed) {
-            return Err(Error {
-                kind: ErrorType::Unauthorized,
-                source: None,
-            });
-        }
+        self.raw_inner(request, None).await
+    }
 
+    /// Execute a request, saving it to the outbox if one is configured.
+    ///
+    /// If the request is replayed from the outbox then the ID of its entry
+    /// is provided, in which case it isn't saved again.
+    #[allow(clippy::too_many_lines)]
+    async fn raw_inner(
+        &self,
+        request: Request,
+        outbox_id: Option<u64>,
+    ) -> Result<Response<Body>, Error> {
         let Request {
             body,
+            durable,
             form,
             headers: req_headers,
             method,
             path: bucket,
             path_str: path,
+            priority,
             use_authorization_token,
         } = request;
 
+        let priority = priority
+            .or(self.priority)
+            .unwrap_or_else(|| Priority::from_path(&bucket));
+
         let protocol = if self.state.use_http { "http" } else { "https" };
         let host = self.state.proxy.as_deref().unwrap_or("discord.com");
 
@@ -2165,9 +2721,7 @@ impl Client {
         #[cfg(feature = "tracing")]
         tracing::debug!("URL: {:?}", url);
 
-        let mut builder = hyper::Request::builder()
-            .method(method.into_hyper())
-            .uri(&url);
+        let mut headers = HeaderMap::new();
 
         if use_authorization_token {
             if let Some(ref token) = self.state.token {
@@ -2181,9 +2735,7 @@ impl Client {
                     }
                 })?;
 
-                if let Some(headers) = builder.headers_mut() {
-                    headers.insert(AUTHORIZATION, value);
-                }
+                headers.insert(AUTHORIZATION, value);
             }
         }
 
@@ -2195,75 +2747,240 @@ impl Client {
             ") Twilight-rs",
         ));
 
-        if let Some(headers) = builder.headers_mut() {
-            if let Some(form) = &form {
-                if let Ok(content_type) = HeaderValue::try_from(form.content_type()) {
-                    headers.insert(CONTENT_TYPE, content_type);
-                }
-            } else if let Some(bytes) = &body {
-                let len = bytes.len();
-                headers.insert(CONTENT_LENGTH, len.into());
-
-                let content_type = HeaderValue::from_static("application/json");
+        if let Some(form) = &form {
+            if let Ok(content_type) = HeaderValue::try_from(form.content_type()) {
                 headers.insert(CONTENT_TYPE, content_type);
             }
+        } else if let Some(bytes) = &body {
+            let len = bytes.len();
+            headers.insert(CONTENT_LENGTH, len.into());
 
-            headers.insert(USER_AGENT, user_agent);
+            let content_type = HeaderValue::from_static("application/json");
+            headers.insert(CONTENT_TYPE, content_type);
+        }
 
-            if let Some(req_headers) = req_headers {
-                for (maybe_name, value) in req_headers {
-                    if let Some(name) = maybe_name {
-                        headers.insert(name, value);
-                    }
+        headers.insert(USER_AGENT, user_agent);
+
+        if let Some(req_headers) = req_headers {
+            for (maybe_name, value) in req_headers {
+                if let Some(name) = maybe_name {
+                    headers.insert(name, value);
                 }
             }
+        }
 
-            if let Some(default_headers) = &self.state.default_headers {
-                for (name, value) in default_headers {
-                    headers.insert(name, HeaderValue::from(value));
-                }
+        if let Some(default_headers) = &self.state.default_headers {
+            for (name, value) in default_headers {
+                headers.insert(name, HeaderValue::from(value));
             }
         }
 
-        let req = if let Some(form) = form {
-            let form_bytes = form.build();
-            if let Some(headers) = builder.headers_mut() {
-                headers.insert(CONTENT_LENGTH, form_bytes.len().into());
-            };
-            builder
-                .body(Body::from(form_bytes))
-                .map_err(|source| Error {
-                    kind: ErrorType::BuildingRequest,
-                    source: Some(Box::new(source)),
-                })?
-        } else if let Some(bytes) = body {
-            builder.body(Body::from(bytes)).map_err(|source| Error {
+        // Streamed form bodies can only be sent once, so they're taken by the
+        // first attempt.
+        let mut streamed_body = None;
+
+        let body = if let Some(form) = form {
+            let form_body = form.into_body().await.map_err(|source| Error {
                 kind: ErrorType::BuildingRequest,
                 source: Some(Box::new(source)),
-            })?
-        } else if method == Method::Put || method == Method::Post || method == Method::Patch {
-            if let Some(headers) = builder.headers_mut() {
+            })?;
+
+            match form_body {
+                FormBody::Buffered(form_bytes) => {
+                    headers.insert(CONTENT_LENGTH, form_bytes.len().into());
+
+                    Some(Bytes::from(form_bytes))
+                }
+                FormBody::Streamed { body, length } => {
+                    if let Some(length) = length {
+                        headers.insert(CONTENT_LENGTH, length.into());
+                    }
+
+                    streamed_body = Some(body);
+
+                    None
+                }
+            }
+        } else if let Some(bytes) = body {
+            Some(Bytes::from(bytes))
+        } else {
+            if method == Method::Put || method == Method::Post || method == Method::Patch {
                 headers.insert(CONTENT_LENGTH, 0.into());
             }
 
-            builder.body(Body::empty()).map_err(|source| Error {
-                kind: ErrorType::BuildingRequest,
-                source: Some(Box::new(source)),
-            })?
-        } else {
-            builder.body(Body::empty()).map_err(|source| Error {
-                kind: ErrorType::BuildingRequest,
-                source: Some(Box::new(source)),
-            })?
+            None
         };
 
+        if let Some(dry_run) = &self.state.dry_run {
+            if method != Method::Get {
+                return dry_run
+                    .intercept(method, bucket, &path, headers, body, streamed_body)
+                    .await;
+            }
+        }
+
+        let (outbox_id, _in_flight) = match &self.state.outbox {
+            Some(store)
+                if durable
+                    && outbox_id.is_none()
+                    && method != Method::Get
+                    && streamed_body.is_none() =>
+            {
+                let entry = OutboxEntry::new(
+                    method,
+                    &path,
+                    &headers,
+                    body.as_deref(),
+                    use_authorization_token,
+                );
+                let id = entry.id();
+
+                // Claim the entry before it's saved so that a concurrent
+                // replay doesn't send it as well.
+                let in_flight = self.state.outbox_in_flight.claim(id);
+
+                store.save(entry).await.map_err(|source| Error {
+                    kind: ErrorType::OutboxStore,
+                    source: Some(source),
+                })?;
+
+                (Some(id), in_flight)
+            }
+            _ => (outbox_id, None),
+        };
+
+        let retry_policy = self
+            .state
+            .retry_policy
+            .as_ref()
+            .filter(|policy| streamed_body.is_none() && policy.applies_to(method));
+        let mut attempts = 1;
+
+        let result = loop {
+            let mut builder = hyper::Request::builder()
+                .method(method.into_hyper())
+                .uri(&url);
+
+            if let Some(builder_headers) = builder.headers_mut() {
+                builder_headers.extend(headers.clone());
+            }
+
+            let req = match builder.body(
+                streamed_body
+                    .take()
+                    .unwrap_or_else(|| body.clone().map_or_else(Body::empty, Body::from)),
+            ) {
+                Ok(req) => req,
+                Err(source) => {
+                    break Err(Error {
+                        kind: ErrorType::BuildingRequest,
+                        source: Some(Box::new(source)),
+                    })
+                }
+            };
+
+            let result = Next::new(self, &self.state.middleware)
+                .run(MiddlewareRequest::new(
+                    req,
+                    bucket.clone(),
+                    priority,
+                    durable,
+                ))
+                .await;
+
+            let policy = match retry_policy {
+                Some(policy) if policy.can_retry(attempts) => policy,
+                _ => break result,
+            };
+
+            let delay = match result {
+                Ok(resp) if policy.retries_status(resp.status()) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!(status = %resp.status(), attempts, "retrying response");
+
+                    policy.response_delay(resp, attempts).await
+                }
+                Err(source) if policy.retries_error(&source) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!(error = %source, attempts, "retrying failed request");
+
+                    policy.backoff(attempts)
+                }
+                other => break other,
+            };
+
+            time::sleep(delay).await;
+            attempts += 1;
+        };
+
+        if let (Some(store), Some(id)) = (&self.state.outbox, outbox_id) {
+            if outbox::is_settled(&result) {
+                Self::remove_outbox_entry(store.as_ref(), id).await;
+            }
+        }
+
+        result
+    }
+
+    /// Remove an entry from the outbox.
+    ///
+    /// The request has already completed, so failing to remove its entry
+    /// only means it will be replayed again.
+    async fn remove_outbox_entry(store: &dyn OutboxStore, id: u64) {
+        #[allow(unused_variables)]
+        if let Err(source) = store.remove(id).await {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(id, error = %source, "failed to remove entry from the outbox");
+        }
+    }
+
+    /// Send a single attempt of a request through the ratelimiter, if one is
+    /// configured.
+    pub(crate) async fn send(
+        &self,
+        bucket: Path,
+        priority: Priority,
+        req: hyper::Request<Body>,
+    ) -> Result<Response<Body>, Error> {
+        // Requests not authorized with the configured token, such as OAuth2
+        // token exchanges, neither depend on nor reveal its validity.
+        let authorized = req.headers().contains_key(AUTHORIZATION);
+
+        if authorized && self.state.token_invalid.load(Ordering::Relaxed) {
+            return Err(Error {
+                kind: ErrorType::Unauthorized,
+                source: None,
+            });
+        }
+
+        if let Some(limit) = &self.state.invalid_request_limit {
+            match limit.action(self.state.invalid_requests.count()) {
+                InvalidRequestAction::Proceed => {}
+                InvalidRequestAction::Refuse => {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!("refusing request: invalid request limit reached");
+
+                    return Err(Error {
+                        kind: ErrorType::InvalidRequestLimitReached,
+                        source: None,
+                    });
+                }
+                InvalidRequestAction::SlowDown(delay) => {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!("slowing down request: invalid request limit nearing");
+
+                    time::sleep(delay).await;
+                }
+            }
+        }
+
         let inner = self.state.http.request(req);
         let fut = time::timeout(self.state.timeout, inner);
 
-        let ratelimiter = match self.state.ratelimiter.as_ref() {
+        let ratelimiter = match self.ratelimiter() {
             Some(ratelimiter) => ratelimiter,
             None => {
-                return fut
+                let resp = fut
                     .await
                     .map_err(|source| Error {
                         kind: ErrorType::RequestTimedOut,
@@ -2272,12 +2989,34 @@ impl Client {
                     .map_err(|source| Error {
                         kind: ErrorType::RequestError,
                         source: Some(Box::new(source)),
-                    });
+                    })?;
+
+                self.state
+                    .invalid_requests
+                    .record(resp.status(), resp.headers());
+
+                return Ok(resp);
             }
         };
 
-        let rx = ratelimiter.get(bucket).await;
-        let tx = rx.await.map_err(|source| Error {
+        let rx = ratelimiter
+            .ticket_with_priority(bucket, priority)
+            .await
+            .map_err(|source| Error {
+                kind: ErrorType::RatelimiterTicket,
+                source: Some(source),
+            })?;
+
+        let ticket = match self.state.max_background_queue_wait {
+            Some(max_wait) if priority == Priority::Background => {
+                time::timeout(max_wait, rx).await.map_err(|source| Error {
+                    kind: ErrorType::QueueWaitExceeded,
+                    source: Some(Box::new(source)),
+                })?
+            }
+            _ => rx.await,
+        };
+        let tx = ticket.map_err(|source| Error {
             kind: ErrorType::RequestCanceled,
             source: Some(Box::new(source)),
         })?;
@@ -2296,20 +3035,24 @@ impl Client {
         // If the API sent back an Unauthorized response, then the client's
         // configured token is permanently invalid and future requests must be
         // ignored to avoid API bans.
-        if resp.status() == StatusCode::UNAUTHORIZED {
+        if authorized && resp.status() == StatusCode::UNAUTHORIZED {
             self.state.token_invalid.store(true, Ordering::Relaxed);
         }
 
+        self.state
+            .invalid_requests
+            .record(resp.status(), resp.headers());
+
         match RatelimitHeaders::try_from(resp.headers()) {
             Ok(v) => {
-                let _res = tx.send(Some(v));
+                let _res = tx.headers(Some(v));
             }
             #[allow(unused_variables)]
             Err(why) => {
                 #[cfg(feature = "tracing")]
                 tracing::warn!("header parsing failed: {:?}; {:?}", why, resp);
 
-                let _res = tx.send(None);
+                let _res = tx.headers(None);
             }
         }
 
@@ -2323,27 +3066,65 @@ impl Client {
     /// Returns an [`ErrorType::Unauthorized`] error type if the configured
     /// token has become invalid due to expiration, revokation, etc.
     pub async fn request<T: DeserializeOwned>(&self, request: Request) -> Result<T, Error> {
-        let resp = self.make_request(request).await?;
-
-        let bytes = body::to_bytes(resp.into_body())
-            .await
-            .map_err(|source| Error {
-                kind: ErrorType::ChunkingResponse,
-                source: Some(Box::new(source)),
-            })?;
+        let bytes = self.request_bytes(request).await?;
 
         crate::json::parse_bytes(&bytes)
     }
 
     pub(crate) async fn request_bytes(&self, request: Request) -> Result<Bytes, Error> {
-        let resp = self.make_request(request).await?;
+        let coalescer = match &self.state.coalescer {
+            Some(coalescer) if request.method == Method::Get && request.headers.is_none() => {
+                coalescer
+            }
+            _ => return self.response::<()>(request).await?.bytes().await,
+        };
+
+        match coalescer.join(&request.path_str, request.use_authorization_token) {
+            Join::Leader(guard) => {
+                let result = match self.response::<()>(request).await {
+                    Ok(response) => response.bytes().await,
+                    Err(source) => Err(source),
+                };
+                guard.complete(&result);
+
+                result
+            }
+            Join::Follower(rx) => {
+                // If the identical request was cancelled, send the request
+                // instead.
+                let outcome = match rx.await {
+                    Ok(Some(outcome)) => outcome,
+                    _ => return self.response::<()>(request).await?.bytes().await,
+                };
+
+                coalescer.record_saved();
+
+                outcome.map_err(|kind| Error { kind, source: None })
+            }
+        }
+    }
 
-        hyper::body::to_bytes(resp.into_body())
+    /// Execute a request, returning the response without reading its body.
+    ///
+    /// The body can be read and deserialized into `T` via the methods of the
+    /// returned [`Response`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ErrorType::Unauthorized`] error type if the configured
+    /// token has become invalid due to expiration, revokation, etc.
+    ///
+    /// Returns an [`ErrorType::Response`] error type if the response doesn't
+    /// have a success status code.
+    ///
+    /// [`Response`]: crate::response::Response
+    pub async fn response<T>(
+        &self,
+        request: Request,
+    ) -> Result<crate::response::Response<T>, Error> {
+        self.make_request(request)
             .await
-            .map_err(|source| Error {
-                kind: ErrorType::ChunkingResponse,
-                source: Some(Box::new(source)),
-            })
+            .map(crate::response::Response::new)
     }
 
     /// Execute a request, checking only that the response was a success.
@@ -2362,6 +3143,13 @@ impl Client {
 
     async fn make_request(&self, request: Request) -> Result<Response<Body>, Error> {
         let resp = self.raw(request).await?;
+
+        Self::check_response(resp).await
+    }
+
+    /// Check that a response has a success status code, returning an error
+    /// containing the response of the API otherwise.
+    async fn check_response(resp: Response<Body>) -> Result<Response<Body>, Error> {
         let status = resp.status();
 
         if status.is_success() {
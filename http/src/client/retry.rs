@@ -0,0 +1,346 @@
+use crate::{
+    api_error::RatelimitedApiError,
+    error::{Error, ErrorType},
+    request::Method,
+};
+use hyper::{body, Body, Response, StatusCode};
+use rand::Rng;
+use std::{convert::TryFrom, time::Duration};
+
+/// Smallest number of seconds that can't be represented by a [`Duration`],
+/// which is 2^64.
+const DURATION_SECS_OVERFLOW: f64 = 18_446_744_073_709_551_616.0;
+
+/// Policy for automatically retrying failed requests.
+///
+/// Requests are retried when a connection error occurs, when a request times
+/// out, or when the response has one of the configured retryable status
+/// codes. Between attempts the client waits with exponential backoff and
+/// jitter, unless the response is a ratelimit that provides a
+/// [`retry_after`] duration, which is honoured instead.
+///
+/// Only idempotent methods (`DELETE`, `GET` and `PUT`) are retried by default,
+/// since retrying a `PATCH` or `POST` request may apply it twice. Use
+/// [`retry_non_idempotent`] to opt in to retrying them.
+///
+/// # Examples
+///
+/// Create a client that attempts requests up to 5 times, waiting at least
+/// 250 milliseconds between attempts:
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use twilight_http::{client::RetryPolicy, Client};
+///
+/// let policy = RetryPolicy::new()
+///     .max_attempts(5)
+///     .base_delay(Duration::from_millis(250));
+///
+/// let client = Client::builder()
+///     .token("my token")
+///     .retry_policy(policy)
+///     .build();
+/// ```
+///
+/// [`retry_after`]: crate::api_error::RatelimitedApiError::retry_after
+/// [`retry_non_idempotent`]: Self::retry_non_idempotent
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RetryPolicy {
+    base_delay: Duration,
+    jitter: bool,
+    max_attempts: u32,
+    max_delay: Duration,
+    request_errors: bool,
+    retry_non_idempotent: bool,
+    status_codes: Vec<StatusCode>,
+    timeouts: bool,
+}
+
+impl RetryPolicy {
+    /// Create a new retry policy with the default settings.
+    ///
+    /// Requests are attempted up to 3 times, starting with a delay of 500
+    /// milliseconds and waiting no longer than 30 seconds between attempts.
+    /// Connection errors, timeouts and the status codes 429, 500, 502, 503
+    /// and 504 are retried.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the initial delay between attempts.
+    ///
+    /// The delay doubles after each failed attempt.
+    ///
+    /// The default is 500 milliseconds.
+    pub const fn base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+
+        self
+    }
+
+    /// Set whether to randomize the delay between attempts.
+    ///
+    /// When enabled the delay is randomly chosen between half of and the full
+    /// computed delay, which prevents many clients from retrying in lockstep.
+    ///
+    /// The default is true.
+    pub const fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+
+        self
+    }
+
+    /// Set the maximum number of attempts, including the first.
+    ///
+    /// A value of 1 or less disables retrying.
+    ///
+    /// The default is 3.
+    pub const fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts;
+
+        self
+    }
+
+    /// Set the maximum delay between attempts.
+    ///
+    /// This does not limit delays provided by the API via ratelimit
+    /// responses.
+    ///
+    /// The default is 30 seconds.
+    pub const fn max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+
+        self
+    }
+
+    /// Set whether to retry requests that failed to send or whose response
+    /// failed to be received, resulting in an [`ErrorType::RequestError`].
+    ///
+    /// The default is true.
+    pub const fn request_errors(mut self, request_errors: bool) -> Self {
+        self.request_errors = request_errors;
+
+        self
+    }
+
+    /// Set whether to retry requests with methods that aren't idempotent,
+    /// which are `PATCH` and `POST`.
+    ///
+    /// The default is false.
+    pub const fn retry_non_idempotent(mut self, retry_non_idempotent: bool) -> Self {
+        self.retry_non_idempotent = retry_non_idempotent;
+
+        self
+    }
+
+    /// Set the response status codes that are retried.
+    ///
+    /// The default is 429, 500, 502, 503 and 504.
+    pub fn status_codes(mut self, status_codes: impl IntoIterator<Item = StatusCode>) -> Self {
+        self.status_codes = status_codes.into_iter().collect();
+
+        self
+    }
+
+    /// Set whether to retry requests that timed out, resulting in an
+    /// [`ErrorType::RequestTimedOut`].
+    ///
+    /// The default is true.
+    pub const fn timeouts(mut self, timeouts: bool) -> Self {
+        self.timeouts = timeouts;
+
+        self
+    }
+
+    /// Whether requests with the given method may be retried.
+    pub(super) const fn applies_to(&self, method: Method) -> bool {
+        self.max_attempts > 1
+            && (self.retry_non_idempotent || !matches!(method, Method::Patch | Method::Post))
+    }
+
+    /// Whether another attempt may be made after the given number of
+    /// attempts.
+    pub(super) const fn can_retry(&self, attempts: u32) -> bool {
+        attempts < self.max_attempts
+    }
+
+    /// Whether an error returned when sending a request is retryable.
+    pub(super) const fn retries_error(&self, error: &Error) -> bool {
+        match error.kind() {
+            ErrorType::RequestError => self.request_errors,
+            ErrorType::RequestTimedOut => self.timeouts,
+            _ => false,
+        }
+    }
+
+    /// Whether a response with the given status code is retryable.
+    pub(super) fn retries_status(&self, status: StatusCode) -> bool {
+        self.status_codes.contains(&status)
+    }
+
+    /// Delay to wait before the next attempt after the given number of
+    /// attempts.
+    pub(super) fn backoff(&self, attempts: u32) -> Duration {
+        let factor = 2_u32.saturating_pow(attempts.saturating_sub(1));
+        let delay = self
+            .base_delay
+            .checked_mul(factor)
+            .map_or(self.max_delay, |delay| delay.min(self.max_delay));
+
+        if !self.jitter {
+            return delay;
+        }
+
+        let millis = u64::try_from(delay.as_millis()).unwrap_or(u64::MAX);
+        let half = millis / 2;
+
+        Duration::from_millis(half + rand::thread_rng().gen_range(0..=millis - half))
+    }
+
+    /// Delay to wait before retrying a request that received the given
+    /// response.
+    ///
+    /// Ratelimited responses provide the amount of time to wait, which is
+    /// preferred over the computed backoff. Durations too large to be
+    /// represented fall back to the maximum delay.
+    pub(super) async fn response_delay(&self, response: Response<Body>, attempts: u32) -> Duration {
+        if response.status() != StatusCode::TOO_MANY_REQUESTS {
+            return self.backoff(attempts);
+        }
+
+        let retry_after = match body::to_bytes(response.into_body()).await {
+            Ok(bytes) => crate::json::from_bytes::<RatelimitedApiError>(&bytes)
+                .ok()
+                .map(|ratelimited| ratelimited.retry_after),
+            Err(_) => None,
+        };
+
+        match retry_after {
+            // `Duration::from_secs_f64` panics if the value overflows.
+            Some(secs) if secs >= DURATION_SECS_OVERFLOW => self.max_delay,
+            Some(secs) if secs.is_finite() && secs >= 0. => Duration::from_secs_f64(secs),
+            _ => self.backoff(attempts),
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            base_delay: Duration::from_millis(500),
+            jitter: true,
+            max_attempts: 3,
+            max_delay: Duration::from_secs(30),
+            request_errors: true,
+            retry_non_idempotent: false,
+            status_codes: vec![
+                StatusCode::TOO_MANY_REQUESTS,
+                StatusCode::INTERNAL_SERVER_ERROR,
+                StatusCode::BAD_GATEWAY,
+                StatusCode::SERVICE_UNAVAILABLE,
+                StatusCode::GATEWAY_TIMEOUT,
+            ],
+            timeouts: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RetryPolicy;
+    use crate::{
+        error::{Error, ErrorType},
+        request::Method,
+    };
+    use hyper::{Body, Response, StatusCode};
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, time::Duration};
+
+    assert_impl_all!(RetryPolicy: Clone, Debug, Default, Eq, PartialEq, Send, Sync);
+
+    #[test]
+    fn test_applies_to() {
+        let policy = RetryPolicy::new();
+        assert!(policy.applies_to(Method::Delete));
+        assert!(policy.applies_to(Method::Get));
+        assert!(policy.applies_to(Method::Put));
+        assert!(!policy.applies_to(Method::Patch));
+        assert!(!policy.applies_to(Method::Post));
+
+        let policy = policy.retry_non_idempotent(true);
+        assert!(policy.applies_to(Method::Patch));
+        assert!(policy.applies_to(Method::Post));
+
+        assert!(!RetryPolicy::new().max_attempts(1).applies_to(Method::Get));
+    }
+
+    #[test]
+    fn test_backoff() {
+        let policy = RetryPolicy::new()
+            .base_delay(Duration::from_millis(100))
+            .jitter(false)
+            .max_delay(Duration::from_millis(350));
+
+        assert_eq!(Duration::from_millis(100), policy.backoff(1));
+        assert_eq!(Duration::from_millis(200), policy.backoff(2));
+        assert_eq!(Duration::from_millis(350), policy.backoff(3));
+        assert_eq!(Duration::from_millis(350), policy.backoff(u32::MAX));
+    }
+
+    #[test]
+    fn test_backoff_jitter() {
+        let policy = RetryPolicy::new().base_delay(Duration::from_millis(100));
+
+        for _ in 0..100 {
+            let delay = policy.backoff(2);
+            assert!(delay >= Duration::from_millis(100));
+            assert!(delay <= Duration::from_millis(200));
+        }
+    }
+
+    #[test]
+    fn test_retries_error() {
+        let error = |kind| Error { kind, source: None };
+        let policy = RetryPolicy::new();
+
+        assert!(policy.retries_error(&error(ErrorType::RequestError)));
+        assert!(policy.retries_error(&error(ErrorType::RequestTimedOut)));
+        assert!(!policy.retries_error(&error(ErrorType::Unauthorized)));
+
+        let policy = policy.request_errors(false).timeouts(false);
+        assert!(!policy.retries_error(&error(ErrorType::RequestError)));
+        assert!(!policy.retries_error(&error(ErrorType::RequestTimedOut)));
+    }
+
+    #[tokio::test]
+    async fn test_response_delay_retry_after() {
+        let policy = RetryPolicy::new();
+        let body = r#"{"global":false,"message":"You are being rate limited.","retry_after":1.5}"#;
+        let response = Response::builder()
+            .status(StatusCode::TOO_MANY_REQUESTS)
+            .body(Body::from(body))
+            .unwrap();
+
+        assert_eq!(
+            Duration::from_millis(1500),
+            policy.response_delay(response, 1).await
+        );
+    }
+
+    #[tokio::test]
+    async fn test_response_delay_retry_after_overflow() {
+        let policy = RetryPolicy::new().max_delay(Duration::from_secs(10));
+        let body =
+            r#"{"global":false,"message":"You are being rate limited.","retry_after":1e300}"#;
+        let response = Response::builder()
+            .status(StatusCode::TOO_MANY_REQUESTS)
+            .body(Body::from(body))
+            .unwrap();
+
+        assert_eq!(
+            Duration::from_secs(10),
+            policy.response_delay(response, 1).await
+        );
+    }
+}
@@ -0,0 +1,297 @@
+use super::ClientBuildError;
+#[cfg(any(feature = "rustls-native-roots", feature = "rustls-webpki-roots"))]
+use super::ClientBuildErrorType;
+use hyper::{
+    client::{
+        connect::{dns::Name, Connect, HttpConnector},
+        Builder as HyperBuilder, Client as HyperClient, ResponseFuture,
+    },
+    service::Service,
+    Body, Request,
+};
+use std::{
+    error::Error,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    future::Future,
+    net::{IpAddr, SocketAddr},
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+    time::Duration,
+    vec::IntoIter,
+};
+
+#[cfg(any(feature = "rustls-native-roots", feature = "rustls-webpki-roots"))]
+type HttpsConnector<T> = hyper_rustls::HttpsConnector<T>;
+#[cfg(all(
+    feature = "hyper-tls",
+    not(feature = "rustls-native-roots"),
+    not(feature = "rustls-webpki-roots")
+))]
+type HttpsConnector<T> = hyper_tls::HttpsConnector<T>;
+
+/// Future returned by [`Resolve::resolve`], resolving to the addresses of a
+/// host.
+pub type ResolveFuture = Pin<
+    Box<
+        dyn Future<Output = Result<Vec<SocketAddr>, Box<dyn Error + Send + Sync>>> + Send + 'static,
+    >,
+>;
+
+/// DNS resolver used to resolve the addresses of hosts to connect to.
+///
+/// Configure a resolver via [`ClientBuilder::resolver`].
+///
+/// [`ClientBuilder::resolver`]: super::ClientBuilder::resolver
+pub trait Resolve: Debug + Send + Sync {
+    /// Resolve the addresses of a host.
+    ///
+    /// The port of the returned addresses is replaced by the port of the
+    /// request.
+    fn resolve(&self, host: &str) -> ResolveFuture;
+}
+
+/// HTTP client sending requests, with its connector erased.
+pub(crate) trait HttpClient: Debug + Send + Sync {
+    fn request(&self, request: Request<Body>) -> ResponseFuture;
+}
+
+impl<C: Connect + Clone + Send + Sync + 'static> HttpClient for HyperClient<C, Body> {
+    fn request(&self, request: Request<Body>) -> ResponseFuture {
+        HyperClient::request(self, request)
+    }
+}
+
+/// Source of the HTTP client configured instead of the default connector.
+pub(crate) trait BuildHttpClient: Debug + Send + Sync {
+    /// Build the HTTP client, applying the pool options of a builder where
+    /// possible.
+    fn build(self: Box<Self>, builder: &HyperBuilder) -> Box<dyn HttpClient>;
+}
+
+/// Custom connector, built into an HTTP client with the pool options.
+pub(super) struct CustomConnector<C>(pub C);
+
+impl<C> Debug for CustomConnector<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_tuple("CustomConnector").finish()
+    }
+}
+
+impl<C: Connect + Clone + Send + Sync + 'static> BuildHttpClient for CustomConnector<C> {
+    fn build(self: Box<Self>, builder: &HyperBuilder) -> Box<dyn HttpClient> {
+        Box::new(builder.build::<_, Body>(self.0))
+    }
+}
+
+/// Preconfigured HTTP client, used as is.
+pub(super) struct Preconfigured<C>(pub HyperClient<C, Body>);
+
+impl<C> Debug for Preconfigured<C> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_tuple("Preconfigured").field(&self.0).finish()
+    }
+}
+
+impl<C: Connect + Clone + Send + Sync + 'static> BuildHttpClient for Preconfigured<C> {
+    fn build(self: Box<Self>, _: &HyperBuilder) -> Box<dyn HttpClient> {
+        Box::new(self.0)
+    }
+}
+
+/// Options of the default connector.
+#[derive(Debug, Default)]
+pub(crate) struct TransportOptions {
+    pub local_address: Option<IpAddr>,
+    pub resolver: Option<Arc<dyn Resolve>>,
+    pub tcp_keepalive: Option<Duration>,
+}
+
+impl TransportOptions {
+    /// Build an HTTP client with the default TLS connector of the enabled
+    /// feature.
+    pub fn build(self, builder: &HyperBuilder) -> Result<Box<dyn HttpClient>, ClientBuildError> {
+        match self.resolver.clone() {
+            Some(resolver) => self.client(
+                builder,
+                HttpConnector::new_with_resolver(ResolverService(resolver)),
+            ),
+            None => self.client(builder, HttpConnector::new()),
+        }
+    }
+
+    fn client<R>(
+        &self,
+        builder: &HyperBuilder,
+        mut http: HttpConnector<R>,
+    ) -> Result<Box<dyn HttpClient>, ClientBuildError>
+    where
+        HttpsConnector<HttpConnector<R>>: Connect + Clone + Send + Sync + 'static,
+    {
+        http.enforce_http(false);
+        http.set_keepalive(self.tcp_keepalive);
+        http.set_local_address(self.local_address);
+
+        Ok(Box::new(builder.build::<_, Body>(tls(http)?)))
+    }
+}
+
+/// Adapter of a [`Resolve`] implementation to hyper's resolver service.
+#[derive(Clone)]
+struct ResolverService(Arc<dyn Resolve>);
+
+impl Service<Name> for ResolverService {
+    type Response = IntoIter<SocketAddr>;
+    type Error = Box<dyn Error + Send + Sync>;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, name: Name) -> Self::Future {
+        let future = self.0.resolve(name.as_str());
+
+        Box::pin(async move { future.await.map(Vec::into_iter) })
+    }
+}
+
+/// TLS configuration of the default connector, created once its root
+/// certificates have been loaded.
+#[cfg(any(feature = "rustls-native-roots", feature = "rustls-webpki-roots"))]
+static TLS_CONFIG: once_cell::sync::OnceCell<Arc<rustls_crate::ClientConfig>> =
+    once_cell::sync::OnceCell::new();
+
+/// Create the TLS configuration of the default connector, with the root
+/// certificates of the enabled feature and the same options as hyper-rustls'
+/// connectors.
+#[cfg(any(feature = "rustls-native-roots", feature = "rustls-webpki-roots"))]
+fn tls_config() -> Result<Arc<rustls_crate::ClientConfig>, ClientBuildError> {
+    let mut config = rustls_crate::ClientConfig::new();
+
+    #[cfg(feature = "rustls-native-roots")]
+    {
+        config.root_store = match rustls_native_certs::load_native_certs() {
+            Ok(store) => store,
+            // Some certificates couldn't be parsed, but the others can still
+            // be used.
+            #[allow(unused_variables)]
+            Err((Some(store), source)) => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!(error = %source, "failed to load some native root certificates");
+
+                store
+            }
+            Err((None, source)) => {
+                return Err(ClientBuildError {
+                    kind: ClientBuildErrorType::TlsRootCertificates,
+                    source: Some(Box::new(source)),
+                })
+            }
+        };
+    }
+    #[cfg(all(feature = "rustls-webpki-roots", not(feature = "rustls-native-roots")))]
+    config
+        .root_store
+        .add_server_trust_anchors(&webpki_roots::TLS_SERVER_ROOTS);
+
+    if config.root_store.is_empty() {
+        return Err(ClientBuildError {
+            kind: ClientBuildErrorType::TlsRootCertificates,
+            source: None,
+        });
+    }
+
+    config.alpn_protocols = vec![b"h2".to_vec(), b"http/1.1".to_vec()];
+    config.ct_logs = Some(&ct_logs::LOGS);
+
+    Ok(Arc::new(config))
+}
+
+/// Wrap a connector with TLS support.
+///
+/// The root certificates are only loaded by the first call that succeeds.
+#[cfg(any(feature = "rustls-native-roots", feature = "rustls-webpki-roots"))]
+fn tls<T>(http: T) -> Result<HttpsConnector<T>, ClientBuildError> {
+    let config = TLS_CONFIG.get_or_try_init(tls_config)?;
+
+    Ok(HttpsConnector::from((http, Arc::clone(config))))
+}
+
+/// Wrap a connector with TLS support.
+#[cfg(all(
+    feature = "hyper-tls",
+    not(feature = "rustls-native-roots"),
+    not(feature = "rustls-webpki-roots")
+))]
+fn tls<T>(http: T) -> Result<HttpsConnector<T>, ClientBuildError> {
+    Ok(HttpsConnector::new_with_connector(http))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Resolve, ResolveFuture};
+    use crate::{test_server, Client};
+    use hyper::{Body, Response};
+    use std::{
+        error::Error,
+        net::{Ipv4Addr, SocketAddr},
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+    use twilight_model::id::ChannelId;
+
+    #[derive(Debug)]
+    struct LocalResolver(Arc<AtomicUsize>);
+
+    impl Resolve for LocalResolver {
+        fn resolve(&self, host: &str) -> ResolveFuture {
+            assert_eq!("discord.test", host);
+            self.0.fetch_add(1, Ordering::Relaxed);
+
+            Box::pin(async { Ok(vec![SocketAddr::from((Ipv4Addr::LOCALHOST, 0))]) })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resolver() -> Result<(), Box<dyn Error>> {
+        let port = test_server::start(|_| async {
+            Response::builder().status(200).body(Body::from("[]"))
+        })
+        .port();
+
+        let resolved = Arc::new(AtomicUsize::new(0));
+
+        let client = Client::builder()
+            .proxy(format!("discord.test:{}", port), true)
+            .token("token")
+            .resolver(Box::new(LocalResolver(Arc::clone(&resolved))))
+            .tcp_keepalive(Duration::from_secs(30))
+            .build();
+
+        assert!(client.pins(ChannelId(1)).await?.is_empty());
+        assert_eq!(1, resolved.load(Ordering::Relaxed));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_hyper_client() -> Result<(), Box<dyn Error>> {
+        let addr = test_server::start(|_| async {
+            Response::builder().status(200).body(Body::from("[]"))
+        });
+
+        let client = Client::builder()
+            .proxy(addr.to_string(), true)
+            .token("token")
+            .hyper_client(hyper::Client::new())
+            .build();
+
+        assert!(client.pins(ChannelId(1)).await?.is_empty());
+
+        Ok(())
+    }
+}
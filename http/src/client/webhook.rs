@@ -0,0 +1,275 @@
+use super::{Client, ClientBuilder};
+use crate::{
+    ratelimiting::Ratelimiter,
+    request::channel::webhook::{
+        DeleteWebhookMessage, ExecuteWebhook, GetWebhookMessage, UpdateWebhookMessage,
+    },
+};
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    sync::Arc,
+};
+use twilight_model::{
+    channel::webhook::{self, WebhookParseError},
+    id::{MessageId, WebhookId},
+};
+
+/// Lightweight client to execute a single webhook and manage its messages.
+///
+/// The client authorizes requests with the token of the webhook, so no bot
+/// token is needed. Webhook clients created from a [`Client`] via
+/// [`with_client`] share its connections and configuration, but each has its
+/// own ratelimiter, so webhooks don't share ratelimit buckets or the global
+/// ratelimit with each other or with a bot.
+///
+/// # Examples
+///
+/// Execute a webhook from its URL and wait for the created message:
+///
+/// ```rust,no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use twilight_http::client::WebhookClient;
+///
+/// let url = "https://discord.com/api/webhooks/1/token";
+/// let webhook = WebhookClient::from_url(url)?;
+///
+/// let message = webhook
+///     .execute()
+///     .content("deployment finished")
+///     .wait(true)
+///     .await?;
+///
+/// if let Some(message) = message {
+///     webhook.delete_message(message.id).await?;
+/// }
+/// # Ok(()) }
+/// ```
+///
+/// [`with_client`]: Self::with_client
+#[derive(Clone)]
+pub struct WebhookClient {
+    client: Client,
+    id: WebhookId,
+    token: String,
+}
+
+impl WebhookClient {
+    /// Create a client for a webhook by its ID and token.
+    ///
+    /// The webhook client creates a [`Client`] of its own. Use
+    /// [`with_client`] to share an existing client between webhooks.
+    ///
+    /// [`with_client`]: Self::with_client
+    pub fn new(webhook_id: WebhookId, token: impl Into<String>) -> Self {
+        Self::with_client(&ClientBuilder::new().build(), webhook_id, token)
+    }
+
+    /// Create a client for a webhook from its URL, such as
+    /// `https://discord.com/api/webhooks/1/token`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`WebhookParseErrorType::SegmentMissing`] error type if the
+    /// URL path doesn't contain the `api` and `webhooks` segments or the ID of
+    /// the webhook.
+    ///
+    /// Returns a [`WebhookParseErrorType::IdInvalid`] error type if the ID
+    /// segment of the URL path is not an integer.
+    ///
+    /// Returns a [`WebhookParseErrorType::TokenMissing`] error type if the URL
+    /// path doesn't contain the token of the webhook.
+    ///
+    /// [`WebhookParseErrorType::IdInvalid`]: twilight_model::channel::webhook::WebhookParseErrorType::IdInvalid
+    /// [`WebhookParseErrorType::SegmentMissing`]: twilight_model::channel::webhook::WebhookParseErrorType::SegmentMissing
+    /// [`WebhookParseErrorType::TokenMissing`]: twilight_model::channel::webhook::WebhookParseErrorType::TokenMissing
+    pub fn from_url(url: &str) -> Result<Self, WebhookParseError> {
+        Self::from_url_with_client(url, &ClientBuilder::new().build())
+    }
+
+    /// Create a client for a webhook from its URL, sending requests with a
+    /// shared client.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`from_url`].
+    ///
+    /// [`from_url`]: Self::from_url
+    pub fn from_url_with_client(url: &str, client: &Client) -> Result<Self, WebhookParseError> {
+        let (id, token) = webhook::parse_url_with_token(url)?;
+
+        Ok(Self::with_client(client, id, token))
+    }
+
+    /// Create a client for a webhook by its ID and token, sending requests
+    /// with a shared client.
+    ///
+    /// The webhook client shares the connections and configuration of the
+    /// client, but waits for ratelimits with a ratelimiter of its own. The
+    /// token of the client isn't sent, since requests are authorized with the
+    /// token of the webhook.
+    ///
+    /// The ratelimiter of the webhook client is a new [`InMemoryRatelimiter`]
+    /// with the default [`GlobalLimit`], regardless of the ratelimiter set via
+    /// [`ClientBuilder::ratelimiter`] or the global limit set via
+    /// [`ClientBuilder::global_limit`]. Use [`with_ratelimiter`] to wait for
+    /// ratelimits with another ratelimiter instead. Ratelimit handling stays
+    /// disabled if it has been disabled for the client.
+    ///
+    /// [`ClientBuilder::global_limit`]: super::ClientBuilder::global_limit
+    /// [`ClientBuilder::ratelimiter`]: super::ClientBuilder::ratelimiter
+    /// [`GlobalLimit`]: crate::ratelimiting::GlobalLimit
+    /// [`InMemoryRatelimiter`]: crate::ratelimiting::InMemoryRatelimiter
+    /// [`with_ratelimiter`]: Self::with_ratelimiter
+    pub fn with_client(client: &Client, webhook_id: WebhookId, token: impl Into<String>) -> Self {
+        Self {
+            client: client.with_isolated_ratelimiter(),
+            id: webhook_id,
+            token: token.into(),
+        }
+    }
+
+    /// Set the ratelimiter the webhook client waits for ratelimits with,
+    /// replacing the one created by [`with_client`].
+    ///
+    /// [`with_client`]: Self::with_client
+    pub fn with_ratelimiter(mut self, ratelimiter: Box<dyn Ratelimiter>) -> Self {
+        self.client = self.client.with_ratelimiter(Arc::from(ratelimiter));
+
+        self
+    }
+
+    /// ID of the webhook.
+    pub const fn id(&self) -> WebhookId {
+        self.id
+    }
+
+    /// Token of the webhook.
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// Underlying client used to make requests.
+    pub const fn client(&self) -> &Client {
+        &self.client
+    }
+
+    /// Execute the webhook, creating a message.
+    ///
+    /// The created message is only returned if [`ExecuteWebhook::wait`] is
+    /// enabled.
+    pub fn execute(&self) -> ExecuteWebhook<'_> {
+        self.client.execute_webhook(self.id, self.token.clone())
+    }
+
+    /// Get a message created by the webhook.
+    pub fn message(&self, message_id: MessageId) -> GetWebhookMessage<'_> {
+        self.client
+            .webhook_message(self.id, self.token.clone(), message_id)
+    }
+
+    /// Update a message created by the webhook.
+    pub fn update_message(&self, message_id: MessageId) -> UpdateWebhookMessage<'_> {
+        self.client
+            .update_webhook_message(self.id, self.token.clone(), message_id)
+    }
+
+    /// Delete a message created by the webhook.
+    pub fn delete_message(&self, message_id: MessageId) -> DeleteWebhookMessage<'_> {
+        self.client
+            .delete_webhook_message(self.id, self.token.clone(), message_id)
+    }
+}
+
+impl Debug for WebhookClient {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("WebhookClient")
+            .field("client", &self.client)
+            .field("id", &self.id)
+            .field("token", &"<redacted>")
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WebhookClient;
+    use crate::{ratelimiting::InMemoryRatelimiter, test_server, Client};
+    use hyper::{Body, Request, Response};
+    use static_assertions::assert_impl_all;
+    use std::{
+        error::Error,
+        fmt::Debug,
+        net::SocketAddr,
+        sync::{Arc, Mutex},
+    };
+    use twilight_model::id::{MessageId, WebhookId};
+
+    assert_impl_all!(WebhookClient: Clone, Debug, Send, Sync);
+
+    type Received = Arc<Mutex<Vec<(String, Option<String>)>>>;
+
+    /// Start a server recording the method, URI and authorization of each
+    /// request, responding without content.
+    fn start_server() -> (SocketAddr, Received) {
+        let received = Received::default();
+        let recorded = Arc::clone(&received);
+
+        let addr = test_server::start(move |request: Request<Body>| {
+            let authorization = request
+                .headers()
+                .get("authorization")
+                .map(|value| value.to_str().unwrap().to_owned());
+            recorded.lock().unwrap().push((
+                format!("{} {}", request.method(), request.uri()),
+                authorization,
+            ));
+
+            async { Response::builder().status(204).body(Body::empty()) }
+        });
+
+        (addr, received)
+    }
+
+    #[tokio::test]
+    async fn test_requests() -> Result<(), Box<dyn Error>> {
+        let (addr, received) = start_server();
+
+        let client = Client::builder()
+            .proxy(addr.to_string(), true)
+            .token("bot token")
+            .build();
+        let webhook = WebhookClient::from_url_with_client(
+            "https://discord.com/api/webhooks/1/token",
+            &client,
+        )?;
+
+        // The webhook waits for ratelimits separately from the client.
+        let ratelimiter = |client: &Client| format!("{:p}", client.ratelimiter().unwrap());
+        assert_ne!(ratelimiter(&client), ratelimiter(webhook.client()));
+
+        // Custom ratelimiters are kept when set on the webhook client.
+        let custom = WebhookClient::with_client(&client, WebhookId(1), "token")
+            .with_ratelimiter(Box::new(InMemoryRatelimiter::with_global_limit(None)));
+        assert_ne!(ratelimiter(webhook.client()), ratelimiter(custom.client()));
+        assert_ne!(ratelimiter(&client), ratelimiter(custom.client()));
+
+        assert!(webhook.execute().content("test").await?.is_none());
+        webhook.update_message(MessageId(2)).content(None)?.await?;
+        webhook.delete_message(MessageId(2)).await?;
+
+        assert_eq!(
+            vec![
+                ("POST /api/v9/webhooks/1/token".to_owned(), None),
+                ("PATCH /api/v9/webhooks/1/token/messages/2".to_owned(), None),
+                (
+                    "DELETE /api/v9/webhooks/1/token/messages/2".to_owned(),
+                    None
+                ),
+            ],
+            *received.lock().unwrap()
+        );
+
+        Ok(())
+    }
+}
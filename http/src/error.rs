@@ -1,5 +1,5 @@
 use crate::api_error::ApiError;
-use hyper::{Body, Response, StatusCode};
+use hyper::{Body, Method, Response, StatusCode};
 use std::{
     error::Error as StdError,
     fmt::{Debug, Display, Formatter, Result as FmtResult},
@@ -54,12 +54,20 @@ impl Display for Error {
 
                 f.write_str(" failed")
             }
+            ErrorType::InvalidRequestLimitReached => {
+                f.write_str("too many invalid requests have been made recently")
+            }
             ErrorType::Json => f.write_str("Given value couldn't be serialized"),
+            ErrorType::OutboxStore => f.write_str("failed to access the outbox store"),
             ErrorType::Parsing { body, .. } => {
                 f.write_str("Response body couldn't be deserialized: ")?;
 
                 Debug::fmt(body, f)
             }
+            ErrorType::QueueWaitExceeded => {
+                f.write_str("background request waited too long to be ratelimited")
+            }
+            ErrorType::RatelimiterTicket => f.write_str("Failed to get ratelimiter ticket"),
             ErrorType::RequestCanceled => {
                 f.write_str("Request was canceled either before or while being sent")
             }
@@ -75,6 +83,13 @@ impl Display for Error {
             ErrorType::ServiceUnavailable { .. } => {
                 f.write_str("api may be temporarily unavailable (received a 503)")
             }
+            ErrorType::UnrecordedRequest { method, path } => {
+                f.write_str("no unplayed interaction in the cassette matches the request ")?;
+                Display::fmt(method, f)?;
+                f.write_str(" ")?;
+
+                f.write_str(path)
+            }
             ErrorType::Unauthorized => {
                 f.write_str("token in use is invalid, expired, or is revoked")
             }
@@ -99,13 +114,38 @@ pub enum ErrorType {
     CreatingHeader {
         name: String,
     },
+    /// Too many invalid requests have been made within the window of the
+    /// configured [`InvalidRequestLimit`].
+    ///
+    /// Making more requests risks the API temporarily banning the IP address
+    /// in use. Consider re-sending the request at a later time.
+    ///
+    /// [`InvalidRequestLimit`]: crate::client::InvalidRequestLimit
+    InvalidRequestLimitReached,
     Json,
+    /// The [`OutboxStore`] failed to save, load, or remove an entry.
+    ///
+    /// [`OutboxStore`]: crate::outbox::OutboxStore
+    OutboxStore,
     Parsing {
         body: Vec<u8>,
     },
+    /// A [`Priority::Background`] request waited in the ratelimiter's queue
+    /// for longer than the configured maximum wait.
+    ///
+    /// [`Priority::Background`]: crate::ratelimiting::Priority::Background
+    QueueWaitExceeded,
+    /// The ratelimiter failed to provide a ticket for the request.
+    RatelimiterTicket,
     RequestCanceled,
     RequestError,
     RequestTimedOut,
+    /// The API responded with an unsuccessful status code.
+    ///
+    /// If the request body was invalid, the errors of the individual fields
+    /// are available via [`GeneralApiError::errors`].
+    ///
+    /// [`GeneralApiError::errors`]: crate::api_error::GeneralApiError::errors
     Response {
         body: Vec<u8>,
         error: ApiError,
@@ -118,6 +158,17 @@ pub enum ErrorType {
     ServiceUnavailable {
         response: Response<Body>,
     },
+    /// No unplayed interaction of a [`Cassette`] in replay mode matches the
+    /// request.
+    ///
+    /// [`Cassette`]: crate::cassette::Cassette
+    UnrecordedRequest {
+        /// Method of the request.
+        method: Method,
+        /// Path of the request relative to the API version, including the
+        /// query.
+        path: String,
+    },
     /// Token in use has become revoked or is otherwise invalid.
     ///
     /// This can occur if a bot token is invalidated or an access token expires
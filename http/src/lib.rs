@@ -101,16 +101,25 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 pub mod api_error;
+pub mod cassette;
 pub mod client;
 pub mod error;
+pub mod middleware;
+pub mod outbox;
 pub mod ratelimiting;
 pub mod request;
+pub mod response;
 pub mod routing;
 
 mod json;
+#[cfg(test)]
+mod test_server;
 
 /// Discord API version used by this crate.
-pub const API_VERSION: u8 = 8;
+///
+/// Version 9 is the first version supporting threads. The gateway connects
+/// with the same version.
+pub const API_VERSION: u8 = 9;
 
 pub use crate::{client::Client, error::Error};
 
@@ -0,0 +1,306 @@
+//! Middleware wrapping the requests made by the [`Client`].
+//!
+//! Middleware is added to a client via [`ClientBuilder::middleware`] and is
+//! called for every attempt of a request, in the order it was added. Each
+//! [`Middleware`] receives the request and a [`Next`] handle to pass the
+//! request on to the rest of the chain, after which the request is
+//! ratelimited and sent. This allows middleware to:
+//!
+//! - modify the headers of the request before it is sent;
+//! - observe the response, or the time it took to receive it;
+//! - skip the rest of the chain by returning a synthetic response, such as
+//!   to inject failures in tests.
+//!
+//! [`Client`]: crate::Client
+//! [`ClientBuilder::middleware`]: crate::client::ClientBuilder::middleware
+
+use crate::{client::Client, error::Error, ratelimiting::Priority, routing::Path};
+use hyper::{header::HeaderMap, Body, Method, Response, Uri};
+use std::{fmt::Debug, future::Future, pin::Pin};
+
+/// Future returned by [`Middleware::handle`] and [`Next::run`].
+pub type MiddlewareFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<Response<Body>, Error>> + Send + 'a>>;
+
+/// Request passed through the middleware chain.
+#[derive(Debug)]
+pub struct MiddlewareRequest {
+    durable: bool,
+    inner: hyper::Request<Body>,
+    path: Path,
+    priority: Priority,
+}
+
+impl MiddlewareRequest {
+    pub(crate) const fn new(
+        inner: hyper::Request<Body>,
+        path: Path,
+        priority: Priority,
+        durable: bool,
+    ) -> Self {
+        Self {
+            durable,
+            inner,
+            path,
+            priority,
+        }
+    }
+
+    /// Whether the request may be written to disk.
+    ///
+    /// Requests carrying secrets, such as `OAuth2` credentials and webhook
+    /// tokens, aren't durable. Middleware persisting requests should leave
+    /// them out or redact them.
+    pub const fn durable(&self) -> bool {
+        self.durable
+    }
+
+    /// Ratelimit path of the request.
+    pub const fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Priority of the request when waiting to be ratelimited.
+    pub const fn priority(&self) -> Priority {
+        self.priority
+    }
+
+    /// Method of the request.
+    pub fn method(&self) -> &Method {
+        self.inner.method()
+    }
+
+    /// URI of the request.
+    pub fn uri(&self) -> &Uri {
+        self.inner.uri()
+    }
+
+    /// Headers of the request.
+    pub fn headers(&self) -> &HeaderMap {
+        self.inner.headers()
+    }
+
+    /// Mutable reference to the headers of the request.
+    pub fn headers_mut(&mut self) -> &mut HeaderMap {
+        self.inner.headers_mut()
+    }
+
+    /// Mutable reference to the body of the request.
+    ///
+    /// Reading the body consumes it, so middleware reading the body needs to
+    /// replace it before passing the request on.
+    pub fn body_mut(&mut self) -> &mut Body {
+        self.inner.body_mut()
+    }
+}
+
+/// Remainder of the middleware chain, ending with sending the request.
+#[derive(Debug)]
+pub struct Next<'a> {
+    client: &'a Client,
+    middleware: &'a [Box<dyn Middleware>],
+}
+
+impl<'a> Next<'a> {
+    pub(crate) const fn new(client: &'a Client, middleware: &'a [Box<dyn Middleware>]) -> Self {
+        Self { client, middleware }
+    }
+
+    /// Pass the request to the next middleware, or send it if this is the
+    /// end of the chain.
+    pub fn run(self, request: MiddlewareRequest) -> MiddlewareFuture<'a> {
+        match self.middleware.split_first() {
+            Some((middleware, rest)) => middleware.handle(request, Self::new(self.client, rest)),
+            None => Box::pin(
+                self.client
+                    .send(request.path, request.priority, request.inner),
+            ),
+        }
+    }
+}
+
+/// Layer of the middleware chain of a [`Client`].
+///
+/// # Examples
+///
+/// Add a header to every request and log how long it took to receive a
+/// response:
+///
+/// ```rust,no_run
+/// use hyper::header::HeaderValue;
+/// use std::time::Instant;
+/// use twilight_http::{
+///     middleware::{Middleware, MiddlewareFuture, MiddlewareRequest, Next},
+///     Client,
+/// };
+///
+/// #[derive(Debug)]
+/// struct Timing;
+///
+/// impl Middleware for Timing {
+///     fn handle<'a>(
+///         &'a self,
+///         mut request: MiddlewareRequest,
+///         next: Next<'a>,
+///     ) -> MiddlewareFuture<'a> {
+///         request
+///             .headers_mut()
+///             .insert("x-request-source", HeaderValue::from_static("my-bot"));
+///
+///         Box::pin(async move {
+///             let path = request.path().clone();
+///             let start = Instant::now();
+///             let response = next.run(request).await;
+///             println!("{:?} took {:?}", path, start.elapsed());
+///
+///             response
+///         })
+///     }
+/// }
+///
+/// let client = Client::builder()
+///     .token("my token")
+///     .middleware(Box::new(Timing))
+///     .build();
+/// ```
+///
+/// [`Client`]: crate::Client
+pub trait Middleware: Debug + Send + Sync {
+    /// Handle a request, usually by passing it to the rest of the chain via
+    /// [`Next::run`].
+    ///
+    /// Returning a response without calling [`Next::run`] skips the rest of
+    /// the chain, in which case the request isn't sent.
+    fn handle<'a>(&'a self, request: MiddlewareRequest, next: Next<'a>) -> MiddlewareFuture<'a>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Middleware, MiddlewareFuture, MiddlewareRequest, Next};
+    use crate::{routing::Path, test_server, Client};
+    use hyper::{header::HeaderValue, Body, Request, Response, StatusCode};
+    use static_assertions::{assert_impl_all, assert_obj_safe};
+    use std::{
+        error::Error,
+        fmt::Debug,
+        net::SocketAddr,
+        sync::{Arc, Mutex},
+    };
+    use twilight_model::id::ChannelId;
+
+    assert_impl_all!(MiddlewareRequest: Debug, Send, Sync);
+    assert_impl_all!(Next<'_>: Debug, Send, Sync);
+    assert_obj_safe!(Middleware);
+
+    /// Middleware recording when requests pass through it and adding its name
+    /// to the `x-layers` header.
+    #[derive(Debug)]
+    struct Recording {
+        events: Arc<Mutex<Vec<String>>>,
+        name: &'static str,
+    }
+
+    impl Middleware for Recording {
+        fn handle<'a>(
+            &'a self,
+            mut request: MiddlewareRequest,
+            next: Next<'a>,
+        ) -> MiddlewareFuture<'a> {
+            request
+                .headers_mut()
+                .append("x-layers", HeaderValue::from_static(self.name));
+
+            Box::pin(async move {
+                self.events
+                    .lock()
+                    .unwrap()
+                    .push(format!("{} {:?}", self.name, request.path()));
+
+                let response = next.run(request).await?;
+
+                self.events
+                    .lock()
+                    .unwrap()
+                    .push(format!("{} {}", self.name, response.status()));
+
+                Ok(response)
+            })
+        }
+    }
+
+    /// Middleware responding to every request without sending it.
+    #[derive(Debug)]
+    struct ShortCircuit;
+
+    impl Middleware for ShortCircuit {
+        fn handle<'a>(&'a self, _: MiddlewareRequest, _: Next<'a>) -> MiddlewareFuture<'a> {
+            Box::pin(async {
+                Ok(Response::builder()
+                    .status(StatusCode::OK)
+                    .body(Body::from("[]"))
+                    .unwrap())
+            })
+        }
+    }
+
+    /// Start a server responding with the number of `x-layers` headers of the
+    /// request, returning its address.
+    fn start_server() -> SocketAddr {
+        test_server::start(|request: Request<Body>| async move {
+            let layers = request.headers().get_all("x-layers").iter().count();
+
+            Response::builder()
+                .header("x-layer-count", layers)
+                .body(Body::from("[]"))
+        })
+    }
+
+    #[tokio::test]
+    async fn test_chain_order() -> Result<(), Box<dyn Error>> {
+        let addr = start_server();
+        let events = Arc::new(Mutex::new(Vec::new()));
+
+        let client = Client::builder()
+            .proxy(addr.to_string(), true)
+            .middleware(Box::new(Recording {
+                events: Arc::clone(&events),
+                name: "outer",
+            }))
+            .middleware(Box::new(Recording {
+                events: Arc::clone(&events),
+                name: "inner",
+            }))
+            .build();
+
+        let response = client.pins(ChannelId(1)).response().await?;
+        assert_eq!("2", response.headers()["x-layer-count"]);
+
+        let path = Path::ChannelsIdPins(1);
+        assert_eq!(
+            vec![
+                format!("outer {:?}", path),
+                format!("inner {:?}", path),
+                "inner 200 OK".to_owned(),
+                "outer 200 OK".to_owned(),
+            ],
+            *events.lock().unwrap()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_short_circuit() -> Result<(), Box<dyn Error>> {
+        // Nothing is listening on this address, so the request fails if it is
+        // sent.
+        let client = Client::builder()
+            .proxy("127.0.0.1:1", true)
+            .ratelimiter(None)
+            .middleware(Box::new(ShortCircuit))
+            .build();
+
+        assert!(client.pins(ChannelId(1)).await?.is_empty());
+
+        Ok(())
+    }
+}
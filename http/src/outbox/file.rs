@@ -0,0 +1,135 @@
+use super::{LoadFuture, OutboxEntry, OutboxStore, RemoveFuture, SaveFuture};
+use std::{
+    ffi::OsStr,
+    io::ErrorKind,
+    path::{Path, PathBuf},
+};
+use tokio::fs;
+
+/// Extension of the files entries are stored in.
+const EXTENSION: &str = "json";
+
+/// [`OutboxStore`] storing each entry as a JSON file in a directory.
+///
+/// The directory is created when the first entry is saved. Entries are
+/// written to a temporary file that is then renamed, so an entry is either
+/// stored completely or not at all if the process stops while saving it.
+///
+/// A directory should only be used by one client at a time.
+#[derive(Clone, Debug)]
+pub struct FileStore {
+    directory: PathBuf,
+}
+
+impl FileStore {
+    /// Create a store persisting entries in a directory.
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        Self {
+            directory: directory.into(),
+        }
+    }
+
+    /// Directory the entries are stored in.
+    pub fn directory(&self) -> &Path {
+        &self.directory
+    }
+
+    fn entry_path(&self, id: u64) -> PathBuf {
+        self.directory.join(format!("{}.{}", id, EXTENSION))
+    }
+}
+
+impl OutboxStore for FileStore {
+    fn load(&self) -> LoadFuture {
+        let directory = self.directory.clone();
+
+        Box::pin(async move {
+            let mut dir = match fs::read_dir(&directory).await {
+                Ok(dir) => dir,
+                Err(source) if source.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+                Err(source) => return Err(source.into()),
+            };
+
+            let mut entries = Vec::new();
+
+            while let Some(file) = dir.next_entry().await? {
+                let path = file.path();
+
+                if path.extension() != Some(OsStr::new(EXTENSION)) {
+                    continue;
+                }
+
+                let bytes = fs::read(&path).await?;
+                entries.push(serde_json::from_slice(&bytes)?);
+            }
+
+            Ok(entries)
+        })
+    }
+
+    fn remove(&self, id: u64) -> RemoveFuture {
+        let path = self.entry_path(id);
+
+        Box::pin(async move {
+            match fs::remove_file(&path).await {
+                Err(source) if source.kind() != ErrorKind::NotFound => Err(source.into()),
+                _ => Ok(()),
+            }
+        })
+    }
+
+    fn save(&self, entry: OutboxEntry) -> SaveFuture {
+        let directory = self.directory.clone();
+        let path = self.entry_path(entry.id());
+
+        Box::pin(async move {
+            let bytes = serde_json::to_vec(&entry)?;
+            let temporary = path.with_extension("tmp");
+
+            fs::create_dir_all(&directory).await?;
+            fs::write(&temporary, bytes).await?;
+            fs::rename(&temporary, &path).await?;
+
+            Ok(())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FileStore;
+    use crate::{
+        outbox::{OutboxEntry, OutboxStore},
+        request::Method,
+    };
+    use hyper::header::HeaderMap;
+    use std::{env, error::Error, fs, process};
+
+    #[tokio::test]
+    async fn test_save_load_remove() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let directory = env::temp_dir().join(format!("twilight-outbox-{}", process::id()));
+        let store = FileStore::new(&directory);
+
+        assert!(store.load().await?.is_empty());
+
+        let entry = OutboxEntry::new(
+            Method::Post,
+            "channels/1/messages",
+            &HeaderMap::new(),
+            Some(br#"{"content":"test"}"#),
+            true,
+        );
+        store.save(entry.clone()).await?;
+
+        assert_eq!(vec![entry.clone()], store.load().await?);
+
+        store.remove(entry.id()).await?;
+        store.remove(entry.id()).await?;
+
+        assert!(store.load().await?.is_empty());
+
+        fs::remove_dir_all(&directory)?;
+
+        Ok(())
+    }
+}
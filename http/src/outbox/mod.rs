@@ -0,0 +1,717 @@
+//! Durable outbox persisting requests until they have been delivered.
+//!
+//! When an [`OutboxStore`] is configured via [`ClientBuilder::outbox`], every
+//! request other than a `GET` request is saved to the store before it is
+//! sent. Once the request has been delivered, or the API has rejected it,
+//! the entry is removed again. Entries of requests that couldn't be
+//! delivered, such as due to a connection error, a ratelimit, or an API
+//! outage, remain in the store.
+//!
+//! After a restart the remaining entries are sent again via
+//! [`Client::replay_outbox`], which applies the configured [`RetryPolicy`]
+//! and reports the entries the API permanently rejected.
+//!
+//! Entries contain the method, path, body, and headers of the request. The
+//! `Authorization` header is never stored; replayed requests are authorized
+//! with the token of the replaying client. Requests with streamed
+//! attachments aren't stored, since their body can only be read once.
+//! Requests that aren't [durable] aren't stored either, so that secrets such
+//! as `OAuth2` credentials and webhook or interaction tokens are never
+//! written to the store.
+//!
+//! A request may have been delivered even though its response was lost, in
+//! which case replaying it applies it twice. Messages created via
+//! [`CreateMessage`] are therefore assigned a random [`CreateMessage::nonce`]
+//! with [`CreateMessage::enforce_nonce`] enabled if they don't have a nonce,
+//! so that the API doesn't create duplicates of them.
+//!
+//! # Examples
+//!
+//! Persist requests to a directory and replay the requests left over from
+//! the previous run:
+//!
+//! ```rust,no_run
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! use std::env;
+//! use twilight_http::{outbox::FileStore, Client};
+//!
+//! let client = Client::builder()
+//!     .token(env::var("DISCORD_TOKEN")?)
+//!     .outbox(Box::new(FileStore::new("outbox")))
+//!     .build();
+//!
+//! let replay = client.replay_outbox().await?;
+//!
+//! for failed in replay.failed() {
+//!     eprintln!("dropped {} {}: {}", failed.entry().method(), failed.entry().path(), failed.error());
+//! }
+//! # Ok(()) }
+//! ```
+//!
+//! [`Client::replay_outbox`]: crate::Client::replay_outbox
+//! [`ClientBuilder::outbox`]: crate::client::ClientBuilder::outbox
+//! [`CreateMessage`]: crate::request::channel::message::CreateMessage
+//! [`CreateMessage::enforce_nonce`]: crate::request::channel::message::CreateMessage::enforce_nonce
+//! [`CreateMessage::nonce`]: crate::request::channel::message::CreateMessage::nonce
+//! [`RetryPolicy`]: crate::client::RetryPolicy
+//! [durable]: crate::request::RequestBuilder::durable
+
+mod file;
+
+pub use self::file::FileStore;
+
+use crate::{
+    cassette::RecordedBody,
+    error::{Error, ErrorType},
+    request::{Method, Request},
+    routing::Path,
+};
+use hyper::{
+    header::{HeaderMap, HeaderName, HeaderValue, AUTHORIZATION, CONTENT_LENGTH, USER_AGENT},
+    Body, Response, StatusCode,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    borrow::Cow,
+    collections::HashSet,
+    convert::TryFrom,
+    error::Error as StdError,
+    fmt::Debug,
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex, PoisonError,
+    },
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// Error returned by an [`OutboxStore`] implementation.
+pub type GenericError = Box<dyn StdError + Send + Sync>;
+
+/// Future returned by [`OutboxStore::load`].
+pub type LoadFuture =
+    Pin<Box<dyn Future<Output = Result<Vec<OutboxEntry>, GenericError>> + Send + 'static>>;
+
+/// Future returned by [`OutboxStore::remove`].
+pub type RemoveFuture = Pin<Box<dyn Future<Output = Result<(), GenericError>> + Send + 'static>>;
+
+/// Future returned by [`OutboxStore::save`].
+pub type SaveFuture = Pin<Box<dyn Future<Output = Result<(), GenericError>> + Send + 'static>>;
+
+/// ID of the most recently created entry.
+static LAST_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Storage backend persisting the entries of the outbox.
+///
+/// Implementations may store entries on disk, as [`FileStore`] does, or in
+/// an external database. Entries implement [`Serialize`] and
+/// [`Deserialize`], so they can be stored in any format supported by serde.
+///
+/// # Examples
+///
+/// Implement a store keeping entries in memory, which doesn't survive
+/// restarts but shows the contract of the trait:
+///
+/// ```
+/// use std::{collections::HashMap, future, sync::Mutex};
+/// use twilight_http::outbox::{LoadFuture, OutboxEntry, OutboxStore, RemoveFuture, SaveFuture};
+///
+/// #[derive(Debug, Default)]
+/// struct MemoryStore(Mutex<HashMap<u64, OutboxEntry>>);
+///
+/// impl OutboxStore for MemoryStore {
+///     fn load(&self) -> LoadFuture {
+///         let entries = self.0.lock().unwrap().values().cloned().collect();
+///
+///         Box::pin(future::ready(Ok(entries)))
+///     }
+///
+///     fn remove(&self, id: u64) -> RemoveFuture {
+///         self.0.lock().unwrap().remove(&id);
+///
+///         Box::pin(future::ready(Ok(())))
+///     }
+///
+///     fn save(&self, entry: OutboxEntry) -> SaveFuture {
+///         self.0.lock().unwrap().insert(entry.id(), entry);
+///
+///         Box::pin(future::ready(Ok(())))
+///     }
+/// }
+/// ```
+pub trait OutboxStore: Debug + Send + Sync {
+    /// Load all entries in the store, in any order.
+    fn load(&self) -> LoadFuture;
+
+    /// Remove the entry with an ID from the store.
+    ///
+    /// Removing an entry that isn't in the store must succeed.
+    fn remove(&self, id: u64) -> RemoveFuture;
+
+    /// Save an entry to the store.
+    ///
+    /// The returned future must only resolve once the entry has been
+    /// persisted.
+    fn save(&self, entry: OutboxEntry) -> SaveFuture;
+}
+
+/// Request persisted in an [`OutboxStore`].
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+pub struct OutboxEntry {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<RecordedBody>,
+    headers: Vec<(String, String)>,
+    id: u64,
+    method: String,
+    path: String,
+    use_authorization_token: bool,
+}
+
+impl OutboxEntry {
+    /// Create an entry from the parts of a request, assigning it a new ID.
+    ///
+    /// The `Authorization`, `Content-Length` and `User-Agent` headers are
+    /// set by the client when the request is sent, so they aren't stored.
+    pub(crate) fn new(
+        method: Method,
+        path: &str,
+        headers: &HeaderMap,
+        body: Option<&[u8]>,
+        use_authorization_token: bool,
+    ) -> Self {
+        let headers = headers
+            .iter()
+            .filter(|(name, _)| {
+                *name != AUTHORIZATION && *name != CONTENT_LENGTH && *name != USER_AGENT
+            })
+            .filter_map(|(name, value)| {
+                value
+                    .to_str()
+                    .ok()
+                    .map(|value| (name.as_str().to_owned(), value.to_owned()))
+            })
+            .collect();
+
+        Self {
+            body: body.map(|body| RecordedBody::new(body.to_vec())),
+            headers,
+            id: next_id(),
+            method: method.into_hyper().as_str().to_owned(),
+            path: path.to_owned(),
+            use_authorization_token,
+        }
+    }
+
+    /// ID of the entry.
+    ///
+    /// IDs increase in the order in which entries are created, which is the
+    /// order in which they're replayed.
+    pub const fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Method of the request, such as `POST`.
+    pub fn method(&self) -> &str {
+        &self.method
+    }
+
+    /// Path of the request relative to the API version, including the query.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Create the request to send the entry with.
+    pub(crate) fn to_request(&self) -> Result<Request, Error> {
+        let method = parse_method(&self.method).ok_or(Error {
+            kind: ErrorType::BuildingRequest,
+            source: None,
+        })?;
+
+        let route = self.path.split('?').next().unwrap_or_default();
+        let path = Path::try_from((method, route)).map_err(|source| Error {
+            kind: ErrorType::BuildingRequest,
+            source: Some(Box::new(source)),
+        })?;
+
+        let mut headers = HeaderMap::new();
+
+        for (name, value) in &self.headers {
+            let header_name = HeaderName::from_bytes(name.as_bytes()).map_err(|source| Error {
+                kind: ErrorType::CreatingHeader { name: name.clone() },
+                source: Some(Box::new(source)),
+            })?;
+            let header_value = HeaderValue::from_str(value).map_err(|source| Error {
+                kind: ErrorType::CreatingHeader { name: name.clone() },
+                source: Some(Box::new(source)),
+            })?;
+
+            headers.insert(header_name, header_value);
+        }
+
+        Ok(Request {
+            body: self.body.clone().map(RecordedBody::into_bytes),
+            durable: true,
+            form: None,
+            headers: Some(headers),
+            method,
+            path,
+            path_str: Cow::Owned(self.path.clone()),
+            priority: None,
+            use_authorization_token: self.use_authorization_token,
+        })
+    }
+}
+
+/// Entry of the outbox that was permanently rejected when it was replayed.
+///
+/// The entry has been removed from the store.
+#[derive(Debug)]
+pub struct FailedEntry {
+    pub(crate) entry: OutboxEntry,
+    pub(crate) error: Error,
+}
+
+impl FailedEntry {
+    /// Immutable reference to the entry that failed.
+    pub const fn entry(&self) -> &OutboxEntry {
+        &self.entry
+    }
+
+    /// Immutable reference to the error the entry failed with.
+    ///
+    /// This is usually an [`ErrorType::Response`] error type containing the
+    /// response of the API.
+    pub const fn error(&self) -> &Error {
+        &self.error
+    }
+
+    /// Consume the failed entry, returning the entry and the error.
+    #[must_use = "consuming the failed entry into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (OutboxEntry, Error) {
+        (self.entry, self.error)
+    }
+}
+
+/// Outcome of replaying the entries of the outbox via
+/// [`Client::replay_outbox`].
+///
+/// [`Client::replay_outbox`]: crate::Client::replay_outbox
+#[derive(Debug, Default)]
+pub struct OutboxReplay {
+    pub(crate) delivered: usize,
+    pub(crate) failed: Vec<FailedEntry>,
+    pub(crate) pending: usize,
+}
+
+impl OutboxReplay {
+    /// Number of entries that were delivered.
+    pub const fn delivered(&self) -> usize {
+        self.delivered
+    }
+
+    /// Entries that the API permanently rejected.
+    pub fn failed(&self) -> &[FailedEntry] {
+        &self.failed
+    }
+
+    /// Consume the replay, returning the entries that the API permanently
+    /// rejected.
+    #[must_use = "consuming the replay and retrieving the failed entries has no effect if left unused"]
+    pub fn into_failed(self) -> Vec<FailedEntry> {
+        self.failed
+    }
+
+    /// Number of entries that couldn't be delivered yet and remain in the
+    /// store.
+    pub const fn pending(&self) -> usize {
+        self.pending
+    }
+}
+
+/// Whether the entry of a request can be removed from the outbox after the
+/// request completed with a result.
+///
+/// Entries are kept if the request may succeed when sent again later, which
+/// is the case for connection errors, ratelimits, server errors, and an
+/// invalid token.
+pub(crate) fn is_settled(result: &Result<Response<Body>, Error>) -> bool {
+    match result {
+        Ok(response) => {
+            let status = response.status();
+
+            !(status.is_server_error()
+                || status == StatusCode::TOO_MANY_REQUESTS
+                || status == StatusCode::UNAUTHORIZED)
+        }
+        Err(source) => matches!(
+            source.kind(),
+            ErrorType::BuildingRequest | ErrorType::CreatingHeader { .. }
+        ),
+    }
+}
+
+/// IDs of the entries whose requests are currently being sent.
+///
+/// Replaying the outbox skips these entries, so that an entry isn't sent
+/// again while the request that saved it, or a concurrent replay, is still
+/// in flight.
+#[derive(Debug, Default)]
+pub(crate) struct InFlight(Mutex<HashSet<u64>>);
+
+impl InFlight {
+    /// Mark an entry as being sent until the returned guard is dropped.
+    ///
+    /// Returns `None` if the entry is already being sent.
+    pub(crate) fn claim(&self, id: u64) -> Option<InFlightGuard<'_>> {
+        let mut ids = self.0.lock().unwrap_or_else(PoisonError::into_inner);
+
+        if ids.insert(id) {
+            Some(InFlightGuard { id, ids: &self.0 })
+        } else {
+            None
+        }
+    }
+}
+
+/// Marks an entry as being sent, unmarking it when dropped.
+pub(crate) struct InFlightGuard<'a> {
+    id: u64,
+    ids: &'a Mutex<HashSet<u64>>,
+}
+
+impl Drop for InFlightGuard<'_> {
+    fn drop(&mut self) {
+        self.ids
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(&self.id);
+    }
+}
+
+/// Create a new entry ID, based on the current time and greater than any
+/// previously created ID.
+fn next_id() -> u64 {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| {
+            u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX)
+        });
+
+    let previous = LAST_ID
+        .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |last| {
+            Some(now.max(last.saturating_add(1)))
+        })
+        .unwrap_or_else(|last| last);
+
+    now.max(previous.saturating_add(1))
+}
+
+fn parse_method(method: &str) -> Option<Method> {
+    Some(match method {
+        "DELETE" => Method::Delete,
+        "GET" => Method::Get,
+        "PATCH" => Method::Patch,
+        "POST" => Method::Post,
+        "PUT" => Method::Put,
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        is_settled, next_id, FailedEntry, FileStore, OutboxEntry, OutboxReplay, OutboxStore,
+        RecordedBody,
+    };
+    use crate::{
+        error::{Error, ErrorType},
+        request::Method,
+        routing::Path,
+        test_server, Client,
+    };
+    use hyper::{
+        header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE},
+        Body, Request, Response,
+    };
+    use serde::{Deserialize, Serialize};
+    use static_assertions::{assert_impl_all, assert_obj_safe};
+    use std::{
+        env,
+        error::Error as StdError,
+        fmt::Debug,
+        fs,
+        net::SocketAddr,
+        process,
+        sync::{
+            atomic::{AtomicBool, AtomicUsize, Ordering},
+            Arc,
+        },
+        time::Duration,
+    };
+    use tokio::time;
+    use twilight_model::id::{ApplicationId, ChannelId, MessageId, WebhookId};
+
+    assert_impl_all!(FailedEntry: Debug, Send, Sync);
+    assert_impl_all!(FileStore: Clone, Debug, OutboxStore, Send, Sync);
+    assert_impl_all!(
+        OutboxEntry: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        PartialEq,
+        Serialize,
+        Send,
+        Sync
+    );
+    assert_impl_all!(OutboxReplay: Debug, Default, Send, Sync);
+    assert_obj_safe!(OutboxStore);
+
+    /// Start a server responding with a bad gateway status until it is marked
+    /// as available, and afterwards creating messages and failing to delete
+    /// unknown messages.
+    fn start_server() -> (SocketAddr, Arc<AtomicBool>) {
+        let available = Arc::new(AtomicBool::new(false));
+        let server_available = Arc::clone(&available);
+
+        let addr = test_server::start(move |request: Request<Body>| {
+            let response = if !server_available.load(Ordering::Relaxed) {
+                Response::builder().status(502).body(Body::empty())
+            } else if request.method() == hyper::Method::POST {
+                Response::builder().status(200).body(Body::from("{}"))
+            } else {
+                Response::builder()
+                    .status(404)
+                    .body(Body::from(r#"{"code":10008,"message":"Unknown Message"}"#))
+            };
+
+            async { response }
+        });
+
+        (addr, available)
+    }
+
+    #[tokio::test]
+    async fn test_replay() -> Result<(), Box<dyn StdError + Send + Sync>> {
+        let (addr, available) = start_server();
+        let directory = env::temp_dir().join(format!("twilight-outbox-replay-{}", process::id()));
+        let store = FileStore::new(&directory);
+
+        let client = Client::builder()
+            .proxy(addr.to_string(), true)
+            .token("token")
+            .outbox(Box::new(store.clone()))
+            .build();
+
+        assert!(client
+            .create_message(ChannelId(1))
+            .content("test")?
+            .nonce(1)
+            .enforce_nonce(true)
+            .await
+            .is_err());
+        assert!(client
+            .delete_message(ChannelId(1), MessageId(2))
+            .await
+            .is_err());
+        assert!(client.channel(ChannelId(1)).await.is_err());
+        assert_eq!(2, store.load().await?.len());
+
+        let replay = client.replay_outbox().await?;
+        assert_eq!(0, replay.delivered());
+        assert!(replay.failed().is_empty());
+        assert_eq!(2, replay.pending());
+
+        available.store(true, Ordering::Relaxed);
+
+        let replay = client.replay_outbox().await?;
+        assert_eq!(1, replay.delivered());
+        assert_eq!(0, replay.pending());
+
+        let failed = replay.into_failed();
+        assert_eq!(1, failed.len());
+        assert_eq!("DELETE", failed[0].entry().method());
+        assert_eq!("channels/1/messages/2", failed[0].entry().path());
+        assert!(matches!(
+            failed[0].error().kind(),
+            ErrorType::Response { status, .. } if status.as_u16() == 404
+        ));
+
+        assert!(store.load().await?.is_empty());
+
+        fs::remove_dir_all(&directory)?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_replay() -> Result<(), Box<dyn StdError + Send + Sync>> {
+        let requests = Arc::new(AtomicUsize::new(0));
+        let server_requests = Arc::clone(&requests);
+
+        // Fail the first request, then respond slowly so that the replays
+        // overlap.
+        let addr = test_server::start(move |_: Request<Body>| {
+            let count = server_requests.fetch_add(1, Ordering::Relaxed);
+
+            async move {
+                if count == 0 {
+                    return Response::builder().status(502).body(Body::empty());
+                }
+
+                time::sleep(Duration::from_millis(100)).await;
+
+                Response::builder().status(200).body(Body::from("{}"))
+            }
+        });
+
+        let directory =
+            env::temp_dir().join(format!("twilight-outbox-concurrent-{}", process::id()));
+        let store = FileStore::new(&directory);
+
+        let client = Client::builder()
+            .proxy(addr.to_string(), true)
+            .token("token")
+            .outbox(Box::new(store.clone()))
+            .build();
+
+        assert!(client
+            .create_message(ChannelId(1))
+            .content("test")?
+            .await
+            .is_err());
+        assert_eq!(1, store.load().await?.len());
+
+        let (first, second) = tokio::join!(client.replay_outbox(), client.replay_outbox());
+        let (first, second) = (first?, second?);
+
+        assert_eq!(2, requests.load(Ordering::Relaxed));
+        assert_eq!(1, first.delivered() + second.delivered());
+        assert_eq!(1, first.pending() + second.pending());
+        assert!(store.load().await?.is_empty());
+
+        fs::remove_dir_all(&directory)?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_message_nonce() -> Result<(), Box<dyn StdError + Send + Sync>> {
+        let (addr, _) = start_server();
+        let directory = env::temp_dir().join(format!("twilight-outbox-nonce-{}", process::id()));
+        let store = FileStore::new(&directory);
+
+        let client = Client::builder()
+            .proxy(addr.to_string(), true)
+            .token("token")
+            .outbox(Box::new(store.clone()))
+            .build();
+
+        assert!(client
+            .create_message(ChannelId(1))
+            .content("test")?
+            .await
+            .is_err());
+
+        let entries = store.load().await?;
+        assert_eq!(1, entries.len());
+
+        let body = match entries[0].body.clone() {
+            Some(RecordedBody::Text(body)) => body,
+            other => panic!("unexpected body {:?}", other),
+        };
+        let body = serde_json::from_str::<serde_json::Value>(&body)?;
+        assert!(body["nonce"].is_u64());
+        assert_eq!(Some(true), body["enforce_nonce"].as_bool());
+
+        fs::remove_dir_all(&directory)?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_secrets_not_stored() -> Result<(), Box<dyn StdError + Send + Sync>> {
+        let (addr, _) = start_server();
+        let directory = env::temp_dir().join(format!("twilight-outbox-secrets-{}", process::id()));
+        let store = FileStore::new(&directory);
+
+        let client = Client::builder()
+            .proxy(addr.to_string(), true)
+            .outbox(Box::new(store.clone()))
+            .build();
+
+        assert!(client
+            .exchange_code(ApplicationId(1), "secret", "code", "https://example.com")
+            .await
+            .is_err());
+        assert!(client
+            .execute_webhook(WebhookId(1), "webhook token")
+            .content("test")
+            .await
+            .is_err());
+        assert!(store.load().await?.is_empty());
+
+        let _ = fs::remove_dir_all(&directory);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_entry_request() {
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, HeaderValue::from_static("Bot token"));
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        headers.insert("x-audit-log-reason", HeaderValue::from_static("spam"));
+
+        let entry = OutboxEntry::new(
+            Method::Delete,
+            "channels/1/messages/2",
+            &headers,
+            None,
+            true,
+        );
+        assert_eq!("DELETE", entry.method());
+        assert!(!entry
+            .headers
+            .iter()
+            .any(|(name, _)| name.as_str() == "authorization"));
+
+        let request = entry.to_request().unwrap();
+        assert_eq!(Method::Delete, request.method);
+        assert_eq!(Path::ChannelsIdMessagesId(Method::Delete, 1), request.path);
+        assert_eq!("channels/1/messages/2", request.path_str);
+
+        let request_headers = request.headers.unwrap();
+        assert!(request_headers.get(AUTHORIZATION).is_none());
+        assert_eq!("spam", request_headers.get("x-audit-log-reason").unwrap());
+    }
+
+    #[test]
+    fn test_ids_increase() {
+        let first = next_id();
+        let second = next_id();
+
+        assert!(second > first);
+    }
+
+    #[test]
+    fn test_is_settled() {
+        let response = |status: u16| {
+            Ok(Response::builder()
+                .status(status)
+                .body(Body::empty())
+                .unwrap())
+        };
+
+        assert!(is_settled(&response(200)));
+        assert!(is_settled(&response(404)));
+        assert!(!is_settled(&response(401)));
+        assert!(!is_settled(&response(429)));
+        assert!(!is_settled(&response(502)));
+        assert!(!is_settled(&Err(Error {
+            kind: ErrorType::RequestTimedOut,
+            source: None,
+        })));
+    }
+}
@@ -23,6 +23,16 @@ pub enum RatelimitHeaders {
 }
 
 impl RatelimitHeaders {
+    /// Hash of the bucket the request was made in, if provided.
+    ///
+    /// Multiple paths may share the same bucket.
+    pub fn bucket(&self) -> Option<&str> {
+        match self {
+            Self::GlobalLimited { .. } | Self::None => None,
+            Self::Present { bucket, .. } => bucket.as_deref(),
+        }
+    }
+
     pub const fn global(&self) -> bool {
         match self {
             Self::GlobalLimited { .. } => true,
@@ -1,19 +1,15 @@
-use super::{headers::RatelimitHeaders, GlobalLockPair};
-use crate::routing::Path;
+use super::{global::GlobalBucket, BucketKey, Buckets, GlobalLockPair, Hashes};
+use crate::ratelimiting::{headers::RatelimitHeaders, ticket::TicketNotifier, Priority};
 use std::{
-    collections::HashMap,
+    collections::{hash_map::Entry, VecDeque},
     sync::{
         atomic::{AtomicU64, Ordering},
-        Arc,
+        Arc, Mutex as StdMutex,
     },
     time::{Duration, Instant},
 };
 use tokio::{
-    sync::{
-        mpsc::{self, UnboundedReceiver, UnboundedSender},
-        oneshot::{self, Sender},
-        Mutex,
-    },
+    sync::{Mutex, Notify},
     time::{sleep, timeout},
 };
 
@@ -27,7 +23,6 @@ pub enum TimeRemaining {
 #[derive(Debug)]
 pub struct Bucket {
     pub limit: AtomicU64,
-    pub path: Path,
     pub queue: BucketQueue,
     pub remaining: AtomicU64,
     pub reset_after: AtomicU64,
@@ -35,10 +30,9 @@ pub struct Bucket {
 }
 
 impl Bucket {
-    pub fn new(path: Path) -> Self {
+    pub fn new() -> Self {
         Self {
             limit: AtomicU64::new(u64::max_value()),
-            path,
             queue: BucketQueue::default(),
             remaining: AtomicU64::new(u64::max_value()),
             reset_after: AtomicU64::new(u64::max_value()),
@@ -112,46 +106,57 @@ impl Bucket {
     }
 }
 
-#[derive(Debug)]
+/// Queue of requests waiting for a bucket, with a lane per [`Priority`].
+///
+/// Requests are popped from the lane of the highest priority first, and in
+/// the order in which they were pushed within a lane.
+#[derive(Debug, Default)]
 pub struct BucketQueue {
-    rx: Mutex<UnboundedReceiver<Sender<Sender<Option<RatelimitHeaders>>>>>,
-    tx: UnboundedSender<Sender<Sender<Option<RatelimitHeaders>>>>,
+    lanes: StdMutex<[VecDeque<TicketNotifier>; Priority::LANES]>,
+    pushed: Notify,
 }
 
 impl BucketQueue {
-    pub fn push(&self, tx: Sender<Sender<Option<RatelimitHeaders>>>) {
-        let _sent = self.tx.send(tx);
+    pub fn push(&self, tx: TicketNotifier, priority: Priority) {
+        self.lanes.lock().expect("queue poisoned")[priority.lane()].push_back(tx);
+        self.pushed.notify_one();
     }
 
-    pub async fn pop(
-        &self,
-        timeout_duration: Duration,
-    ) -> Option<Sender<Sender<Option<RatelimitHeaders>>>> {
-        let mut rx = self.rx.lock().await;
+    pub async fn pop(&self, timeout_duration: Duration) -> Option<(Priority, TicketNotifier)> {
+        timeout(timeout_duration, async {
+            loop {
+                if let Some(next) = self.try_pop() {
+                    return next;
+                }
 
-        match timeout(timeout_duration, rx.recv()).await.ok() {
-            Some(x) => x,
-            None => None,
-        }
+                self.pushed.notified().await;
+            }
+        })
+        .await
+        .ok()
     }
-}
 
-impl Default for BucketQueue {
-    fn default() -> Self {
-        let (tx, rx) = mpsc::unbounded_channel();
+    fn try_pop(&self) -> Option<(Priority, TicketNotifier)> {
+        let mut lanes = self.lanes.lock().expect("queue poisoned");
 
-        Self {
-            rx: Mutex::new(rx),
-            tx,
-        }
+        [Priority::Urgent, Priority::Normal, Priority::Background]
+            .iter()
+            .find_map(|priority| lanes[priority.lane()].pop_front().map(|tx| (*priority, tx)))
     }
 }
 
 pub(super) struct BucketQueueTask {
+    /// Additional keys the bucket has been registered under.
+    aliases: Vec<BucketKey>,
     bucket: Arc<Bucket>,
-    buckets: Arc<Mutex<HashMap<Path, Arc<Bucket>>>>,
+    buckets: Buckets,
     global: Arc<GlobalLockPair>,
-    path: Path,
+    /// Proactive global limit, if the bucket is bound to one.
+    global_bucket: Option<Arc<GlobalBucket>>,
+    hashes: Hashes,
+    /// Whether the bucket hash of the key's path has been learned.
+    hash_learned: bool,
+    key: BucketKey,
 }
 
 impl BucketQueueTask {
@@ -159,30 +164,39 @@ impl BucketQueueTask {
 
     pub fn new(
         bucket: Arc<Bucket>,
-        buckets: Arc<Mutex<HashMap<Path, Arc<Bucket>>>>,
+        buckets: Buckets,
         global: Arc<GlobalLockPair>,
-        path: Path,
+        global_bucket: Option<Arc<GlobalBucket>>,
+        hashes: Hashes,
+        key: BucketKey,
     ) -> Self {
         Self {
+            aliases: Vec::new(),
             bucket,
             buckets,
             global,
-            path,
+            global_bucket,
+            hashes,
+            hash_learned: false,
+            key,
         }
     }
 
-    pub async fn run(self) {
+    pub async fn run(mut self) {
         #[cfg(feature = "tracing")]
-        let span = tracing::debug_span!("background queue task", path=?self.path);
+        let span = tracing::debug_span!("background queue task", key=?self.key);
 
-        while let Some(queue_tx) = self.next().await {
-            let (tx, rx) = oneshot::channel();
+        while let Some((priority, queue_tx)) = self.next().await {
+            self.global.wait(priority).await;
 
-            if self.global.is_locked() {
-                self.global.0.lock().await;
+            if let Some(global_bucket) = &self.global_bucket {
+                global_bucket.acquire(priority).await;
             }
 
-            let _sent = queue_tx.send(tx);
+            let rx = match queue_tx.available() {
+                Some(rx) => rx,
+                None => continue,
+            };
 
             #[cfg(feature = "tracing")]
             tracing::debug!(parent: &span, "starting to wait for response headers",);
@@ -204,10 +218,19 @@ impl BucketQueueTask {
         #[cfg(feature = "tracing")]
         tracing::debug!(parent: &span, "bucket appears finished, removing");
 
-        self.buckets.lock().await.remove(&self.path);
+        let mut buckets = self.buckets.lock().await;
+
+        for key in Some(&self.key).into_iter().chain(&self.aliases) {
+            // Only remove keys still pointing to this bucket.
+            if let Entry::Occupied(entry) = buckets.entry(key.clone()) {
+                if Arc::ptr_eq(entry.get(), &self.bucket) {
+                    entry.remove();
+                }
+            }
+        }
     }
 
-    async fn handle_headers(&self, headers: &RatelimitHeaders) {
+    async fn handle_headers(&mut self, headers: &RatelimitHeaders) {
         let ratelimits = match headers {
             RatelimitHeaders::GlobalLimited { reset_after } => {
                 self.lock_global(Duration::from_secs(*reset_after)).await;
@@ -216,6 +239,7 @@ impl BucketQueueTask {
             }
             RatelimitHeaders::None => return,
             RatelimitHeaders::Present {
+                bucket,
                 global,
                 limit,
                 remaining,
@@ -226,18 +250,51 @@ impl BucketQueueTask {
                     self.lock_global(Duration::from_secs(*reset_after)).await;
                 }
 
+                if let Some(hash) = bucket {
+                    self.learn_hash(hash).await;
+                }
+
                 Some((*limit, *remaining, *reset_after))
             }
         };
 
         #[cfg(feature = "tracing")]
-        tracing::debug!(path=?self.path, "updating bucket");
+        tracing::debug!(key=?self.key, "updating bucket");
         self.bucket.update(ratelimits).await;
     }
 
+    /// Learn the bucket hash of the path this bucket was created for.
+    ///
+    /// Future requests to the path are queued on the bucket of the hash. If
+    /// no bucket exists for the hash yet then this bucket is registered for
+    /// it, so that its ratelimit information carries over.
+    async fn learn_hash(&mut self, hash: &str) {
+        let path = match &self.key {
+            BucketKey::Path(path) if !self.hash_learned => path,
+            _ => return,
+        };
+
+        self.hash_learned = true;
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(?path, %hash, "learned bucket hash");
+
+        self.hashes
+            .lock()
+            .await
+            .insert(path.clone(), hash.to_owned());
+
+        let key = BucketKey::hash(hash.to_owned(), path);
+
+        if let Entry::Vacant(entry) = self.buckets.lock().await.entry(key.clone()) {
+            entry.insert(Arc::clone(&self.bucket));
+            self.aliases.push(key);
+        }
+    }
+
     async fn lock_global(&self, wait: Duration) {
         #[cfg(feature = "tracing")]
-        tracing::debug!(path=?self.path, "request got global ratelimited");
+        tracing::debug!(key=?self.key, "request got global ratelimited");
         self.global.lock();
         let lock = self.global.0.lock().await;
         sleep(wait).await;
@@ -246,9 +303,9 @@ impl BucketQueueTask {
         drop(lock);
     }
 
-    async fn next(&self) -> Option<Sender<Sender<Option<RatelimitHeaders>>>> {
+    async fn next(&self) -> Option<(Priority, TicketNotifier)> {
         #[cfg(feature = "tracing")]
-        tracing::debug!(path=?self.path, "starting to get next in queue");
+        tracing::debug!(key=?self.key, "starting to get next in queue");
 
         self.wait_if_needed().await;
 
@@ -257,7 +314,7 @@ impl BucketQueueTask {
 
     async fn wait_if_needed(&self) {
         #[cfg(feature = "tracing")]
-        let span = tracing::debug_span!("waiting for bucket to refresh", path=?self.path);
+        let span = tracing::debug_span!("waiting for bucket to refresh", key=?self.key);
 
         let wait = {
             if self.bucket.remaining() > 0 {
@@ -293,3 +350,33 @@ impl BucketQueueTask {
         self.bucket.try_reset().await;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::BucketQueue;
+    use crate::ratelimiting::{ticket, Priority};
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_queue_priority_order() {
+        let queue = BucketQueue::default();
+
+        let (background, _background_rx) = ticket::channel();
+        let (normal, _normal_rx) = ticket::channel();
+        let (urgent, _urgent_rx) = ticket::channel();
+        queue.push(background, Priority::Background);
+        queue.push(normal, Priority::Normal);
+        queue.push(urgent, Priority::Urgent);
+
+        let mut order = Vec::new();
+
+        while let Some((priority, _)) = queue.pop(Duration::from_millis(10)).await {
+            order.push(priority);
+        }
+
+        assert_eq!(
+            vec![Priority::Urgent, Priority::Normal, Priority::Background],
+            order
+        );
+    }
+}
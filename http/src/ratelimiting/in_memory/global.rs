@@ -0,0 +1,213 @@
+use super::waiters::PriorityWaiters;
+use crate::ratelimiting::Priority;
+use std::time::{Duration, Instant};
+use tokio::{sync::Mutex, time::sleep};
+
+/// Configuration of the proactive global ratelimit of an
+/// [`InMemoryRatelimiter`].
+///
+/// The API limits the number of requests a bot may make per second across
+/// all routes. Proactively limiting requests avoids receiving global
+/// ratelimit responses when bursting past this limit.
+///
+/// The default limit is 50 requests per second, with interaction callbacks
+/// exempted since they aren't bound to the global ratelimit.
+///
+/// # Examples
+///
+/// Allow 100 requests per second, for a bot that has been granted a higher
+/// global ratelimit:
+///
+/// ```rust,no_run
+/// use twilight_http::{ratelimiting::GlobalLimit, Client};
+///
+/// let client = Client::builder()
+///     .token("my token")
+///     .global_limit(GlobalLimit::new(100))
+///     .build();
+/// ```
+///
+/// [`InMemoryRatelimiter`]: super::InMemoryRatelimiter
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct GlobalLimit {
+    exempt_interactions: bool,
+    requests_per_second: u32,
+}
+
+impl GlobalLimit {
+    /// Create a new global limit allowing a number of requests per second.
+    ///
+    /// A limit of 0 is treated as 1.
+    pub fn new(requests_per_second: u32) -> Self {
+        Self {
+            exempt_interactions: true,
+            requests_per_second: requests_per_second.max(1),
+        }
+    }
+
+    /// Set whether requests to interaction callbacks are exempt from the
+    /// limit.
+    ///
+    /// Followup messages are sent through webhook routes and are always
+    /// limited.
+    ///
+    /// The default is true.
+    pub const fn exempt_interactions(mut self, exempt_interactions: bool) -> Self {
+        self.exempt_interactions = exempt_interactions;
+
+        self
+    }
+
+    /// Whether requests to interaction callbacks are exempt from the limit.
+    pub const fn is_exempting_interactions(&self) -> bool {
+        self.exempt_interactions
+    }
+
+    /// Number of requests allowed per second.
+    pub const fn requests_per_second(&self) -> u32 {
+        self.requests_per_second
+    }
+}
+
+impl Default for GlobalLimit {
+    fn default() -> Self {
+        Self::new(50)
+    }
+}
+
+#[derive(Debug)]
+struct GlobalBucketState {
+    remaining: u32,
+    reset_at: Option<Instant>,
+}
+
+/// Token bucket enforcing a [`GlobalLimit`], refilled every second.
+///
+/// Requests waiting for a token yield to waiting requests of a higher
+/// [`Priority`].
+#[derive(Debug)]
+pub(super) struct GlobalBucket {
+    limit: GlobalLimit,
+    state: Mutex<GlobalBucketState>,
+    waiters: PriorityWaiters,
+}
+
+impl GlobalBucket {
+    const PERIOD: Duration = Duration::from_secs(1);
+
+    pub fn new(limit: GlobalLimit) -> Self {
+        Self {
+            limit,
+            state: Mutex::new(GlobalBucketState {
+                remaining: limit.requests_per_second(),
+                reset_at: None,
+            }),
+            waiters: PriorityWaiters::default(),
+        }
+    }
+
+    pub const fn limit(&self) -> GlobalLimit {
+        self.limit
+    }
+
+    /// Take a token from the bucket, waiting for it to be refilled if none
+    /// are remaining or requests of a higher priority are waiting.
+    pub async fn acquire(&self, priority: Priority) {
+        let _waiting = self.waiters.register(priority);
+
+        loop {
+            self.waiters.yield_to_higher(priority).await;
+
+            let wait = {
+                let mut state = self.state.lock().await;
+                let now = Instant::now();
+
+                match state.reset_at {
+                    Some(reset_at) if reset_at > now => {}
+                    _ => {
+                        state.remaining = self.limit.requests_per_second();
+                        state.reset_at = Some(now + Self::PERIOD);
+                    }
+                }
+
+                if state.remaining > 0 {
+                    state.remaining -= 1;
+
+                    return;
+                }
+
+                state
+                    .reset_at
+                    .map_or(Duration::from_secs(0), |reset_at| reset_at - now)
+            };
+
+            #[cfg(feature = "tracing")]
+            tracing::debug!(milliseconds = %wait.as_millis(), "waiting for global bucket");
+
+            sleep(wait).await;
+        }
+    }
+
+    /// Number of tokens remaining and the time until the bucket is refilled.
+    pub async fn remaining(&self) -> (u32, Option<Duration>) {
+        let state = self.state.lock().await;
+        let now = Instant::now();
+
+        match state.reset_at {
+            Some(reset_at) if reset_at > now => (state.remaining, Some(reset_at - now)),
+            _ => (self.limit.requests_per_second(), None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{GlobalBucket, GlobalLimit};
+    use crate::ratelimiting::Priority;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash, time::Duration};
+    use tokio::time::{self, Instant};
+
+    assert_impl_all!(
+        GlobalLimit: Clone,
+        Copy,
+        Debug,
+        Default,
+        Eq,
+        Hash,
+        PartialEq,
+        Send,
+        Sync
+    );
+
+    #[test]
+    fn test_limit() {
+        let limit = GlobalLimit::default();
+        assert_eq!(50, limit.requests_per_second());
+        assert!(limit.is_exempting_interactions());
+
+        assert_eq!(1, GlobalLimit::new(0).requests_per_second());
+        assert!(!GlobalLimit::new(5)
+            .exempt_interactions(false)
+            .is_exempting_interactions());
+    }
+
+    #[tokio::test]
+    async fn test_acquire() {
+        let bucket = GlobalBucket::new(GlobalLimit::new(2));
+        assert_eq!((2, None), bucket.remaining().await);
+
+        let start = Instant::now();
+        bucket.acquire(Priority::Normal).await;
+        bucket.acquire(Priority::Normal).await;
+        assert!(start.elapsed() < Duration::from_millis(500));
+        assert_eq!(0, bucket.remaining().await.0);
+
+        // The third request has to wait for the bucket to be refilled.
+        time::timeout(Duration::from_secs(2), bucket.acquire(Priority::Normal))
+            .await
+            .expect("bucket is refilled");
+        assert!(start.elapsed() >= Duration::from_millis(900));
+        assert_eq!(1, bucket.remaining().await.0);
+    }
+}
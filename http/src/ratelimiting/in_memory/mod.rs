@@ -0,0 +1,413 @@
+//! In-memory ratelimiter tracking buckets in the current process.
+
+mod bucket;
+mod global;
+mod waiters;
+
+pub use self::global::GlobalLimit;
+
+use self::{
+    bucket::{Bucket, BucketQueueTask, TimeRemaining},
+    global::GlobalBucket,
+    waiters::PriorityWaiters,
+};
+use super::{
+    ticket::{self, TicketNotifier},
+    GetTicketFuture, GlobalRatelimitState, GlobalStateFuture, Priority, Ratelimiter,
+    TimeUntilAvailableFuture,
+};
+use crate::routing::Path;
+use std::{
+    collections::hash_map::{Entry, HashMap},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+use tokio::sync::Mutex;
+
+/// Global lock. We use a pair to avoid actually locking the mutex every check.
+/// This allows futures to only wait on the global lock when a global ratelimit
+/// is in place by, in turn, waiting for a guard, and then each immediately
+/// dropping it.
+///
+/// Once the lock is released, waiting requests yield to waiting requests of a
+/// higher priority.
+#[derive(Debug, Default)]
+struct GlobalLockPair(Mutex<()>, AtomicBool, PriorityWaiters);
+
+impl GlobalLockPair {
+    /// Wait until the global lock is released, if it is locked.
+    pub async fn wait(&self, priority: Priority) {
+        if !self.is_locked() {
+            return;
+        }
+
+        let _waiting = self.2.register(priority);
+        drop(self.0.lock().await);
+
+        self.2.yield_to_higher(priority).await;
+    }
+
+    pub fn lock(&self) {
+        self.1.store(true, Ordering::Release);
+    }
+
+    pub fn unlock(&self) {
+        self.1.store(false, Ordering::Release);
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.1.load(Ordering::Relaxed)
+    }
+}
+
+/// Key identifying a bucket.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+enum BucketKey {
+    /// Bucket hash returned by the API, scoped to the major parameter of the
+    /// paths sharing the bucket.
+    Hash {
+        hash: String,
+        major_parameter: Option<u64>,
+    },
+    /// Path whose bucket hash hasn't been learned yet.
+    Path(Path),
+}
+
+impl BucketKey {
+    const fn hash(hash: String, path: &Path) -> Self {
+        Self::Hash {
+            hash,
+            major_parameter: path.major_parameter(),
+        }
+    }
+}
+
+type Buckets = Arc<Mutex<HashMap<BucketKey, Arc<Bucket>>>>;
+
+type Hashes = Arc<Mutex<HashMap<Path, String>>>;
+
+/// Default ratelimiter, tracking buckets in the current process.
+///
+/// Requests are proactively limited by a [`GlobalLimit`] before being
+/// released from their bucket's queue, avoiding global ratelimits imposed by
+/// the API when many buckets are used at once.
+///
+/// Buckets are initially tracked per [`Path`]. The API groups some paths into
+/// one bucket, so the bucket hash returned in responses is learned for each
+/// path and requests to paths sharing a bucket hash and major parameter are
+/// queued on the same bucket.
+///
+/// Cloning the ratelimiter is cheap and clones share their buckets, so a
+/// clone may be given to multiple clients using the same token.
+#[derive(Clone, Debug)]
+pub struct InMemoryRatelimiter {
+    buckets: Buckets,
+    global: Arc<GlobalLockPair>,
+    global_bucket: Option<Arc<GlobalBucket>>,
+    hashes: Hashes,
+}
+
+impl InMemoryRatelimiter {
+    /// Create a new ratelimiter with the default [`GlobalLimit`].
+    ///
+    /// Most users won't need to use this directly. If you're creating your own
+    /// HTTP proxy then this is good to use for your own ratelimiting.
+    pub fn new() -> Self {
+        Self::with_global_limit(GlobalLimit::default())
+    }
+
+    /// Create a new ratelimiter with a global limit.
+    ///
+    /// If the argument is `None` then requests will only be globally limited
+    /// after the API responds with a global ratelimit.
+    pub fn with_global_limit(global_limit: impl Into<Option<GlobalLimit>>) -> Self {
+        Self {
+            buckets: Buckets::default(),
+            global: Arc::default(),
+            global_bucket: global_limit
+                .into()
+                .map(|limit| Arc::new(GlobalBucket::new(limit))),
+            hashes: Hashes::default(),
+        }
+    }
+
+    /// Global limit requests are proactively limited by, if any.
+    pub fn global_limit(&self) -> Option<GlobalLimit> {
+        self.global_bucket.as_ref().map(|bucket| bucket.limit())
+    }
+
+    /// Bucket hash learned for a path, if any.
+    pub async fn bucket_hash(&self, path: &Path) -> Option<String> {
+        self.hashes.lock().await.get(path).cloned()
+    }
+
+    /// Key of the bucket that requests to a path are queued on.
+    async fn key(hashes: &Mutex<HashMap<Path, String>>, path: Path) -> BucketKey {
+        match hashes.lock().await.get(&path) {
+            Some(hash) => BucketKey::hash(hash.clone(), &path),
+            None => BucketKey::Path(path),
+        }
+    }
+
+    async fn entry(
+        buckets: &Buckets,
+        key: BucketKey,
+        tx: TicketNotifier,
+        priority: Priority,
+    ) -> (Arc<Bucket>, bool) {
+        // nb: not realisically point of contention
+        let mut buckets = buckets.lock().await;
+
+        match buckets.entry(key) {
+            Entry::Occupied(bucket) => {
+                #[cfg(feature = "tracing")]
+                tracing::debug!("got existing bucket: {:?}", bucket.key());
+
+                let bucket = bucket.into_mut();
+                bucket.queue.push(tx, priority);
+                #[cfg(feature = "tracing")]
+                tracing::debug!("added request into bucket queue");
+
+                (Arc::clone(&bucket), false)
+            }
+            Entry::Vacant(entry) => {
+                #[cfg(feature = "tracing")]
+                tracing::debug!("making new bucket: {:?}", entry.key());
+                let bucket = Bucket::new();
+                bucket.queue.push(tx, priority);
+
+                let bucket = Arc::new(bucket);
+                entry.insert(Arc::clone(&bucket));
+
+                (bucket, true)
+            }
+        }
+    }
+}
+
+impl Default for InMemoryRatelimiter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Ratelimiter for InMemoryRatelimiter {
+    fn ticket(&self, path: Path) -> GetTicketFuture {
+        let priority = Priority::from_path(&path);
+
+        self.ticket_with_priority(path, priority)
+    }
+
+    fn ticket_with_priority(&self, path: Path, priority: Priority) -> GetTicketFuture {
+        #[cfg(feature = "tracing")]
+        tracing::debug!("getting bucket for path: {:?}", path);
+
+        let buckets = Arc::clone(&self.buckets);
+        let global = Arc::clone(&self.global);
+        let hashes = Arc::clone(&self.hashes);
+
+        // Interaction callbacks aren't bound to the global ratelimit.
+        let global_bucket = self.global_bucket.as_ref().filter(|bucket| {
+            !(bucket.limit().is_exempting_interactions()
+                && matches!(path, Path::InteractionCallback(_)))
+        });
+        let global_bucket = global_bucket.map(Arc::clone);
+
+        Box::pin(async move {
+            let key = Self::key(&hashes, path).await;
+            let (tx, rx) = ticket::channel();
+            let (bucket, fresh) = Self::entry(&buckets, key.clone(), tx, priority).await;
+
+            if fresh {
+                tokio::spawn(
+                    BucketQueueTask::new(bucket, buckets, global, global_bucket, hashes, key).run(),
+                );
+            }
+
+            Ok(rx)
+        })
+    }
+
+    fn global_state(&self) -> GlobalStateFuture {
+        let global = Arc::clone(&self.global);
+        let global_bucket = self.global_bucket.clone();
+
+        Box::pin(async move {
+            let (limit, remaining, reset_after) = match global_bucket {
+                Some(bucket) => {
+                    let (remaining, reset_after) = bucket.remaining().await;
+
+                    (
+                        Some(bucket.limit().requests_per_second()),
+                        Some(remaining),
+                        reset_after,
+                    )
+                }
+                None => (None, None, None),
+            };
+
+            Ok(Some(GlobalRatelimitState {
+                limit,
+                locked: global.is_locked(),
+                remaining,
+                reset_after,
+            }))
+        })
+    }
+
+    fn time_until_available(&self, path: &Path) -> TimeUntilAvailableFuture {
+        let buckets = Arc::clone(&self.buckets);
+        let hashes = Arc::clone(&self.hashes);
+        let path = path.clone();
+
+        Box::pin(async move {
+            let key = Self::key(&hashes, path).await;
+            let buckets = buckets.lock().await;
+
+            let bucket = match buckets.get(&key) {
+                Some(bucket) => bucket,
+                None => return Ok(None),
+            };
+
+            Ok(match bucket.time_remaining().await {
+                TimeRemaining::Finished | TimeRemaining::NotStarted => None,
+                TimeRemaining::Some(duration) => Some(duration),
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BucketKey, GlobalLimit, InMemoryRatelimiter};
+    use crate::{
+        ratelimiting::{GlobalRatelimitState, RatelimitHeaders, Ratelimiter},
+        routing::Path,
+    };
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug, sync::Arc, time::Duration};
+    use tokio::time;
+
+    assert_impl_all!(InMemoryRatelimiter: Clone, Debug, Default, Ratelimiter, Send, Sync);
+
+    /// Make a request to a path, reporting the given bucket hash, and wait
+    /// for the ratelimiter to learn it.
+    async fn request(
+        ratelimiter: &InMemoryRatelimiter,
+        path: Path,
+        hash: &str,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let sender = ratelimiter.ticket(path.clone()).await?.await?;
+        let _sent = sender.headers(Some(RatelimitHeaders::Present {
+            bucket: Some(hash.to_owned()),
+            global: false,
+            limit: 5,
+            remaining: 4,
+            reset: 0,
+            reset_after: 1000,
+        }));
+
+        for _ in 0..1000 {
+            if ratelimiter.bucket_hash(&path).await.is_some() {
+                return Ok(());
+            }
+
+            time::sleep(Duration::from_millis(1)).await;
+        }
+
+        Err("bucket hash was not learned".into())
+    }
+
+    #[tokio::test]
+    async fn test_global_state() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let ratelimiter = InMemoryRatelimiter::with_global_limit(GlobalLimit::new(5));
+        assert_eq!(Some(GlobalLimit::new(5)), ratelimiter.global_limit());
+
+        let _sender = ratelimiter.ticket(Path::ChannelsIdPins(1)).await?.await?;
+        let state = ratelimiter.global_state().await?.expect("state is known");
+        assert_eq!(Some(5), state.limit);
+        assert_eq!(Some(4), state.remaining);
+        assert!(state.reset_after.is_some());
+        assert!(!state.locked);
+
+        // Interaction callbacks are exempt from the limit by default.
+        let _sender = ratelimiter
+            .ticket(Path::InteractionCallback(2))
+            .await?
+            .await?;
+        let state = ratelimiter.global_state().await?.expect("state is known");
+        assert_eq!(Some(4), state.remaining);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_global_state_unlimited() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let ratelimiter = InMemoryRatelimiter::with_global_limit(None);
+        let _sender = ratelimiter.ticket(Path::ChannelsIdPins(1)).await?.await?;
+
+        assert_eq!(
+            Some(GlobalRatelimitState {
+                limit: None,
+                locked: false,
+                remaining: None,
+                reset_after: None,
+            }),
+            ratelimiter.global_state().await?,
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_paths_share_bucket() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let ratelimiter = InMemoryRatelimiter::new();
+        let pins = Path::ChannelsIdPins(1);
+        let pin_message = Path::ChannelsIdPinsMessageId(1);
+
+        request(&ratelimiter, pins.clone(), "abcd").await?;
+        request(&ratelimiter, pin_message.clone(), "abcd").await?;
+
+        let pins_key = InMemoryRatelimiter::key(&ratelimiter.hashes, pins).await;
+        let pin_message_key = InMemoryRatelimiter::key(&ratelimiter.hashes, pin_message).await;
+        assert_eq!(pins_key, pin_message_key);
+        assert_eq!(
+            BucketKey::Hash {
+                hash: "abcd".to_owned(),
+                major_parameter: Some(1),
+            },
+            pins_key,
+        );
+
+        // The bucket of the first path is shared with the hash, carrying over
+        // its ratelimit information.
+        let buckets = ratelimiter.buckets.lock().await;
+        let shared = buckets.get(&pins_key).expect("hash bucket exists");
+        let original = buckets
+            .get(&BucketKey::Path(Path::ChannelsIdPins(1)))
+            .expect("path bucket exists");
+        assert!(Arc::ptr_eq(shared, original));
+        assert_eq!(4, shared.remaining());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_major_parameter_separates_buckets() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let ratelimiter = InMemoryRatelimiter::new();
+
+        request(&ratelimiter, Path::ChannelsIdPins(1), "abcd").await?;
+        request(&ratelimiter, Path::ChannelsIdPins(2), "abcd").await?;
+
+        let first = InMemoryRatelimiter::key(&ratelimiter.hashes, Path::ChannelsIdPins(1)).await;
+        let second = InMemoryRatelimiter::key(&ratelimiter.hashes, Path::ChannelsIdPins(2)).await;
+        assert_ne!(first, second);
+
+        let buckets = ratelimiter.buckets.lock().await;
+        assert!(!Arc::ptr_eq(&buckets[&first], &buckets[&second]));
+
+        Ok(())
+    }
+}
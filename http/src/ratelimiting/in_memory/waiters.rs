@@ -0,0 +1,98 @@
+use crate::ratelimiting::Priority;
+use std::sync::Mutex;
+use tokio::sync::Notify;
+
+/// Number of requests of each priority waiting for a shared resource, such
+/// as the global ratelimit, allowing requests to yield to waiting requests
+/// of a higher priority.
+#[derive(Debug, Default)]
+pub(super) struct PriorityWaiters {
+    counts: Mutex<[usize; Priority::LANES]>,
+    released: Notify,
+}
+
+impl PriorityWaiters {
+    /// Register a request of a priority as waiting until the returned guard
+    /// is dropped.
+    pub fn register(&self, priority: Priority) -> WaiterGuard<'_> {
+        self.counts.lock().expect("waiters poisoned")[priority.lane()] += 1;
+
+        WaiterGuard {
+            priority,
+            waiters: self,
+        }
+    }
+
+    /// Wait until no requests of a higher priority than a priority are
+    /// waiting.
+    pub async fn yield_to_higher(&self, priority: Priority) {
+        loop {
+            let released = self.released.notified();
+
+            if !self.has_higher(priority) {
+                return;
+            }
+
+            released.await;
+        }
+    }
+
+    fn has_higher(&self, priority: Priority) -> bool {
+        let counts = self.counts.lock().expect("waiters poisoned");
+
+        counts[..priority.lane()].iter().any(|count| *count > 0)
+    }
+}
+
+/// Guard of a waiting request, unregistering it when dropped.
+pub(super) struct WaiterGuard<'a> {
+    priority: Priority,
+    waiters: &'a PriorityWaiters,
+}
+
+impl Drop for WaiterGuard<'_> {
+    fn drop(&mut self) {
+        self.waiters.counts.lock().expect("waiters poisoned")[self.priority.lane()] -= 1;
+        self.waiters.released.notify_waiters();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PriorityWaiters;
+    use crate::ratelimiting::Priority;
+    use std::time::Duration;
+    use tokio::time;
+
+    #[tokio::test]
+    async fn test_yield_to_higher() {
+        let waiters = PriorityWaiters::default();
+
+        let urgent = waiters.register(Priority::Urgent);
+        let _background = waiters.register(Priority::Background);
+
+        // Waiting requests of a lower priority don't hold up requests.
+        time::timeout(
+            Duration::from_millis(100),
+            waiters.yield_to_higher(Priority::Urgent),
+        )
+        .await
+        .expect("no higher priority request is waiting");
+
+        assert!(time::timeout(
+            Duration::from_millis(100),
+            waiters.yield_to_higher(Priority::Normal)
+        )
+        .await
+        .is_err());
+
+        drop(urgent);
+
+        time::timeout(
+            Duration::from_millis(100),
+            waiters.yield_to_higher(Priority::Background),
+        )
+        .await
+        .expect("urgent request is no longer waiting");
+    }
+}
@@ -1,130 +1,249 @@
+//! Ratelimiting functionality for HTTP requests.
+//!
+//! The [`Ratelimiter`] trait allows the [`Client`] to be used with any
+//! ratelimiting backend, such as one shared by multiple processes. By default
+//! the client uses the [`InMemoryRatelimiter`], which tracks buckets in the
+//! current process.
+//!
+//! [`Client`]: crate::Client
+
 pub mod error;
+pub mod in_memory;
+pub mod ticket;
 
-mod bucket;
 mod headers;
+mod priority;
 
 pub use self::{
     error::{RatelimitError, RatelimitResult},
     headers::RatelimitHeaders,
+    in_memory::{GlobalLimit, InMemoryRatelimiter},
+    priority::Priority,
 };
 
+use self::ticket::TicketReceiver;
 use crate::routing::Path;
-use bucket::{Bucket, BucketQueueTask, TimeRemaining};
 use std::{
-    collections::hash_map::{Entry, HashMap},
-    sync::{
-        atomic::{AtomicBool, Ordering},
-        Arc,
-    },
+    error::Error,
+    fmt::Debug,
+    future::{self, Future},
+    pin::Pin,
     time::Duration,
 };
-use tokio::sync::{
-    oneshot::{self, Receiver, Sender},
-    Mutex,
-};
 
-/// Global lock. We use a pair to avoid actually locking the mutex every check.
-/// This allows futures to only wait on the global lock when a global ratelimit
-/// is in place by, in turn, waiting for a guard, and then each immediately
-/// dropping it.
-#[derive(Debug, Default)]
-struct GlobalLockPair(Mutex<()>, AtomicBool);
+/// Error returned by a [`Ratelimiter`] implementation.
+pub type GenericError = Box<dyn Error + Send + Sync>;
+
+/// Future returned by [`Ratelimiter::ticket`].
+pub type GetTicketFuture =
+    Pin<Box<dyn Future<Output = Result<TicketReceiver, GenericError>> + Send + 'static>>;
+
+/// Future returned by [`Ratelimiter::global_state`].
+pub type GlobalStateFuture = Pin<
+    Box<dyn Future<Output = Result<Option<GlobalRatelimitState>, GenericError>> + Send + 'static>,
+>;
+
+/// Future returned by [`Ratelimiter::time_until_available`].
+pub type TimeUntilAvailableFuture =
+    Pin<Box<dyn Future<Output = Result<Option<Duration>, GenericError>> + Send + 'static>>;
+
+/// State of the global ratelimit shared by all paths.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct GlobalRatelimitState {
+    /// Number of requests allowed per second, if requests are proactively
+    /// limited.
+    pub limit: Option<u32>,
+    /// Whether the API has globally ratelimited requests, in which case no
+    /// requests are made until the ratelimit resets.
+    pub locked: bool,
+    /// Number of requests that can be made before the proactive limit is
+    /// reached, if requests are proactively limited.
+    pub remaining: Option<u32>,
+    /// Time until the number of remaining requests is reset, if requests
+    /// have been made in the current period.
+    pub reset_after: Option<Duration>,
+}
 
-impl GlobalLockPair {
-    pub fn lock(&self) {
-        self.1.store(true, Ordering::Release);
-    }
+/// Ratelimiter that decides when requests to a [`Path`] can be made.
+///
+/// Implementations may track buckets in memory, as [`InMemoryRatelimiter`]
+/// does, or in an external store shared by multiple processes using the same
+/// token.
+///
+/// # Examples
+///
+/// Implement a ratelimiter that allows every request to be made immediately:
+///
+/// ```
+/// use std::future;
+/// use twilight_http::{
+///     ratelimiting::{ticket, GetTicketFuture, Ratelimiter, TimeUntilAvailableFuture},
+///     routing::Path,
+/// };
+///
+/// #[derive(Debug)]
+/// struct Unlimited;
+///
+/// impl Ratelimiter for Unlimited {
+///     fn ticket(&self, _: Path) -> GetTicketFuture {
+///         let (notifier, receiver) = ticket::channel();
+///
+///         // The headers of the response are of no interest.
+///         let _headers = notifier.available();
+///
+///         Box::pin(future::ready(Ok(receiver)))
+///     }
+///
+///     fn time_until_available(&self, _: &Path) -> TimeUntilAvailableFuture {
+///         Box::pin(future::ready(Ok(None)))
+///     }
+/// }
+/// ```
+pub trait Ratelimiter: Debug + Send + Sync {
+    /// Retrieve a ticket to make a request to a path.
+    ///
+    /// The returned [`TicketReceiver`] resolves once the request can be
+    /// made. The requester must then report the ratelimit headers of the
+    /// response through the resolved [`TicketSender`].
+    ///
+    /// [`TicketSender`]: ticket::TicketSender
+    fn ticket(&self, path: Path) -> GetTicketFuture;
 
-    pub fn unlock(&self) {
-        self.1.store(false, Ordering::Release);
+    /// Retrieve a ticket to make a request of a [`Priority`] to a path.
+    ///
+    /// Implementations should release tickets of a higher priority before
+    /// tickets of a lower priority. The default implementation ignores the
+    /// priority and calls [`ticket`].
+    ///
+    /// [`ticket`]: Self::ticket
+    fn ticket_with_priority(&self, path: Path, _priority: Priority) -> GetTicketFuture {
+        self.ticket(path)
     }
 
-    pub fn is_locked(&self) -> bool {
-        self.1.load(Ordering::Relaxed)
+    /// Retrieve the state of the global ratelimit.
+    ///
+    /// The default implementation returns `None`, indicating that the state
+    /// isn't known.
+    fn global_state(&self) -> GlobalStateFuture {
+        Box::pin(future::ready(Ok(None)))
     }
-}
 
-#[derive(Clone, Debug, Default)]
-pub struct Ratelimiter {
-    buckets: Arc<Mutex<HashMap<Path, Arc<Bucket>>>>,
-    global: Arc<GlobalLockPair>,
+    /// Provide an estimate for the time left until a path can be used
+    /// without being ratelimited.
+    ///
+    /// This method is not guaranteed to be accurate and may return `None` if
+    /// either no ratelimit is known or buckets are remaining.
+    fn time_until_available(&self, path: &Path) -> TimeUntilAvailableFuture;
 }
 
-impl Ratelimiter {
-    /// Create a new ratelimiter.
-    ///
-    /// Most users won't need to use this directly. If you're creating your own
-    /// HTTP proxy then this is good to use for your own ratelimiting.
-    pub fn new() -> Self {
-        Self::default()
+#[cfg(test)]
+mod tests {
+    use super::{
+        ticket, GetTicketFuture, InMemoryRatelimiter, RatelimitHeaders, Ratelimiter,
+        TimeUntilAvailableFuture,
+    };
+    use crate::{routing::Path, test_server, Client};
+    use hyper::{Body, Response};
+    use static_assertions::assert_obj_safe;
+    use std::{
+        error::Error,
+        future,
+        net::SocketAddr,
+        sync::{Arc, Mutex},
+    };
+    use twilight_model::id::ChannelId;
+
+    assert_obj_safe!(Ratelimiter);
+
+    /// Ratelimiter that immediately hands out tickets, recording the paths
+    /// they were requested for and the headers reported back.
+    #[derive(Clone, Debug, Default)]
+    struct RecordingRatelimiter {
+        headers: Arc<Mutex<Vec<Option<RatelimitHeaders>>>>,
+        paths: Arc<Mutex<Vec<Path>>>,
     }
 
-    pub async fn get(&self, path: Path) -> Receiver<Sender<Option<RatelimitHeaders>>> {
-        #[cfg(feature = "tracing")]
-        tracing::debug!("getting bucket for path: {:?}", path);
-
-        let (tx, rx) = oneshot::channel();
-        let (bucket, fresh) = self.entry(path.clone(), tx).await;
-
-        if fresh {
-            tokio::spawn(
-                BucketQueueTask::new(
-                    bucket,
-                    Arc::clone(&self.buckets),
-                    Arc::clone(&self.global),
-                    path,
-                )
-                .run(),
-            );
+    impl Ratelimiter for RecordingRatelimiter {
+        fn ticket(&self, path: Path) -> GetTicketFuture {
+            self.paths.lock().unwrap().push(path);
+
+            let (notifier, receiver) = ticket::channel();
+            let headers = notifier.available().expect("receiver is alive");
+            let recorded = Arc::clone(&self.headers);
+
+            tokio::spawn(async move {
+                if let Ok(headers) = headers.await {
+                    recorded.lock().unwrap().push(headers);
+                }
+            });
+
+            Box::pin(future::ready(Ok(receiver)))
         }
 
-        rx
+        fn time_until_available(&self, _: &Path) -> TimeUntilAvailableFuture {
+            Box::pin(future::ready(Ok(None)))
+        }
     }
 
-    /// Provide an estimate for the time left until a path can be used
-    /// without being ratelimited.
-    ///
-    /// This method is not guaranteed to be accurate and may return
-    /// None if either no ratelimit is known or buckets are remaining.
-    pub async fn time_until_available(&self, path: &Path) -> Option<Duration> {
-        let buckets = self.buckets.lock().await;
-        match buckets.get(path)?.time_remaining().await {
-            TimeRemaining::Finished | TimeRemaining::NotStarted => None,
-            TimeRemaining::Some(duration) => Some(duration),
-        }
+    /// Start a server responding to every request with ratelimit headers and
+    /// an empty JSON array, returning its address.
+    fn start_server() -> SocketAddr {
+        test_server::start(|_| async {
+            Response::builder()
+                .header("x-ratelimit-bucket", "abcd")
+                .header("x-ratelimit-limit", "5")
+                .header("x-ratelimit-remaining", "4")
+                .header("x-ratelimit-reset", "1625000000.5")
+                .header("x-ratelimit-reset-after", "1.5")
+                .body(Body::from("[]"))
+        })
     }
 
-    async fn entry(
-        &self,
-        path: Path,
-        tx: Sender<Sender<Option<RatelimitHeaders>>>,
-    ) -> (Arc<Bucket>, bool) {
-        // nb: not realisically point of contention
-        let mut buckets = self.buckets.lock().await;
-
-        match buckets.entry(path.clone()) {
-            Entry::Occupied(bucket) => {
-                #[cfg(feature = "tracing")]
-                tracing::debug!("got existing bucket: {:?}", path);
-
-                let bucket = bucket.into_mut();
-                bucket.queue.push(tx);
-                #[cfg(feature = "tracing")]
-                tracing::debug!("added request into bucket queue: {:?}", path);
-
-                (Arc::clone(&bucket), false)
-            }
-            Entry::Vacant(entry) => {
-                #[cfg(feature = "tracing")]
-                tracing::debug!("making new bucket for path: {:?}", path);
-                let bucket = Bucket::new(path.clone());
-                bucket.queue.push(tx);
-
-                let bucket = Arc::new(bucket);
-                entry.insert(Arc::clone(&bucket));
-
-                (bucket, true)
-            }
-        }
+    #[tokio::test]
+    async fn test_global_state_default() -> Result<(), Box<dyn Error + Send + Sync>> {
+        assert!(RecordingRatelimiter::default()
+            .global_state()
+            .await?
+            .is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_in_memory_is_ratelimiter() {
+        let ratelimiter: Box<dyn Ratelimiter> = Box::new(InMemoryRatelimiter::new());
+        assert!(format!("{:?}", ratelimiter).starts_with("InMemoryRatelimiter"));
+    }
+
+    #[tokio::test]
+    async fn test_custom_ratelimiter() -> Result<(), Box<dyn Error>> {
+        let addr = start_server();
+        let ratelimiter = RecordingRatelimiter::default();
+
+        let client = Client::builder()
+            .proxy(addr.to_string(), true)
+            .ratelimiter(Some(Box::new(ratelimiter.clone())))
+            .build();
+
+        let pins = client.pins(ChannelId(1)).await?;
+        assert!(pins.is_empty());
+
+        assert_eq!(
+            [Path::ChannelsIdPins(1)].as_ref(),
+            ratelimiter.paths.lock().unwrap().as_slice()
+        );
+
+        // The headers are recorded by a separate task.
+        tokio::task::yield_now().await;
+
+        let headers = ratelimiter.headers.lock().unwrap();
+        assert!(matches!(
+            headers.as_slice(),
+            [Some(RatelimitHeaders::Present { bucket: Some(bucket), limit: 5, remaining: 4, .. })]
+                if bucket == "abcd"
+        ));
+
+        Ok(())
     }
 }
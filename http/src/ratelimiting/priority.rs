@@ -0,0 +1,147 @@
+use crate::routing::Path;
+
+/// Priority of a request when waiting to be ratelimited.
+///
+/// Requests of a higher priority are released from a bucket's queue and
+/// take global ratelimit capacity before requests of a lower priority,
+/// regardless of the order in which they were made. Requests of the same
+/// priority are released in the order in which they were made.
+///
+/// Unless a priority is set, it is derived from the [`Path`] of the request
+/// via [`Priority::from_path`].
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum Priority {
+    /// Requests that can be delayed, such as fetching audit logs or pruning
+    /// members.
+    ///
+    /// Background requests can be configured to fail if they wait in the
+    /// queue for too long via [`ClientBuilder::max_background_queue_wait`].
+    ///
+    /// [`ClientBuilder::max_background_queue_wait`]: crate::client::ClientBuilder::max_background_queue_wait
+    Background,
+    /// Requests without any particular urgency.
+    Normal,
+    /// Requests that must be made as soon as possible, such as responding
+    /// to interactions within their deadline.
+    Urgent,
+}
+
+impl Priority {
+    /// Number of priorities, each of which is queued in its own lane.
+    pub(crate) const LANES: usize = 3;
+
+    /// Derive the default priority of a request from its path.
+    ///
+    /// Interaction callbacks are [`Urgent`], fetching audit logs and pruning
+    /// members are [`Background`], and all other requests are [`Normal`].
+    ///
+    /// Followup messages and updates of original responses share their paths
+    /// with webhooks, so their requests set the [`Urgent`] priority
+    /// themselves.
+    ///
+    /// [`Background`]: Self::Background
+    /// [`Normal`]: Self::Normal
+    /// [`Urgent`]: Self::Urgent
+    pub const fn from_path(path: &Path) -> Self {
+        match path {
+            Path::InteractionCallback(_) => Self::Urgent,
+            Path::GuildsIdAuditLogs(_) | Path::GuildsIdPrune(_) => Self::Background,
+            _ => Self::Normal,
+        }
+    }
+
+    /// Index of the queue lane of the priority, with the lane of the highest
+    /// priority first.
+    pub(crate) const fn lane(self) -> usize {
+        match self {
+            Self::Urgent => 0,
+            Self::Normal => 1,
+            Self::Background => 2,
+        }
+    }
+}
+
+impl Default for Priority {
+    fn default() -> Self {
+        Self::Normal
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Priority;
+    use crate::{error::ErrorType, routing::Path, test_server, Client};
+    use hyper::{Body, Response};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug, hash::Hash, time::Duration};
+    use twilight_model::id::ChannelId;
+
+    assert_impl_all!(
+        Priority: Clone,
+        Copy,
+        Debug,
+        Default,
+        Eq,
+        Hash,
+        Ord,
+        PartialEq,
+        Send,
+        Sync
+    );
+
+    #[test]
+    fn test_order() {
+        assert!(Priority::Urgent > Priority::Normal);
+        assert!(Priority::Normal > Priority::Background);
+        assert_eq!(Priority::Normal, Priority::default());
+    }
+
+    #[test]
+    fn test_from_path() {
+        assert_eq!(
+            Priority::Urgent,
+            Priority::from_path(&Path::InteractionCallback(1))
+        );
+        assert_eq!(
+            Priority::Background,
+            Priority::from_path(&Path::GuildsIdAuditLogs(1))
+        );
+        assert_eq!(
+            Priority::Normal,
+            Priority::from_path(&Path::ChannelsIdMessages(1))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_max_background_queue_wait() -> Result<(), Box<dyn Error>> {
+        // Every response exhausts the bucket for a minute.
+        let addr = test_server::start(|_| async {
+            Response::builder()
+                .status(200)
+                .header("x-ratelimit-bucket", "pins")
+                .header("x-ratelimit-limit", "1")
+                .header("x-ratelimit-remaining", "0")
+                .header("x-ratelimit-reset", "0")
+                .header("x-ratelimit-reset-after", "60")
+                .body(Body::from("[]"))
+        });
+
+        let client = Client::builder()
+            .proxy(addr.to_string(), true)
+            .token("token")
+            .max_background_queue_wait(Duration::from_millis(100))
+            .build();
+
+        assert!(client.pins(ChannelId(1)).await?.is_empty());
+
+        let error = client
+            .with_priority(Priority::Background)
+            .pins(ChannelId(1))
+            .await
+            .unwrap_err();
+        assert!(matches!(error.kind(), ErrorType::QueueWaitExceeded));
+
+        Ok(())
+    }
+}
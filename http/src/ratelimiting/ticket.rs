@@ -0,0 +1,133 @@
+//! Flow of tickets between a [`Ratelimiter`] and the requester.
+//!
+//! A ticket represents permission to make a request. The requester retrieves
+//! a [`TicketReceiver`] from the ratelimiter, which resolves to a
+//! [`TicketSender`] once the ratelimiter decides that the request can be
+//! made. The requester then makes the request and sends the response's
+//! ratelimit headers back through the [`TicketSender`].
+//!
+//! Ratelimiter implementations create a pair of a [`TicketNotifier`] and a
+//! [`TicketReceiver`] via [`channel`], hand out the receiver, and call
+//! [`TicketNotifier::available`] when the request may be made.
+//!
+//! [`Ratelimiter`]: super::Ratelimiter
+
+use super::headers::RatelimitHeaders;
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tokio::sync::oneshot::{self, error::RecvError, Receiver, Sender};
+
+/// Create a new pair of a ticket notifier and receiver.
+pub fn channel() -> (TicketNotifier, TicketReceiver) {
+    let (tx, rx) = oneshot::channel();
+
+    (TicketNotifier(tx), TicketReceiver(rx))
+}
+
+/// Used by a ratelimiter to notify the requester that a request can be made.
+#[derive(Debug)]
+pub struct TicketNotifier(Sender<TicketSender>);
+
+impl TicketNotifier {
+    /// Signal to the requester that a request can be made.
+    ///
+    /// Returns a future resolving to the ratelimit headers of the response,
+    /// or `None` if the requester is no longer waiting for the ticket.
+    pub fn available(self) -> Option<TicketHeaders> {
+        let (tx, rx) = oneshot::channel();
+
+        self.0.send(TicketSender(tx)).ok()?;
+
+        Some(TicketHeaders(rx))
+    }
+}
+
+/// Future resolving to the ratelimit headers of the response to a request
+/// made with a ticket.
+///
+/// Resolves to `None` if the headers couldn't be parsed or the request
+/// failed, and to an error if the requester dropped the ticket without
+/// reporting any headers.
+#[derive(Debug)]
+pub struct TicketHeaders(Receiver<Option<RatelimitHeaders>>);
+
+impl Future for TicketHeaders {
+    type Output = Result<Option<RatelimitHeaders>, RecvError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.0).poll(cx)
+    }
+}
+
+/// Future resolving to a [`TicketSender`] once a request can be made.
+///
+/// Resolves to an error if the ratelimiter dropped the ticket.
+#[derive(Debug)]
+pub struct TicketReceiver(Receiver<TicketSender>);
+
+impl Future for TicketReceiver {
+    type Output = Result<TicketSender, RecvError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.0).poll(cx)
+    }
+}
+
+/// Used by the requester to send the ratelimit headers of the response back
+/// to the ratelimiter.
+#[derive(Debug)]
+pub struct TicketSender(Sender<Option<RatelimitHeaders>>);
+
+impl TicketSender {
+    /// Send the ratelimit headers of the response to the ratelimiter.
+    ///
+    /// Send `None` if the headers couldn't be parsed or the request failed.
+    ///
+    /// # Errors
+    ///
+    /// Returns the headers back if the ratelimiter is no longer waiting for
+    /// them.
+    pub fn headers(
+        self,
+        headers: Option<RatelimitHeaders>,
+    ) -> Result<(), Option<RatelimitHeaders>> {
+        self.0.send(headers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TicketHeaders, TicketNotifier, TicketReceiver, TicketSender};
+    use crate::ratelimiting::RatelimitHeaders;
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug, future::Future};
+
+    assert_impl_all!(TicketHeaders: Debug, Future, Send, Sync);
+    assert_impl_all!(TicketNotifier: Debug, Send, Sync);
+    assert_impl_all!(TicketReceiver: Debug, Future, Send, Sync);
+    assert_impl_all!(TicketSender: Debug, Send, Sync);
+
+    #[tokio::test]
+    async fn test_flow() -> Result<(), Box<dyn Error>> {
+        let (notifier, receiver) = super::channel();
+
+        let headers = notifier.available().expect("receiver is alive");
+        let sender = receiver.await?;
+        assert!(sender.headers(Some(RatelimitHeaders::None)).is_ok());
+
+        assert!(matches!(headers.await?, Some(RatelimitHeaders::None)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_receiver_dropped() {
+        let (notifier, receiver) = super::channel();
+        drop(receiver);
+
+        assert!(notifier.available().is_none());
+    }
+}
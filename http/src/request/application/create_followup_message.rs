@@ -1,11 +1,17 @@
 use crate::{
     client::Client,
-    error::Error,
-    request::{Form, Pending, Request},
+    error::Error as HttpError,
+    ratelimiting::Priority,
+    request::{validate, AttachmentFile, AttachmentPayload, Form, Pending, Request},
     routing::Route,
 };
 use serde::Serialize;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
 use twilight_model::{
+    application::component::Component,
     channel::{
         embed::Embed,
         message::{AllowedMentions, MessageFlags},
@@ -14,10 +20,73 @@ use twilight_model::{
     id::ApplicationId,
 };
 
+/// A followup message can not be created as configured.
+#[derive(Debug)]
+pub struct CreateFollowupMessageError {
+    kind: CreateFollowupMessageErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl CreateFollowupMessageError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &CreateFollowupMessageErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        CreateFollowupMessageErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for CreateFollowupMessageError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            CreateFollowupMessageErrorType::ComponentsInvalid { .. } => {
+                f.write_str("the message components are invalid")
+            }
+        }
+    }
+}
+
+impl Error for CreateFollowupMessageError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`CreateFollowupMessageError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum CreateFollowupMessageErrorType {
+    /// Provided components are invalid.
+    ComponentsInvalid {
+        /// Provided components.
+        components: Vec<Component>,
+    },
+}
+
 #[derive(Default, Serialize)]
 pub(crate) struct CreateFollowupMessageFields {
     #[serde(skip_serializing_if = "Option::is_none")]
     avatar_url: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    components: Vec<Component>,
     #[serde(skip_serializing_if = "Option::is_none")]
     content: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -60,7 +129,7 @@ pub(crate) struct CreateFollowupMessageFields {
 /// [`file`]: Self::file
 pub struct CreateFollowupMessage<'a> {
     pub(crate) fields: CreateFollowupMessageFields,
-    files: Vec<(String, Vec<u8>)>,
+    files: Vec<AttachmentFile>,
     fut: Option<Pending<'a, Option<Message>>>,
     http: &'a Client,
     token: String,
@@ -90,6 +159,25 @@ impl<'a> CreateFollowupMessage<'a> {
         self
     }
 
+    /// Attach a file to the webhook, with its metadata.
+    ///
+    /// Unlike [`file`], the contents may be streamed from disk or from a
+    /// reader. Refer to [`AttachmentFile`] for more information.
+    ///
+    /// [`file`]: Self::file
+    pub fn attachment_file(mut self, file: AttachmentFile) -> Self {
+        self.files.push(file);
+
+        self
+    }
+
+    /// Attach multiple files to the webhook, with their metadata.
+    pub fn attachment_files(mut self, files: impl IntoIterator<Item = AttachmentFile>) -> Self {
+        self.files.extend(files);
+
+        self
+    }
+
     /// The URL of the avatar of the webhook.
     pub fn avatar_url(mut self, avatar_url: impl Into<String>) -> Self {
         self.fields.avatar_url.replace(avatar_url.into());
@@ -97,6 +185,30 @@ impl<'a> CreateFollowupMessage<'a> {
         self
     }
 
+    /// Set the list of components of the message.
+    ///
+    /// Calling this method multiple times will clear previous calls.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CreateFollowupMessageErrorType::ComponentsInvalid`] error type if the
+    /// components are invalid.
+    pub fn components(
+        mut self,
+        components: Vec<Component>,
+    ) -> Result<Self, CreateFollowupMessageError> {
+        if let Err(source) = validate::components(&components) {
+            return Err(CreateFollowupMessageError {
+                kind: CreateFollowupMessageErrorType::ComponentsInvalid { components },
+                source: Some(Box::new(source)),
+            });
+        }
+
+        self.fields.components = components;
+
+        Ok(self)
+    }
+
     /// The content of the webook's message.
     ///
     /// Up to 2000 UTF-16 codepoints.
@@ -128,7 +240,7 @@ impl<'a> CreateFollowupMessage<'a> {
     ///
     /// This method is repeatable.
     pub fn file(mut self, name: impl Into<String>, file: impl Into<Vec<u8>>) -> Self {
-        self.files.push((name.into(), file.into()));
+        self.files.push(AttachmentFile::from_bytes(name, file));
 
         self
     }
@@ -219,24 +331,24 @@ impl<'a> CreateFollowupMessage<'a> {
         self
     }
 
-    fn start(&mut self) -> Result<(), Error> {
+    fn request(&mut self) -> Result<Request, HttpError> {
         let mut request = Request::builder(Route::ExecuteWebhook {
             token: self.token.clone(),
             wait: None,
             webhook_id: self.application_id.0,
-        });
+        })
+        .durable(false)
+        .priority(Priority::Urgent);
 
         if !self.files.is_empty() || self.fields.payload_json.is_some() {
             let mut form = Form::new();
-
-            for (index, (name, file)) in self.files.drain(..).enumerate() {
-                form.file(format!("{}", index).as_bytes(), name.as_bytes(), &file);
-            }
+            let attachments = form.attachments(self.files.drain(..));
 
             if let Some(payload_json) = &self.fields.payload_json {
                 form.payload_json(&payload_json);
             } else {
-                let body = crate::json::to_vec(&self.fields).map_err(Error::json)?;
+                let payload = AttachmentPayload::new(&self.fields, &attachments);
+                let body = crate::json::to_vec(&payload).map_err(HttpError::json)?;
                 form.payload_json(&body);
             }
 
@@ -245,11 +357,39 @@ impl<'a> CreateFollowupMessage<'a> {
             request = request.json(&self.fields)?;
         }
 
-        self.fut
-            .replace(Box::pin(self.http.request(request.build())));
+        Ok(request.build())
+    }
+
+    fn start(&mut self) -> Result<(), HttpError> {
+        let request = self.request()?;
+        self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
     }
 }
 
-poll_req!(CreateFollowupMessage<'_>, Option<Message>);
+poll_req!(mut, CreateFollowupMessage<'_>, Option<Message>);
+
+#[cfg(test)]
+mod tests {
+    use super::CreateFollowupMessage;
+    use crate::{client::Client, ratelimiting::Priority, request::Request, routing::Route};
+    use twilight_model::id::ApplicationId;
+
+    #[test]
+    fn test_request() {
+        let client = Client::new("token");
+
+        let mut builder = CreateFollowupMessage::new(&client, ApplicationId(1), "token");
+        let actual = builder.request().expect("failed to create request");
+
+        let expected = Request::from_route(Route::ExecuteWebhook {
+            token: "token".to_owned(),
+            wait: None,
+            webhook_id: 1,
+        });
+
+        assert_eq!(expected.path, actual.path);
+        assert_eq!(Some(Priority::Urgent), actual.priority());
+    }
+}
@@ -8,7 +8,7 @@ use crate::{
     routing::Route,
 };
 use twilight_model::{
-    application::command::{Command, CommandOption},
+    application::command::{Command, CommandOption, CommandType},
     id::ApplicationId,
 };
 
@@ -17,7 +17,9 @@ use twilight_model::{
 /// The name must be between 3 and 32 characters in length, and the description
 /// must be between 1 and 100 characters in length. Creating a command with the
 /// same name as an already-existing global command will overwwrite the old
-/// command. See [the discord docs] for more information.
+/// command. Context menu commands have no description or options, and their
+/// name may be between 1 and 32 characters in length, including spaces. See
+/// [the discord docs] for more information.
 ///
 /// [the discord docs]: https://discord.com/developers/docs/interactions/slash-commands#create-global-application-command
 pub struct CreateGlobalCommand<'a> {
@@ -41,11 +43,13 @@ impl<'a> CreateGlobalCommand<'a> {
         if !validate::command_name(&name) {
             return Err(InteractionError {
                 kind: InteractionErrorType::CommandNameValidationFailed { name },
+                source: None,
             });
         }
         if !validate::command_description(&description) {
             return Err(InteractionError {
                 kind: InteractionErrorType::CommandDescriptionValidationFailed { description },
+                source: None,
             });
         }
 
@@ -57,6 +61,7 @@ impl<'a> CreateGlobalCommand<'a> {
                 default_permission: None,
                 description,
                 id: None,
+                kind: CommandType::ChatInput,
                 options: vec![],
             },
             application_id,
@@ -66,6 +71,46 @@ impl<'a> CreateGlobalCommand<'a> {
         })
     }
 
+    pub(crate) fn context_menu(
+        http: &'a Client,
+        application_id: ApplicationId,
+        name: impl Into<String>,
+        kind: CommandType,
+    ) -> Result<Self, InteractionError> {
+        let name = name.into();
+
+        if !kind.is_context_menu() {
+            return Err(InteractionError {
+                kind: InteractionErrorType::CommandTypeInvalid { kind },
+                source: None,
+            });
+        }
+
+        if !validate::context_menu_command_name(&name) {
+            return Err(InteractionError {
+                kind: InteractionErrorType::CommandNameValidationFailed { name },
+                source: None,
+            });
+        }
+
+        Ok(Self {
+            command: Command {
+                application_id: Some(application_id),
+                guild_id: None,
+                name,
+                default_permission: None,
+                description: String::new(),
+                id: None,
+                kind,
+                options: Vec::new(),
+            },
+            application_id,
+            fut: None,
+            http,
+            optional_option_added: false,
+        })
+    }
+
     /// Add a command option.
     ///
     /// Required command options must be added before optional options.
@@ -74,7 +119,30 @@ impl<'a> CreateGlobalCommand<'a> {
     ///
     /// Retuns an [`InteractionErrorType::CommandOptionsRequiredFirst`]
     /// if a required option was added after an optional option.
+    ///
+    /// Returns an [`InteractionErrorType::CommandTypeInvalid`] if the command
+    /// is a context menu command, which can't have options.
+    ///
+    /// Returns an [`InteractionErrorType::CommandOptionAutocompleteChoices`]
+    /// if the option, or an option nested under it, is autocompleted and has
+    /// choices.
     pub fn add_command_option(mut self, option: CommandOption) -> Result<Self, InteractionError> {
+        if self.command.kind.is_context_menu() {
+            return Err(InteractionError {
+                kind: InteractionErrorType::CommandTypeInvalid {
+                    kind: self.command.kind,
+                },
+                source: None,
+            });
+        }
+
+        if !validate::command_option(&option) {
+            return Err(InteractionError {
+                kind: InteractionErrorType::CommandOptionAutocompleteChoices { option },
+                source: None,
+            });
+        }
+
         if !self.optional_option_added && !option.is_required() {
             self.optional_option_added = true
         }
@@ -82,6 +150,7 @@ impl<'a> CreateGlobalCommand<'a> {
         if option.is_required() && self.optional_option_added {
             return Err(InteractionError {
                 kind: InteractionErrorType::CommandOptionsRequiredFirst { option },
+                source: None,
             });
         }
 
@@ -97,17 +166,86 @@ impl<'a> CreateGlobalCommand<'a> {
         self
     }
 
-    fn start(&mut self) -> Result<(), HttpError> {
+    fn request(&self) -> Result<Request, HttpError> {
         let request = Request::builder(Route::CreateGlobalCommand {
             application_id: self.application_id.0,
         })
         .json(&self.command)?;
 
-        self.fut
-            .replace(Box::pin(self.http.verify(request.build())));
+        Ok(request.build())
+    }
+
+    fn start(&mut self) -> Result<(), HttpError> {
+        let request = self.request()?;
+        self.fut.replace(Box::pin(self.http.verify(request)));
 
         Ok(())
     }
 }
 
 poll_req!(CreateGlobalCommand<'_>, ());
+
+#[cfg(test)]
+mod tests {
+    use super::CreateGlobalCommand;
+    use crate::{request::application::InteractionErrorType, Client};
+    use twilight_model::{
+        application::command::{
+            ChoiceCommandOptionData, CommandOption, CommandOptionChoice, CommandType,
+        },
+        id::ApplicationId,
+    };
+
+    fn option(autocomplete: bool) -> CommandOption {
+        CommandOption::String(ChoiceCommandOptionData {
+            autocomplete,
+            choices: vec![CommandOptionChoice::String {
+                name: "rust".to_owned(),
+                value: "rust".to_owned(),
+            }],
+            description: "language".to_owned(),
+            name: "language".to_owned(),
+            required: true,
+        })
+    }
+
+    #[test]
+    fn test_autocomplete_choices() {
+        let http = Client::new("token");
+
+        let request =
+            CreateGlobalCommand::new(&http, ApplicationId(1), "search", "search something")
+                .unwrap()
+                .add_command_option(option(false));
+        assert!(request.is_ok());
+
+        let request =
+            CreateGlobalCommand::new(&http, ApplicationId(1), "search", "search something")
+                .unwrap()
+                .add_command_option(option(true));
+        assert!(matches!(
+            request.err().map(|error| error.into_parts().0),
+            Some(InteractionErrorType::CommandOptionAutocompleteChoices { .. })
+        ));
+    }
+
+    #[test]
+    fn test_context_menu_name() {
+        let http = Client::new("token");
+
+        let request = CreateGlobalCommand::context_menu(
+            &http,
+            ApplicationId(1),
+            "Report Message",
+            CommandType::Message,
+        );
+        assert!(request.is_ok());
+
+        let request =
+            CreateGlobalCommand::context_menu(&http, ApplicationId(1), "", CommandType::User);
+        assert!(matches!(
+            request.err().map(|error| error.into_parts().0),
+            Some(InteractionErrorType::CommandNameValidationFailed { .. })
+        ));
+    }
+}
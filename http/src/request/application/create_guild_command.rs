@@ -8,7 +8,7 @@ use crate::{
     routing::Route,
 };
 use twilight_model::{
-    application::command::{Command, CommandOption},
+    application::command::{Command, CommandOption, CommandType},
     id::{ApplicationId, GuildId},
 };
 
@@ -17,7 +17,9 @@ use twilight_model::{
 /// The name must be between 3 and 32 characters in length, and the description
 /// must be between 1 and 100 characters in length. Creating a guild command
 /// with the same name as an already-existing guild command in the same guild
-/// will overwrite the old command. See [the discord docs] for more information.
+/// will overwrite the old command. Context menu commands have no description
+/// or options, and their name may be between 1 and 32 characters in length,
+/// including spaces. See [the discord docs] for more information.
 ///
 /// [the discord docs]: https://discord.com/developers/docs/interactions/slash-commands#create-guild-application-command
 pub struct CreateGuildCommand<'a> {
@@ -43,12 +45,14 @@ impl<'a> CreateGuildCommand<'a> {
         if !validate::command_name(&name) {
             return Err(InteractionError {
                 kind: InteractionErrorType::CommandNameValidationFailed { name },
+                source: None,
             });
         }
 
         if !validate::command_description(&description) {
             return Err(InteractionError {
                 kind: InteractionErrorType::CommandDescriptionValidationFailed { description },
+                source: None,
             });
         }
 
@@ -60,6 +64,7 @@ impl<'a> CreateGuildCommand<'a> {
                 default_permission: None,
                 description,
                 id: None,
+                kind: CommandType::ChatInput,
                 options: vec![],
             },
             application_id,
@@ -70,6 +75,48 @@ impl<'a> CreateGuildCommand<'a> {
         })
     }
 
+    pub(crate) fn context_menu(
+        http: &'a Client,
+        application_id: ApplicationId,
+        guild_id: GuildId,
+        name: impl Into<String>,
+        kind: CommandType,
+    ) -> Result<Self, InteractionError> {
+        let name = name.into();
+
+        if !kind.is_context_menu() {
+            return Err(InteractionError {
+                kind: InteractionErrorType::CommandTypeInvalid { kind },
+                source: None,
+            });
+        }
+
+        if !validate::context_menu_command_name(&name) {
+            return Err(InteractionError {
+                kind: InteractionErrorType::CommandNameValidationFailed { name },
+                source: None,
+            });
+        }
+
+        Ok(Self {
+            command: Command {
+                application_id: Some(application_id),
+                guild_id: None,
+                name,
+                default_permission: None,
+                description: String::new(),
+                id: None,
+                kind,
+                options: Vec::new(),
+            },
+            application_id,
+            guild_id,
+            fut: None,
+            http,
+            optional_option_added: false,
+        })
+    }
+
     /// Whether the command is enabled by default when the app is added to
     /// a guild.
     pub fn default_permission(mut self, default: bool) -> Self {
@@ -86,7 +133,30 @@ impl<'a> CreateGuildCommand<'a> {
     ///
     /// Retuns an [`InteractionErrorType::CommandOptionsRequiredFirst`]
     /// if a required option was added after an optional option.
+    ///
+    /// Returns an [`InteractionErrorType::CommandTypeInvalid`] if the command
+    /// is a context menu command, which can't have options.
+    ///
+    /// Returns an [`InteractionErrorType::CommandOptionAutocompleteChoices`]
+    /// if the option, or an option nested under it, is autocompleted and has
+    /// choices.
     pub fn add_command_option(mut self, option: CommandOption) -> Result<Self, InteractionError> {
+        if self.command.kind.is_context_menu() {
+            return Err(InteractionError {
+                kind: InteractionErrorType::CommandTypeInvalid {
+                    kind: self.command.kind,
+                },
+                source: None,
+            });
+        }
+
+        if !validate::command_option(&option) {
+            return Err(InteractionError {
+                kind: InteractionErrorType::CommandOptionAutocompleteChoices { option },
+                source: None,
+            });
+        }
+
         if !self.optional_option_added && !option.is_required() {
             self.optional_option_added = true
         }
@@ -94,6 +164,7 @@ impl<'a> CreateGuildCommand<'a> {
         if option.is_required() && self.optional_option_added {
             return Err(InteractionError {
                 kind: InteractionErrorType::CommandOptionsRequiredFirst { option },
+                source: None,
             });
         }
 
@@ -102,18 +173,103 @@ impl<'a> CreateGuildCommand<'a> {
         Ok(self)
     }
 
-    fn start(&mut self) -> Result<(), HttpError> {
+    fn request(&self) -> Result<Request, HttpError> {
         let request = Request::builder(Route::CreateGuildCommand {
             application_id: self.application_id.0,
             guild_id: self.guild_id.0,
         })
         .json(&self.command)?;
 
-        self.fut
-            .replace(Box::pin(self.http.verify(request.build())));
+        Ok(request.build())
+    }
+
+    fn start(&mut self) -> Result<(), HttpError> {
+        let request = self.request()?;
+        self.fut.replace(Box::pin(self.http.verify(request)));
 
         Ok(())
     }
 }
 
 poll_req!(CreateGuildCommand<'_>, ());
+
+#[cfg(test)]
+mod tests {
+    use super::CreateGuildCommand;
+    use crate::{request::application::InteractionErrorType, Client};
+    use twilight_model::{
+        application::command::{
+            ChoiceCommandOptionData, CommandOption, CommandOptionChoice, CommandType,
+        },
+        id::{ApplicationId, GuildId},
+    };
+
+    fn option(autocomplete: bool) -> CommandOption {
+        CommandOption::String(ChoiceCommandOptionData {
+            autocomplete,
+            choices: vec![CommandOptionChoice::String {
+                name: "rust".to_owned(),
+                value: "rust".to_owned(),
+            }],
+            description: "language".to_owned(),
+            name: "language".to_owned(),
+            required: true,
+        })
+    }
+
+    #[test]
+    fn test_autocomplete_choices() {
+        let http = Client::new("token");
+
+        let request = CreateGuildCommand::new(
+            &http,
+            ApplicationId(1),
+            GuildId(2),
+            "search",
+            "search something",
+        )
+        .unwrap()
+        .add_command_option(option(false));
+        assert!(request.is_ok());
+
+        let request = CreateGuildCommand::new(
+            &http,
+            ApplicationId(1),
+            GuildId(2),
+            "search",
+            "search something",
+        )
+        .unwrap()
+        .add_command_option(option(true));
+        assert!(matches!(
+            request.err().map(|error| error.into_parts().0),
+            Some(InteractionErrorType::CommandOptionAutocompleteChoices { .. })
+        ));
+    }
+
+    #[test]
+    fn test_context_menu_name() {
+        let http = Client::new("token");
+
+        let request = CreateGuildCommand::context_menu(
+            &http,
+            ApplicationId(1),
+            GuildId(2),
+            "Report Message",
+            CommandType::Message,
+        );
+        assert!(request.is_ok());
+
+        let request = CreateGuildCommand::context_menu(
+            &http,
+            ApplicationId(1),
+            GuildId(2),
+            "a".repeat(33),
+            CommandType::User,
+        );
+        assert!(matches!(
+            request.err().map(|error| error.into_parts().0),
+            Some(InteractionErrorType::CommandNameValidationFailed { .. })
+        ));
+    }
+}
@@ -47,13 +47,14 @@ impl<'a> DeleteFollowupMessage<'a> {
     }
 
     fn request(&self) -> Result<Request, Error> {
-        let request = Request::from_route(Route::DeleteWebhookMessage {
+        let request = Request::builder(Route::DeleteWebhookMessage {
             message_id: self.message_id.0,
             token: self.token.clone(),
             webhook_id: self.application_id.0,
-        });
+        })
+        .durable(false);
 
-        Ok(request)
+        Ok(request.build())
     }
 
     fn start(&mut self) -> Result<(), Error> {
@@ -28,12 +28,15 @@ impl<'a> DeleteGlobalCommand<'a> {
         }
     }
 
-    fn start(&mut self) -> Result<(), Error> {
-        let request = Request::from_route(Route::DeleteGlobalCommand {
+    fn request(&self) -> Result<Request, Error> {
+        Ok(Request::from_route(Route::DeleteGlobalCommand {
             application_id: self.application_id.0,
             command_id: self.command_id.0,
-        });
+        }))
+    }
 
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.verify(request)));
 
         Ok(())
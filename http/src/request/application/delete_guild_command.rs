@@ -31,13 +31,16 @@ impl<'a> DeleteGuildCommand<'a> {
         }
     }
 
-    fn start(&mut self) -> Result<(), Error> {
-        let request = Request::from_route(Route::DeleteGuildCommand {
+    fn request(&self) -> Result<Request, Error> {
+        Ok(Request::from_route(Route::DeleteGuildCommand {
             application_id: self.application_id.0,
             command_id: self.command_id.0,
             guild_id: self.guild_id.0,
-        });
+        }))
+    }
 
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.verify(request)));
 
         Ok(())
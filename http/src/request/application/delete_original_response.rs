@@ -48,12 +48,13 @@ impl<'a> DeleteOriginalResponse<'a> {
     }
 
     fn request(&self) -> Result<Request, Error> {
-        let request = Request::from_route(Route::DeleteInteractionOriginal {
+        let request = Request::builder(Route::DeleteInteractionOriginal {
             application_id: self.application_id.0,
             interaction_token: self.token.clone(),
-        });
+        })
+        .durable(false);
 
-        Ok(request)
+        Ok(request.build())
     }
 
     fn start(&mut self) -> Result<(), Error> {
@@ -34,13 +34,16 @@ impl<'a> GetCommandPermissions<'a> {
         }
     }
 
-    fn start(&mut self) -> Result<(), Error> {
-        let request = Request::from_route(Route::GetCommandPermissions {
+    fn request(&self) -> Result<Request, Error> {
+        Ok(Request::from_route(Route::GetCommandPermissions {
             application_id: self.application_id.0,
             command_id: self.command_id.0,
             guild_id: self.guild_id.0,
-        });
+        }))
+    }
 
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
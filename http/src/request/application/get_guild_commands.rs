@@ -27,12 +27,15 @@ impl<'a> GetGuildCommands<'a> {
         }
     }
 
-    fn start(&mut self) -> Result<(), Error> {
-        let request = Request::from_route(Route::GetGuildCommands {
+    fn request(&self) -> Result<Request, Error> {
+        Ok(Request::from_route(Route::GetGuildCommands {
             application_id: self.application_id.0,
             guild_id: self.guild_id.0,
-        });
+        }))
+    }
 
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
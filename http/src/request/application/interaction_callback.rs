@@ -1,7 +1,8 @@
+use super::{InteractionError, InteractionErrorType};
 use crate::{
     client::Client,
-    error::Error,
-    request::{Pending, Request},
+    error::{Error, ErrorType},
+    request::{validate, Pending, Request},
     routing::Route,
 };
 use twilight_model::{application::callback::InteractionResponse, id::InteractionId};
@@ -31,18 +32,116 @@ impl<'a> InteractionCallback<'a> {
         }
     }
 
-    fn start(&mut self) -> Result<(), Error> {
+    /// Validate the components, autocomplete choices, or modal of the
+    /// response.
+    fn validate(&self) -> Result<(), InteractionError> {
+        match &self.response {
+            InteractionResponse::ChannelMessageWithSource(data)
+            | InteractionResponse::DeferredChannelMessageWithSource(data)
+            | InteractionResponse::UpdateMessage(data) => {
+                if let Some(components) = &data.components {
+                    validate::components(components).map_err(|source| InteractionError {
+                        kind: InteractionErrorType::ComponentsValidationFailed {
+                            components: components.clone(),
+                        },
+                        source: Some(Box::new(source)),
+                    })?;
+                }
+            }
+            InteractionResponse::ApplicationCommandAutocompleteResult(data)
+                if !validate::autocomplete_choices(data.choices.len()) =>
+            {
+                return Err(InteractionError {
+                    kind: InteractionErrorType::TooManyAutocompleteChoices {
+                        choices: data.choices.clone(),
+                    },
+                    source: None,
+                });
+            }
+            InteractionResponse::Modal(modal) => {
+                validate::modal(modal).map_err(|source| InteractionError {
+                    kind: InteractionErrorType::ModalValidationFailed {
+                        modal: modal.clone(),
+                    },
+                    source: Some(Box::new(source)),
+                })?;
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn request(&self) -> Result<Request, Error> {
+        self.validate().map_err(|source| Error {
+            kind: ErrorType::BuildingRequest,
+            source: Some(Box::new(source)),
+        })?;
+
         let request = Request::builder(Route::InteractionCallback {
             interaction_id: self.interaction_id.0,
             interaction_token: self.interaction_token.clone(),
         })
+        .durable(false)
         .json(&self.response)?;
 
-        self.fut
-            .replace(Box::pin(self.http.verify(request.build())));
+        Ok(request.build())
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
+        self.fut.replace(Box::pin(self.http.verify(request)));
 
         Ok(())
     }
 }
 
 poll_req!(InteractionCallback<'_>, ());
+
+#[cfg(test)]
+mod tests {
+    use super::InteractionCallback;
+    use crate::{
+        client::Client,
+        error::ErrorType,
+        request::application::{InteractionError, InteractionErrorType},
+    };
+    use twilight_model::{
+        application::{
+            callback::{Autocomplete, InteractionResponse},
+            command::CommandOptionChoice,
+        },
+        id::InteractionId,
+    };
+
+    fn autocomplete(choices: usize) -> InteractionResponse {
+        InteractionResponse::ApplicationCommandAutocompleteResult(Autocomplete {
+            choices: (0..choices)
+                .map(|index| CommandOptionChoice::Int {
+                    name: index.to_string(),
+                    value: 1,
+                })
+                .collect(),
+        })
+    }
+
+    #[test]
+    fn test_validation() {
+        let client = Client::new("token");
+
+        let builder =
+            InteractionCallback::new(&client, InteractionId(1), "token", autocomplete(25));
+        assert!(builder.request().is_ok());
+
+        let builder =
+            InteractionCallback::new(&client, InteractionId(1), "token", autocomplete(26));
+        let (kind, source) = builder.request().unwrap_err().into_parts();
+        assert!(matches!(kind, ErrorType::BuildingRequest));
+
+        let source = source.unwrap().downcast::<InteractionError>().unwrap();
+        assert!(matches!(
+            source.kind(),
+            InteractionErrorType::TooManyAutocompleteChoices { choices } if choices.len() == 26
+        ));
+    }
+}
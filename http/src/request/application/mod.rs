@@ -21,7 +21,9 @@ mod update_guild_command;
 mod update_original_response;
 
 pub use self::{
-    create_followup_message::CreateFollowupMessage,
+    create_followup_message::{
+        CreateFollowupMessage, CreateFollowupMessageError, CreateFollowupMessageErrorType,
+    },
     create_global_command::CreateGlobalCommand,
     create_guild_command::CreateGuildCommand,
     delete_followup_message::DeleteFollowupMessage,
@@ -52,12 +54,17 @@ use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
 };
-use twilight_model::application::command::CommandOption;
+use twilight_model::application::{
+    callback::Modal,
+    command::{CommandOption, CommandOptionChoice, CommandType},
+    component::Component,
+};
 
 /// The error created if the creation of interaction fails.
 #[derive(Debug)]
 pub struct InteractionError {
     pub(crate) kind: InteractionErrorType,
+    pub(crate) source: Option<Box<dyn Error + Send + Sync>>,
 }
 
 #[derive(Debug)]
@@ -66,11 +73,29 @@ pub enum InteractionErrorType {
     /// Application id was not set on the client.
     ApplicationIdNotPresent,
     /// Command name validation failed.
+    ///
+    /// Names of chat input commands must be between 3 and 32 characters, and
+    /// names of context menu commands between 1 and 32 characters.
     CommandNameValidationFailed { name: String },
     /// Command description validation failed.
     CommandDescriptionValidationFailed { description: String },
     /// Required command options have to be passed before optional ones.
     CommandOptionsRequiredFirst { option: CommandOption },
+    /// An autocompleted command option has predetermined choices.
+    ///
+    /// The option may be nested under a subcommand or subcommand group.
+    CommandOptionAutocompleteChoices { option: CommandOption },
+    /// Command type is invalid for the request.
+    ///
+    /// Context menu commands must be of the user or message type, and can't
+    /// have options.
+    CommandTypeInvalid { kind: CommandType },
+    /// Components of an interaction response are invalid.
+    ComponentsValidationFailed { components: Vec<Component> },
+    /// Modal of an interaction response is invalid.
+    ModalValidationFailed { modal: Modal },
+    /// More than 25 autocomplete choices were set.
+    TooManyAutocompleteChoices { choices: Vec<CommandOptionChoice> },
     /// More than 10 permission overwrites were set.
     TooManyCommandPermissions,
 }
@@ -83,16 +108,15 @@ impl InteractionError {
     }
 
     /// Consume the error, returning the source error if there is any.
-    #[allow(clippy::unused_self)]
     #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
     pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
-        None
+        self.source
     }
 
     /// Consume the error, returning the owned error type and the source error.
     #[must_use = "consuming the error into its parts has no effect if left unused"]
     pub fn into_parts(self) -> (InteractionErrorType, Option<Box<dyn Error + Send + Sync>>) {
-        (self.kind, None)
+        (self.kind, self.source)
     }
 }
 
@@ -103,7 +127,7 @@ impl Display for InteractionError {
                 f.write_str("application id not present")
             }
             InteractionErrorType::CommandNameValidationFailed { .. } => {
-                f.write_str("command name must be between 3 and 32 characters")
+                f.write_str("command name length is invalid")
             }
             InteractionErrorType::CommandDescriptionValidationFailed { .. } => {
                 f.write_str("command description must be between 1 and 100 characters")
@@ -111,6 +135,24 @@ impl Display for InteractionError {
             InteractionErrorType::CommandOptionsRequiredFirst { .. } => {
                 f.write_str("optional command options must be added after required")
             }
+            InteractionErrorType::CommandOptionAutocompleteChoices { .. } => {
+                f.write_str("autocompleted command options can't have choices")
+            }
+            InteractionErrorType::CommandTypeInvalid { kind } => {
+                f.write_str("command type ")?;
+                f.write_str(kind.kind())?;
+
+                f.write_str(" is invalid for the request")
+            }
+            InteractionErrorType::ComponentsValidationFailed { .. } => {
+                f.write_str("the response components are invalid")
+            }
+            InteractionErrorType::ModalValidationFailed { .. } => {
+                f.write_str("the response modal is invalid")
+            }
+            InteractionErrorType::TooManyAutocompleteChoices { .. } => {
+                f.write_str("more than 25 autocomplete choices were set")
+            }
             InteractionErrorType::TooManyCommandPermissions { .. } => {
                 f.write_str("more than 10 permission overwrites were set")
             }
@@ -118,4 +160,10 @@ impl Display for InteractionError {
     }
 }
 
-impl Error for InteractionError {}
+impl Error for InteractionError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
@@ -62,6 +62,7 @@ impl<'a> SetCommandPermissions<'a> {
         {
             return Err(InteractionError {
                 kind: InteractionErrorType::TooManyCommandPermissions,
+                source: None,
             });
         }
 
@@ -74,15 +75,19 @@ impl<'a> SetCommandPermissions<'a> {
         })
     }
 
-    fn start(&mut self) -> Result<(), Error> {
+    fn request(&self) -> Result<Request, Error> {
         let request = Request::builder(Route::SetCommandPermissions {
             application_id: self.application_id.0,
             guild_id: self.guild_id.0,
         })
         .json(&self.fields)?;
 
-        self.fut
-            .replace(Box::pin(self.http.request(request.build())));
+        Ok(request.build())
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
+        self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
     }
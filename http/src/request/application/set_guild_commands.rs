@@ -1,7 +1,10 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{
+        application::{InteractionError, InteractionErrorType},
+        validate, Pending, Request,
+    },
     routing::Route,
 };
 use twilight_model::{
@@ -27,28 +30,107 @@ impl<'a> SetGuildCommands<'a> {
         application_id: ApplicationId,
         guild_id: GuildId,
         commands: Vec<Command>,
-    ) -> Self {
-        Self {
+    ) -> Result<Self, InteractionError> {
+        if let Some(option) = commands
+            .iter()
+            .flat_map(|command| command.options.iter())
+            .find(|option| !validate::command_option(option))
+        {
+            return Err(InteractionError {
+                kind: InteractionErrorType::CommandOptionAutocompleteChoices {
+                    option: option.clone(),
+                },
+                source: None,
+            });
+        }
+
+        Ok(Self {
             commands,
             application_id,
             guild_id,
             fut: None,
             http,
-        }
+        })
     }
 
-    fn start(&mut self) -> Result<(), Error> {
+    fn request(&self) -> Result<Request, Error> {
         let request = Request::builder(Route::SetGuildCommands {
             application_id: self.application_id.0,
             guild_id: self.guild_id.0,
         })
         .json(&self.commands)?;
 
-        self.fut
-            .replace(Box::pin(self.http.verify(request.build())));
+        Ok(request.build())
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
+        self.fut.replace(Box::pin(self.http.verify(request)));
 
         Ok(())
     }
 }
 
 poll_req!(SetGuildCommands<'_>, ());
+
+#[cfg(test)]
+mod tests {
+    use super::SetGuildCommands;
+    use crate::{request::application::InteractionErrorType, Client};
+    use twilight_model::{
+        application::command::{
+            ChoiceCommandOptionData, Command, CommandOption, CommandOptionChoice, CommandType,
+        },
+        id::{ApplicationId, GuildId},
+    };
+
+    fn option(autocomplete: bool) -> CommandOption {
+        CommandOption::String(ChoiceCommandOptionData {
+            autocomplete,
+            choices: vec![CommandOptionChoice::String {
+                name: "rust".to_owned(),
+                value: "rust".to_owned(),
+            }],
+            description: "language".to_owned(),
+            name: "language".to_owned(),
+            required: true,
+        })
+    }
+
+    fn command(option: CommandOption) -> Command {
+        Command {
+            application_id: None,
+            guild_id: None,
+            name: "search".to_owned(),
+            default_permission: None,
+            description: "search something".to_owned(),
+            id: None,
+            kind: CommandType::ChatInput,
+            options: vec![option],
+        }
+    }
+
+    #[test]
+    fn test_autocomplete_choices() {
+        let http = Client::new("token");
+
+        let request = SetGuildCommands::new(
+            &http,
+            ApplicationId(1),
+            GuildId(2),
+            vec![command(option(false))],
+        );
+        assert!(request.is_ok());
+
+        let request = SetGuildCommands::new(
+            &http,
+            ApplicationId(1),
+            GuildId(2),
+            vec![command(option(false)), command(option(true))],
+        );
+        assert!(matches!(
+            request.err().map(|error| error.into_parts().0),
+            Some(InteractionErrorType::CommandOptionAutocompleteChoices { .. })
+        ));
+    }
+}
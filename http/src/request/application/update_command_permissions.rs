@@ -44,6 +44,7 @@ impl<'a> UpdateCommandPermissions<'a> {
         if !validate::command_permissions(permissions.len()) {
             return Err(InteractionError {
                 kind: InteractionErrorType::TooManyCommandPermissions,
+                source: None,
             });
         }
 
@@ -57,7 +58,7 @@ impl<'a> UpdateCommandPermissions<'a> {
         })
     }
 
-    fn start(&mut self) -> Result<(), Error> {
+    fn request(&self) -> Result<Request, Error> {
         let request = Request::builder(Route::UpdateCommandPermissions {
             application_id: self.application_id.0,
             command_id: self.command_id.0,
@@ -65,8 +66,12 @@ impl<'a> UpdateCommandPermissions<'a> {
         })
         .json(&self.fields)?;
 
-        self.fut
-            .replace(Box::pin(self.http.request(request.build())));
+        Ok(request.build())
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
+        self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
     }
@@ -3,15 +3,18 @@
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{validate, Form, NullableField, Pending, Request},
+    ratelimiting::Priority,
+    request::{validate, AttachmentFile, AttachmentPayload, Form, NullableField, Pending, Request},
     routing::Route,
 };
 use serde::Serialize;
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
+    mem,
 };
 use twilight_model::{
+    application::component::Component,
     channel::{embed::Embed, message::AllowedMentions, Attachment},
     id::{ApplicationId, MessageId},
 };
@@ -51,6 +54,9 @@ impl UpdateFollowupMessageError {
 impl Display for UpdateFollowupMessageError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
+            UpdateFollowupMessageErrorType::ComponentsInvalid { .. } => {
+                f.write_str("the message components are invalid")
+            }
             UpdateFollowupMessageErrorType::ContentInvalid { .. } => {
                 f.write_str("message content is invalid")
             }
@@ -78,6 +84,11 @@ impl Error for UpdateFollowupMessageError {
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum UpdateFollowupMessageErrorType {
+    /// Provided components are invalid.
+    ComponentsInvalid {
+        /// Provided components.
+        components: Vec<Component>,
+    },
     /// Content is over 2000 UTF-16 characters.
     ContentInvalid {
         /// Provided content.
@@ -110,6 +121,8 @@ struct UpdateFollowupMessageFields {
     #[serde(skip_serializing_if = "Vec::is_empty")]
     attachments: Vec<Attachment>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    components: Option<NullableField<Vec<Component>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     content: Option<NullableField<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     embeds: Option<NullableField<Vec<Embed>>>,
@@ -154,7 +167,7 @@ struct UpdateFollowupMessageFields {
 /// [`DeleteFollowupMessage`]: super::DeleteFollowupMessage
 pub struct UpdateFollowupMessage<'a> {
     fields: UpdateFollowupMessageFields,
-    files: Vec<(String, Vec<u8>)>,
+    files: Vec<AttachmentFile>,
     fut: Option<Pending<'a, ()>>,
     http: &'a Client,
     message_id: MessageId,
@@ -215,6 +228,53 @@ impl<'a> UpdateFollowupMessage<'a> {
         self
     }
 
+    /// Attach a file to the followup message, with its metadata.
+    ///
+    /// Unlike [`file`], the contents may be streamed from disk or from a
+    /// reader. Refer to [`AttachmentFile`] for more information.
+    ///
+    /// [`file`]: Self::file
+    pub fn attachment_file(mut self, file: AttachmentFile) -> Self {
+        self.files.push(file);
+
+        self
+    }
+
+    /// Attach multiple files to the followup message, with their metadata.
+    pub fn attachment_files(mut self, files: impl IntoIterator<Item = AttachmentFile>) -> Self {
+        self.files.extend(files);
+
+        self
+    }
+
+    /// Set the list of components of the message.
+    ///
+    /// Pass `None` to remove all of the components.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`UpdateFollowupMessageErrorType::ComponentsInvalid`] error type if the
+    /// components are invalid.
+    pub fn components(
+        mut self,
+        components: Option<Vec<Component>>,
+    ) -> Result<Self, UpdateFollowupMessageError> {
+        if let Some(Err(source)) = components.as_deref().map(validate::components) {
+            return Err(UpdateFollowupMessageError {
+                kind: UpdateFollowupMessageErrorType::ComponentsInvalid {
+                    components: components.unwrap_or_default(),
+                },
+                source: Some(Box::new(source)),
+            });
+        }
+
+        self.fields
+            .components
+            .replace(NullableField::from_option(components));
+
+        Ok(self)
+    }
+
     /// Set the content of the message.
     ///
     /// Pass `None` if you want to remove the message content.
@@ -334,7 +394,7 @@ impl<'a> UpdateFollowupMessage<'a> {
     ///
     /// This method is repeatable.
     pub fn file(mut self, name: impl Into<String>, file: impl Into<Vec<u8>>) -> Self {
-        self.files.push((name.into(), file.into()));
+        self.files.push(AttachmentFile::from_bytes(name, file));
 
         self
     }
@@ -371,19 +431,21 @@ impl<'a> UpdateFollowupMessage<'a> {
             message_id: self.message_id.0,
             token: self.token.clone(),
             webhook_id: self.application_id.0,
-        });
+        })
+        .durable(false)
+        .priority(Priority::Urgent);
 
         if !self.files.is_empty() || self.fields.payload_json.is_some() {
             let mut form = Form::new();
-
-            for (index, (name, file)) in self.files.drain(..).enumerate() {
-                form.file(format!("{}", index).as_bytes(), name.as_bytes(), &file);
-            }
+            let attachments = form.attachments(self.files.drain(..));
 
             if let Some(payload_json) = &self.fields.payload_json {
                 form.payload_json(&payload_json);
             } else {
-                let body = crate::json::to_vec(&self.fields).map_err(HttpError::json)?;
+                let existing = mem::take(&mut self.fields.attachments);
+                let payload =
+                    AttachmentPayload::new(&self.fields, &attachments).existing(&existing);
+                let body = crate::json::to_vec(&payload).map_err(HttpError::json)?;
                 form.payload_json(&body);
             }
 
@@ -403,4 +465,29 @@ impl<'a> UpdateFollowupMessage<'a> {
     }
 }
 
-poll_req!(UpdateFollowupMessage<'_>, ());
+poll_req!(mut, UpdateFollowupMessage<'_>, ());
+
+#[cfg(test)]
+mod tests {
+    use super::UpdateFollowupMessage;
+    use crate::{client::Client, ratelimiting::Priority, request::Request, routing::Route};
+    use twilight_model::id::{ApplicationId, MessageId};
+
+    #[test]
+    fn test_request() {
+        let client = Client::new("token");
+
+        let mut builder =
+            UpdateFollowupMessage::new(&client, ApplicationId(1), "token", MessageId(2));
+        let actual = builder.request().expect("failed to create request");
+
+        let expected = Request::from_route(Route::UpdateWebhookMessage {
+            message_id: 2,
+            token: "token".to_owned(),
+            webhook_id: 1,
+        });
+
+        assert_eq!(expected.path, actual.path);
+        assert_eq!(Some(Priority::Urgent), actual.priority());
+    }
+}
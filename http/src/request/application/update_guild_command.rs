@@ -1,7 +1,10 @@
 use crate::{
     client::Client,
     error::Error,
-    request::{Pending, Request},
+    request::{
+        application::{InteractionError, InteractionErrorType},
+        validate, Pending, Request,
+    },
     routing::Route,
 };
 use twilight_model::{
@@ -66,17 +69,30 @@ impl<'a> UpdateGuildCommand<'a> {
     }
 
     /// Edit the command options of the command.
-    pub fn push_command_option(mut self, option: CommandOption) -> Self {
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InteractionErrorType::CommandOptionAutocompleteChoices`]
+    /// if the option, or an option nested under it, is autocompleted and has
+    /// choices.
+    pub fn push_command_option(mut self, option: CommandOption) -> Result<Self, InteractionError> {
+        if !validate::command_option(&option) {
+            return Err(InteractionError {
+                kind: InteractionErrorType::CommandOptionAutocompleteChoices { option },
+                source: None,
+            });
+        }
+
         if let Some(ref mut arr) = self.fields.options {
             arr.push(option);
         } else {
             self.fields.options = Some(vec![option]);
         }
 
-        self
+        Ok(self)
     }
 
-    fn start(&mut self) -> Result<(), Error> {
+    fn request(&self) -> Result<Request, Error> {
         let request = Request::builder(Route::UpdateGuildCommand {
             application_id: self.application_id.0,
             command_id: self.command_id.0,
@@ -84,11 +100,54 @@ impl<'a> UpdateGuildCommand<'a> {
         })
         .json(&self.fields)?;
 
-        self.fut
-            .replace(Box::pin(self.http.verify(request.build())));
+        Ok(request.build())
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
+        self.fut.replace(Box::pin(self.http.verify(request)));
 
         Ok(())
     }
 }
 
 poll_req!(UpdateGuildCommand<'_>, ());
+
+#[cfg(test)]
+mod tests {
+    use super::UpdateGuildCommand;
+    use crate::{request::application::InteractionErrorType, Client};
+    use twilight_model::{
+        application::command::{ChoiceCommandOptionData, CommandOption, CommandOptionChoice},
+        id::{ApplicationId, CommandId, GuildId},
+    };
+
+    fn option(autocomplete: bool) -> CommandOption {
+        CommandOption::String(ChoiceCommandOptionData {
+            autocomplete,
+            choices: vec![CommandOptionChoice::String {
+                name: "rust".to_owned(),
+                value: "rust".to_owned(),
+            }],
+            description: "language".to_owned(),
+            name: "language".to_owned(),
+            required: true,
+        })
+    }
+
+    #[test]
+    fn test_autocomplete_choices() {
+        let http = Client::new("token");
+
+        let request = UpdateGuildCommand::new(&http, ApplicationId(1), GuildId(2), CommandId(3))
+            .push_command_option(option(false));
+        assert!(request.is_ok());
+
+        let request = UpdateGuildCommand::new(&http, ApplicationId(1), GuildId(2), CommandId(3))
+            .push_command_option(option(true));
+        assert!(matches!(
+            request.err().map(|error| error.into_parts().0),
+            Some(InteractionErrorType::CommandOptionAutocompleteChoices { .. })
+        ));
+    }
+}
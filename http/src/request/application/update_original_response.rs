@@ -3,15 +3,18 @@
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{validate, Form, NullableField, Pending, Request},
+    ratelimiting::Priority,
+    request::{validate, AttachmentFile, AttachmentPayload, Form, NullableField, Pending, Request},
     routing::Route,
 };
 use serde::Serialize;
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
+    mem,
 };
 use twilight_model::{
+    application::component::Component,
     channel::{embed::Embed, message::AllowedMentions, Attachment, Message},
     id::ApplicationId,
 };
@@ -51,6 +54,9 @@ impl UpdateOriginalResponseError {
 impl Display for UpdateOriginalResponseError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
+            UpdateOriginalResponseErrorType::ComponentsInvalid { .. } => {
+                f.write_str("the message components are invalid")
+            }
             UpdateOriginalResponseErrorType::ContentInvalid { .. } => {
                 f.write_str("message content is invalid")
             }
@@ -78,6 +84,11 @@ impl Error for UpdateOriginalResponseError {
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum UpdateOriginalResponseErrorType {
+    /// Provided components are invalid.
+    ComponentsInvalid {
+        /// Provided components.
+        components: Vec<Component>,
+    },
     /// Content is over 2000 UTF-16 characters.
     ContentInvalid {
         /// Provided content.
@@ -110,6 +121,8 @@ struct UpdateOriginalResponseFields {
     #[serde(skip_serializing_if = "Vec::is_empty")]
     attachments: Vec<Attachment>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    components: Option<NullableField<Vec<Component>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     content: Option<NullableField<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     embeds: Option<NullableField<Vec<Embed>>>,
@@ -155,7 +168,7 @@ struct UpdateOriginalResponseFields {
 pub struct UpdateOriginalResponse<'a> {
     application_id: ApplicationId,
     fields: UpdateOriginalResponseFields,
-    files: Vec<(String, Vec<u8>)>,
+    files: Vec<AttachmentFile>,
     fut: Option<Pending<'a, Message>>,
     http: &'a Client,
     token: String,
@@ -212,6 +225,53 @@ impl<'a> UpdateOriginalResponse<'a> {
         self
     }
 
+    /// Attach a file to the original response, with its metadata.
+    ///
+    /// Unlike [`file`], the contents may be streamed from disk or from a
+    /// reader. Refer to [`AttachmentFile`] for more information.
+    ///
+    /// [`file`]: Self::file
+    pub fn attachment_file(mut self, file: AttachmentFile) -> Self {
+        self.files.push(file);
+
+        self
+    }
+
+    /// Attach multiple files to the original response, with their metadata.
+    pub fn attachment_files(mut self, files: impl IntoIterator<Item = AttachmentFile>) -> Self {
+        self.files.extend(files);
+
+        self
+    }
+
+    /// Set the list of components of the message.
+    ///
+    /// Pass `None` to remove all of the components.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`UpdateOriginalResponseErrorType::ComponentsInvalid`] error type if the
+    /// components are invalid.
+    pub fn components(
+        mut self,
+        components: Option<Vec<Component>>,
+    ) -> Result<Self, UpdateOriginalResponseError> {
+        if let Some(Err(source)) = components.as_deref().map(validate::components) {
+            return Err(UpdateOriginalResponseError {
+                kind: UpdateOriginalResponseErrorType::ComponentsInvalid {
+                    components: components.unwrap_or_default(),
+                },
+                source: Some(Box::new(source)),
+            });
+        }
+
+        self.fields
+            .components
+            .replace(NullableField::from_option(components));
+
+        Ok(self)
+    }
+
     /// Set the content of the message.
     ///
     /// Pass `None` if you want to remove the message content.
@@ -332,7 +392,7 @@ impl<'a> UpdateOriginalResponse<'a> {
     ///
     /// This method is repeatable.
     pub fn file(mut self, name: impl Into<String>, file: impl Into<Vec<u8>>) -> Self {
-        self.files.push((name.into(), file.into()));
+        self.files.push(AttachmentFile::from_bytes(name, file));
 
         self
     }
@@ -368,19 +428,21 @@ impl<'a> UpdateOriginalResponse<'a> {
         let mut request = Request::builder(Route::UpdateInteractionOriginal {
             application_id: self.application_id.0,
             interaction_token: self.token.clone(),
-        });
+        })
+        .durable(false)
+        .priority(Priority::Urgent);
 
         if !self.files.is_empty() || self.fields.payload_json.is_some() {
             let mut form = Form::new();
-
-            for (index, (name, file)) in self.files.drain(..).enumerate() {
-                form.file(format!("{}", index).as_bytes(), name.as_bytes(), &file);
-            }
+            let attachments = form.attachments(self.files.drain(..));
 
             if let Some(payload_json) = &self.fields.payload_json {
                 form.payload_json(&payload_json);
             } else {
-                let body = crate::json::to_vec(&self.fields).map_err(HttpError::json)?;
+                let existing = mem::take(&mut self.fields.attachments);
+                let payload =
+                    AttachmentPayload::new(&self.fields, &attachments).existing(&existing);
+                let body = crate::json::to_vec(&payload).map_err(HttpError::json)?;
                 form.payload_json(&body);
             }
 
@@ -400,4 +462,27 @@ impl<'a> UpdateOriginalResponse<'a> {
     }
 }
 
-poll_req!(UpdateOriginalResponse<'_>, Message);
+poll_req!(mut, UpdateOriginalResponse<'_>, Message);
+
+#[cfg(test)]
+mod tests {
+    use super::UpdateOriginalResponse;
+    use crate::{client::Client, ratelimiting::Priority, request::Request, routing::Route};
+    use twilight_model::id::ApplicationId;
+
+    #[test]
+    fn test_request() {
+        let client = Client::new("token");
+
+        let mut builder = UpdateOriginalResponse::new(&client, ApplicationId(1), "token");
+        let actual = builder.request().expect("failed to create request");
+
+        let expected = Request::from_route(Route::UpdateInteractionOriginal {
+            application_id: 1,
+            interaction_token: "token".to_owned(),
+        });
+
+        assert_eq!(expected.path, actual.path);
+        assert_eq!(Some(Priority::Urgent), actual.priority());
+    }
+}
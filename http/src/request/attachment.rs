@@ -0,0 +1,333 @@
+use serde::{ser::SerializeSeq, Serialize, Serializer};
+use std::{
+    fmt::{Debug, Formatter, Result as FmtResult},
+    io::Result as IoResult,
+    path::PathBuf,
+    pin::Pin,
+};
+use tokio::{fs::File, io::AsyncRead};
+use twilight_model::channel::Attachment;
+
+/// Reader streaming the contents of a file.
+pub(crate) type AttachmentReader = Pin<Box<dyn AsyncRead + Send + Sync>>;
+
+/// Source of the contents of an [`AttachmentFile`].
+pub(crate) enum AttachmentSource {
+    /// Contents held in memory.
+    Bytes(Vec<u8>),
+    /// Contents streamed when the request is sent.
+    Stream(StreamSource),
+}
+
+/// Source of contents streamed when the request is sent.
+pub(crate) enum StreamSource {
+    /// File on disk, opened when the request is sent.
+    Path(PathBuf),
+    /// Reader streaming the contents, with the length if known.
+    Reader {
+        length: Option<u64>,
+        reader: AttachmentReader,
+    },
+}
+
+impl StreamSource {
+    /// Open the source, returning the reader and its length if known.
+    pub(crate) async fn open(self) -> IoResult<(AttachmentReader, Option<u64>)> {
+        match self {
+            Self::Path(path) => {
+                let file = File::open(path).await?;
+                let length = file.metadata().await?.len();
+
+                Ok((Box::pin(file), Some(length)))
+            }
+            Self::Reader { length, reader } => Ok((reader, length)),
+        }
+    }
+}
+
+impl Debug for AttachmentSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Bytes(bytes) => f.debug_tuple("Bytes").field(&bytes.len()).finish(),
+            Self::Stream(source) => f.debug_tuple("Stream").field(source).finish(),
+        }
+    }
+}
+
+impl Debug for StreamSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Path(path) => f.debug_tuple("Path").field(path).finish(),
+            Self::Reader { length, .. } => f
+                .debug_struct("Reader")
+                .field("length", length)
+                .field("reader", &"<reader>")
+                .finish(),
+        }
+    }
+}
+
+/// File attached to a message.
+///
+/// The contents of a file may be held in memory, read from a path on disk,
+/// or streamed from an [`AsyncRead`] reader. Files read from disk or from a
+/// reader are streamed into the request body instead of being buffered in
+/// memory. The `Content-Length` of the request is set when the length of
+/// every file is known, which is always the case for files held in memory or
+/// read from disk.
+///
+/// Requests with streamed files are never retried, as their body can only be
+/// sent once.
+///
+/// # Examples
+///
+/// Send a message with a spoilered image read from disk:
+///
+/// ```rust,no_run
+/// use twilight_http::{request::AttachmentFile, Client};
+/// use twilight_model::id::ChannelId;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new("my token");
+///
+/// let file = AttachmentFile::from_path("images/twilight.png")
+///     .description("The Twilight logo")
+///     .spoiler(true);
+///
+/// client
+///     .create_message(ChannelId(1))
+///     .attachment_file(file)
+///     .await?;
+/// # Ok(()) }
+/// ```
+#[derive(Debug)]
+pub struct AttachmentFile {
+    description: Option<String>,
+    filename: String,
+    source: AttachmentSource,
+    spoiler: bool,
+}
+
+impl AttachmentFile {
+    /// Create a file from contents held in memory.
+    pub fn from_bytes(filename: impl Into<String>, bytes: impl Into<Vec<u8>>) -> Self {
+        Self::new(filename.into(), AttachmentSource::Bytes(bytes.into()))
+    }
+
+    /// Create a file read from a path on disk when the request is sent.
+    ///
+    /// The filename is the final component of the path. If the file can't be
+    /// opened when the request is sent then the request fails with an
+    /// [`ErrorType::BuildingRequest`] error type.
+    ///
+    /// [`ErrorType::BuildingRequest`]: crate::error::ErrorType::BuildingRequest
+    pub fn from_path(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let filename = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        Self::new(filename, AttachmentSource::Stream(StreamSource::Path(path)))
+    }
+
+    /// Create a file streamed from a reader when the request is sent.
+    ///
+    /// If the length of the contents is provided then it must be exact, as it
+    /// is used as part of the `Content-Length` of the request.
+    pub fn from_reader(
+        filename: impl Into<String>,
+        reader: impl AsyncRead + Send + Sync + 'static,
+        length: impl Into<Option<u64>>,
+    ) -> Self {
+        Self::new(
+            filename.into(),
+            AttachmentSource::Stream(StreamSource::Reader {
+                length: length.into(),
+                reader: Box::pin(reader),
+            }),
+        )
+    }
+
+    const fn new(filename: String, source: AttachmentSource) -> Self {
+        Self {
+            description: None,
+            filename,
+            source,
+            spoiler: false,
+        }
+    }
+
+    /// Set the description of the file, shown as its alt text.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description.replace(description.into());
+
+        self
+    }
+
+    /// Set whether the file is marked as a spoiler.
+    ///
+    /// Spoilered files have their filename prefixed with `SPOILER_`.
+    pub const fn spoiler(mut self, spoiler: bool) -> Self {
+        self.spoiler = spoiler;
+
+        self
+    }
+
+    /// Name of the file as it is uploaded.
+    pub(crate) fn filename(&self) -> String {
+        if self.spoiler && !self.filename.starts_with(SPOILER_PREFIX) {
+            format!("{}{}", SPOILER_PREFIX, self.filename)
+        } else {
+            self.filename.clone()
+        }
+    }
+
+    /// Consume the file, returning its description and source.
+    pub(crate) fn into_parts(self) -> (Option<String>, AttachmentSource) {
+        (self.description, self.source)
+    }
+}
+
+/// Prefix of the filename of files marked as spoilers.
+const SPOILER_PREFIX: &str = "SPOILER_";
+
+/// Metadata of an attached file sent in the JSON payload of a request.
+#[derive(Debug, Serialize)]
+pub(crate) struct AttachmentMetadata {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub filename: String,
+    pub id: usize,
+}
+
+/// JSON payload of a request with files, containing the fields of the
+/// request and the metadata of its files.
+#[derive(Serialize)]
+pub(crate) struct AttachmentPayload<'a, T> {
+    #[serde(skip_serializing_if = "PayloadAttachments::is_empty")]
+    attachments: PayloadAttachments<'a>,
+    #[serde(flatten)]
+    fields: &'a T,
+}
+
+impl<'a, T> AttachmentPayload<'a, T> {
+    pub(crate) const fn new(fields: &'a T, files: &'a [AttachmentMetadata]) -> Self {
+        Self {
+            attachments: PayloadAttachments {
+                existing: &[],
+                files,
+            },
+            fields,
+        }
+    }
+
+    /// Set the attachments already present in the message to keep, which
+    /// mustn't also be serialized as part of the fields.
+    pub(crate) const fn existing(mut self, existing: &'a [Attachment]) -> Self {
+        self.attachments.existing = existing;
+
+        self
+    }
+}
+
+/// Attachments of a payload, the existing attachments to keep followed by
+/// the metadata of new files.
+struct PayloadAttachments<'a> {
+    existing: &'a [Attachment],
+    files: &'a [AttachmentMetadata],
+}
+
+impl PayloadAttachments<'_> {
+    const fn is_empty(&self) -> bool {
+        self.existing.is_empty() && self.files.is_empty()
+    }
+}
+
+impl Serialize for PayloadAttachments<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.existing.len() + self.files.len()))?;
+
+        for attachment in self.existing {
+            seq.serialize_element(attachment)?;
+        }
+
+        for file in self.files {
+            seq.serialize_element(file)?;
+        }
+
+        seq.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AttachmentFile;
+    use crate::{test_server, Client};
+    use hyper::{body, header::CONTENT_LENGTH, Body, Request, Response};
+    use static_assertions::assert_impl_all;
+    use std::{
+        error::Error,
+        fmt::Debug,
+        io::Cursor,
+        sync::{Arc, Mutex},
+    };
+    use twilight_model::id::ChannelId;
+
+    assert_impl_all!(AttachmentFile: Debug, Send, Sync);
+
+    #[test]
+    fn test_filename() {
+        let file = AttachmentFile::from_bytes("a.png", Vec::new());
+        assert_eq!("a.png", file.filename());
+        assert_eq!("SPOILER_a.png", file.spoiler(true).filename());
+
+        let file = AttachmentFile::from_bytes("SPOILER_a.png", Vec::new()).spoiler(true);
+        assert_eq!("SPOILER_a.png", file.filename());
+
+        let file = AttachmentFile::from_path("images/b.gif");
+        assert_eq!("b.gif", file.filename());
+    }
+
+    #[tokio::test]
+    async fn test_streamed_request() -> Result<(), Box<dyn Error>> {
+        let received = Arc::new(Mutex::new(None));
+        let recorded = Arc::clone(&received);
+
+        let addr = test_server::start(move |request: Request<Body>| {
+            let recorded = Arc::clone(&recorded);
+
+            async move {
+                let length = request.headers().get(CONTENT_LENGTH).cloned();
+                let bytes = body::to_bytes(request.into_body()).await?;
+                recorded.lock().unwrap().replace((length, bytes));
+
+                Ok::<_, hyper::Error>(Response::new(Body::from(r#"{"content": "a"}"#)))
+            }
+        });
+
+        let client = Client::builder().proxy(addr.to_string(), true).build();
+        let file = AttachmentFile::from_reader("a.txt", Cursor::new(b"hello".to_vec()), 5)
+            .description("greeting");
+
+        // The response isn't a valid message, only the request is checked.
+        let _ = client
+            .create_message(ChannelId(1))
+            .attachment_file(file)
+            .await;
+
+        let (length, bytes) = received.lock().unwrap().take().expect("request received");
+        let body = String::from_utf8(bytes.to_vec())?;
+
+        assert_eq!(
+            Some(bytes.len().to_string()),
+            length.map(|length| length.to_str().unwrap().to_owned())
+        );
+        assert!(body.contains("name=\"files[0]\"; filename=\"a.txt\"\r\n\r\nhello"));
+        assert!(body
+            .contains(r#""attachments":[{"description":"greeting","filename":"a.txt","id":0}]"#));
+
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 use super::{Form, Method};
 use crate::{
     error::Error,
+    ratelimiting::Priority,
     routing::{Path, Route},
 };
 use hyper::header::{HeaderMap, HeaderName, HeaderValue};
@@ -68,11 +69,13 @@ impl RequestBuilder {
     pub const fn raw(method: Method, path: Path, path_and_query: String) -> Self {
         Self(Request {
             body: None,
+            durable: true,
             form: None,
             headers: None,
             method,
             path,
             path_str: Cow::Owned(path_and_query),
+            priority: None,
             use_authorization_token: true,
         })
     }
@@ -92,6 +95,23 @@ impl RequestBuilder {
         self
     }
 
+    /// Whether the request may be saved to an outbox to be replayed later,
+    /// such as after a restart.
+    ///
+    /// Defaults to `true`. Requests carrying secrets that must not be written
+    /// to disk, such as `OAuth2` credentials and webhook tokens, aren't
+    /// durable.
+    ///
+    /// Refer to the [`outbox`] module for more information.
+    ///
+    /// [`outbox`]: crate::outbox
+    #[must_use = "request has not been fully built"]
+    pub const fn durable(mut self, durable: bool) -> Self {
+        self.0.durable = durable;
+
+        self
+    }
+
     /// Set the multipart form.
     #[must_use = "request has not been fully built"]
     pub fn form(mut self, form: Form) -> Self {
@@ -123,6 +143,20 @@ impl RequestBuilder {
         Ok(self.body(bytes))
     }
 
+    /// Set the priority of the request when waiting to be ratelimited.
+    ///
+    /// Defaults to the priority derived from the path of the request via
+    /// [`Priority::from_path`], unless the request is made with a client
+    /// returned by [`Client::with_priority`].
+    ///
+    /// [`Client::with_priority`]: crate::Client::with_priority
+    #[must_use = "request has not been fully built"]
+    pub const fn priority(mut self, priority: Priority) -> Self {
+        self.0.priority = Some(priority);
+
+        self
+    }
+
     /// Whether to use the client's authorization token in the request, if one
     /// is set.
     ///
@@ -138,6 +172,8 @@ impl RequestBuilder {
 pub struct Request {
     /// The body of the request, if any.
     pub body: Option<Vec<u8>>,
+    /// Whether the request may be saved to an outbox.
+    pub(crate) durable: bool,
     /// The multipart form of the request, if any.
     pub form: Option<Form>,
     /// The headers to set in the request, if any.
@@ -148,6 +184,9 @@ pub struct Request {
     pub path: Path,
     /// The URI path to request.
     pub path_str: Cow<'static, str>,
+    /// Priority of the request when waiting to be ratelimited, if it isn't
+    /// derived from the path.
+    pub(crate) priority: Option<Priority>,
     /// Whether to use the client's authorization token in the request.
     pub(crate) use_authorization_token: bool,
 }
@@ -168,11 +207,13 @@ impl Request {
     ) -> Self {
         Self {
             body,
+            durable: true,
             form: None,
             headers,
             method: route.method(),
             path: route.path(),
             path_str: Cow::Owned(route.display().to_string()),
+            priority: None,
             use_authorization_token: true,
         }
     }
@@ -225,15 +266,28 @@ impl Request {
     pub fn from_route(route: Route) -> Self {
         Self {
             body: None,
+            durable: true,
             form: None,
             headers: None,
             method: route.method(),
             path: route.path(),
             path_str: Cow::Owned(route.display().to_string()),
+            priority: None,
             use_authorization_token: true,
         }
     }
 
+    /// Priority of the request when waiting to be ratelimited, if it has
+    /// been set.
+    pub const fn priority(&self) -> Option<Priority> {
+        self.priority
+    }
+
+    /// Whether the request may be saved to an outbox to be replayed later.
+    pub const fn durable(&self) -> bool {
+        self.durable
+    }
+
     /// Whether to use the client's authorization token in the request.
     pub const fn use_authorization_token(&self) -> bool {
         self.use_authorization_token
@@ -244,11 +298,13 @@ impl From<Route> for Request {
     fn from(route: Route) -> Self {
         Self {
             body: None,
+            durable: true,
             form: None,
             headers: None,
             method: route.method(),
             path: route.path(),
             path_str: Cow::Owned(route.display().to_string()),
+            priority: None,
             use_authorization_token: true,
         }
     }
@@ -258,11 +314,13 @@ impl From<(Vec<u8>, Route)> for Request {
     fn from((body, route): (Vec<u8>, Route)) -> Self {
         Self {
             body: Some(body),
+            durable: true,
             form: None,
             headers: None,
             method: route.method(),
             path: route.path(),
             path_str: Cow::Owned(route.display().to_string()),
+            priority: None,
             use_authorization_token: true,
         }
     }
@@ -272,11 +330,13 @@ impl From<(Form, Route)> for Request {
     fn from((form, route): (Form, Route)) -> Self {
         Self {
             body: None,
+            durable: true,
             form: Some(form),
             headers: None,
             method: route.method(),
             path: route.path(),
             path_str: Cow::Owned(route.display().to_string()),
+            priority: None,
             use_authorization_token: true,
         }
     }
@@ -286,11 +346,13 @@ impl From<(Vec<u8>, Form, Route)> for Request {
     fn from((body, form, route): (Vec<u8>, Form, Route)) -> Self {
         Self {
             body: Some(body),
+            durable: true,
             form: Some(form),
             headers: None,
             method: route.method(),
             path: route.path(),
             path_str: Cow::Owned(route.display().to_string()),
+            priority: None,
             use_authorization_token: true,
         }
     }
@@ -300,11 +362,13 @@ impl From<(HeaderMap<HeaderValue>, Route)> for Request {
     fn from((headers, route): (HeaderMap<HeaderValue>, Route)) -> Self {
         Self {
             body: None,
+            durable: true,
             form: None,
             headers: Some(headers),
             method: route.method(),
             path: route.path(),
             path_str: Cow::Owned(route.display().to_string()),
+            priority: None,
             use_authorization_token: true,
         }
     }
@@ -314,11 +378,13 @@ impl From<(Vec<u8>, HeaderMap<HeaderValue>, Route)> for Request {
     fn from((body, headers, route): (Vec<u8>, HeaderMap<HeaderValue>, Route)) -> Self {
         Self {
             body: Some(body),
+            durable: true,
             form: None,
             headers: Some(headers),
             method: route.method(),
             path: route.path(),
             path_str: Cow::Owned(route.display().to_string()),
+            priority: None,
             use_authorization_token: true,
         }
     }
@@ -328,11 +394,13 @@ impl From<(Form, HeaderMap<HeaderValue>, Route)> for Request {
     fn from((form, headers, route): (Form, HeaderMap<HeaderValue>, Route)) -> Self {
         Self {
             body: None,
+            durable: true,
             form: Some(form),
             headers: Some(headers),
             method: route.method(),
             path: route.path(),
             path_str: Cow::Owned(route.display().to_string()),
+            priority: None,
             use_authorization_token: true,
         }
     }
@@ -355,6 +423,7 @@ mod tests {
 
         let builder = RequestBuilder::raw(Method::Post, path, path_and_query);
         assert!(builder.0.body.is_none());
+        assert!(builder.0.durable);
         assert!(builder.0.form.is_none());
         assert!(builder.0.headers.is_none());
         assert_eq!(Method::Post, builder.0.method);
@@ -22,11 +22,14 @@ impl<'a> CreateTypingTrigger<'a> {
         }
     }
 
-    fn start(&mut self) -> Result<(), Error> {
-        let request = Request::from_route(Route::CreateTypingTrigger {
+    fn request(&self) -> Result<Request, Error> {
+        Ok(Request::from_route(Route::CreateTypingTrigger {
             channel_id: self.channel_id.0,
-        });
+        }))
+    }
 
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.verify(request)));
 
         Ok(())
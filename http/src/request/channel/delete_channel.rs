@@ -24,7 +24,7 @@ impl<'a> DeleteChannel<'a> {
         }
     }
 
-    fn start(&mut self) -> Result<(), Error> {
+    fn request(&self) -> Result<Request, Error> {
         let mut request = Request::builder(Route::DeleteChannel {
             channel_id: self.channel_id.0,
         });
@@ -33,8 +33,12 @@ impl<'a> DeleteChannel<'a> {
             request = request.headers(request::audit_header(reason)?);
         }
 
-        self.fut
-            .replace(Box::pin(self.http.request(request.build())));
+        Ok(request.build())
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
+        self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
     }
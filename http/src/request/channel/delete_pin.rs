@@ -26,7 +26,7 @@ impl<'a> DeletePin<'a> {
         }
     }
 
-    fn start(&mut self) -> Result<(), Error> {
+    fn request(&self) -> Result<Request, Error> {
         let mut request = Request::builder(Route::UnpinMessage {
             channel_id: self.channel_id.0,
             message_id: self.message_id.0,
@@ -36,8 +36,12 @@ impl<'a> DeletePin<'a> {
             request = request.headers(request::audit_header(reason)?);
         }
 
-        self.fut
-            .replace(Box::pin(self.http.verify(request.build())));
+        Ok(request.build())
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
+        self.fut.replace(Box::pin(self.http.verify(request)));
 
         Ok(())
     }
@@ -34,13 +34,16 @@ impl<'a> FollowNewsChannel<'a> {
         }
     }
 
-    fn start(&mut self) -> Result<(), Error> {
-        let request = Request::builder(Route::FollowNewsChannel {
+    fn request(&self) -> Result<Request, Error> {
+        Ok(Request::builder(Route::FollowNewsChannel {
             channel_id: self.channel_id.0,
         })
         .json(&self.fields)?
-        .build();
+        .build())
+    }
 
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
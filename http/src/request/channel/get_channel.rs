@@ -40,11 +40,14 @@ impl<'a> GetChannel<'a> {
         }
     }
 
-    fn start(&mut self) -> Result<(), Error> {
-        let request = Request::from_route(Route::GetChannel {
+    fn request(&self) -> Result<Request, Error> {
+        Ok(Request::from_route(Route::GetChannel {
             channel_id: self.channel_id.0,
-        });
+        }))
+    }
 
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.request_bytes(request)));
 
         Ok(())
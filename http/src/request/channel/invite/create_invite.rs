@@ -246,7 +246,7 @@ impl<'a> CreateInvite<'a> {
         self
     }
 
-    fn start(&mut self) -> Result<(), HttpError> {
+    fn request(&self) -> Result<Request, HttpError> {
         let mut request = Request::builder(Route::CreateInvite {
             channel_id: self.channel_id.0,
         })
@@ -256,8 +256,12 @@ impl<'a> CreateInvite<'a> {
             request = request.headers(request::audit_header(reason)?);
         }
 
-        self.fut
-            .replace(Box::pin(self.http.request(request.build())));
+        Ok(request.build())
+    }
+
+    fn start(&mut self) -> Result<(), HttpError> {
+        let request = self.request()?;
+        self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
     }
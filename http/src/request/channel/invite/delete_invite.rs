@@ -29,7 +29,7 @@ impl<'a> DeleteInvite<'a> {
         }
     }
 
-    fn start(&mut self) -> Result<(), Error> {
+    fn request(&self) -> Result<Request, Error> {
         let mut request = Request::builder(Route::DeleteInvite {
             code: self.code.clone(),
         });
@@ -38,8 +38,12 @@ impl<'a> DeleteInvite<'a> {
             request = request.headers(request::audit_header(reason)?);
         }
 
-        self.fut
-            .replace(Box::pin(self.http.verify(request.build())));
+        Ok(request.build())
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
+        self.fut.replace(Box::pin(self.http.verify(request)));
 
         Ok(())
     }
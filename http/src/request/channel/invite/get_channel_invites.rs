@@ -28,11 +28,14 @@ impl<'a> GetChannelInvites<'a> {
         }
     }
 
-    fn start(&mut self) -> Result<(), Error> {
-        let request = Request::from_route(Route::GetChannelInvites {
+    fn request(&self) -> Result<Request, Error> {
+        Ok(Request::from_route(Route::GetChannelInvites {
             channel_id: self.channel_id.0,
-        });
+        }))
+    }
 
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
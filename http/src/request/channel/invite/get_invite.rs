@@ -67,13 +67,16 @@ impl<'a> GetInvite<'a> {
         self
     }
 
-    fn start(&mut self) -> Result<(), Error> {
-        let request = Request::from_route(Route::GetInviteWithExpiration {
+    fn request(&self) -> Result<Request, Error> {
+        Ok(Request::from_route(Route::GetInviteWithExpiration {
             code: self.code.clone(),
             with_counts: self.fields.with_counts,
             with_expiration: self.fields.with_expiration,
-        });
+        }))
+    }
 
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.request_bytes(request)));
 
         Ok(())
@@ -4,16 +4,18 @@ use crate::{
     request::{
         multipart::Form,
         validate::{self, EmbedValidationError},
-        Pending, Request,
+        AttachmentFile, AttachmentPayload, Pending, Request,
     },
     routing::Route,
 };
+use rand::Rng;
 use serde::Serialize;
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
 };
 use twilight_model::{
+    application::component::Component,
     channel::{
         embed::Embed,
         message::{AllowedMentions, MessageReference},
@@ -62,6 +64,9 @@ impl CreateMessageError {
 impl Display for CreateMessageError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
+            CreateMessageErrorType::ComponentsInvalid { .. } => {
+                f.write_str("the message components are invalid")
+            }
             CreateMessageErrorType::ContentInvalid { .. } => {
                 f.write_str("the message content is invalid")
             }
@@ -91,6 +96,11 @@ impl Error for CreateMessageError {
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum CreateMessageErrorType {
+    /// Provided components are invalid.
+    ComponentsInvalid {
+        /// Provided components.
+        components: Vec<Component>,
+    },
     /// Returned when the content is over 2000 UTF-16 characters.
     ContentInvalid {
         /// Provided content.
@@ -107,11 +117,15 @@ pub enum CreateMessageErrorType {
 
 #[derive(Default, Serialize)]
 pub(crate) struct CreateMessageFields {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    components: Vec<Component>,
     #[serde(skip_serializing_if = "Option::is_none")]
     content: Option<String>,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     embeds: Vec<Embed>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    enforce_nonce: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     message_reference: Option<MessageReference>,
     #[serde(skip_serializing_if = "Option::is_none")]
     nonce: Option<u64>,
@@ -146,7 +160,7 @@ pub(crate) struct CreateMessageFields {
 pub struct CreateMessage<'a> {
     channel_id: ChannelId,
     pub(crate) fields: CreateMessageFields,
-    files: Vec<(String, Vec<u8>)>,
+    files: Vec<AttachmentFile>,
     fut: Option<Pending<'a, Message>>,
     http: &'a Client,
 }
@@ -172,6 +186,46 @@ impl<'a> CreateMessage<'a> {
         self
     }
 
+    /// Attach a file to the message, with its metadata.
+    ///
+    /// Unlike [`file`], the contents may be streamed from disk or from a
+    /// reader. Refer to [`AttachmentFile`] for more information.
+    ///
+    /// [`file`]: Self::file
+    pub fn attachment_file(mut self, file: AttachmentFile) -> Self {
+        self.files.push(file);
+
+        self
+    }
+
+    /// Attach multiple files to the message, with their metadata.
+    pub fn attachment_files(mut self, files: impl IntoIterator<Item = AttachmentFile>) -> Self {
+        self.files.extend(files);
+
+        self
+    }
+
+    /// Set the list of components of the message.
+    ///
+    /// Calling this method multiple times will clear previous calls.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CreateMessageErrorType::ComponentsInvalid`] error type if the
+    /// components are invalid.
+    pub fn components(mut self, components: Vec<Component>) -> Result<Self, CreateMessageError> {
+        if let Err(source) = validate::components(&components) {
+            return Err(CreateMessageError {
+                kind: CreateMessageErrorType::ComponentsInvalid { components },
+                source: Some(Box::new(source)),
+            });
+        }
+
+        self.fields.components = components;
+
+        Ok(self)
+    }
+
     /// Set the content of the message.
     ///
     /// The maximum length is 2000 UTF-16 characters.
@@ -249,6 +303,21 @@ impl<'a> CreateMessage<'a> {
         Ok(self)
     }
 
+    /// Whether the API checks that the [`nonce`] hasn't been used by a
+    /// recently created message.
+    ///
+    /// If the nonce has been used, the API responds with the existing message
+    /// instead of creating a duplicate. This makes it safe to send the same
+    /// message again, such as when it is replayed from the [`outbox`].
+    ///
+    /// [`nonce`]: Self::nonce
+    /// [`outbox`]: crate::outbox
+    pub const fn enforce_nonce(mut self, enforce_nonce: bool) -> Self {
+        self.fields.enforce_nonce = Some(enforce_nonce);
+
+        self
+    }
+
     /// Whether to fail sending if the reply no longer exists.
     pub fn fail_if_not_exists(mut self) -> Self {
         self.fields.message_reference = Some(self.fields.message_reference.map_or_else(
@@ -271,7 +340,7 @@ impl<'a> CreateMessage<'a> {
     ///
     /// The file is raw binary data. It can be an image, or any other kind of file.
     pub fn file(mut self, name: impl Into<String>, file: impl Into<Vec<u8>>) -> Self {
-        self.files.push((name.into(), file.into()));
+        self.files.push(AttachmentFile::from_bytes(name, file));
 
         self
     }
@@ -289,6 +358,15 @@ impl<'a> CreateMessage<'a> {
     }
 
     /// Attach a nonce to the message, for optimistic message sending.
+    ///
+    /// Use [`enforce_nonce`] to prevent duplicate messages from being created
+    /// when a message with the same nonce is sent again.
+    ///
+    /// If the client has an [`outbox`] and no nonce is set, a random nonce is
+    /// attached and enforced, unless [`enforce_nonce`] has been disabled.
+    ///
+    /// [`enforce_nonce`]: Self::enforce_nonce
+    /// [`outbox`]: crate::outbox
     pub fn nonce(mut self, nonce: u64) -> Self {
         self.fields.nonce.replace(nonce);
 
@@ -336,22 +414,27 @@ impl<'a> CreateMessage<'a> {
         self
     }
 
-    fn start(&mut self) -> Result<(), HttpError> {
+    fn request(&mut self) -> Result<Request, HttpError> {
+        // Messages may be replayed from the outbox, which mustn't create
+        // duplicates of them.
+        if self.http.has_outbox() && self.fields.nonce.is_none() {
+            self.fields.nonce = Some(rand::thread_rng().gen());
+            self.fields.enforce_nonce.get_or_insert(true);
+        }
+
         let mut request = Request::builder(Route::CreateMessage {
             channel_id: self.channel_id.0,
         });
 
         if !self.files.is_empty() || self.fields.payload_json.is_some() {
             let mut form = Form::new();
-
-            for (index, (name, file)) in self.files.drain(..).enumerate() {
-                form.file(format!("{}", index).as_bytes(), name.as_bytes(), &file);
-            }
+            let attachments = form.attachments(self.files.drain(..));
 
             if let Some(payload_json) = &self.fields.payload_json {
                 form.payload_json(&payload_json);
             } else {
-                let body = crate::json::to_vec(&self.fields).map_err(HttpError::json)?;
+                let payload = AttachmentPayload::new(&self.fields, &attachments);
+                let body = crate::json::to_vec(&payload).map_err(HttpError::json)?;
                 form.payload_json(&body);
             }
 
@@ -360,11 +443,15 @@ impl<'a> CreateMessage<'a> {
             request = request.json(&self.fields)?;
         }
 
-        self.fut
-            .replace(Box::pin(self.http.request(request.build())));
+        Ok(request.build())
+    }
+
+    fn start(&mut self) -> Result<(), HttpError> {
+        let request = self.request()?;
+        self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
     }
 }
 
-poll_req!(CreateMessage<'_>, Message);
+poll_req!(mut, CreateMessage<'_>, Message);
@@ -2,7 +2,7 @@ use super::GetChannelMessagesConfigured;
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{validate, Pending, Request},
+    request::{validate, Paginator, Pending, Request},
     routing::Route,
 };
 use std::{
@@ -175,15 +175,71 @@ impl<'a> GetChannelMessages<'a> {
         Ok(self)
     }
 
-    fn start(&mut self) -> Result<(), HttpError> {
-        let request = Request::from_route(Route::GetMessages {
+    /// Stream the messages of the channel, starting with the most recent
+    /// message and paging backwards.
+    ///
+    /// Each page contains up to the configured [`limit`], or the maximum of
+    /// 100 messages if none is configured.
+    ///
+    /// # Examples
+    ///
+    /// Process messages until one that has already been processed is
+    /// reached:
+    ///
+    /// ```rust,no_run
+    /// use futures_util::stream::StreamExt;
+    /// use twilight_http::Client;
+    /// use twilight_model::id::{ChannelId, MessageId};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    /// let client = Client::new("my token");
+    /// let last_processed = MessageId(234);
+    ///
+    /// let mut messages = client
+    ///     .channel_messages(ChannelId(123))
+    ///     .stream()
+    ///     .until(last_processed.0);
+    ///
+    /// while let Some(message) = messages.next().await {
+    ///     println!("{}", message?.content);
+    /// }
+    /// # Ok(()) }
+    /// ```
+    ///
+    /// [`limit`]: Self::limit
+    pub fn stream(self) -> Paginator<'a, Message> {
+        let channel_id = self.channel_id;
+        let http = self.http;
+
+        Paginator::before(
+            self.fields.limit.unwrap_or(100),
+            |message: &Message| message.id.0,
+            move |before, limit| {
+                Box::pin(GetChannelMessagesConfigured::new(
+                    http,
+                    channel_id,
+                    None,
+                    None,
+                    before.map(MessageId),
+                    Some(limit),
+                ))
+            },
+        )
+    }
+
+    fn request(&self) -> Result<Request, HttpError> {
+        Ok(Request::from_route(Route::GetMessages {
             after: None,
             around: None,
             before: None,
             channel_id: self.channel_id.0,
             limit: self.fields.limit,
-        });
+        }))
+    }
 
+    fn start(&mut self) -> Result<(), HttpError> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
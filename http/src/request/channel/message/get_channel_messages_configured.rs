@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{validate, Pending, Request},
+    request::{validate, Paginator, Pending, Request},
     routing::Route,
 };
 use std::{
@@ -129,15 +129,80 @@ impl<'a> GetChannelMessagesConfigured<'a> {
         Ok(self)
     }
 
-    fn start(&mut self) -> Result<(), HttpError> {
-        let request = Request::from_route(Route::GetMessages {
+    /// Stream the messages of the channel, paging in the configured
+    /// direction.
+    ///
+    /// Messages after the configured message are streamed in ascending order,
+    /// while messages before it are streamed in descending order. If messages
+    /// around a message were requested, the first page is streamed in
+    /// descending order followed by older messages.
+    ///
+    /// Each page contains up to the configured [`limit`], or the maximum of
+    /// 100 messages if none is configured.
+    ///
+    /// [`limit`]: Self::limit
+    pub fn stream(self) -> Paginator<'a, Message> {
+        let Self {
+            after,
+            around,
+            before,
+            channel_id,
+            http,
+            ..
+        } = self;
+        let page_size = self.fields.limit.unwrap_or(100);
+        let id = |message: &Message| message.id.0;
+
+        if after.is_some() {
+            return Paginator::after(page_size, id, move |cursor, limit| {
+                Box::pin(Self::new(
+                    http,
+                    channel_id,
+                    cursor.map(MessageId).or(after),
+                    None,
+                    None,
+                    Some(limit),
+                ))
+            });
+        }
+
+        let paginator = Paginator::before(page_size, id, move |cursor, limit| {
+            let request = match cursor {
+                Some(cursor) => Self::new(
+                    http,
+                    channel_id,
+                    None,
+                    None,
+                    Some(MessageId(cursor)),
+                    Some(limit),
+                ),
+                None => Self::new(http, channel_id, None, around, before, Some(limit)),
+            };
+
+            Box::pin(request)
+        });
+
+        // Messages newer than the message are also returned when requesting
+        // messages around it, so the first page may be short.
+        if around.is_some() {
+            paginator.partial_first_page()
+        } else {
+            paginator
+        }
+    }
+
+    fn request(&self) -> Result<Request, HttpError> {
+        Ok(Request::from_route(Route::GetMessages {
             after: self.after.map(|x| x.0),
             around: self.around.map(|x| x.0),
             before: self.before.map(|x| x.0),
             channel_id: self.channel_id.0,
             limit: self.fields.limit,
-        });
+        }))
+    }
 
+    fn start(&mut self) -> Result<(), HttpError> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
@@ -27,12 +27,15 @@ impl<'a> GetMessage<'a> {
         }
     }
 
-    fn start(&mut self) -> Result<(), Error> {
-        let request = Request::from_route(Route::GetMessage {
+    fn request(&self) -> Result<Request, Error> {
+        Ok(Request::from_route(Route::GetMessage {
             channel_id: self.channel_id.0,
             message_id: self.message_id.0,
-        });
+        }))
+    }
 
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.request_bytes(request)));
 
         Ok(())
@@ -13,6 +13,7 @@ use std::{
     fmt::{Display, Formatter, Result as FmtResult},
 };
 use twilight_model::{
+    application::component::Component,
     channel::{
         embed::Embed,
         message::{AllowedMentions, MessageFlags},
@@ -61,6 +62,9 @@ impl UpdateMessageError {
 impl Display for UpdateMessageError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
+            UpdateMessageErrorType::ComponentsInvalid { .. } => {
+                f.write_str("the message components are invalid")
+            }
             UpdateMessageErrorType::ContentInvalid { .. } => {
                 f.write_str("the message content is invalid")
             }
@@ -90,6 +94,11 @@ impl Error for UpdateMessageError {
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum UpdateMessageErrorType {
+    /// Provided components are invalid.
+    ComponentsInvalid {
+        /// Provided components.
+        components: Vec<Component>,
+    },
     /// Returned when the content is over 2000 UTF-16 characters.
     ContentInvalid {
         /// Provided content.
@@ -120,6 +129,8 @@ struct UpdateMessageFields {
     //   `"content": null` in the JSON;
     // - None: Don't serialize the field at all, not modifying the state.
     #[serde(skip_serializing_if = "Option::is_none")]
+    components: Option<NullableField<Vec<Component>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     content: Option<NullableField<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     embeds: Option<Vec<Embed>>,
@@ -205,6 +216,36 @@ impl<'a> UpdateMessage<'a> {
         self
     }
 
+    /// Set the list of components of the message.
+    ///
+    /// Pass `None` to remove all of the components.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`UpdateMessageErrorType::ComponentsInvalid`] error type if the
+    /// components are invalid.
+    pub fn components(
+        mut self,
+        components: impl Into<Option<Vec<Component>>>,
+    ) -> Result<Self, UpdateMessageError> {
+        let components = components.into();
+
+        if let Some(Err(source)) = components.as_deref().map(validate::components) {
+            return Err(UpdateMessageError {
+                kind: UpdateMessageErrorType::ComponentsInvalid {
+                    components: components.unwrap_or_default(),
+                },
+                source: Some(Box::new(source)),
+            });
+        }
+
+        self.fields
+            .components
+            .replace(NullableField::from_option(components));
+
+        Ok(self)
+    }
+
     /// Set the content of the message.
     ///
     /// Pass `None` if you want to remove the message content.
@@ -337,14 +378,17 @@ impl<'a> UpdateMessage<'a> {
         self
     }
 
-    fn start(&mut self) -> Result<(), HttpError> {
-        let request = Request::builder(Route::UpdateMessage {
+    fn request(&self) -> Result<Request, HttpError> {
+        Ok(Request::builder(Route::UpdateMessage {
             channel_id: self.channel_id.0,
             message_id: self.message_id.0,
         })
         .json(&self.fields)?
-        .build();
+        .build())
+    }
 
+    fn start(&mut self) -> Result<(), HttpError> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
@@ -55,16 +55,16 @@ impl<'a> CreateReaction<'a> {
         }
     }
 
-    fn request(&self) -> Request {
-        Request::from_route(Route::CreateReaction {
+    fn request(&self) -> Result<Request, Error> {
+        Ok(Request::from_route(Route::CreateReaction {
             channel_id: self.channel_id.0,
             emoji: self.emoji.display().to_string(),
             message_id: self.message_id.0,
-        })
+        }))
     }
 
     fn start(&mut self) -> Result<(), Error> {
-        let request = self.request();
+        let request = self.request()?;
 
         self.fut.replace(Box::pin(self.http.verify(request)));
 
@@ -83,10 +83,11 @@ mod tests {
         Client,
     };
     use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
+    use std::error::Error;
     use twilight_model::id::{ChannelId, MessageId};
 
     #[test]
-    fn test_request() {
+    fn test_request() -> Result<(), Box<dyn Error>> {
         let client = Client::new("foo");
 
         let emoji = RequestReactionType::Unicode {
@@ -94,7 +95,7 @@ mod tests {
         };
 
         let builder = CreateReaction::new(&client, ChannelId(123), MessageId(456), emoji);
-        let actual = builder.request();
+        let actual = builder.request()?;
 
         let expected = Request::from_route(Route::CreateReaction {
             channel_id: 123,
@@ -103,5 +104,7 @@ mod tests {
         });
 
         assert_eq!(actual.path_str, expected.path_str);
+
+        Ok(())
     }
 }
@@ -35,14 +35,17 @@ impl<'a> DeleteReaction<'a> {
         }
     }
 
-    fn start(&mut self) -> Result<(), Error> {
-        let request = Request::from_route(Route::DeleteReaction {
+    fn request(&self) -> Result<Request, Error> {
+        Ok(Request::from_route(Route::DeleteReaction {
             channel_id: self.channel_id.0,
             emoji: self.emoji.display().to_string(),
             message_id: self.message_id.0,
             user: self.target_user.clone(),
-        });
+        }))
+    }
 
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.verify(request)));
 
         Ok(())
@@ -2,7 +2,7 @@ use super::RequestReactionType;
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{validate, Pending, Request},
+    request::{validate, Paginator, Pending, Request},
     routing::Route,
 };
 use std::{
@@ -126,15 +126,48 @@ impl<'a> GetReactions<'a> {
         Ok(self)
     }
 
-    fn start(&mut self) -> Result<(), HttpError> {
-        let request = Request::from_route(Route::GetReactionUsers {
+    /// Stream all users that reacted with the emoji, paging through them in
+    /// order of user ID.
+    ///
+    /// Each page contains up to the configured [`limit`], or the maximum of
+    /// 100 users if none is configured.
+    ///
+    /// [`limit`]: Self::limit
+    pub fn stream(self) -> Paginator<'a, User> {
+        let Self {
+            channel_id,
+            emoji,
+            fields,
+            http,
+            message_id,
+            ..
+        } = self;
+
+        Paginator::after(
+            fields.limit.unwrap_or(100),
+            |user: &User| user.id.0,
+            move |cursor, limit| {
+                let mut request = Self::new(http, channel_id, message_id, emoji.clone());
+                request.fields.after = cursor.map(UserId).or(fields.after);
+                request.fields.limit.replace(limit);
+
+                Box::pin(request)
+            },
+        )
+    }
+
+    fn request(&self) -> Result<Request, HttpError> {
+        Ok(Request::from_route(Route::GetReactionUsers {
             after: self.fields.after.map(|x| x.0),
             channel_id: self.channel_id.0,
             emoji: self.emoji.display().to_string(),
             limit: self.fields.limit,
             message_id: self.message_id.0,
-        });
+        }))
+    }
 
+    fn start(&mut self) -> Result<(), HttpError> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
@@ -124,11 +124,14 @@ impl<'a> CreateStageInstance<'a> {
         self
     }
 
-    fn start(&mut self) -> Result<(), HttpError> {
-        let request = Request::builder(Route::CreateStageInstance)
+    fn request(&self) -> Result<Request, HttpError> {
+        Ok(Request::builder(Route::CreateStageInstance)
             .json(&self.fields)?
-            .build();
+            .build())
+    }
 
+    fn start(&mut self) -> Result<(), HttpError> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.verify(request)));
 
         Ok(())
@@ -24,11 +24,14 @@ impl<'a> DeleteStageInstance<'a> {
         }
     }
 
-    fn start(&mut self) -> Result<(), Error> {
-        let request = Request::from_route(Route::DeleteStageInstance {
+    fn request(&self) -> Result<Request, Error> {
+        Ok(Request::from_route(Route::DeleteStageInstance {
             channel_id: self.channel_id.0,
-        });
+        }))
+    }
 
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.verify(request)));
 
         Ok(())
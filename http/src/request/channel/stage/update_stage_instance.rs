@@ -121,13 +121,16 @@ impl<'a> UpdateStageInstance<'a> {
         Ok(self)
     }
 
-    fn start(&mut self) -> Result<(), HttpError> {
-        let request = Request::builder(Route::UpdateStageInstance {
+    fn request(&self) -> Result<Request, HttpError> {
+        Ok(Request::builder(Route::UpdateStageInstance {
             channel_id: self.channel_id.0,
         })
         .json(&self.fields)?
-        .build();
+        .build())
+    }
 
+    fn start(&mut self) -> Result<(), HttpError> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.verify(request)));
 
         Ok(())
@@ -0,0 +1,45 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Pending, Request},
+    routing::Route,
+};
+use twilight_model::id::{ChannelId, UserId};
+
+/// Add a member to a thread.
+///
+/// The thread must not be archived, and the current user must be able to send
+/// messages in it.
+pub struct AddThreadMember<'a> {
+    channel_id: ChannelId,
+    fut: Option<Pending<'a, ()>>,
+    http: &'a Client,
+    user_id: UserId,
+}
+
+impl<'a> AddThreadMember<'a> {
+    pub(crate) fn new(http: &'a Client, channel_id: ChannelId, user_id: UserId) -> Self {
+        Self {
+            channel_id,
+            fut: None,
+            http,
+            user_id,
+        }
+    }
+
+    fn request(&self) -> Result<Request, Error> {
+        Ok(Request::from_route(Route::AddThreadMember {
+            channel_id: self.channel_id.0,
+            user_id: self.user_id.0,
+        }))
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
+        self.fut.replace(Box::pin(self.http.verify(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(AddThreadMember<'_>, ());
@@ -0,0 +1,183 @@
+use crate::{
+    client::Client,
+    error::Error as HttpError,
+    request::{validate, Pending, Request},
+    routing::Route,
+};
+use serde::Serialize;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    channel::{thread::AutoArchiveDuration, Channel, ChannelType},
+    id::ChannelId,
+};
+
+/// The thread can not be created as configured.
+#[derive(Debug)]
+pub struct CreateThreadError {
+    kind: CreateThreadErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl CreateThreadError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &CreateThreadErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (CreateThreadErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for CreateThreadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            CreateThreadErrorType::NameInvalid { .. } => {
+                f.write_str("the length of the name is invalid")
+            }
+            CreateThreadErrorType::TypeInvalid { .. } => {
+                f.write_str("the type of the channel is not a thread type")
+            }
+        }
+    }
+}
+
+impl Error for CreateThreadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`CreateThreadError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum CreateThreadErrorType {
+    /// The length of the name is either fewer than 1 UTF-16 character or more
+    /// than 100 UTF-16 characters.
+    NameInvalid {
+        /// Provided name.
+        name: String,
+    },
+    /// The type of the channel is not a thread type.
+    TypeInvalid {
+        /// Provided type.
+        kind: ChannelType,
+    },
+}
+
+#[derive(Serialize)]
+struct CreateThreadFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    auto_archive_duration: Option<AutoArchiveDuration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    invitable: Option<bool>,
+    #[serde(rename = "type")]
+    kind: ChannelType,
+    name: String,
+}
+
+/// Create a thread in a channel that is not started from a message.
+///
+/// Private threads require the guild to have the `PRIVATE_THREADS` feature.
+pub struct CreateThread<'a> {
+    channel_id: ChannelId,
+    fields: CreateThreadFields,
+    fut: Option<Pending<'a, Channel>>,
+    http: &'a Client,
+}
+
+impl<'a> CreateThread<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        channel_id: ChannelId,
+        name: impl Into<String>,
+        kind: ChannelType,
+    ) -> Result<Self, CreateThreadError> {
+        Self::_new(http, channel_id, name.into(), kind)
+    }
+
+    fn _new(
+        http: &'a Client,
+        channel_id: ChannelId,
+        name: String,
+        kind: ChannelType,
+    ) -> Result<Self, CreateThreadError> {
+        if !validate::channel_name(&name) {
+            return Err(CreateThreadError {
+                kind: CreateThreadErrorType::NameInvalid { name },
+                source: None,
+            });
+        }
+
+        if !kind.is_thread() {
+            return Err(CreateThreadError {
+                kind: CreateThreadErrorType::TypeInvalid { kind },
+                source: None,
+            });
+        }
+
+        Ok(Self {
+            channel_id,
+            fields: CreateThreadFields {
+                auto_archive_duration: None,
+                invitable: None,
+                kind,
+                name,
+            },
+            fut: None,
+            http,
+        })
+    }
+
+    /// Set the duration of inactivity after which the thread is
+    /// automatically archived.
+    pub const fn auto_archive_duration(
+        mut self,
+        auto_archive_duration: AutoArchiveDuration,
+    ) -> Self {
+        self.fields.auto_archive_duration = Some(auto_archive_duration);
+
+        self
+    }
+
+    /// Set whether members without the `MANAGE_THREADS` permission can add
+    /// other members to the thread.
+    ///
+    /// Only applicable to private threads.
+    pub const fn invitable(mut self, invitable: bool) -> Self {
+        self.fields.invitable = Some(invitable);
+
+        self
+    }
+
+    fn request(&self) -> Result<Request, HttpError> {
+        Ok(Request::builder(Route::CreateThread {
+            channel_id: self.channel_id.0,
+        })
+        .json(&self.fields)?
+        .build())
+    }
+
+    fn start(&mut self) -> Result<(), HttpError> {
+        let request = self.request()?;
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(CreateThread<'_>, Channel);
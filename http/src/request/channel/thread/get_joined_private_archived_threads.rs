@@ -0,0 +1,64 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Pending, Request},
+    routing::Route,
+};
+use twilight_model::{channel::thread::ThreadsListing, id::ChannelId};
+
+/// Get the archived private threads in a channel that the current user has
+/// joined, most recently created first.
+///
+/// Requires the `READ_MESSAGE_HISTORY` permission.
+pub struct GetJoinedPrivateArchivedThreads<'a> {
+    before: Option<ChannelId>,
+    channel_id: ChannelId,
+    fut: Option<Pending<'a, ThreadsListing>>,
+    http: &'a Client,
+    limit: Option<u64>,
+}
+
+impl<'a> GetJoinedPrivateArchivedThreads<'a> {
+    pub(crate) fn new(http: &'a Client, channel_id: ChannelId) -> Self {
+        Self {
+            before: None,
+            channel_id,
+            fut: None,
+            http,
+            limit: None,
+        }
+    }
+
+    /// Get the threads before a thread ID.
+    pub const fn before(mut self, before: ChannelId) -> Self {
+        self.before = Some(before);
+
+        self
+    }
+
+    /// Set the maximum number of threads to get.
+    pub const fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+
+        self
+    }
+
+    fn request(&self) -> Result<Request, Error> {
+        Ok(Request::from_route(
+            Route::GetJoinedPrivateArchivedThreads {
+                before: self.before.map(|id| id.0),
+                channel_id: self.channel_id.0,
+                limit: self.limit,
+            },
+        ))
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(GetJoinedPrivateArchivedThreads<'_>, ThreadsListing);
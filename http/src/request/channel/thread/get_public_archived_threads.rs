@@ -0,0 +1,62 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Pending, Request},
+    routing::Route,
+};
+use twilight_model::{channel::thread::ThreadsListing, id::ChannelId};
+
+/// Get the archived public threads in a channel, most recently archived
+/// first.
+///
+/// Requires the `READ_MESSAGE_HISTORY` permission.
+pub struct GetPublicArchivedThreads<'a> {
+    before: Option<String>,
+    channel_id: ChannelId,
+    fut: Option<Pending<'a, ThreadsListing>>,
+    http: &'a Client,
+    limit: Option<u64>,
+}
+
+impl<'a> GetPublicArchivedThreads<'a> {
+    pub(crate) fn new(http: &'a Client, channel_id: ChannelId) -> Self {
+        Self {
+            before: None,
+            channel_id,
+            fut: None,
+            http,
+            limit: None,
+        }
+    }
+
+    /// Get the threads archived before an ISO8601 timestamp.
+    pub fn before(mut self, before: impl Into<String>) -> Self {
+        self.before.replace(before.into());
+
+        self
+    }
+
+    /// Set the maximum number of threads to get.
+    pub const fn limit(mut self, limit: u64) -> Self {
+        self.limit = Some(limit);
+
+        self
+    }
+
+    fn request(&self) -> Result<Request, Error> {
+        Ok(Request::from_route(Route::GetPublicArchivedThreads {
+            before: self.before.clone(),
+            channel_id: self.channel_id.0,
+            limit: self.limit,
+        }))
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(GetPublicArchivedThreads<'_>, ThreadsListing);
@@ -0,0 +1,224 @@
+use crate::{
+    client::Client,
+    error::Error as HttpError,
+    request::{validate, Pending, Request},
+    routing::Route,
+};
+use serde::Serialize;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    channel::{thread::AutoArchiveDuration, Channel},
+    id::ChannelId,
+};
+
+/// The thread can not be updated as configured.
+#[derive(Debug)]
+pub struct UpdateThreadError {
+    kind: UpdateThreadErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl UpdateThreadError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &UpdateThreadErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (UpdateThreadErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for UpdateThreadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            UpdateThreadErrorType::NameInvalid { .. } => {
+                f.write_str("the length of the name is invalid")
+            }
+            UpdateThreadErrorType::RateLimitPerUserInvalid { .. } => {
+                f.write_str("the rate limit per user is invalid")
+            }
+        }
+    }
+}
+
+impl Error for UpdateThreadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`UpdateThreadError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum UpdateThreadErrorType {
+    /// The length of the name is either fewer than 1 UTF-16 character or more
+    /// than 100 UTF-16 characters.
+    NameInvalid {
+        /// Provided name.
+        name: String,
+    },
+    /// The seconds of the rate limit per user is more than 21600.
+    RateLimitPerUserInvalid {
+        /// Provided ratelimit.
+        rate_limit_per_user: u64,
+    },
+}
+
+#[derive(Default, Serialize)]
+struct UpdateThreadFields {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    archived: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    auto_archive_duration: Option<AutoArchiveDuration>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    invitable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    locked: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rate_limit_per_user: Option<u64>,
+}
+
+/// Update a thread.
+///
+/// All fields are optional. Archived threads can only be unarchived, unless
+/// they are locked, in which case the `MANAGE_THREADS` permission is required.
+pub struct UpdateThread<'a> {
+    channel_id: ChannelId,
+    fields: UpdateThreadFields,
+    fut: Option<Pending<'a, Channel>>,
+    http: &'a Client,
+}
+
+impl<'a> UpdateThread<'a> {
+    pub(crate) fn new(http: &'a Client, channel_id: ChannelId) -> Self {
+        Self {
+            channel_id,
+            fields: UpdateThreadFields::default(),
+            fut: None,
+            http,
+        }
+    }
+
+    /// Set whether the thread is archived.
+    pub const fn archived(mut self, archived: bool) -> Self {
+        self.fields.archived = Some(archived);
+
+        self
+    }
+
+    /// Set the duration of inactivity after which the thread is
+    /// automatically archived.
+    pub const fn auto_archive_duration(
+        mut self,
+        auto_archive_duration: AutoArchiveDuration,
+    ) -> Self {
+        self.fields.auto_archive_duration = Some(auto_archive_duration);
+
+        self
+    }
+
+    /// Set whether members without the `MANAGE_THREADS` permission can add
+    /// other members to the thread.
+    ///
+    /// Only applicable to private threads.
+    pub const fn invitable(mut self, invitable: bool) -> Self {
+        self.fields.invitable = Some(invitable);
+
+        self
+    }
+
+    /// Set whether the thread is locked, in which case only members with the
+    /// `MANAGE_THREADS` permission can unarchive it.
+    pub const fn locked(mut self, locked: bool) -> Self {
+        self.fields.locked = Some(locked);
+
+        self
+    }
+
+    /// Set the name.
+    ///
+    /// The minimum length is 1 UTF-16 character and the maximum is 100 UTF-16
+    /// characters.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`UpdateThreadErrorType::NameInvalid`] error type if the
+    /// name length is too short or too long.
+    pub fn name(self, name: impl Into<String>) -> Result<Self, UpdateThreadError> {
+        self._name(name.into())
+    }
+
+    fn _name(mut self, name: String) -> Result<Self, UpdateThreadError> {
+        if !validate::channel_name(&name) {
+            return Err(UpdateThreadError {
+                kind: UpdateThreadErrorType::NameInvalid { name },
+                source: None,
+            });
+        }
+
+        self.fields.name.replace(name);
+
+        Ok(self)
+    }
+
+    /// Set the number of seconds that a user must wait before they are able
+    /// to send another message.
+    ///
+    /// The minimum is 0 and the maximum is 21600.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`UpdateThreadErrorType::RateLimitPerUserInvalid`] error
+    /// type if the amount is greater than 21600.
+    pub fn rate_limit_per_user(
+        mut self,
+        rate_limit_per_user: u64,
+    ) -> Result<Self, UpdateThreadError> {
+        if rate_limit_per_user > 21600 {
+            return Err(UpdateThreadError {
+                kind: UpdateThreadErrorType::RateLimitPerUserInvalid {
+                    rate_limit_per_user,
+                },
+                source: None,
+            });
+        }
+
+        self.fields.rate_limit_per_user.replace(rate_limit_per_user);
+
+        Ok(self)
+    }
+
+    fn request(&self) -> Result<Request, HttpError> {
+        Ok(Request::builder(Route::UpdateChannel {
+            channel_id: self.channel_id.0,
+        })
+        .json(&self.fields)?
+        .build())
+    }
+
+    fn start(&mut self) -> Result<(), HttpError> {
+        let request = self.request()?;
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(UpdateThread<'_>, Channel);
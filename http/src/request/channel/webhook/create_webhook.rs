@@ -67,7 +67,7 @@ impl<'a> CreateWebhook<'a> {
         self
     }
 
-    fn start(&mut self) -> Result<(), Error> {
+    fn request(&self) -> Result<Request, Error> {
         let mut request = Request::builder(Route::CreateWebhook {
             channel_id: self.channel_id.0,
         })
@@ -77,8 +77,12 @@ impl<'a> CreateWebhook<'a> {
             request = request.headers(request::audit_header(reason)?);
         }
 
-        self.fut
-            .replace(Box::pin(self.http.request(request.build())));
+        Ok(request.build())
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
+        self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
     }
@@ -37,18 +37,26 @@ impl<'a> DeleteWebhook<'a> {
         self
     }
 
-    fn start(&mut self) -> Result<(), Error> {
+    fn request(&self) -> Result<Request, Error> {
         let mut request = Request::builder(Route::DeleteWebhook {
             webhook_id: self.id.0,
             token: self.fields.token.clone(),
         });
 
+        if self.fields.token.is_some() {
+            request = request.durable(false);
+        }
+
         if let Some(reason) = self.reason.as_ref() {
             request = request.headers(request::audit_header(reason)?);
         }
 
-        self.fut
-            .replace(Box::pin(self.http.verify(request.build())));
+        Ok(request.build())
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
+        self.fut.replace(Box::pin(self.http.verify(request)));
 
         Ok(())
     }
@@ -56,6 +56,7 @@ impl<'a> DeleteWebhookMessage<'a> {
             token: self.token.clone(),
             webhook_id: self.webhook_id.0,
         })
+        .durable(false)
         .use_authorization_token(false);
 
         if let Some(reason) = self.reason.as_ref() {
@@ -1,25 +1,91 @@
 use crate::{
     client::Client,
-    error::{Error, ErrorType},
-    request::{Form, PendingOption, Request},
+    error::{Error as HttpError, ErrorType},
+    request::{validate, AttachmentFile, AttachmentPayload, Form, PendingOption, Request},
     routing::Route,
 };
 use hyper::StatusCode;
 use serde::Serialize;
 use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
     future::Future,
     pin::Pin,
     task::{Context, Poll},
 };
 use twilight_model::{
+    application::component::Component,
     channel::{embed::Embed, message::AllowedMentions, Message},
     id::WebhookId,
 };
 
+/// A webhook can not be executed as configured.
+#[derive(Debug)]
+pub struct ExecuteWebhookError {
+    kind: ExecuteWebhookErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl ExecuteWebhookError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ExecuteWebhookErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        ExecuteWebhookErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, self.source)
+    }
+}
+
+impl Display for ExecuteWebhookError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            ExecuteWebhookErrorType::ComponentsInvalid { .. } => {
+                f.write_str("the message components are invalid")
+            }
+        }
+    }
+}
+
+impl Error for ExecuteWebhookError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`ExecuteWebhookError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ExecuteWebhookErrorType {
+    /// Provided components are invalid.
+    ComponentsInvalid {
+        /// Provided components.
+        components: Vec<Component>,
+    },
+}
+
 #[derive(Default, Serialize)]
 pub(crate) struct ExecuteWebhookFields {
     #[serde(skip_serializing_if = "Option::is_none")]
     avatar_url: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    components: Vec<Component>,
     #[serde(skip_serializing_if = "Option::is_none")]
     content: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -63,7 +129,7 @@ pub(crate) struct ExecuteWebhookFields {
 /// [`file`]: Self::file
 pub struct ExecuteWebhook<'a> {
     pub(crate) fields: ExecuteWebhookFields,
-    files: Vec<(String, Vec<u8>)>,
+    files: Vec<AttachmentFile>,
     fut: Option<PendingOption<'a>>,
     http: &'a Client,
     token: String,
@@ -89,6 +155,25 @@ impl<'a> ExecuteWebhook<'a> {
         self
     }
 
+    /// Attach a file to the webhook, with its metadata.
+    ///
+    /// Unlike [`file`], the contents may be streamed from disk or from a
+    /// reader. Refer to [`AttachmentFile`] for more information.
+    ///
+    /// [`file`]: Self::file
+    pub fn attachment_file(mut self, file: AttachmentFile) -> Self {
+        self.files.push(file);
+
+        self
+    }
+
+    /// Attach multiple files to the webhook, with their metadata.
+    pub fn attachment_files(mut self, files: impl IntoIterator<Item = AttachmentFile>) -> Self {
+        self.files.extend(files);
+
+        self
+    }
+
     /// The URL of the avatar of the webhook.
     pub fn avatar_url(mut self, avatar_url: impl Into<String>) -> Self {
         self.fields.avatar_url.replace(avatar_url.into());
@@ -96,6 +181,29 @@ impl<'a> ExecuteWebhook<'a> {
         self
     }
 
+    /// Set the list of components of the message.
+    ///
+    /// Calling this method multiple times will clear previous calls.
+    ///
+    /// Components can only be sent by webhooks owned by an application.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ExecuteWebhookErrorType::ComponentsInvalid`] error type if the
+    /// components are invalid.
+    pub fn components(mut self, components: Vec<Component>) -> Result<Self, ExecuteWebhookError> {
+        if let Err(source) = validate::components(&components) {
+            return Err(ExecuteWebhookError {
+                kind: ExecuteWebhookErrorType::ComponentsInvalid { components },
+                source: Some(Box::new(source)),
+            });
+        }
+
+        self.fields.components = components;
+
+        Ok(self)
+    }
+
     /// The content of the webook's message.
     ///
     /// Up to 2000 UTF-16 codepoints, same as a message.
@@ -116,7 +224,7 @@ impl<'a> ExecuteWebhook<'a> {
     ///
     /// This method is repeatable.
     pub fn file(mut self, name: impl Into<String>, file: impl Into<Vec<u8>>) -> Self {
-        self.files.push((name.into(), file.into()));
+        self.files.push(AttachmentFile::from_bytes(name, file));
 
         self
     }
@@ -213,7 +321,7 @@ impl<'a> ExecuteWebhook<'a> {
         self
     }
 
-    fn start(&mut self) -> Result<(), Error> {
+    fn start(&mut self) -> Result<(), HttpError> {
         let mut request = Request::builder(Route::ExecuteWebhook {
             token: self.token.clone(),
             wait: self.fields.wait,
@@ -222,19 +330,17 @@ impl<'a> ExecuteWebhook<'a> {
 
         // Webhook executions don't need the authorization token, only the
         // webhook token.
-        request = request.use_authorization_token(false);
+        request = request.durable(false).use_authorization_token(false);
 
         if !self.files.is_empty() || self.fields.payload_json.is_some() {
             let mut form = Form::new();
-
-            for (index, (name, file)) in self.files.drain(..).enumerate() {
-                form.file(format!("{}", index).as_bytes(), name.as_bytes(), &file);
-            }
+            let attachments = form.attachments(self.files.drain(..));
 
             if let Some(payload_json) = &self.fields.payload_json {
                 form.payload_json(&payload_json);
             } else {
-                let body = crate::json::to_vec(&self.fields).map_err(Error::json)?;
+                let payload = AttachmentPayload::new(&self.fields, &attachments);
+                let body = crate::json::to_vec(&payload).map_err(HttpError::json)?;
                 form.payload_json(&body);
             }
 
@@ -251,14 +357,14 @@ impl<'a> ExecuteWebhook<'a> {
 }
 
 impl Future for ExecuteWebhook<'_> {
-    type Output = Result<Option<Message>, Error>;
+    type Output = Result<Option<Message>, HttpError>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
         loop {
             if let Some(fut) = self.as_mut().fut.as_mut() {
                 let bytes = match fut.as_mut().poll(cx) {
                     Poll::Ready(Ok(bytes)) => bytes,
-                    Poll::Ready(Err(Error {
+                    Poll::Ready(Err(HttpError {
                         kind: ErrorType::Response { status, .. },
                         source: None,
                     })) if status == StatusCode::NOT_FOUND => {
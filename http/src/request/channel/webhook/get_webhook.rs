@@ -37,7 +37,7 @@ impl<'a> GetWebhook<'a> {
         self
     }
 
-    fn start(&mut self) -> Result<(), Error> {
+    fn request(&self) -> Result<Request, Error> {
         let mut request = Request::builder(Route::GetWebhook {
             token: self.fields.token.clone(),
             webhook_id: self.id.0,
@@ -49,8 +49,12 @@ impl<'a> GetWebhook<'a> {
             request = request.use_authorization_token(false);
         }
 
-        self.fut
-            .replace(Box::pin(self.http.request_bytes(request.build())));
+        Ok(request.build())
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
+        self.fut.replace(Box::pin(self.http.request_bytes(request)));
 
         Ok(())
     }
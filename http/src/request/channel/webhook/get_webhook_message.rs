@@ -37,15 +37,18 @@ impl<'a> GetWebhookMessage<'a> {
         }
     }
 
-    fn start(&mut self) -> Result<(), Error> {
-        let request = Request::builder(Route::GetWebhookMessage {
+    fn request(&self) -> Result<Request, Error> {
+        Ok(Request::builder(Route::GetWebhookMessage {
             message_id: self.message_id.0,
             token: self.token.clone(),
             webhook_id: self.webhook_id.0,
         })
         .use_authorization_token(false)
-        .build();
+        .build())
+    }
 
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.request_bytes(request)));
 
         Ok(())
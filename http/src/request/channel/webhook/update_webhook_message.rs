@@ -4,7 +4,8 @@ use crate::{
     client::Client,
     error::Error as HttpError,
     request::{
-        self, validate, AuditLogReason, AuditLogReasonError, Form, NullableField, Pending, Request,
+        self, validate, AttachmentFile, AttachmentPayload, AuditLogReason, AuditLogReasonError,
+        Form, NullableField, Pending, Request,
     },
     routing::Route,
 };
@@ -12,8 +13,10 @@ use serde::Serialize;
 use std::{
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
+    mem,
 };
 use twilight_model::{
+    application::component::Component,
     channel::{embed::Embed, message::AllowedMentions, Attachment},
     id::{MessageId, WebhookId},
 };
@@ -53,6 +56,9 @@ impl UpdateWebhookMessageError {
 impl Display for UpdateWebhookMessageError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match &self.kind {
+            UpdateWebhookMessageErrorType::ComponentsInvalid { .. } => {
+                f.write_str("the message components are invalid")
+            }
             UpdateWebhookMessageErrorType::ContentInvalid { .. } => {
                 f.write_str("message content is invalid")
             }
@@ -80,6 +86,11 @@ impl Error for UpdateWebhookMessageError {
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum UpdateWebhookMessageErrorType {
+    /// Provided components are invalid.
+    ComponentsInvalid {
+        /// Provided components.
+        components: Vec<Component>,
+    },
     /// Content is over 2000 UTF-16 characters.
     ContentInvalid {
         /// Provided content.
@@ -112,6 +123,8 @@ struct UpdateWebhookMessageFields {
     #[serde(skip_serializing_if = "Vec::is_empty")]
     attachments: Vec<Attachment>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    components: Option<NullableField<Vec<Component>>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     content: Option<NullableField<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     embeds: Option<NullableField<Vec<Embed>>>,
@@ -153,7 +166,7 @@ struct UpdateWebhookMessageFields {
 /// [`DeleteWebhookMessage`]: super::DeleteWebhookMessage
 pub struct UpdateWebhookMessage<'a> {
     fields: UpdateWebhookMessageFields,
-    files: Vec<(String, Vec<u8>)>,
+    files: Vec<AttachmentFile>,
     fut: Option<Pending<'a, ()>>,
     http: &'a Client,
     message_id: MessageId,
@@ -216,6 +229,55 @@ impl<'a> UpdateWebhookMessage<'a> {
         self
     }
 
+    /// Attach a file to the webhook, with its metadata.
+    ///
+    /// Unlike [`file`], the contents may be streamed from disk or from a
+    /// reader. Refer to [`AttachmentFile`] for more information.
+    ///
+    /// [`file`]: Self::file
+    pub fn attachment_file(mut self, file: AttachmentFile) -> Self {
+        self.files.push(file);
+
+        self
+    }
+
+    /// Attach multiple files to the webhook, with their metadata.
+    pub fn attachment_files(mut self, files: impl IntoIterator<Item = AttachmentFile>) -> Self {
+        self.files.extend(files);
+
+        self
+    }
+
+    /// Set the list of components of the message.
+    ///
+    /// Pass `None` to remove all of the components.
+    ///
+    /// Components can only be sent by webhooks owned by an application.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`UpdateWebhookMessageErrorType::ComponentsInvalid`] error type if the
+    /// components are invalid.
+    pub fn components(
+        mut self,
+        components: Option<Vec<Component>>,
+    ) -> Result<Self, UpdateWebhookMessageError> {
+        if let Some(Err(source)) = components.as_deref().map(validate::components) {
+            return Err(UpdateWebhookMessageError {
+                kind: UpdateWebhookMessageErrorType::ComponentsInvalid {
+                    components: components.unwrap_or_default(),
+                },
+                source: Some(Box::new(source)),
+            });
+        }
+
+        self.fields
+            .components
+            .replace(NullableField::from_option(components));
+
+        Ok(self)
+    }
+
     /// Set the content of the message.
     ///
     /// Pass `None` if you want to remove the message content.
@@ -329,7 +391,7 @@ impl<'a> UpdateWebhookMessage<'a> {
     ///
     /// This method is repeatable.
     pub fn file(mut self, name: impl Into<String>, file: impl Into<Vec<u8>>) -> Self {
-        self.files.push((name.into(), file.into()));
+        self.files.push(AttachmentFile::from_bytes(name, file));
 
         self
     }
@@ -367,19 +429,20 @@ impl<'a> UpdateWebhookMessage<'a> {
             token: self.token.clone(),
             webhook_id: self.webhook_id.0,
         })
+        .durable(false)
         .use_authorization_token(false);
 
         if !self.files.is_empty() || self.fields.payload_json.is_some() {
             let mut form = Form::new();
-
-            for (index, (name, file)) in self.files.drain(..).enumerate() {
-                form.file(format!("{}", index).as_bytes(), name.as_bytes(), &file);
-            }
+            let attachments = form.attachments(self.files.drain(..));
 
             if let Some(payload_json) = &self.fields.payload_json {
                 form.payload_json(&payload_json);
             } else {
-                let body = crate::json::to_vec(&self.fields).map_err(HttpError::json)?;
+                let existing = mem::take(&mut self.fields.attachments);
+                let payload =
+                    AttachmentPayload::new(&self.fields, &attachments).existing(&existing);
+                let body = crate::json::to_vec(&payload).map_err(HttpError::json)?;
                 form.payload_json(&body);
             }
 
@@ -412,7 +475,7 @@ impl<'a> AuditLogReason for UpdateWebhookMessage<'a> {
     }
 }
 
-poll_req!(UpdateWebhookMessage<'_>, ());
+poll_req!(mut, UpdateWebhookMessage<'_>, ());
 
 #[cfg(test)]
 mod tests {
@@ -437,6 +500,7 @@ mod tests {
         let body = UpdateWebhookMessageFields {
             allowed_mentions: None,
             attachments: Vec::new(),
+            components: None,
             content: Some(NullableField::Value("test".to_owned())),
             embeds: None,
             payload_json: None,
@@ -59,15 +59,19 @@ impl<'a> UpdateWebhookWithToken<'a> {
         self
     }
 
-    fn start(&mut self) -> Result<(), Error> {
-        let request = Request::builder(Route::UpdateWebhook {
+    fn request(&self) -> Result<Request, Error> {
+        Ok(Request::builder(Route::UpdateWebhook {
             token: Some(self.token.clone()),
             webhook_id: self.webhook_id.0,
         })
+        .durable(false)
         .json(&self.fields)?
         .use_authorization_token(false)
-        .build();
+        .build())
+    }
 
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
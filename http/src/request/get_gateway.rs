@@ -58,9 +58,13 @@ impl<'a> GetGateway<'a> {
         GetGatewayAuthed::new(self.http)
     }
 
-    fn start(&mut self) -> Result<(), Error> {
-        let request = Request::from_route(Route::GetGateway);
+    #[allow(clippy::unused_self)]
+    fn request(&self) -> Result<Request, Error> {
+        Ok(Request::from_route(Route::GetGateway))
+    }
 
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
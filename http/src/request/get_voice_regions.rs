@@ -17,9 +17,13 @@ impl<'a> GetVoiceRegions<'a> {
         Self { fut: None, http }
     }
 
-    fn start(&mut self) -> Result<(), Error> {
-        let request = Request::from_route(Route::GetVoiceRegions);
+    #[allow(clippy::unused_self)]
+    fn request(&self) -> Result<Request, Error> {
+        Ok(Request::from_route(Route::GetVoiceRegions))
+    }
 
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
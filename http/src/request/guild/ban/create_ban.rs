@@ -129,14 +129,17 @@ impl<'a> CreateBan<'a> {
         Ok(self)
     }
 
-    fn start(&mut self) -> Result<(), HttpError> {
-        let request = Request::from_route(Route::CreateBan {
+    fn request(&self) -> Result<Request, HttpError> {
+        Ok(Request::from_route(Route::CreateBan {
             delete_message_days: self.fields.delete_message_days,
             guild_id: self.guild_id.0,
             reason: self.fields.reason.clone(),
             user_id: self.user_id.0,
-        });
+        }))
+    }
 
+    fn start(&mut self) -> Result<(), HttpError> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.verify(request)));
 
         Ok(())
@@ -29,12 +29,15 @@ impl<'a> GetBan<'a> {
         }
     }
 
-    fn start(&mut self) -> Result<(), Error> {
-        let request = Request::from_route(Route::GetBan {
+    fn request(&self) -> Result<Request, Error> {
+        Ok(Request::from_route(Route::GetBan {
             guild_id: self.guild_id.0,
             user_id: self.user_id.0,
-        });
+        }))
+    }
 
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.request_bytes(request)));
 
         Ok(())
@@ -1,10 +1,71 @@
 use crate::{
     client::Client,
-    error::Error,
-    request::{Pending, Request},
+    error::Error as HttpError,
+    request::{validate, Paginator, Pending, Request},
     routing::Route,
 };
-use twilight_model::{guild::Ban, id::GuildId};
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+use twilight_model::{
+    guild::Ban,
+    id::{GuildId, UserId},
+};
+
+/// The error returned if the request can not be created as configured.
+#[derive(Debug)]
+pub struct GetBansError {
+    kind: GetBansErrorType,
+}
+
+impl GetBansError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &GetBansErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (GetBansErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, None)
+    }
+}
+
+impl Display for GetBansError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            GetBansErrorType::LimitInvalid { .. } => f.write_str("the limit is invalid"),
+        }
+    }
+}
+
+impl Error for GetBansError {}
+
+/// Type of [`GetBansError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum GetBansErrorType {
+    /// The maximum number of bans to retrieve is either 0 or more than 1000.
+    LimitInvalid {
+        /// Provided maximum number of bans to retrieve.
+        limit: u64,
+    },
+}
+
+#[derive(Default)]
+struct GetBansFields {
+    after: Option<UserId>,
+    limit: Option<u64>,
+}
 
 /// Retrieve the bans for a guild.
 ///
@@ -26,6 +87,7 @@ use twilight_model::{guild::Ban, id::GuildId};
 /// # Ok(()) }
 /// ```
 pub struct GetBans<'a> {
+    fields: GetBansFields,
     fut: Option<Pending<'a, Vec<Ban>>>,
     guild_id: GuildId,
     http: &'a Client,
@@ -34,17 +96,74 @@ pub struct GetBans<'a> {
 impl<'a> GetBans<'a> {
     pub(crate) fn new(http: &'a Client, guild_id: GuildId) -> Self {
         Self {
+            fields: GetBansFields::default(),
             fut: None,
             guild_id,
             http,
         }
     }
 
-    fn start(&mut self) -> Result<(), Error> {
-        let request = Request::from_route(Route::GetBans {
+    /// Get bans of users after this ID.
+    pub fn after(mut self, after: UserId) -> Self {
+        self.fields.after.replace(after);
+
+        self
+    }
+
+    /// Set the maximum number of bans to retrieve.
+    ///
+    /// The minimum is 1 and the maximum is 1000.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`GetBansErrorType::LimitInvalid`] error type if the amount
+    /// is less than 1 or greater than 1000.
+    pub fn limit(mut self, limit: u64) -> Result<Self, GetBansError> {
+        if !validate::get_guild_bans_limit(limit) {
+            return Err(GetBansError {
+                kind: GetBansErrorType::LimitInvalid { limit },
+            });
+        }
+
+        self.fields.limit.replace(limit);
+
+        Ok(self)
+    }
+
+    /// Stream all bans of the guild, paging through them in order of user ID.
+    ///
+    /// Each page contains up to the configured [`limit`], or the maximum of
+    /// 1000 bans if none is configured.
+    ///
+    /// [`limit`]: Self::limit
+    pub fn stream(self) -> Paginator<'a, Ban> {
+        let guild_id = self.guild_id;
+        let http = self.http;
+        let after = self.fields.after;
+
+        Paginator::after(
+            self.fields.limit.unwrap_or(1000),
+            |ban: &Ban| ban.user.id.0,
+            move |cursor, limit| {
+                let mut request = Self::new(http, guild_id);
+                request.fields.after = cursor.map(UserId).or(after);
+                request.fields.limit.replace(limit);
+
+                Box::pin(request)
+            },
+        )
+    }
+
+    fn request(&self) -> Result<Request, HttpError> {
+        Ok(Request::from_route(Route::GetBans {
+            after: self.fields.after.map(|x| x.0),
             guild_id: self.guild_id.0,
-        });
+            limit: self.fields.limit,
+        }))
+    }
 
+    fn start(&mut self) -> Result<(), HttpError> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
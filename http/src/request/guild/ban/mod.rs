@@ -1,7 +1,7 @@
 pub mod create_ban;
+pub mod get_bans;
 
 mod delete_ban;
 mod get_ban;
-mod get_bans;
 
 pub use self::{create_ban::CreateBan, delete_ban::DeleteBan, get_ban::GetBan, get_bans::GetBans};
@@ -475,11 +475,14 @@ impl<'a> CreateGuild<'a> {
         Ok(self)
     }
 
-    fn start(&mut self) -> Result<(), HttpError> {
-        let request = Request::builder(Route::CreateGuild)
+    fn request(&self) -> Result<Request, HttpError> {
+        Ok(Request::builder(Route::CreateGuild)
             .json(&self.fields)?
-            .build();
+            .build())
+    }
 
+    fn start(&mut self) -> Result<(), HttpError> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
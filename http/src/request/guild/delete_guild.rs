@@ -22,11 +22,14 @@ impl<'a> DeleteGuild<'a> {
         }
     }
 
-    fn start(&mut self) -> Result<(), Error> {
-        let request = Request::from_route(Route::DeleteGuild {
+    fn request(&self) -> Result<Request, Error> {
+        Ok(Request::from_route(Route::DeleteGuild {
             guild_id: self.guild_id.0,
-        });
+        }))
+    }
 
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.verify(request)));
 
         Ok(())
@@ -64,7 +64,7 @@ impl<'a> CreateEmoji<'a> {
         self
     }
 
-    fn start(&mut self) -> Result<(), Error> {
+    fn request(&self) -> Result<Request, Error> {
         let mut request = Request::builder(Route::CreateEmoji {
             guild_id: self.guild_id.0,
         })
@@ -74,8 +74,12 @@ impl<'a> CreateEmoji<'a> {
             request = request.headers(request::audit_header(reason)?);
         }
 
-        self.fut
-            .replace(Box::pin(self.http.request(request.build())));
+        Ok(request.build())
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
+        self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
     }
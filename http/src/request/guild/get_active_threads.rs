@@ -0,0 +1,39 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Pending, Request},
+    routing::Route,
+};
+use twilight_model::{channel::thread::ThreadsListing, id::GuildId};
+
+/// Get the active threads in a guild, including public and private threads.
+pub struct GetActiveThreads<'a> {
+    fut: Option<Pending<'a, ThreadsListing>>,
+    guild_id: GuildId,
+    http: &'a Client,
+}
+
+impl<'a> GetActiveThreads<'a> {
+    pub(crate) fn new(http: &'a Client, guild_id: GuildId) -> Self {
+        Self {
+            fut: None,
+            guild_id,
+            http,
+        }
+    }
+
+    fn request(&self) -> Result<Request, Error> {
+        Ok(Request::from_route(Route::GetActiveThreads {
+            guild_id: self.guild_id.0,
+        }))
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(GetActiveThreads<'_>, ThreadsListing);
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{validate, Pending, Request},
+    request::{validate, Paginator, Pending, Request},
     routing::Route,
 };
 use std::{
@@ -9,7 +9,7 @@ use std::{
     fmt::{Display, Formatter, Result as FmtResult},
 };
 use twilight_model::{
-    guild::audit_log::{AuditLog, AuditLogEvent},
+    guild::audit_log::{AuditLog, AuditLogEntry, AuditLogEvent},
     id::{GuildId, UserId},
 };
 
@@ -148,15 +148,60 @@ impl<'a> GetAuditLog<'a> {
         self
     }
 
-    fn start(&mut self) -> Result<(), HttpError> {
-        let request = Request::from_route(Route::GetAuditLogs {
+    /// Stream the entries of the audit log, starting with the most recent
+    /// entry and paging backwards.
+    ///
+    /// Only the entries are streamed; the users, webhooks and integrations
+    /// referenced by them aren't included. Each page contains up to the
+    /// configured [`limit`], or the maximum of 100 entries if none is
+    /// configured.
+    ///
+    /// [`limit`]: Self::limit
+    pub fn stream(self) -> Paginator<'a, AuditLogEntry> {
+        let Self {
+            fields,
+            guild_id,
+            http,
+            ..
+        } = self;
+        let GetAuditLogFields {
+            action_type,
+            before,
+            limit,
+            user_id,
+        } = fields;
+
+        Paginator::before(
+            limit.unwrap_or(100),
+            |entry: &AuditLogEntry| entry.id.0,
+            move |cursor, limit| {
+                let mut request = Self::new(http, guild_id);
+                request.fields.action_type = action_type;
+                request.fields.before = cursor.or(before);
+                request.fields.limit.replace(limit);
+                request.fields.user_id = user_id;
+
+                Box::pin(async move {
+                    Ok(request
+                        .await?
+                        .map_or_else(Vec::new, |audit_log| audit_log.audit_log_entries))
+                })
+            },
+        )
+    }
+
+    fn request(&self) -> Result<Request, HttpError> {
+        Ok(Request::from_route(Route::GetAuditLogs {
             action_type: self.fields.action_type.map(|x| x as u64),
             before: self.fields.before,
             guild_id: self.guild_id.0,
             limit: self.fields.limit,
             user_id: self.fields.user_id.map(|x| x.0),
-        });
+        }))
+    }
 
+    fn start(&mut self) -> Result<(), HttpError> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
@@ -37,12 +37,15 @@ impl<'a> GetGuild<'a> {
         self
     }
 
-    fn start(&mut self) -> Result<(), Error> {
-        let request = Request::from_route(Route::GetGuild {
+    fn request(&self) -> Result<Request, Error> {
+        Ok(Request::from_route(Route::GetGuild {
             guild_id: self.guild_id.0,
             with_counts: self.fields.with_counts,
-        });
+        }))
+    }
 
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
@@ -119,13 +119,16 @@ impl<'a> GetGuildPruneCount<'a> {
         self
     }
 
-    fn start(&mut self) -> Result<(), HttpError> {
-        let request = Request::from_route(Route::GetGuildPruneCount {
+    fn request(&self) -> Result<Request, HttpError> {
+        Ok(Request::from_route(Route::GetGuildPruneCount {
             days: self.fields.days,
             guild_id: self.guild_id.0,
             include_roles: self.fields.include_roles.clone(),
-        });
+        }))
+    }
 
+    fn start(&mut self) -> Result<(), HttpError> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
@@ -24,11 +24,14 @@ impl<'a> GetGuildVoiceRegions<'a> {
         }
     }
 
-    fn start(&mut self) -> Result<(), Error> {
-        let request = Request::from_route(Route::GetGuildVoiceRegions {
+    fn request(&self) -> Result<Request, Error> {
+        Ok(Request::from_route(Route::GetGuildVoiceRegions {
             guild_id: self.guild_id.0,
-        });
+        }))
+    }
 
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
@@ -174,6 +174,7 @@ impl<'a> AddGuildMember<'a> {
             guild_id: self.guild_id.0,
             user_id: self.user_id.0,
         })
+        .durable(false)
         .json(&self.fields)?
         .build();
 
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{validate, Pending, Request},
+    request::{validate, Paginator, Pending, Request},
     routing::Route,
 };
 use hyper::body::Bytes;
@@ -156,6 +156,35 @@ impl<'a> GetGuildMembers<'a> {
         self
     }
 
+    /// Stream all members of the guild, paging through them in order of
+    /// user ID.
+    ///
+    /// Each page contains up to the configured [`limit`], or the maximum of
+    /// 1000 members if none is configured.
+    ///
+    /// [`limit`]: Self::limit
+    pub fn stream(self) -> Paginator<'a, Member> {
+        let Self {
+            fields,
+            guild_id,
+            http,
+            ..
+        } = self;
+
+        Paginator::after(
+            fields.limit.unwrap_or(1000),
+            |member: &Member| member.user.id.0,
+            move |cursor, limit| {
+                let mut request = Self::new(http, guild_id);
+                request.fields.after = cursor.map(UserId).or(fields.after);
+                request.fields.limit.replace(limit);
+                request.fields.presences = fields.presences;
+
+                Box::pin(request)
+            },
+        )
+    }
+
     fn start(&mut self) -> Result<(), HttpError> {
         let request = Request::from_route(Route::GetGuildMembers {
             after: self.fields.after.map(|x| x.0),
@@ -33,7 +33,7 @@ impl<'a> RemoveRoleFromMember<'a> {
         }
     }
 
-    fn start(&mut self) -> Result<(), Error> {
+    fn request(&self) -> Result<Request, Error> {
         let mut request = Request::builder(Route::RemoveMemberRole {
             guild_id: self.guild_id.0,
             role_id: self.role_id.0,
@@ -44,8 +44,12 @@ impl<'a> RemoveRoleFromMember<'a> {
             request = request.headers(request::audit_header(reason)?);
         }
 
-        self.fut
-            .replace(Box::pin(self.http.verify(request.build())));
+        Ok(request.build())
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
+        self.fut.replace(Box::pin(self.http.verify(request)));
 
         Ok(())
     }
@@ -33,13 +33,16 @@ impl<'a> UpdateRolePositions<'a> {
         }
     }
 
-    fn start(&mut self) -> Result<(), Error> {
-        let request = Request::builder(Route::UpdateRolePositions {
+    fn request(&self) -> Result<Request, Error> {
+        Ok(Request::builder(Route::UpdateRolePositions {
             guild_id: self.guild_id.0,
         })
         .json(&self.roles)?
-        .build();
+        .build())
+    }
 
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
@@ -30,13 +30,16 @@ impl<'a> UpdateCurrentUserNick<'a> {
         }
     }
 
-    fn start(&mut self) -> Result<(), Error> {
-        let request = Request::builder(Route::UpdateNickname {
+    fn request(&self) -> Result<Request, Error> {
+        Ok(Request::builder(Route::UpdateNickname {
             guild_id: self.guild_id.0,
         })
         .json(&self.fields)?
-        .build();
+        .build())
+    }
 
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.verify(request)));
 
         Ok(())
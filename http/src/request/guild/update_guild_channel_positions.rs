@@ -58,13 +58,16 @@ impl<'a> UpdateGuildChannelPositions<'a> {
         }
     }
 
-    fn start(&mut self) -> Result<(), Error> {
-        let request = Request::builder(Route::UpdateGuildChannels {
+    fn request(&self) -> Result<Request, Error> {
+        Ok(Request::builder(Route::UpdateGuildChannels {
             guild_id: self.guild_id.0,
         })
         .json(&self.positions)?
-        .build();
+        .build())
+    }
 
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.verify(request)));
 
         Ok(())
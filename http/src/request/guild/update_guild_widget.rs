@@ -53,13 +53,16 @@ impl<'a> UpdateGuildWidget<'a> {
         self
     }
 
-    fn start(&mut self) -> Result<(), Error> {
-        let request = Request::builder(Route::UpdateGuildWidget {
+    fn request(&self) -> Result<Request, Error> {
+        Ok(Request::builder(Route::UpdateGuildWidget {
             guild_id: self.guild_id.0,
         })
         .json(&self.fields)?
-        .build();
+        .build())
+    }
 
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
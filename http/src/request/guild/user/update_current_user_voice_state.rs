@@ -76,13 +76,16 @@ impl<'a> UpdateCurrentUserVoiceState<'a> {
         self
     }
 
-    fn start(&mut self) -> Result<(), Error> {
-        let request = Request::builder(Route::UpdateCurrentUserVoiceState {
+    fn request(&self) -> Result<Request, Error> {
+        Ok(Request::builder(Route::UpdateCurrentUserVoiceState {
             guild_id: self.guild_id.0,
         })
         .json(&self.fields)?
-        .build();
+        .build())
+    }
 
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.verify(request)));
 
         Ok(())
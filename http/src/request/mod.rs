@@ -1,6 +1,16 @@
 macro_rules! poll_req {
-    ($ty: ty, $ret: ty) => {
-        impl std::future::Future for $ty {
+    ($name: ident<'_>, $ret: ty) => {
+        poll_req!(@response $name, $ret);
+        poll_req!(@future $name, $ret);
+    };
+
+    (mut, $name: ident<'_>, $ret: ty) => {
+        poll_req!(@response $name, $ret, mut);
+        poll_req!(@future $name, $ret);
+    };
+
+    (@future $name: ident, $ret: ty) => {
+        impl std::future::Future for $name<'_> {
             type Output = ::std::result::Result<$ret, $crate::error::Error>;
 
             fn poll(
@@ -20,8 +30,10 @@ macro_rules! poll_req {
         }
     };
 
-    (opt, $ty: ty, $ret: ty) => {
-        impl std::future::Future for $ty {
+    (opt, $name: ident<'_>, $ret: ty) => {
+        poll_req!(@response $name, $ret);
+
+        impl std::future::Future for $name<'_> {
             type Output = ::std::result::Result<Option<$ret>, $crate::error::Error>;
 
             fn poll(
@@ -53,15 +65,31 @@ macro_rules! poll_req {
             }
         }
     };
+
+    (@response $name: ident, $ret: ty $(, $mutability: tt)?) => {
+        impl<'a> $name<'a> {
+            /// Execute the request, resolving to the [`Response`] instead of
+            /// only the deserialized model.
+            ///
+            /// [`Response`]: crate::response::Response
+            pub fn response($($mutability)? self) -> $crate::response::ResponseFuture<'a, $ret> {
+                let request = self.request();
+
+                $crate::response::ResponseFuture::new(self.http, request)
+            }
+        }
+    };
 }
 
 pub mod application;
 pub mod channel;
 pub mod guild;
+pub mod oauth;
 pub mod prelude;
 pub mod template;
 pub mod user;
 
+mod attachment;
 mod audit_reason;
 mod base;
 mod get_gateway;
@@ -69,9 +97,11 @@ mod get_gateway_authed;
 mod get_user_application;
 mod get_voice_regions;
 mod multipart;
+mod paginate;
 mod validate;
 
 pub use self::{
+    attachment::AttachmentFile,
     audit_reason::{AuditLogReason, AuditLogReasonError},
     base::{Request, RequestBuilder},
     get_gateway::GetGateway,
@@ -79,8 +109,11 @@ pub use self::{
     get_user_application::GetUserApplicationInfo,
     get_voice_regions::GetVoiceRegions,
     multipart::Form,
+    paginate::Paginator,
 };
 
+pub(crate) use self::{attachment::AttachmentPayload, multipart::FormBody};
+
 use crate::error::{Error, ErrorType};
 use hyper::body::Bytes;
 use hyper::{
@@ -111,7 +144,32 @@ pub enum Method {
 }
 
 impl Method {
-    pub(crate) const fn into_hyper(self) -> HyperMethod {
+    /// Convert a method supported by the API from its `hyper` equivalent.
+    ///
+    /// Returns `None` for methods the API doesn't use, such as `OPTIONS`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hyper::Method as HyperMethod;
+    /// use twilight_http::request::Method;
+    ///
+    /// assert_eq!(Some(Method::Patch), Method::from_hyper(&HyperMethod::PATCH));
+    /// assert_eq!(None, Method::from_hyper(&HyperMethod::OPTIONS));
+    /// ```
+    pub const fn from_hyper(method: &HyperMethod) -> Option<Self> {
+        match *method {
+            HyperMethod::DELETE => Some(Self::Delete),
+            HyperMethod::GET => Some(Self::Get),
+            HyperMethod::PATCH => Some(Self::Patch),
+            HyperMethod::POST => Some(Self::Post),
+            HyperMethod::PUT => Some(Self::Put),
+            _ => None,
+        }
+    }
+
+    /// Convert the method into its `hyper` equivalent.
+    pub const fn into_hyper(self) -> HyperMethod {
         match self {
             Self::Delete => HyperMethod::DELETE,
             Self::Get => HyperMethod::GET,
@@ -187,5 +245,18 @@ mod tests {
         assert_eq!(HyperMethod::PATCH, Method::Patch.into_hyper());
         assert_eq!(HyperMethod::POST, Method::Post.into_hyper());
         assert_eq!(HyperMethod::PUT, Method::Put.into_hyper());
+
+        for method in &[
+            Method::Delete,
+            Method::Get,
+            Method::Patch,
+            Method::Post,
+            Method::Put,
+        ] {
+            assert_eq!(Some(*method), Method::from_hyper(&method.into_hyper()));
+        }
+
+        assert!(Method::from_hyper(&HyperMethod::HEAD).is_none());
+        assert!(Method::from_hyper(&HyperMethod::OPTIONS).is_none());
     }
 }
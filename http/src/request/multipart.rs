@@ -1,9 +1,30 @@
+use super::attachment::{
+    AttachmentFile, AttachmentMetadata, AttachmentReader, AttachmentSource, StreamSource,
+};
+use futures_core::Stream;
+use hyper::{body::Bytes, Body};
 use rand::{distributions::Alphanumeric, Rng};
+use std::{
+    collections::VecDeque,
+    io::Result as IoResult,
+    mem,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tokio::io::ReadBuf;
+
+/// Segment of a form preceding the segment currently being written.
+#[derive(Debug)]
+enum Segment {
+    Bytes(Vec<u8>),
+    Stream(StreamSource),
+}
 
 #[derive(Debug)]
 pub struct Form {
     boundary: [u8; 15],
     buffer: Vec<u8>,
+    segments: Vec<Segment>,
 }
 
 impl Form {
@@ -11,14 +32,6 @@ impl Form {
         Self::default()
     }
 
-    pub fn build(mut self) -> Vec<u8> {
-        self.buffer.extend(b"\r\n");
-        self.boundary();
-        self.buffer.extend(b"--");
-
-        self.buffer
-    }
-
     pub fn content_type(&self) -> Vec<u8> {
         const NAME: &str = "multipart/form-data; boundary=";
 
@@ -29,6 +42,49 @@ impl Form {
         content_type
     }
 
+    /// Add a file, streaming it into the body of the request if it isn't held
+    /// in memory.
+    pub fn attachment(&mut self, name: &[u8], file: AttachmentFile) -> &mut Self {
+        let filename = file.filename();
+        let (_, source) = file.into_parts();
+
+        self.source(name, filename.as_bytes(), source)
+    }
+
+    /// Add files named `files[n]` by their index, returning the metadata to
+    /// send in the JSON payload.
+    ///
+    /// The metadata is empty if none of the files has a description, as it
+    /// isn't needed.
+    pub(crate) fn attachments(
+        &mut self,
+        files: impl IntoIterator<Item = AttachmentFile>,
+    ) -> Vec<AttachmentMetadata> {
+        let mut metadata = Vec::new();
+
+        for (id, file) in files.into_iter().enumerate() {
+            let filename = file.filename();
+            let (description, source) = file.into_parts();
+            self.source(
+                format!("files[{}]", id).as_bytes(),
+                filename.as_bytes(),
+                source,
+            );
+
+            metadata.push(AttachmentMetadata {
+                description,
+                filename,
+                id,
+            });
+        }
+
+        if metadata.iter().all(|file| file.description.is_none()) {
+            metadata.clear();
+        }
+
+        metadata
+    }
+
     pub fn file(&mut self, name: &[u8], filename: &[u8], data: &[u8]) -> &mut Self {
         self.start();
         self.name(name);
@@ -55,6 +111,70 @@ impl Form {
         self
     }
 
+    /// Finish the form, returning its body.
+    ///
+    /// Forms without streamed files are buffered into memory, while streamed
+    /// files are opened and read as the body is sent.
+    pub(crate) async fn into_body(mut self) -> IoResult<FormBody> {
+        self.buffer.extend(b"\r\n");
+        self.boundary();
+        self.buffer.extend(b"--");
+
+        if self.segments.is_empty() {
+            return Ok(FormBody::Buffered(self.buffer));
+        }
+
+        let Self {
+            buffer, segments, ..
+        } = self;
+        let mut chunks = VecDeque::with_capacity(segments.len() + 1);
+        let mut length = Some(0);
+
+        for segment in segments {
+            let (chunk, chunk_length) = match segment {
+                Segment::Bytes(bytes) => {
+                    let chunk_length = bytes.len() as u64;
+
+                    (Chunk::Bytes(Bytes::from(bytes)), Some(chunk_length))
+                }
+                Segment::Stream(source) => {
+                    let (reader, chunk_length) = source.open().await?;
+
+                    (Chunk::Reader(reader), chunk_length)
+                }
+            };
+
+            length = length.and_then(|length: u64| Some(length + chunk_length?));
+            chunks.push_back(chunk);
+        }
+
+        length = length.map(|length| length + buffer.len() as u64);
+        chunks.push_back(Chunk::Bytes(Bytes::from(buffer)));
+
+        Ok(FormBody::Streamed {
+            body: Body::wrap_stream(FormStream { chunks }),
+            length,
+        })
+    }
+
+    fn source(&mut self, name: &[u8], filename: &[u8], source: AttachmentSource) -> &mut Self {
+        match source {
+            AttachmentSource::Bytes(bytes) => self.file(name, filename, &bytes),
+            AttachmentSource::Stream(source) => {
+                self.start();
+                self.name(name);
+                self.filename(filename);
+                self.data(&[]);
+
+                let buffer = mem::take(&mut self.buffer);
+                self.segments.push(Segment::Bytes(buffer));
+                self.segments.push(Segment::Stream(source));
+
+                self
+            }
+        }
+    }
+
     fn start(&mut self) {
         self.buffer.extend(b"\r\n");
         self.boundary();
@@ -96,6 +216,167 @@ impl Default for Form {
         Self {
             boundary,
             buffer: Vec::new(),
+            segments: Vec::new(),
+        }
+    }
+}
+
+/// Body of a finished form.
+pub(crate) enum FormBody {
+    /// Body held in memory, which can be sent multiple times.
+    Buffered(Vec<u8>),
+    /// Body streaming files as it is sent, which can only be sent once.
+    Streamed {
+        body: Body,
+        /// Length of the body, if the length of every file is known.
+        length: Option<u64>,
+    },
+}
+
+/// Chunk of a streamed form body.
+enum Chunk {
+    Bytes(Bytes),
+    Reader(AttachmentReader),
+}
+
+/// Stream of the chunks of a form body, reading streamed files as they are
+/// reached.
+struct FormStream {
+    chunks: VecDeque<Chunk>,
+}
+
+impl FormStream {
+    /// Maximum size of each chunk read from a streamed file.
+    const READ_SIZE: usize = 16 * 1024;
+}
+
+impl Stream for FormStream {
+    type Item = IoResult<Bytes>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            let reader = match self.chunks.front_mut() {
+                Some(Chunk::Bytes(bytes)) => {
+                    let bytes = mem::take(bytes);
+                    self.chunks.pop_front();
+
+                    return Poll::Ready(Some(Ok(bytes)));
+                }
+                Some(Chunk::Reader(reader)) => reader,
+                None => return Poll::Ready(None),
+            };
+
+            let mut buf = vec![0; Self::READ_SIZE];
+            let mut read_buf = ReadBuf::new(&mut buf);
+
+            match reader.as_mut().poll_read(cx, &mut read_buf) {
+                Poll::Ready(Ok(())) => {}
+                Poll::Ready(Err(source)) => return Poll::Ready(Some(Err(source))),
+                Poll::Pending => return Poll::Pending,
+            }
+
+            let filled = read_buf.filled().len();
+
+            if filled == 0 {
+                self.chunks.pop_front();
+
+                continue;
+            }
+
+            buf.truncate(filled);
+
+            return Poll::Ready(Some(Ok(Bytes::from(buf))));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Form, FormBody};
+    use crate::request::AttachmentFile;
+    use hyper::body;
+    use std::{error::Error, io::Cursor};
+
+    fn boundary(form: &Form) -> String {
+        String::from_utf8(form.boundary.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_buffered() -> Result<(), Box<dyn Error>> {
+        let mut form = Form::new();
+        let boundary = boundary(&form);
+        form.part(b"a", b"1");
+
+        let body = match form.into_body().await? {
+            FormBody::Buffered(body) => body,
+            FormBody::Streamed { .. } => panic!("form isn't buffered"),
+        };
+
+        let expected = format!(
+            "\r\n--{b}\r\nContent-Disposition: form-data; name=\"a\"\r\n\r\n1\r\n--{b}--",
+            b = boundary
+        );
+        assert_eq!(expected.as_bytes(), body.as_slice());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_streamed() -> Result<(), Box<dyn Error>> {
+        let mut form = Form::new();
+        let boundary = boundary(&form);
+        let metadata = form.attachments(vec![
+            AttachmentFile::from_bytes("a.txt", b"abc".to_vec()).spoiler(true),
+            AttachmentFile::from_reader("b.txt", Cursor::new(b"def".to_vec()), 3).description("b"),
+        ]);
+
+        assert_eq!(2, metadata.len());
+        assert_eq!(Some("b"), metadata[1].description.as_deref());
+        assert_eq!("SPOILER_a.txt", metadata[0].filename);
+
+        let (body, length) = match form.into_body().await? {
+            FormBody::Streamed { body, length } => (body, length),
+            FormBody::Buffered(_) => panic!("form isn't streamed"),
+        };
+        let bytes = body::to_bytes(body).await?;
+
+        let expected = format!(
+            "\r\n--{b}\r\nContent-Disposition: form-data; name=\"files[0]\"; \
+             filename=\"SPOILER_a.txt\"\r\n\r\nabc\
+             \r\n--{b}\r\nContent-Disposition: form-data; name=\"files[1]\"; \
+             filename=\"b.txt\"\r\n\r\ndef\r\n--{b}--",
+            b = boundary
+        );
+        assert_eq!(expected.as_bytes(), &bytes[..]);
+        assert_eq!(Some(bytes.len() as u64), length);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_streamed_unknown_length() -> Result<(), Box<dyn Error>> {
+        let mut form = Form::new();
+        form.attachment(
+            b"file",
+            AttachmentFile::from_reader("a.txt", Cursor::new(vec![1; 40_000]), None),
+        );
+
+        match form.into_body().await? {
+            FormBody::Streamed { body, length } => {
+                assert!(length.is_none());
+                assert!(body::to_bytes(body).await?.len() > 40_000);
+            }
+            FormBody::Buffered(_) => panic!("form isn't streamed"),
         }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_streamed_missing_path() {
+        let mut form = Form::new();
+        form.attachment(b"file", AttachmentFile::from_path("/nonexistent/file.png"));
+
+        assert!(form.into_body().await.is_err());
     }
 }
@@ -0,0 +1,199 @@
+use super::{scope_names, url_encode};
+use twilight_model::{
+    guild::Permissions,
+    id::{ApplicationId, GuildId},
+    oauth::Scope,
+};
+
+/// Base URL of the authorization page.
+const AUTHORIZE_URL: &str = "https://discord.com/api/oauth2/authorize";
+
+/// Whether the user is prompted to authorize an application they have
+/// already authorized.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Prompt {
+    /// Always prompt the user to authorize the application.
+    Consent,
+    /// Skip the prompt if the user has already authorized the application
+    /// with the requested scopes.
+    None,
+}
+
+impl Prompt {
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Consent => "consent",
+            Self::None => "none",
+        }
+    }
+}
+
+/// Build the URL of the authorization page to direct users to in the
+/// authorization code flow.
+///
+/// Once the user authorizes the application, they are redirected to the
+/// redirect URI with a `code` query parameter, which can be exchanged for an
+/// access token via [`Client::exchange_code`].
+///
+/// # Examples
+///
+/// ```rust
+/// use twilight_http::request::oauth::AuthorizationUrl;
+/// use twilight_model::{id::ApplicationId, oauth::Scope};
+///
+/// let url = AuthorizationUrl::new(ApplicationId(1), &[Scope::Identify, Scope::Guilds])
+///     .redirect_uri("https://example.com/callback")
+///     .state("15773059ghq9183habn")
+///     .build();
+///
+/// assert_eq!(
+///     "https://discord.com/api/oauth2/authorize?response_type=code&client_id=1\
+///      &scope=identify%20guilds&redirect_uri=https%3A%2F%2Fexample.com%2Fcallback\
+///      &state=15773059ghq9183habn",
+///     url,
+/// );
+/// ```
+///
+/// [`Client::exchange_code`]: crate::Client::exchange_code
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuthorizationUrl {
+    client_id: ApplicationId,
+    disable_guild_select: Option<bool>,
+    guild_id: Option<GuildId>,
+    permissions: Option<Permissions>,
+    prompt: Option<Prompt>,
+    redirect_uri: Option<String>,
+    scopes: Vec<Scope>,
+    state: Option<String>,
+}
+
+impl AuthorizationUrl {
+    /// Create a builder for the authorization URL of an application
+    /// requesting scopes.
+    pub fn new(client_id: ApplicationId, scopes: &[Scope]) -> Self {
+        Self {
+            client_id,
+            disable_guild_select: None,
+            guild_id: None,
+            permissions: None,
+            prompt: None,
+            redirect_uri: None,
+            scopes: scopes.to_vec(),
+            state: None,
+        }
+    }
+
+    /// Whether the user is prevented from choosing another guild than the
+    /// one set via [`guild_id`].
+    ///
+    /// [`guild_id`]: Self::guild_id
+    pub const fn disable_guild_select(mut self, disable_guild_select: bool) -> Self {
+        self.disable_guild_select = Some(disable_guild_select);
+
+        self
+    }
+
+    /// Guild to preselect when adding a bot or a webhook to a guild.
+    pub const fn guild_id(mut self, guild_id: GuildId) -> Self {
+        self.guild_id = Some(guild_id);
+
+        self
+    }
+
+    /// Permissions to request for the bot when the [`Scope::Bot`] scope is
+    /// requested.
+    pub const fn permissions(mut self, permissions: Permissions) -> Self {
+        self.permissions = Some(permissions);
+
+        self
+    }
+
+    /// Whether to prompt a user who has already authorized the application.
+    pub const fn prompt(mut self, prompt: Prompt) -> Self {
+        self.prompt = Some(prompt);
+
+        self
+    }
+
+    /// URI to redirect the user to once they authorize the application.
+    ///
+    /// This must be one of the redirect URIs registered for the application.
+    pub fn redirect_uri(mut self, redirect_uri: impl Into<String>) -> Self {
+        self.redirect_uri = Some(redirect_uri.into());
+
+        self
+    }
+
+    /// Unique value passed back to the redirect URI, used to protect
+    /// against cross-site request forgery.
+    pub fn state(mut self, state: impl Into<String>) -> Self {
+        self.state = Some(state.into());
+
+        self
+    }
+
+    /// Consume the builder, returning the URL.
+    pub fn build(self) -> String {
+        let client_id = self.client_id.0.to_string();
+        let scope = scope_names(&self.scopes);
+        let guild_id = self.guild_id.map(|guild_id| guild_id.0.to_string());
+        let permissions = self
+            .permissions
+            .map(|permissions| permissions.bits().to_string());
+        let disable_guild_select = self.disable_guild_select.map(|disable| disable.to_string());
+
+        let pairs = vec![
+            ("response_type", Some("code")),
+            ("client_id", Some(client_id.as_str())),
+            ("scope", Some(scope.as_str())),
+            ("redirect_uri", self.redirect_uri.as_deref()),
+            ("state", self.state.as_deref()),
+            ("prompt", self.prompt.map(Prompt::name)),
+            ("permissions", permissions.as_deref()),
+            ("guild_id", guild_id.as_deref()),
+            ("disable_guild_select", disable_guild_select.as_deref()),
+        ];
+
+        let query = url_encode(
+            pairs
+                .into_iter()
+                .filter_map(|(name, value)| value.map(|value| (name, value))),
+        );
+
+        format!("{}?{}", AUTHORIZE_URL, query)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AuthorizationUrl, Prompt};
+    use static_assertions::assert_impl_all;
+    use std::fmt::Debug;
+    use twilight_model::{
+        guild::Permissions,
+        id::{ApplicationId, GuildId},
+        oauth::Scope,
+    };
+
+    assert_impl_all!(AuthorizationUrl: Clone, Debug, Send, Sync);
+    assert_impl_all!(Prompt: Clone, Copy, Debug, Send, Sync);
+
+    #[test]
+    fn test_bot_url() {
+        let url =
+            AuthorizationUrl::new(ApplicationId(1), &[Scope::Bot, Scope::ApplicationsCommands])
+                .permissions(Permissions::SEND_MESSAGES | Permissions::VIEW_CHANNEL)
+                .guild_id(GuildId(2))
+                .disable_guild_select(true)
+                .prompt(Prompt::None)
+                .build();
+
+        assert_eq!(
+            "https://discord.com/api/oauth2/authorize?response_type=code&client_id=1\
+             &scope=bot%20applications.commands&prompt=none&permissions=3072\
+             &guild_id=2&disable_guild_select=true",
+            url
+        );
+    }
+}
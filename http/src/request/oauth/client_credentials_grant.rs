@@ -0,0 +1,66 @@
+use super::{scope_names, token_request};
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Pending, Request},
+    routing::Route,
+};
+use twilight_model::{
+    id::ApplicationId,
+    oauth::{AccessToken, Scope},
+};
+
+/// Get an access token for the user owning the application, or the owner of
+/// its team, via the client credentials grant.
+///
+/// Access tokens granted this way have no refresh token.
+pub struct ClientCredentialsGrant<'a> {
+    client_id: ApplicationId,
+    client_secret: String,
+    fut: Option<Pending<'a, AccessToken>>,
+    http: &'a Client,
+    scopes: Vec<Scope>,
+}
+
+impl<'a> ClientCredentialsGrant<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        client_id: ApplicationId,
+        client_secret: impl Into<String>,
+    ) -> Self {
+        Self {
+            client_id,
+            client_secret: client_secret.into(),
+            fut: None,
+            http,
+            scopes: Vec::new(),
+        }
+    }
+
+    /// Set the scopes to request for the access token.
+    pub fn scopes(mut self, scopes: &[Scope]) -> Self {
+        self.scopes = scopes.to_vec();
+
+        self
+    }
+
+    fn request(&self) -> Result<Request, Error> {
+        let scope = scope_names(&self.scopes);
+
+        Ok(token_request(
+            Route::CreateAccessToken,
+            self.client_id,
+            &self.client_secret,
+            vec![("grant_type", "client_credentials"), ("scope", &scope)],
+        ))
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(ClientCredentialsGrant<'_>, AccessToken);
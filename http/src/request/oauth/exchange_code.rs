@@ -0,0 +1,62 @@
+use super::token_request;
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Pending, Request},
+    routing::Route,
+};
+use twilight_model::{id::ApplicationId, oauth::AccessToken};
+
+/// Exchange the code of the authorization code flow for an access token.
+///
+/// The redirect URI must be the same as the one the user was redirected to
+/// with the code.
+pub struct ExchangeCode<'a> {
+    client_id: ApplicationId,
+    client_secret: String,
+    code: String,
+    fut: Option<Pending<'a, AccessToken>>,
+    http: &'a Client,
+    redirect_uri: String,
+}
+
+impl<'a> ExchangeCode<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        client_id: ApplicationId,
+        client_secret: impl Into<String>,
+        code: impl Into<String>,
+        redirect_uri: impl Into<String>,
+    ) -> Self {
+        Self {
+            client_id,
+            client_secret: client_secret.into(),
+            code: code.into(),
+            fut: None,
+            http,
+            redirect_uri: redirect_uri.into(),
+        }
+    }
+
+    fn request(&self) -> Result<Request, Error> {
+        Ok(token_request(
+            Route::CreateAccessToken,
+            self.client_id,
+            &self.client_secret,
+            vec![
+                ("grant_type", "authorization_code"),
+                ("code", self.code.as_str()),
+                ("redirect_uri", self.redirect_uri.as_str()),
+            ],
+        ))
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(ExchangeCode<'_>, AccessToken);
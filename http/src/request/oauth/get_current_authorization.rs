@@ -0,0 +1,40 @@
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Pending, Request},
+    routing::Route,
+};
+use twilight_model::oauth::CurrentAuthorizationInformation;
+
+/// Get information about the authorization of the current access token.
+///
+/// The client must be authorized with a `Bearer` access token.
+pub struct GetCurrentAuthorizationInformation<'a> {
+    fut: Option<Pending<'a, CurrentAuthorizationInformation>>,
+    http: &'a Client,
+}
+
+impl<'a> GetCurrentAuthorizationInformation<'a> {
+    pub(crate) fn new(http: &'a Client) -> Self {
+        Self { fut: None, http }
+    }
+
+    #[allow(clippy::unused_self)]
+    fn request(&self) -> Result<Request, Error> {
+        Ok(Request::from_route(
+            Route::GetCurrentAuthorizationInformation,
+        ))
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(
+    GetCurrentAuthorizationInformation<'_>,
+    CurrentAuthorizationInformation
+);
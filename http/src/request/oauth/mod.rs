@@ -0,0 +1,224 @@
+//! Requests of the `OAuth2` flows.
+//!
+//! An application authorizes users by directing them to an
+//! [`AuthorizationUrl`], after which the user is redirected back to the
+//! application with a code. The code is exchanged for an access token via
+//! [`Client::exchange_code`], which is refreshed via
+//! [`Client::refresh_access_token`] once it expires.
+//!
+//! Requests exchanging or revoking tokens authorize with the client ID and
+//! secret of the application rather than the token of the [`Client`], so
+//! any client can make them. Access tokens are used by creating a client
+//! with the [`AccessToken::authorization`] as its token.
+//!
+//! [`AccessToken::authorization`]: twilight_model::oauth::AccessToken::authorization
+//! [`Client`]: crate::Client
+//! [`Client::exchange_code`]: crate::Client::exchange_code
+//! [`Client::refresh_access_token`]: crate::Client::refresh_access_token
+
+mod authorization_url;
+mod client_credentials_grant;
+mod exchange_code;
+mod get_current_authorization;
+mod refresh_access_token;
+mod revoke_access_token;
+
+pub use self::{
+    authorization_url::{AuthorizationUrl, Prompt},
+    client_credentials_grant::ClientCredentialsGrant,
+    exchange_code::ExchangeCode,
+    get_current_authorization::GetCurrentAuthorizationInformation,
+    refresh_access_token::RefreshAccessToken,
+    revoke_access_token::RevokeAccessToken,
+};
+
+use super::Request;
+use crate::routing::Route;
+use hyper::header::{HeaderValue, CONTENT_TYPE};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use std::iter;
+use twilight_model::{id::ApplicationId, oauth::Scope};
+
+/// Characters to percent-encode in URL-encoded forms and query strings.
+const URL_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'*')
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_');
+
+/// Space-separated names of scopes, as used by the API.
+fn scope_names(scopes: &[Scope]) -> String {
+    scopes
+        .iter()
+        .map(|scope| scope.name())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// URL-encode name and value pairs as a query string or form body.
+fn url_encode<'a>(pairs: impl IntoIterator<Item = (&'a str, &'a str)>) -> String {
+    let mut encoded = String::new();
+
+    for (name, value) in pairs {
+        if !encoded.is_empty() {
+            encoded.push('&');
+        }
+
+        encoded.push_str(name);
+        encoded.push('=');
+        encoded.extend(utf8_percent_encode(value, URL_ENCODE_SET));
+    }
+
+    encoded
+}
+
+/// Create a request to a token endpoint with a URL-encoded form body,
+/// authorized with the credentials of the application.
+fn token_request<'a>(
+    route: Route,
+    client_id: ApplicationId,
+    client_secret: &'a str,
+    fields: impl IntoIterator<Item = (&'a str, &'a str)>,
+) -> Request {
+    let client_id = client_id.0.to_string();
+    let credentials = [
+        ("client_id", client_id.as_str()),
+        ("client_secret", client_secret),
+    ];
+
+    let body = format!(
+        "{}&{}",
+        url_encode(credentials.iter().copied()),
+        url_encode(fields)
+    );
+
+    let content_type = (
+        CONTENT_TYPE,
+        HeaderValue::from_static("application/x-www-form-urlencoded"),
+    );
+
+    Request::builder(route)
+        .body(body.into_bytes())
+        .durable(false)
+        .headers(iter::once(content_type))
+        .use_authorization_token(false)
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{scope_names, url_encode};
+    use crate::{test_server, Client};
+    use hyper::{body, Body, Request, Response};
+    use std::{
+        error::Error,
+        net::SocketAddr,
+        sync::{Arc, Mutex},
+    };
+    use twilight_model::{id::ApplicationId, oauth::Scope};
+
+    type Received = Arc<Mutex<Vec<(String, Option<String>, String)>>>;
+
+    /// Start a server recording the path, authorization and body of each
+    /// request, responding with an access token.
+    fn start_server() -> (SocketAddr, Received) {
+        let received = Received::default();
+        let recorded = Arc::clone(&received);
+
+        let addr = test_server::start(move |request: Request<Body>| {
+            let recorded = Arc::clone(&recorded);
+
+            async move {
+                let path = request.uri().path().to_owned();
+                let authorization = request
+                    .headers()
+                    .get("authorization")
+                    .map(|value| value.to_str().unwrap().to_owned());
+                let body = body::to_bytes(request.into_body()).await?;
+                let body = String::from_utf8(body.to_vec()).unwrap();
+                recorded.lock().unwrap().push((path, authorization, body));
+
+                Ok::<_, hyper::Error>(Response::new(Body::from(
+                    r#"{
+                        "access_token": "access",
+                        "expires_in": 604800,
+                        "refresh_token": "refresh",
+                        "scope": "identify",
+                        "token_type": "Bearer"
+                    }"#,
+                )))
+            }
+        });
+
+        (addr, received)
+    }
+
+    #[test]
+    fn test_scope_names() {
+        assert_eq!(
+            "identify guilds.join",
+            scope_names(&[Scope::Identify, Scope::GuildsJoin])
+        );
+        assert!(scope_names(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_url_encode() {
+        assert_eq!(
+            "code=abc&redirect_uri=https%3A%2F%2Fexample.com%2Fcallback",
+            url_encode(vec![
+                ("code", "abc"),
+                ("redirect_uri", "https://example.com/callback"),
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn test_token_requests() -> Result<(), Box<dyn Error>> {
+        let (addr, received) = start_server();
+
+        let client = Client::builder()
+            .proxy(addr.to_string(), true)
+            .token("Bot token")
+            .build();
+
+        let token = client
+            .exchange_code(ApplicationId(1), "secret", "code", "https://a.b/c")
+            .await?;
+        assert_eq!("Bearer access", token.authorization());
+        assert_eq!(vec![Scope::Identify], token.scopes);
+
+        client
+            .client_credentials_grant(ApplicationId(1), "secret")
+            .scopes(&[Scope::Identify, Scope::Connections])
+            .await?;
+        client
+            .revoke_access_token(ApplicationId(1), "secret", "refresh")
+            .await?;
+
+        let received = received.lock().unwrap();
+
+        assert_eq!(
+            (
+                "/api/v9/oauth2/token".to_owned(),
+                None,
+                "client_id=1&client_secret=secret&grant_type=authorization_code&code=code\
+                 &redirect_uri=https%3A%2F%2Fa.b%2Fc"
+                    .to_owned()
+            ),
+            received[0]
+        );
+        assert_eq!(
+            "client_id=1&client_secret=secret&grant_type=client_credentials\
+             &scope=identify%20connections",
+            received[1].2
+        );
+        assert_eq!("/api/v9/oauth2/token/revoke", received[2].0);
+        assert_eq!(
+            "client_id=1&client_secret=secret&token=refresh",
+            received[2].2
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use super::token_request;
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Pending, Request},
+    routing::Route,
+};
+use twilight_model::{id::ApplicationId, oauth::AccessToken};
+
+/// Exchange a refresh token for a new access token.
+///
+/// The refresh token is invalidated, so the refresh token of the new access
+/// token needs to be used for the next refresh.
+pub struct RefreshAccessToken<'a> {
+    client_id: ApplicationId,
+    client_secret: String,
+    fut: Option<Pending<'a, AccessToken>>,
+    http: &'a Client,
+    refresh_token: String,
+}
+
+impl<'a> RefreshAccessToken<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        client_id: ApplicationId,
+        client_secret: impl Into<String>,
+        refresh_token: impl Into<String>,
+    ) -> Self {
+        Self {
+            client_id,
+            client_secret: client_secret.into(),
+            fut: None,
+            http,
+            refresh_token: refresh_token.into(),
+        }
+    }
+
+    fn request(&self) -> Result<Request, Error> {
+        Ok(token_request(
+            Route::CreateAccessToken,
+            self.client_id,
+            &self.client_secret,
+            vec![
+                ("grant_type", "refresh_token"),
+                ("refresh_token", self.refresh_token.as_str()),
+            ],
+        ))
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
+        self.fut.replace(Box::pin(self.http.request(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(RefreshAccessToken<'_>, AccessToken);
@@ -0,0 +1,52 @@
+use super::token_request;
+use crate::{
+    client::Client,
+    error::Error,
+    request::{Pending, Request},
+    routing::Route,
+};
+use twilight_model::id::ApplicationId;
+
+/// Revoke an access or refresh token, invalidating it.
+pub struct RevokeAccessToken<'a> {
+    client_id: ApplicationId,
+    client_secret: String,
+    fut: Option<Pending<'a, ()>>,
+    http: &'a Client,
+    token: String,
+}
+
+impl<'a> RevokeAccessToken<'a> {
+    pub(crate) fn new(
+        http: &'a Client,
+        client_id: ApplicationId,
+        client_secret: impl Into<String>,
+        token: impl Into<String>,
+    ) -> Self {
+        Self {
+            client_id,
+            client_secret: client_secret.into(),
+            fut: None,
+            http,
+            token: token.into(),
+        }
+    }
+
+    fn request(&self) -> Result<Request, Error> {
+        Ok(token_request(
+            Route::RevokeAccessToken,
+            self.client_id,
+            &self.client_secret,
+            vec![("token", self.token.as_str())],
+        ))
+    }
+
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
+        self.fut.replace(Box::pin(self.http.verify(request)));
+
+        Ok(())
+    }
+}
+
+poll_req!(RevokeAccessToken<'_>, ());
@@ -0,0 +1,375 @@
+use super::Pending;
+use crate::error::Error;
+use futures_core::{ready, FusedStream, Stream};
+use std::{
+    cmp::Reverse,
+    collections::VecDeque,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Direction in which pages are requested.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Direction {
+    /// Request items with IDs greater than the cursor, yielding them in
+    /// ascending order.
+    After,
+    /// Request items with IDs less than the cursor, yielding them in
+    /// descending order.
+    Before,
+}
+
+/// Function requesting a page of up to a number of items, given the ID to
+/// page from.
+///
+/// The ID is `None` when the first page is requested, in which case the
+/// configuration of the originating request is used.
+type FetchPage<'a, T> = Box<dyn Fn(Option<u64>, u64) -> Pending<'a, Vec<T>> + Send + Sync + 'a>;
+
+/// Stream of items of an endpoint returning a list, transparently requesting
+/// pages as items are consumed.
+///
+/// Created via the `stream` method of the requests of paginated endpoints,
+/// such as [`GetChannelMessages::stream`]. Each page requests the maximum
+/// number of items allowed by the endpoint unless a limit was configured on
+/// the request. The stream ends once the endpoint returns a page with fewer
+/// items than requested, or once a bound configured via [`max_items`] or
+/// [`until`] has been reached.
+///
+/// If a request fails the error is yielded and the stream ends.
+///
+/// # Examples
+///
+/// Collect the last 250 messages sent in a channel:
+///
+/// ```rust,no_run
+/// use futures_util::stream::TryStreamExt;
+/// use twilight_http::Client;
+/// use twilight_model::id::ChannelId;
+///
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let client = Client::new("my token");
+///
+/// let messages = client
+///     .channel_messages(ChannelId(1))
+///     .stream()
+///     .max_items(250)
+///     .try_collect::<Vec<_>>()
+///     .await?;
+/// # Ok(()) }
+/// ```
+///
+/// [`GetChannelMessages::stream`]: super::channel::message::GetChannelMessages::stream
+/// [`max_items`]: Self::max_items
+/// [`until`]: Self::until
+pub struct Paginator<'a, T> {
+    buffer: VecDeque<T>,
+    cursor: Option<u64>,
+    direction: Direction,
+    done: bool,
+    fetch: FetchPage<'a, T>,
+    fut: Option<Pending<'a, Vec<T>>>,
+    id: fn(&T) -> u64,
+    page_size: u64,
+    partial_first_page: bool,
+    remaining: Option<u64>,
+    until: Option<u64>,
+}
+
+impl<'a, T> Paginator<'a, T> {
+    /// Create a paginator requesting items with increasing IDs.
+    pub(crate) fn after(
+        page_size: u64,
+        id: fn(&T) -> u64,
+        fetch: impl Fn(Option<u64>, u64) -> Pending<'a, Vec<T>> + Send + Sync + 'a,
+    ) -> Self {
+        Self::new(Direction::After, page_size, id, Box::new(fetch))
+    }
+
+    /// Create a paginator requesting items with decreasing IDs.
+    pub(crate) fn before(
+        page_size: u64,
+        id: fn(&T) -> u64,
+        fetch: impl Fn(Option<u64>, u64) -> Pending<'a, Vec<T>> + Send + Sync + 'a,
+    ) -> Self {
+        Self::new(Direction::Before, page_size, id, Box::new(fetch))
+    }
+
+    fn new(
+        direction: Direction,
+        page_size: u64,
+        id: fn(&T) -> u64,
+        fetch: FetchPage<'a, T>,
+    ) -> Self {
+        Self {
+            buffer: VecDeque::new(),
+            cursor: None,
+            direction,
+            done: false,
+            fetch,
+            fut: None,
+            id,
+            page_size,
+            partial_first_page: false,
+            remaining: None,
+            until: None,
+        }
+    }
+
+    /// Set the maximum number of items to yield.
+    ///
+    /// The last page only requests as many items as are still needed.
+    pub const fn max_items(mut self, max_items: u64) -> Self {
+        self.remaining = Some(max_items);
+
+        self
+    }
+
+    /// Set the ID at which to stop yielding items.
+    ///
+    /// When paging forwards the stream ends before the first item with an ID
+    /// greater than or equal to this ID, and when paging backwards before the
+    /// first item with an ID less than or equal to it. For example, stream
+    /// messages until one that has already been processed is reached.
+    pub const fn until(mut self, id: u64) -> Self {
+        self.until = Some(id);
+
+        self
+    }
+
+    /// Mark that the first page may contain fewer items than requested even
+    /// if more items exist in the paging direction.
+    pub(crate) const fn partial_first_page(mut self) -> Self {
+        self.partial_first_page = true;
+
+        self
+    }
+
+    /// Number of items to request in the next page.
+    fn request_size(&self) -> u64 {
+        self.remaining
+            .map_or(self.page_size, |remaining| remaining.min(self.page_size))
+    }
+
+    /// Whether an item is past the configured bound.
+    fn reached_until(&self, item: &T) -> bool {
+        let until = match self.until {
+            Some(until) => until,
+            None => return false,
+        };
+
+        let id = (self.id)(item);
+
+        match self.direction {
+            Direction::After => id >= until,
+            Direction::Before => id <= until,
+        }
+    }
+
+    /// Take the next buffered item, ending the stream if a bound is reached.
+    fn next_buffered(&mut self) -> Option<T> {
+        let item = self.buffer.pop_front()?;
+
+        if self.remaining == Some(0) || self.reached_until(&item) {
+            self.buffer.clear();
+            self.done = true;
+
+            return None;
+        }
+
+        if let Some(remaining) = self.remaining.as_mut() {
+            *remaining -= 1;
+        }
+
+        Some(item)
+    }
+
+    /// Handle a received page, buffering its items in paging order.
+    fn receive(&mut self, mut page: Vec<T>, requested: u64) {
+        let first_page = self.cursor.is_none();
+        let short = (page.len() as u64) < requested;
+
+        if page.is_empty() || (short && !(first_page && self.partial_first_page)) {
+            self.done = true;
+        }
+
+        let id = self.id;
+
+        match self.direction {
+            Direction::After => page.sort_unstable_by_key(id),
+            Direction::Before => page.sort_unstable_by_key(|item| Reverse(id(item))),
+        }
+
+        if let Some(last) = page.last() {
+            self.cursor = Some(id(last));
+        }
+
+        self.buffer.extend(page);
+    }
+}
+
+impl<T: Unpin> Stream for Paginator<'_, T> {
+    type Item = Result<T, Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+
+        loop {
+            if let Some(item) = this.next_buffered() {
+                return Poll::Ready(Some(Ok(item)));
+            }
+
+            if this.done || this.remaining == Some(0) {
+                this.done = true;
+
+                return Poll::Ready(None);
+            }
+
+            let requested = this.request_size();
+
+            if this.fut.is_none() {
+                this.fut = Some((this.fetch)(this.cursor, requested));
+            }
+
+            let fut = this.fut.as_mut().expect("future is created");
+            let result = ready!(fut.as_mut().poll(cx));
+            this.fut = None;
+
+            match result {
+                Ok(page) => this.receive(page, requested),
+                Err(source) => {
+                    this.done = true;
+
+                    return Poll::Ready(Some(Err(source)));
+                }
+            }
+        }
+    }
+}
+
+impl<T: Unpin> FusedStream for Paginator<'_, T> {
+    fn is_terminated(&self) -> bool {
+        self.done && self.buffer.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Paginator;
+    use crate::error::{Error, ErrorType};
+    use futures_core::{FusedStream, Stream};
+    use futures_util::stream::{StreamExt, TryStreamExt};
+    use static_assertions::assert_impl_all;
+    use std::{
+        future,
+        sync::{Arc, Mutex},
+    };
+    use twilight_model::channel::Message;
+
+    assert_impl_all!(Paginator<'_, Message>: FusedStream, Send, Stream, Unpin);
+
+    type Calls = Arc<Mutex<Vec<(Option<u64>, u64)>>>;
+
+    /// Create a paginator over the IDs 1 through `total`, paging forwards or
+    /// backwards and recording each requested cursor and size.
+    fn paginator(after: bool, total: u64, page_size: u64) -> (Paginator<'static, u64>, Calls) {
+        let calls = Calls::default();
+        let recorded = Arc::clone(&calls);
+
+        let fetch = move |cursor: Option<u64>, limit: u64| {
+            recorded.lock().unwrap().push((cursor, limit));
+
+            // Pages are returned in descending order, as the API does.
+            let page: Vec<u64> = if after {
+                let start = cursor.unwrap_or(0) + 1;
+                let end = (start + limit).min(total + 1);
+                (start..end).rev().collect()
+            } else {
+                let end = cursor.unwrap_or(total + 1);
+                let start = end.saturating_sub(limit).max(1);
+                (start..end).rev().collect()
+            };
+
+            Box::pin(future::ready(Ok(page))) as _
+        };
+
+        let paginator = if after {
+            Paginator::after(page_size, |id| *id, fetch)
+        } else {
+            Paginator::before(page_size, |id| *id, fetch)
+        };
+
+        (paginator, calls)
+    }
+
+    #[tokio::test]
+    async fn test_after() -> Result<(), Error> {
+        let (paginator, calls) = paginator(true, 5, 2);
+        let items = paginator.try_collect::<Vec<_>>().await?;
+
+        assert_eq!(vec![1, 2, 3, 4, 5], items);
+        assert_eq!(
+            vec![(None, 2), (Some(2), 2), (Some(4), 2)],
+            *calls.lock().unwrap()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_before() -> Result<(), Error> {
+        let (paginator, calls) = paginator(false, 4, 2);
+        let items = paginator.try_collect::<Vec<_>>().await?;
+
+        assert_eq!(vec![4, 3, 2, 1], items);
+
+        // The last page is full, so an empty page has to be requested to know
+        // that no items are left.
+        assert_eq!(
+            vec![(None, 2), (Some(3), 2), (Some(1), 2)],
+            *calls.lock().unwrap()
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_max_items() -> Result<(), Error> {
+        let (paginator, calls) = paginator(false, 100, 10);
+        let items = paginator.max_items(15).try_collect::<Vec<_>>().await?;
+
+        assert_eq!((86..=100).rev().collect::<Vec<_>>(), items);
+        assert_eq!(vec![(None, 10), (Some(91), 5)], *calls.lock().unwrap());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_until() -> Result<(), Error> {
+        let (paginator, calls) = paginator(true, 100, 10);
+        let items = paginator.until(13).try_collect::<Vec<_>>().await?;
+
+        assert_eq!((1..=12).collect::<Vec<_>>(), items);
+        assert_eq!(vec![(None, 10), (Some(10), 10)], *calls.lock().unwrap());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_error_ends_stream() {
+        let mut paginator = Paginator::after(
+            10,
+            |id: &u64| *id,
+            |_, _| {
+                Box::pin(future::ready(Err(Error {
+                    kind: ErrorType::RequestCanceled,
+                    source: None,
+                })))
+            },
+        );
+
+        assert!(matches!(paginator.next().await, Some(Err(_))));
+        assert!(paginator.next().await.is_none());
+    }
+}
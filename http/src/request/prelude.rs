@@ -1,6 +1,6 @@
 pub use super::{
     audit_reason::{AuditLogReason, AuditLogReasonError},
-    channel::{invite::*, message::*, reaction::*, stage::*, webhook::*, *},
+    channel::{invite::*, message::*, reaction::*, stage::*, thread::*, webhook::*, *},
     get_gateway::GetGateway,
     get_gateway_authed::GetGatewayAuthed,
     get_voice_regions::GetVoiceRegions,
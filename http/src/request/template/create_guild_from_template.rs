@@ -132,13 +132,16 @@ impl<'a> CreateGuildFromTemplate<'a> {
         self
     }
 
-    fn start(&mut self) -> Result<(), HttpError> {
-        let request = Request::builder(Route::CreateGuildFromTemplate {
+    fn request(&self) -> Result<Request, HttpError> {
+        Ok(Request::builder(Route::CreateGuildFromTemplate {
             template_code: self.template_code.clone(),
         })
         .json(&self.fields)?
-        .build();
+        .build())
+    }
 
+    fn start(&mut self) -> Result<(), HttpError> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
@@ -32,12 +32,15 @@ impl<'a> DeleteTemplate<'a> {
         }
     }
 
-    fn start(&mut self) -> Result<(), Error> {
-        let request = Request::from_route(Route::DeleteTemplate {
+    fn request(&self) -> Result<Request, Error> {
+        Ok(Request::from_route(Route::DeleteTemplate {
             guild_id: self.guild_id.0,
             template_code: self.template_code.clone(),
-        });
+        }))
+    }
 
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.verify(request)));
 
         Ok(())
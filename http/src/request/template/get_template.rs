@@ -26,11 +26,14 @@ impl<'a> GetTemplate<'a> {
         }
     }
 
-    fn start(&mut self) -> Result<(), Error> {
-        let request = Request::from_route(Route::GetTemplate {
+    fn request(&self) -> Result<Request, Error> {
+        Ok(Request::from_route(Route::GetTemplate {
             template_code: self.template_code.clone(),
-        });
+        }))
+    }
 
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
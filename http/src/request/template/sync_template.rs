@@ -32,12 +32,15 @@ impl<'a> SyncTemplate<'a> {
         }
     }
 
-    fn start(&mut self) -> Result<(), Error> {
-        let request = Request::from_route(Route::SyncTemplate {
+    fn request(&self) -> Result<Request, Error> {
+        Ok(Request::from_route(Route::SyncTemplate {
             guild_id: self.guild_id.0,
             template_code: self.template_code.clone(),
-        });
+        }))
+    }
 
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
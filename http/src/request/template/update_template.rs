@@ -159,14 +159,17 @@ impl<'a> UpdateTemplate<'a> {
         Ok(self)
     }
 
-    fn start(&mut self) -> Result<(), HttpError> {
-        let request = Request::builder(Route::UpdateTemplate {
+    fn request(&self) -> Result<Request, HttpError> {
+        Ok(Request::builder(Route::UpdateTemplate {
             guild_id: self.guild_id.0,
             template_code: self.template_code.clone(),
         })
         .json(&self.fields)?
-        .build();
+        .build())
+    }
 
+    fn start(&mut self) -> Result<(), HttpError> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
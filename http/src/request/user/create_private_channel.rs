@@ -29,11 +29,14 @@ impl<'a> CreatePrivateChannel<'a> {
             http,
         }
     }
-    fn start(&mut self) -> Result<(), Error> {
-        let request = Request::builder(Route::CreatePrivateChannel)
+    fn request(&self) -> Result<Request, Error> {
+        Ok(Request::builder(Route::CreatePrivateChannel)
             .json(&self.fields)?
-            .build();
+            .build())
+    }
 
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
@@ -1,7 +1,7 @@
 use crate::{
     client::Client,
     error::Error as HttpError,
-    request::{validate, Pending, Request},
+    request::{validate, Paginator, Pending, Request},
     routing::Route,
 };
 use std::{
@@ -150,13 +150,51 @@ impl<'a> GetCurrentUserGuilds<'a> {
         Ok(self)
     }
 
-    fn start(&mut self) -> Result<(), HttpError> {
-        let request = Request::from_route(Route::GetGuilds {
+    /// Stream all guilds of the current user.
+    ///
+    /// If only [`before`] was configured the guilds are streamed in
+    /// descending order of ID, otherwise in ascending order.
+    ///
+    /// Each page contains up to the configured [`limit`], or the maximum of
+    /// 100 guilds if none is configured.
+    ///
+    /// [`before`]: Self::before
+    /// [`limit`]: Self::limit
+    pub fn stream(self) -> Paginator<'a, CurrentUserGuild> {
+        let Self { fields, http, .. } = self;
+        let page_size = fields.limit.unwrap_or(100);
+        let id = |guild: &CurrentUserGuild| guild.id.0;
+
+        let request = move |after, before, limit| {
+            let mut request = Self::new(http);
+            request.fields.after = after;
+            request.fields.before = before;
+            request.fields.limit.replace(limit);
+
+            Box::pin(request) as Pending<'a, _>
+        };
+
+        if fields.before.is_some() && fields.after.is_none() {
+            Paginator::before(page_size, id, move |cursor, limit| {
+                request(None, cursor.map(GuildId).or(fields.before), limit)
+            })
+        } else {
+            Paginator::after(page_size, id, move |cursor, limit| {
+                request(cursor.map(GuildId).or(fields.after), None, limit)
+            })
+        }
+    }
+
+    fn request(&self) -> Result<Request, HttpError> {
+        Ok(Request::from_route(Route::GetGuilds {
             after: self.fields.after.map(|x| x.0),
             before: self.fields.before.map(|x| x.0),
             limit: self.fields.limit,
-        });
+        }))
+    }
 
+    fn start(&mut self) -> Result<(), HttpError> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
@@ -22,11 +22,14 @@ impl<'a> GetUser<'a> {
         }
     }
 
-    fn start(&mut self) -> Result<(), Error> {
-        let request = Request::from_route(Route::GetUser {
+    fn request(&self) -> Result<Request, Error> {
+        Ok(Request::from_route(Route::GetUser {
             target_user: self.target_user.clone(),
-        });
+        }))
+    }
 
+    fn start(&mut self) -> Result<(), Error> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.request_bytes(request)));
 
         Ok(())
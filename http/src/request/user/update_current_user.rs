@@ -133,11 +133,14 @@ impl<'a> UpdateCurrentUser<'a> {
         Ok(self)
     }
 
-    fn start(&mut self) -> Result<(), HttpError> {
-        let request = Request::builder(Route::UpdateCurrentUser)
+    fn request(&self) -> Result<Request, HttpError> {
+        Ok(Request::builder(Route::UpdateCurrentUser)
             .json(&self.fields)?
-            .build();
+            .build())
+    }
 
+    fn start(&mut self) -> Result<(), HttpError> {
+        let request = self.request()?;
         self.fut.replace(Box::pin(self.http.request(request)));
 
         Ok(())
@@ -5,9 +5,481 @@
 /// different modules.
 use std::{
     error::Error,
-    fmt::{Display, Formatter, Result as FmtResult},
+    fmt::{Debug, Display, Formatter, Result as FmtResult},
 };
-use twilight_model::channel::embed::Embed;
+use twilight_model::{
+    application::{
+        callback::Modal,
+        command::CommandOption,
+        component::{Button, ButtonStyle, Component, ComponentType, SelectMenu, TextInput},
+    },
+    channel::embed::Embed,
+};
+
+/// A component is not valid.
+///
+/// Referenced values are used from [the Discord docs][docs].
+///
+/// [docs]: https://discord.com/developers/docs/interactions/message-components
+#[derive(Debug)]
+pub struct ComponentValidationError {
+    kind: ComponentValidationErrorType,
+}
+
+impl ComponentValidationError {
+    /// The maximum number of components in an action row.
+    pub const ACTION_ROW_COMPONENT_COUNT: usize = 5;
+
+    /// The maximum number of root components in a message.
+    pub const COMPONENT_COUNT: usize = 5;
+
+    /// The maximum length of a component custom ID in codepoints.
+    pub const COMPONENT_CUSTOM_ID_LENGTH: usize = 100;
+
+    /// The maximum length of a button label in codepoints.
+    pub const COMPONENT_LABEL_LENGTH: usize = 80;
+
+    /// The maximum length of a modal title in codepoints.
+    pub const MODAL_TITLE_LENGTH: usize = 45;
+
+    /// The maximum number of options that may be selected in a select menu.
+    pub const SELECT_MAXIMUM_VALUES_LIMIT: usize = 25;
+
+    /// The maximum number of options that must be selected in a select menu.
+    pub const SELECT_MINIMUM_VALUES_LIMIT: usize = 25;
+
+    /// The maximum number of options in a select menu.
+    pub const SELECT_OPTION_COUNT: usize = 25;
+
+    /// The maximum length of a select menu option description in codepoints.
+    pub const SELECT_OPTION_DESCRIPTION_LENGTH: usize = 100;
+
+    /// The maximum length of a select menu option label in codepoints.
+    pub const SELECT_OPTION_LABEL_LENGTH: usize = 100;
+
+    /// The maximum length of a select menu option value in codepoints.
+    pub const SELECT_OPTION_VALUE_LENGTH: usize = 100;
+
+    /// The maximum length of a select menu placeholder in codepoints.
+    pub const SELECT_PLACEHOLDER_LENGTH: usize = 100;
+
+    /// The maximum length of a text input label in codepoints.
+    pub const TEXT_INPUT_LABEL_LENGTH: usize = 45;
+
+    /// The maximum length of the text entered into a text input in
+    /// codepoints.
+    pub const TEXT_INPUT_LENGTH: usize = 4000;
+
+    /// The maximum length of a text input placeholder in codepoints.
+    pub const TEXT_INPUT_PLACEHOLDER_LENGTH: usize = 100;
+
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &ComponentValidationErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[allow(clippy::unused_self)]
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        None
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(
+        self,
+    ) -> (
+        ComponentValidationErrorType,
+        Option<Box<dyn Error + Send + Sync>>,
+    ) {
+        (self.kind, None)
+    }
+}
+
+impl Display for ComponentValidationError {
+    #[allow(clippy::too_many_lines)]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match &self.kind {
+            ComponentValidationErrorType::ActionRowComponentCount { count } => {
+                f.write_str("an action row has ")?;
+                Display::fmt(count, f)?;
+                f.write_str(" children, but must have between 1 and ")?;
+
+                Display::fmt(&Self::ACTION_ROW_COMPONENT_COUNT, f)
+            }
+            ComponentValidationErrorType::ActionRowSelectMenuConflict => {
+                f.write_str("a select menu must be the only component in its action row")
+            }
+            ComponentValidationErrorType::ButtonConflict => {
+                f.write_str("button has both a custom id and url, which is never valid")
+            }
+            ComponentValidationErrorType::ButtonStyle { style } => {
+                f.write_str("button has a type of ")?;
+                Debug::fmt(style, f)?;
+                f.write_str(", which must have a ")?;
+
+                f.write_str(if *style == ButtonStyle::Link {
+                    "url"
+                } else {
+                    "custom id"
+                })?;
+
+                f.write_str(" configured")
+            }
+            ComponentValidationErrorType::ComponentCount { count } => {
+                Display::fmt(count, f)?;
+                f.write_str(" components were provided, but the max is ")?;
+
+                Display::fmt(&Self::COMPONENT_COUNT, f)
+            }
+            ComponentValidationErrorType::ComponentCustomIdLength { chars } => {
+                f.write_str("a component's custom id is ")?;
+                Display::fmt(chars, f)?;
+                f.write_str(" characters long, but the max is ")?;
+
+                Display::fmt(&Self::COMPONENT_CUSTOM_ID_LENGTH, f)
+            }
+            ComponentValidationErrorType::ComponentLabelLength { chars } => {
+                f.write_str("a component's label is ")?;
+                Display::fmt(chars, f)?;
+                f.write_str(" characters long, but the max is ")?;
+
+                Display::fmt(&Self::COMPONENT_LABEL_LENGTH, f)
+            }
+            ComponentValidationErrorType::InvalidChildComponent { kind } => {
+                f.write_str("a '")?;
+                f.write_str(kind.name())?;
+
+                f.write_str("' component was provided, but can not be a child component")
+            }
+            ComponentValidationErrorType::InvalidRootComponent { kind } => {
+                f.write_str("a '")?;
+                f.write_str(kind.name())?;
+
+                f.write_str("' component was provided, but can not be a root component")
+            }
+            ComponentValidationErrorType::ModalActionRowComponentCount { count } => {
+                f.write_str("a modal action row has ")?;
+                Display::fmt(count, f)?;
+
+                f.write_str(" children, but must have exactly 1")
+            }
+            ComponentValidationErrorType::ModalComponentsEmpty => {
+                f.write_str("a modal must have at least 1 component")
+            }
+            ComponentValidationErrorType::ModalTitleLength { chars } => {
+                f.write_str("a modal's title is ")?;
+                Display::fmt(chars, f)?;
+                f.write_str(" characters long, but the max is ")?;
+
+                Display::fmt(&Self::MODAL_TITLE_LENGTH, f)
+            }
+            ComponentValidationErrorType::SelectMaximumValuesCount { count } => {
+                f.write_str("maximum number of values that can be chosen is ")?;
+                Display::fmt(count, f)?;
+                f.write_str(", but must be greater than or equal to 1 and at most ")?;
+
+                Display::fmt(&Self::SELECT_MAXIMUM_VALUES_LIMIT, f)
+            }
+            ComponentValidationErrorType::SelectMinimumValuesCount { count } => {
+                f.write_str("minimum number of values that must be chosen is ")?;
+                Display::fmt(count, f)?;
+                f.write_str(", but must be at most ")?;
+
+                Display::fmt(&Self::SELECT_MINIMUM_VALUES_LIMIT, f)
+            }
+            ComponentValidationErrorType::SelectOptionCount { count } => {
+                f.write_str("a select menu has ")?;
+                Display::fmt(count, f)?;
+                f.write_str(" options, but must have at least 1 and at most ")?;
+
+                Display::fmt(&Self::SELECT_OPTION_COUNT, f)
+            }
+            ComponentValidationErrorType::SelectOptionDescriptionLength { chars } => {
+                f.write_str("a select menu option's description is ")?;
+                Display::fmt(chars, f)?;
+                f.write_str(" characters long, but the max is ")?;
+
+                Display::fmt(&Self::SELECT_OPTION_DESCRIPTION_LENGTH, f)
+            }
+            ComponentValidationErrorType::SelectOptionLabelLength { chars } => {
+                f.write_str("a select menu option's label is ")?;
+                Display::fmt(chars, f)?;
+                f.write_str(" characters long, but the max is ")?;
+
+                Display::fmt(&Self::SELECT_OPTION_LABEL_LENGTH, f)
+            }
+            ComponentValidationErrorType::SelectOptionValueLength { chars } => {
+                f.write_str("a select menu option's value is ")?;
+                Display::fmt(chars, f)?;
+                f.write_str(" characters long, but the max is ")?;
+
+                Display::fmt(&Self::SELECT_OPTION_VALUE_LENGTH, f)
+            }
+            ComponentValidationErrorType::SelectPlaceholderLength { chars } => {
+                f.write_str("a select menu's placeholder is ")?;
+                Display::fmt(chars, f)?;
+                f.write_str(" characters long, but the max is ")?;
+
+                Display::fmt(&Self::SELECT_PLACEHOLDER_LENGTH, f)
+            }
+            ComponentValidationErrorType::SelectValuesConflict {
+                max_values,
+                min_values,
+                options,
+            } => {
+                f.write_str("minimum number of values that must be chosen is ")?;
+                Display::fmt(min_values, f)?;
+                f.write_str(", but the maximum number of values is ")?;
+                Display::fmt(max_values, f)?;
+                f.write_str(" and the select menu has ")?;
+                Display::fmt(options, f)?;
+
+                f.write_str(" options")
+            }
+            ComponentValidationErrorType::TextInputLabelLength { chars } => {
+                f.write_str("a text input's label is ")?;
+                Display::fmt(chars, f)?;
+                f.write_str(" characters long, but the max is ")?;
+
+                Display::fmt(&Self::TEXT_INPUT_LABEL_LENGTH, f)
+            }
+            ComponentValidationErrorType::TextInputLengthConflict {
+                max_length,
+                min_length,
+            } => {
+                f.write_str("a text input's minimum length of ")?;
+                Display::fmt(min_length, f)?;
+                f.write_str(" is larger than its maximum length of ")?;
+
+                Display::fmt(max_length, f)
+            }
+            ComponentValidationErrorType::TextInputMaxLength { len } => {
+                f.write_str("a text input's maximum length is ")?;
+                Display::fmt(len, f)?;
+                f.write_str(", but must be at least 1 and at most ")?;
+
+                Display::fmt(&Self::TEXT_INPUT_LENGTH, f)
+            }
+            ComponentValidationErrorType::TextInputMinLength { len } => {
+                f.write_str("a text input's minimum length is ")?;
+                Display::fmt(len, f)?;
+                f.write_str(", but must be at most ")?;
+
+                Display::fmt(&Self::TEXT_INPUT_LENGTH, f)
+            }
+            ComponentValidationErrorType::TextInputPlaceholderLength { chars } => {
+                f.write_str("a text input's placeholder is ")?;
+                Display::fmt(chars, f)?;
+                f.write_str(" characters long, but the max is ")?;
+
+                Display::fmt(&Self::TEXT_INPUT_PLACEHOLDER_LENGTH, f)
+            }
+            ComponentValidationErrorType::TextInputValueLength { chars } => {
+                f.write_str("a text input's value is ")?;
+                Display::fmt(chars, f)?;
+                f.write_str(" characters long, but the max is ")?;
+
+                Display::fmt(&Self::TEXT_INPUT_LENGTH, f)
+            }
+        }
+    }
+}
+
+impl Error for ComponentValidationError {}
+
+/// Type of [`ComponentValidationError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ComponentValidationErrorType {
+    /// An action row has no components or more than
+    /// [the maximum][`ACTION_ROW_COMPONENT_COUNT`] number of components.
+    ///
+    /// [`ACTION_ROW_COMPONENT_COUNT`]: ComponentValidationError::ACTION_ROW_COMPONENT_COUNT
+    ActionRowComponentCount {
+        /// The number of components that were provided.
+        count: usize,
+    },
+    /// A select menu shares its action row with other components.
+    ActionRowSelectMenuConflict,
+    /// A button has both a custom ID and a URL.
+    ButtonConflict,
+    /// A button is missing the custom ID or URL required by its style.
+    ///
+    /// Link buttons require a URL, while buttons of the other styles require
+    /// a custom ID.
+    ButtonStyle {
+        /// Style of the button.
+        style: ButtonStyle,
+    },
+    /// There are more than [the maximum][`COMPONENT_COUNT`] number of root
+    /// components.
+    ///
+    /// [`COMPONENT_COUNT`]: ComponentValidationError::COMPONENT_COUNT
+    ComponentCount {
+        /// The number of components that were provided.
+        count: usize,
+    },
+    /// A component's custom ID is larger than
+    /// [the maximum][`COMPONENT_CUSTOM_ID_LENGTH`].
+    ///
+    /// [`COMPONENT_CUSTOM_ID_LENGTH`]: ComponentValidationError::COMPONENT_CUSTOM_ID_LENGTH
+    ComponentCustomIdLength {
+        /// The number of codepoints that were provided.
+        chars: usize,
+    },
+    /// A component's label is larger than
+    /// [the maximum][`COMPONENT_LABEL_LENGTH`].
+    ///
+    /// [`COMPONENT_LABEL_LENGTH`]: ComponentValidationError::COMPONENT_LABEL_LENGTH
+    ComponentLabelLength {
+        /// The number of codepoints that were provided.
+        chars: usize,
+    },
+    /// A component can't be contained in an action row, such as an action
+    /// row itself, a text input in a message, or a button in a modal.
+    InvalidChildComponent {
+        /// Type of the provided component.
+        kind: ComponentType,
+    },
+    /// A component other than an action row was provided at the root.
+    InvalidRootComponent {
+        /// Type of the provided component.
+        kind: ComponentType,
+    },
+    /// An action row of a modal doesn't contain exactly one text input.
+    ModalActionRowComponentCount {
+        /// The number of components that were provided.
+        count: usize,
+    },
+    /// A modal has no components.
+    ModalComponentsEmpty,
+    /// A modal's title is larger than [the maximum][`MODAL_TITLE_LENGTH`].
+    ///
+    /// [`MODAL_TITLE_LENGTH`]: ComponentValidationError::MODAL_TITLE_LENGTH
+    ModalTitleLength {
+        /// The number of codepoints that were provided.
+        chars: usize,
+    },
+    /// The maximum number of values that can be chosen in a select menu is
+    /// zero or larger than [the maximum][`SELECT_MAXIMUM_VALUES_LIMIT`].
+    ///
+    /// [`SELECT_MAXIMUM_VALUES_LIMIT`]: ComponentValidationError::SELECT_MAXIMUM_VALUES_LIMIT
+    SelectMaximumValuesCount {
+        /// The number of values that were provided.
+        count: usize,
+    },
+    /// The minimum number of values that must be chosen in a select menu is
+    /// larger than [the maximum][`SELECT_MINIMUM_VALUES_LIMIT`].
+    ///
+    /// [`SELECT_MINIMUM_VALUES_LIMIT`]: ComponentValidationError::SELECT_MINIMUM_VALUES_LIMIT
+    SelectMinimumValuesCount {
+        /// The number of values that were provided.
+        count: usize,
+    },
+    /// A select menu has no options or more than
+    /// [the maximum][`SELECT_OPTION_COUNT`] number of options.
+    ///
+    /// [`SELECT_OPTION_COUNT`]: ComponentValidationError::SELECT_OPTION_COUNT
+    SelectOptionCount {
+        /// The number of options that were provided.
+        count: usize,
+    },
+    /// A select menu option's description is larger than
+    /// [the maximum][`SELECT_OPTION_DESCRIPTION_LENGTH`].
+    ///
+    /// [`SELECT_OPTION_DESCRIPTION_LENGTH`]: ComponentValidationError::SELECT_OPTION_DESCRIPTION_LENGTH
+    SelectOptionDescriptionLength {
+        /// The number of codepoints that were provided.
+        chars: usize,
+    },
+    /// A select menu option's label is larger than
+    /// [the maximum][`SELECT_OPTION_LABEL_LENGTH`].
+    ///
+    /// [`SELECT_OPTION_LABEL_LENGTH`]: ComponentValidationError::SELECT_OPTION_LABEL_LENGTH
+    SelectOptionLabelLength {
+        /// The number of codepoints that were provided.
+        chars: usize,
+    },
+    /// A select menu option's value is larger than
+    /// [the maximum][`SELECT_OPTION_VALUE_LENGTH`].
+    ///
+    /// [`SELECT_OPTION_VALUE_LENGTH`]: ComponentValidationError::SELECT_OPTION_VALUE_LENGTH
+    SelectOptionValueLength {
+        /// The number of codepoints that were provided.
+        chars: usize,
+    },
+    /// A select menu's placeholder is larger than
+    /// [the maximum][`SELECT_PLACEHOLDER_LENGTH`].
+    ///
+    /// [`SELECT_PLACEHOLDER_LENGTH`]: ComponentValidationError::SELECT_PLACEHOLDER_LENGTH
+    SelectPlaceholderLength {
+        /// The number of codepoints that were provided.
+        chars: usize,
+    },
+    /// The minimum number of values that must be chosen in a select menu is
+    /// larger than the maximum number of values that can be chosen or the
+    /// number of options.
+    ///
+    /// Discord defaults both numbers to 1 when they aren't provided.
+    SelectValuesConflict {
+        /// The maximum number of values that can be chosen.
+        max_values: usize,
+        /// The minimum number of values that must be chosen.
+        min_values: usize,
+        /// The number of options of the select menu.
+        options: usize,
+    },
+    /// A text input's label is larger than
+    /// [the maximum][`TEXT_INPUT_LABEL_LENGTH`].
+    ///
+    /// [`TEXT_INPUT_LABEL_LENGTH`]: ComponentValidationError::TEXT_INPUT_LABEL_LENGTH
+    TextInputLabelLength {
+        /// The number of codepoints that were provided.
+        chars: usize,
+    },
+    /// A text input's minimum length is larger than its maximum length.
+    TextInputLengthConflict {
+        /// The maximum length that was provided.
+        max_length: usize,
+        /// The minimum length that was provided.
+        min_length: usize,
+    },
+    /// A text input's maximum length is zero or larger than
+    /// [the maximum][`TEXT_INPUT_LENGTH`].
+    ///
+    /// [`TEXT_INPUT_LENGTH`]: ComponentValidationError::TEXT_INPUT_LENGTH
+    TextInputMaxLength {
+        /// The length that was provided.
+        len: usize,
+    },
+    /// A text input's minimum length is larger than
+    /// [the maximum][`TEXT_INPUT_LENGTH`].
+    ///
+    /// [`TEXT_INPUT_LENGTH`]: ComponentValidationError::TEXT_INPUT_LENGTH
+    TextInputMinLength {
+        /// The length that was provided.
+        len: usize,
+    },
+    /// A text input's placeholder is larger than
+    /// [the maximum][`TEXT_INPUT_PLACEHOLDER_LENGTH`].
+    ///
+    /// [`TEXT_INPUT_PLACEHOLDER_LENGTH`]: ComponentValidationError::TEXT_INPUT_PLACEHOLDER_LENGTH
+    TextInputPlaceholderLength {
+        /// The number of codepoints that were provided.
+        chars: usize,
+    },
+    /// A text input's pre-filled value is larger than
+    /// [the maximum][`TEXT_INPUT_LENGTH`].
+    ///
+    /// [`TEXT_INPUT_LENGTH`]: ComponentValidationError::TEXT_INPUT_LENGTH
+    TextInputValueLength {
+        /// The number of codepoints that were provided.
+        chars: usize,
+    },
+}
 
 /// An embed is not valid.
 ///
@@ -217,6 +689,334 @@ fn _channel_name(value: &str) -> bool {
     (1..=100).contains(&len)
 }
 
+/// Validate the root components of a message or interaction response.
+///
+/// Refer to [`ComponentValidationErrorType`] for the limits that are checked.
+pub fn components(components: &[Component]) -> Result<(), ComponentValidationError> {
+    if components.len() > ComponentValidationError::COMPONENT_COUNT {
+        return Err(ComponentValidationError {
+            kind: ComponentValidationErrorType::ComponentCount {
+                count: components.len(),
+            },
+        });
+    }
+
+    for component in components {
+        match component {
+            Component::ActionRow(action_row) => action_row_components(&action_row.components)?,
+            other => {
+                return Err(ComponentValidationError {
+                    kind: ComponentValidationErrorType::InvalidRootComponent { kind: other.kind() },
+                })
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn action_row_components(components: &[Component]) -> Result<(), ComponentValidationError> {
+    if !(1..=ComponentValidationError::ACTION_ROW_COMPONENT_COUNT).contains(&components.len()) {
+        return Err(ComponentValidationError {
+            kind: ComponentValidationErrorType::ActionRowComponentCount {
+                count: components.len(),
+            },
+        });
+    }
+
+    if components.len() > 1
+        && components
+            .iter()
+            .any(|component| matches!(component, Component::SelectMenu(_)))
+    {
+        return Err(ComponentValidationError {
+            kind: ComponentValidationErrorType::ActionRowSelectMenuConflict,
+        });
+    }
+
+    for component in components {
+        match component {
+            Component::ActionRow(_) => {
+                return Err(ComponentValidationError {
+                    kind: ComponentValidationErrorType::InvalidChildComponent {
+                        kind: ComponentType::ActionRow,
+                    },
+                })
+            }
+            Component::Button(button) => self::button(button)?,
+            Component::SelectMenu(select_menu) => self::select_menu(select_menu)?,
+            Component::TextInput(_) => {
+                return Err(ComponentValidationError {
+                    kind: ComponentValidationErrorType::InvalidChildComponent {
+                        kind: ComponentType::TextInput,
+                    },
+                })
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn button(button: &Button) -> Result<(), ComponentValidationError> {
+    let has_custom_id = button.custom_id.is_some();
+    let has_url = button.url.is_some();
+
+    if has_custom_id && has_url {
+        return Err(ComponentValidationError {
+            kind: ComponentValidationErrorType::ButtonConflict,
+        });
+    }
+
+    let is_link = button.style == ButtonStyle::Link;
+
+    if (is_link && !has_url) || (!is_link && !has_custom_id) {
+        return Err(ComponentValidationError {
+            kind: ComponentValidationErrorType::ButtonStyle {
+                style: button.style,
+            },
+        });
+    }
+
+    if let Some(custom_id) = button.custom_id.as_ref() {
+        component_custom_id(custom_id)?;
+    }
+
+    if let Some(label) = button.label.as_ref() {
+        let chars = label.chars().count();
+
+        if chars > ComponentValidationError::COMPONENT_LABEL_LENGTH {
+            return Err(ComponentValidationError {
+                kind: ComponentValidationErrorType::ComponentLabelLength { chars },
+            });
+        }
+    }
+
+    Ok(())
+}
+
+fn select_menu(select_menu: &SelectMenu) -> Result<(), ComponentValidationError> {
+    component_custom_id(&select_menu.custom_id)?;
+
+    if let Some(max_values) = select_menu.max_values {
+        let count = usize::from(max_values);
+
+        if !(1..=ComponentValidationError::SELECT_MAXIMUM_VALUES_LIMIT).contains(&count) {
+            return Err(ComponentValidationError {
+                kind: ComponentValidationErrorType::SelectMaximumValuesCount { count },
+            });
+        }
+    }
+
+    if let Some(min_values) = select_menu.min_values {
+        let count = usize::from(min_values);
+
+        if count > ComponentValidationError::SELECT_MINIMUM_VALUES_LIMIT {
+            return Err(ComponentValidationError {
+                kind: ComponentValidationErrorType::SelectMinimumValuesCount { count },
+            });
+        }
+    }
+
+    let count = select_menu.options.len();
+
+    if !(1..=ComponentValidationError::SELECT_OPTION_COUNT).contains(&count) {
+        return Err(ComponentValidationError {
+            kind: ComponentValidationErrorType::SelectOptionCount { count },
+        });
+    }
+
+    let max_values = select_menu.max_values.map_or(1, usize::from);
+    let min_values = select_menu.min_values.map_or(1, usize::from);
+
+    if min_values > max_values || min_values > count {
+        return Err(ComponentValidationError {
+            kind: ComponentValidationErrorType::SelectValuesConflict {
+                max_values,
+                min_values,
+                options: count,
+            },
+        });
+    }
+
+    for option in &select_menu.options {
+        if let Some(description) = option.description.as_ref() {
+            let chars = description.chars().count();
+
+            if chars > ComponentValidationError::SELECT_OPTION_DESCRIPTION_LENGTH {
+                return Err(ComponentValidationError {
+                    kind: ComponentValidationErrorType::SelectOptionDescriptionLength { chars },
+                });
+            }
+        }
+
+        let chars = option.label.chars().count();
+
+        if chars > ComponentValidationError::SELECT_OPTION_LABEL_LENGTH {
+            return Err(ComponentValidationError {
+                kind: ComponentValidationErrorType::SelectOptionLabelLength { chars },
+            });
+        }
+
+        let chars = option.value.chars().count();
+
+        if chars > ComponentValidationError::SELECT_OPTION_VALUE_LENGTH {
+            return Err(ComponentValidationError {
+                kind: ComponentValidationErrorType::SelectOptionValueLength { chars },
+            });
+        }
+    }
+
+    if let Some(placeholder) = select_menu.placeholder.as_ref() {
+        let chars = placeholder.chars().count();
+
+        if chars > ComponentValidationError::SELECT_PLACEHOLDER_LENGTH {
+            return Err(ComponentValidationError {
+                kind: ComponentValidationErrorType::SelectPlaceholderLength { chars },
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Validate a modal sent in response to an interaction.
+///
+/// Refer to [`ComponentValidationErrorType`] for the limits that are checked.
+pub fn modal(modal: &Modal) -> Result<(), ComponentValidationError> {
+    component_custom_id(&modal.custom_id)?;
+
+    let chars = modal.title.chars().count();
+
+    if chars > ComponentValidationError::MODAL_TITLE_LENGTH {
+        return Err(ComponentValidationError {
+            kind: ComponentValidationErrorType::ModalTitleLength { chars },
+        });
+    }
+
+    if modal.components.is_empty() {
+        return Err(ComponentValidationError {
+            kind: ComponentValidationErrorType::ModalComponentsEmpty,
+        });
+    }
+
+    if modal.components.len() > ComponentValidationError::COMPONENT_COUNT {
+        return Err(ComponentValidationError {
+            kind: ComponentValidationErrorType::ComponentCount {
+                count: modal.components.len(),
+            },
+        });
+    }
+
+    for component in &modal.components {
+        let action_row = match component {
+            Component::ActionRow(action_row) => action_row,
+            other => {
+                return Err(ComponentValidationError {
+                    kind: ComponentValidationErrorType::InvalidRootComponent { kind: other.kind() },
+                })
+            }
+        };
+
+        match action_row.components.as_slice() {
+            [Component::TextInput(text_input)] => self::text_input(text_input)?,
+            [other] => {
+                return Err(ComponentValidationError {
+                    kind: ComponentValidationErrorType::InvalidChildComponent {
+                        kind: other.kind(),
+                    },
+                })
+            }
+            components => {
+                return Err(ComponentValidationError {
+                    kind: ComponentValidationErrorType::ModalActionRowComponentCount {
+                        count: components.len(),
+                    },
+                })
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn text_input(text_input: &TextInput) -> Result<(), ComponentValidationError> {
+    component_custom_id(&text_input.custom_id)?;
+
+    let chars = text_input.label.chars().count();
+
+    if chars > ComponentValidationError::TEXT_INPUT_LABEL_LENGTH {
+        return Err(ComponentValidationError {
+            kind: ComponentValidationErrorType::TextInputLabelLength { chars },
+        });
+    }
+
+    if let Some(max_length) = text_input.max_length {
+        let len = usize::from(max_length);
+
+        if !(1..=ComponentValidationError::TEXT_INPUT_LENGTH).contains(&len) {
+            return Err(ComponentValidationError {
+                kind: ComponentValidationErrorType::TextInputMaxLength { len },
+            });
+        }
+    }
+
+    if let Some(min_length) = text_input.min_length {
+        let len = usize::from(min_length);
+
+        if len > ComponentValidationError::TEXT_INPUT_LENGTH {
+            return Err(ComponentValidationError {
+                kind: ComponentValidationErrorType::TextInputMinLength { len },
+            });
+        }
+    }
+
+    if let (Some(max_length), Some(min_length)) = (text_input.max_length, text_input.min_length) {
+        if min_length > max_length {
+            return Err(ComponentValidationError {
+                kind: ComponentValidationErrorType::TextInputLengthConflict {
+                    max_length: usize::from(max_length),
+                    min_length: usize::from(min_length),
+                },
+            });
+        }
+    }
+
+    if let Some(placeholder) = text_input.placeholder.as_ref() {
+        let chars = placeholder.chars().count();
+
+        if chars > ComponentValidationError::TEXT_INPUT_PLACEHOLDER_LENGTH {
+            return Err(ComponentValidationError {
+                kind: ComponentValidationErrorType::TextInputPlaceholderLength { chars },
+            });
+        }
+    }
+
+    if let Some(value) = text_input.value.as_ref() {
+        let chars = value.chars().count();
+
+        if chars > ComponentValidationError::TEXT_INPUT_LENGTH {
+            return Err(ComponentValidationError {
+                kind: ComponentValidationErrorType::TextInputValueLength { chars },
+            });
+        }
+    }
+
+    Ok(())
+}
+
+fn component_custom_id(custom_id: &str) -> Result<(), ComponentValidationError> {
+    let chars = custom_id.chars().count();
+
+    if chars > ComponentValidationError::COMPONENT_CUSTOM_ID_LENGTH {
+        return Err(ComponentValidationError {
+            kind: ComponentValidationErrorType::ComponentCustomIdLength { chars },
+        });
+    }
+
+    Ok(())
+}
+
 pub fn content_limit(value: impl AsRef<str>) -> bool {
     _content_limit(value.as_ref())
 }
@@ -333,6 +1133,11 @@ pub const fn get_current_user_guilds_limit(value: u64) -> bool {
     value >= 1 && value <= 100
 }
 
+pub const fn get_guild_bans_limit(value: u64) -> bool {
+    // <https://discord.com/developers/docs/resources/guild#get-guild-bans-query-string-params>
+    value >= 1 && value <= 1000
+}
+
 pub const fn get_guild_members_limit(value: u64) -> bool {
     // <https://discordapp.com/developers/docs/resources/guild#list-guild-members-query-string-params>
     value >= 1 && value <= 1000
@@ -440,6 +1245,21 @@ fn _command_name(value: &str) -> bool {
     (3..=32).contains(&len)
 }
 
+/// Whether the name of a context menu command is valid.
+///
+/// Unlike names of chat input commands, it may contain spaces and uppercase
+/// characters.
+pub fn context_menu_command_name(value: impl AsRef<str>) -> bool {
+    _context_menu_command_name(value.as_ref())
+}
+
+fn _context_menu_command_name(value: &str) -> bool {
+    let len = value.chars().count();
+
+    // https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-naming
+    (1..=32).contains(&len)
+}
+
 pub fn command_description(value: impl AsRef<str>) -> bool {
     _command_description(value.as_ref())
 }
@@ -451,6 +1271,31 @@ fn _command_description(value: &str) -> bool {
     (1..=100).contains(&len)
 }
 
+pub fn autocomplete_choices(len: usize) -> bool {
+    // https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object-autocomplete
+    (0..=25).contains(&len)
+}
+
+/// Whether a command option and its nested options are valid.
+///
+/// Autocompleted options can't have predetermined choices.
+pub fn command_option(option: &CommandOption) -> bool {
+    match option {
+        CommandOption::SubCommand(data) | CommandOption::SubCommandGroup(data) => {
+            data.options.iter().all(command_option)
+        }
+        CommandOption::String(data) | CommandOption::Integer(data) => {
+            // https://discord.com/developers/docs/interactions/application-commands#application-command-object-application-command-option-structure
+            !data.autocomplete || data.choices.is_empty()
+        }
+        CommandOption::Boolean(_)
+        | CommandOption::User(_)
+        | CommandOption::Channel(_)
+        | CommandOption::Role(_)
+        | CommandOption::Mentionable(_) => true,
+    }
+}
+
 pub fn command_permissions(len: usize) -> bool {
     // https://discord.com/developers/docs/interactions/slash-commands#edit-application-command-permissions
     (0..=10).contains(&len)
@@ -459,7 +1304,13 @@ pub fn command_permissions(len: usize) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use twilight_model::channel::embed::{EmbedAuthor, EmbedField, EmbedFooter};
+    use twilight_model::{
+        application::{
+            command::{ChoiceCommandOptionData, CommandOptionChoice, OptionsCommandOptionData},
+            component::{ActionRow, SelectMenuOption, TextInputStyle},
+        },
+        channel::embed::{EmbedAuthor, EmbedField, EmbedFooter},
+    };
 
     fn base_embed() -> Embed {
         Embed {
@@ -497,6 +1348,365 @@ mod tests {
         assert!(!channel_name("a".repeat(101)));
     }
 
+    fn base_button() -> Button {
+        Button {
+            custom_id: Some("button".to_owned()),
+            disabled: false,
+            emoji: None,
+            label: Some("click".to_owned()),
+            style: ButtonStyle::Primary,
+            url: None,
+        }
+    }
+
+    fn base_select_menu() -> SelectMenu {
+        SelectMenu {
+            custom_id: "menu".to_owned(),
+            disabled: false,
+            max_values: None,
+            min_values: None,
+            options: vec![SelectMenuOption {
+                default: false,
+                description: None,
+                emoji: None,
+                label: "option".to_owned(),
+                value: "1".to_owned(),
+            }],
+            placeholder: None,
+        }
+    }
+
+    fn base_text_input() -> TextInput {
+        TextInput {
+            custom_id: "input".to_owned(),
+            label: "name".to_owned(),
+            max_length: None,
+            min_length: None,
+            placeholder: None,
+            required: None,
+            style: TextInputStyle::Short,
+            value: None,
+        }
+    }
+
+    fn base_modal() -> Modal {
+        Modal {
+            components: vec![row(vec![Component::TextInput(base_text_input())])],
+            custom_id: "modal".to_owned(),
+            title: "sign up".to_owned(),
+        }
+    }
+
+    fn row(components: Vec<Component>) -> Component {
+        Component::ActionRow(ActionRow { components })
+    }
+
+    #[test]
+    fn test_command_option() {
+        let choices = vec![CommandOptionChoice::String {
+            name: "rust".to_owned(),
+            value: "rust".to_owned(),
+        }];
+        let option = |autocomplete, choices| {
+            CommandOption::String(ChoiceCommandOptionData {
+                autocomplete,
+                choices,
+                description: "language".to_owned(),
+                name: "language".to_owned(),
+                required: false,
+            })
+        };
+        let subcommand = |option| {
+            CommandOption::SubCommand(OptionsCommandOptionData {
+                description: "search".to_owned(),
+                name: "search".to_owned(),
+                options: vec![option],
+                required: false,
+            })
+        };
+
+        assert!(command_option(&option(false, choices.clone())));
+        assert!(command_option(&option(true, Vec::new())));
+        assert!(command_option(&subcommand(option(true, Vec::new()))));
+
+        assert!(!command_option(&option(true, choices.clone())));
+        assert!(!command_option(&subcommand(option(true, choices))));
+    }
+
+    #[test]
+    fn test_context_menu_command_name() {
+        assert!(context_menu_command_name("a"));
+        assert!(context_menu_command_name("Report Message"));
+        assert!(context_menu_command_name("a".repeat(32)));
+
+        assert!(!context_menu_command_name(""));
+        assert!(!context_menu_command_name("a".repeat(33)));
+    }
+
+    #[test]
+    fn test_components() {
+        assert!(components(&[
+            row(vec![Component::Button(base_button())]),
+            row(vec![Component::SelectMenu(base_select_menu())]),
+        ])
+        .is_ok());
+
+        assert!(matches!(
+            components(&vec![row(vec![Component::Button(base_button())]); 6])
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::ComponentCount { count: 6 }
+        ));
+        assert!(matches!(
+            components(&[Component::Button(base_button())])
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::InvalidRootComponent {
+                kind: ComponentType::Button
+            }
+        ));
+        assert!(matches!(
+            components(&[row(vec![row(vec![Component::Button(base_button())])])])
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::InvalidChildComponent {
+                kind: ComponentType::ActionRow
+            }
+        ));
+        assert!(matches!(
+            components(&[row(Vec::new())]).unwrap_err().kind(),
+            ComponentValidationErrorType::ActionRowComponentCount { count: 0 }
+        ));
+        assert!(matches!(
+            components(&[row(vec![
+                Component::Button(base_button()),
+                Component::SelectMenu(base_select_menu()),
+            ])])
+            .unwrap_err()
+            .kind(),
+            ComponentValidationErrorType::ActionRowSelectMenuConflict
+        ));
+        assert!(matches!(
+            components(&[row(vec![Component::Button(base_button()); 6])])
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::ActionRowComponentCount { count: 6 }
+        ));
+    }
+
+    #[test]
+    fn test_components_button() {
+        let mut link = base_button();
+        link.style = ButtonStyle::Link;
+        assert!(matches!(
+            button(&link).unwrap_err().kind(),
+            ComponentValidationErrorType::ButtonStyle {
+                style: ButtonStyle::Link
+            }
+        ));
+
+        link.url = Some("https://twilight.rs".to_owned());
+        assert!(matches!(
+            button(&link).unwrap_err().kind(),
+            ComponentValidationErrorType::ButtonConflict
+        ));
+
+        link.custom_id = None;
+        assert!(button(&link).is_ok());
+
+        let mut label = base_button();
+        label.label = Some("a".repeat(81));
+        assert!(matches!(
+            button(&label).unwrap_err().kind(),
+            ComponentValidationErrorType::ComponentLabelLength { chars: 81 }
+        ));
+
+        let mut custom_id = base_button();
+        custom_id.custom_id = Some("a".repeat(101));
+        assert!(matches!(
+            button(&custom_id).unwrap_err().kind(),
+            ComponentValidationErrorType::ComponentCustomIdLength { chars: 101 }
+        ));
+    }
+
+    #[test]
+    fn test_components_select_menu() {
+        let mut max_values = base_select_menu();
+        max_values.max_values = Some(0);
+        assert!(matches!(
+            select_menu(&max_values).unwrap_err().kind(),
+            ComponentValidationErrorType::SelectMaximumValuesCount { count: 0 }
+        ));
+
+        let mut min_values = base_select_menu();
+        min_values.min_values = Some(26);
+        assert!(matches!(
+            select_menu(&min_values).unwrap_err().kind(),
+            ComponentValidationErrorType::SelectMinimumValuesCount { count: 26 }
+        ));
+
+        let mut options = base_select_menu();
+        options.options.clear();
+        assert!(matches!(
+            select_menu(&options).unwrap_err().kind(),
+            ComponentValidationErrorType::SelectOptionCount { count: 0 }
+        ));
+
+        let mut description = base_select_menu();
+        description.options[0].description = Some("a".repeat(101));
+        assert!(matches!(
+            select_menu(&description).unwrap_err().kind(),
+            ComponentValidationErrorType::SelectOptionDescriptionLength { chars: 101 }
+        ));
+
+        let mut placeholder = base_select_menu();
+        placeholder.placeholder = Some("a".repeat(101));
+        assert!(matches!(
+            select_menu(&placeholder).unwrap_err().kind(),
+            ComponentValidationErrorType::SelectPlaceholderLength { chars: 101 }
+        ));
+
+        let mut values = base_select_menu();
+        values.options = vec![values.options[0].clone(); 3];
+        values.max_values = Some(3);
+        values.min_values = Some(2);
+        assert!(select_menu(&values).is_ok());
+
+        values.max_values = Some(1);
+        assert!(matches!(
+            select_menu(&values).unwrap_err().kind(),
+            ComponentValidationErrorType::SelectValuesConflict {
+                max_values: 1,
+                min_values: 2,
+                options: 3,
+            }
+        ));
+
+        values.max_values = None;
+        assert!(matches!(
+            select_menu(&values).unwrap_err().kind(),
+            ComponentValidationErrorType::SelectValuesConflict {
+                max_values: 1,
+                min_values: 2,
+                options: 3,
+            }
+        ));
+
+        values.max_values = Some(5);
+        values.min_values = Some(4);
+        assert!(matches!(
+            select_menu(&values).unwrap_err().kind(),
+            ComponentValidationErrorType::SelectValuesConflict {
+                max_values: 5,
+                min_values: 4,
+                options: 3,
+            }
+        ));
+    }
+
+    #[test]
+    fn test_modal() {
+        assert!(modal(&base_modal()).is_ok());
+
+        let mut title = base_modal();
+        title.title = "a".repeat(46);
+        assert!(matches!(
+            modal(&title).unwrap_err().kind(),
+            ComponentValidationErrorType::ModalTitleLength { chars: 46 }
+        ));
+
+        let mut empty = base_modal();
+        empty.components = Vec::new();
+        assert!(matches!(
+            modal(&empty).unwrap_err().kind(),
+            ComponentValidationErrorType::ModalComponentsEmpty
+        ));
+
+        let mut count = base_modal();
+        count.components = vec![count.components[0].clone(); 6];
+        assert!(matches!(
+            modal(&count).unwrap_err().kind(),
+            ComponentValidationErrorType::ComponentCount { count: 6 }
+        ));
+
+        let mut button = base_modal();
+        button.components = vec![row(vec![Component::Button(base_button())])];
+        assert!(matches!(
+            modal(&button).unwrap_err().kind(),
+            ComponentValidationErrorType::InvalidChildComponent {
+                kind: ComponentType::Button
+            }
+        ));
+
+        let mut inputs = base_modal();
+        inputs.components = vec![row(vec![Component::TextInput(base_text_input()); 2])];
+        assert!(matches!(
+            modal(&inputs).unwrap_err().kind(),
+            ComponentValidationErrorType::ModalActionRowComponentCount { count: 2 }
+        ));
+
+        assert!(matches!(
+            components(&[row(vec![Component::TextInput(base_text_input())])])
+                .unwrap_err()
+                .kind(),
+            ComponentValidationErrorType::InvalidChildComponent {
+                kind: ComponentType::TextInput
+            }
+        ));
+    }
+
+    #[test]
+    fn test_modal_text_input() {
+        let mut label = base_text_input();
+        label.label = "a".repeat(46);
+        assert!(matches!(
+            text_input(&label).unwrap_err().kind(),
+            ComponentValidationErrorType::TextInputLabelLength { chars: 46 }
+        ));
+
+        let mut max_length = base_text_input();
+        max_length.max_length = Some(0);
+        assert!(matches!(
+            text_input(&max_length).unwrap_err().kind(),
+            ComponentValidationErrorType::TextInputMaxLength { len: 0 }
+        ));
+
+        let mut min_length = base_text_input();
+        min_length.min_length = Some(4001);
+        assert!(matches!(
+            text_input(&min_length).unwrap_err().kind(),
+            ComponentValidationErrorType::TextInputMinLength { len: 4001 }
+        ));
+
+        let mut conflict = base_text_input();
+        conflict.max_length = Some(10);
+        conflict.min_length = Some(11);
+        assert!(matches!(
+            text_input(&conflict).unwrap_err().kind(),
+            ComponentValidationErrorType::TextInputLengthConflict {
+                max_length: 10,
+                min_length: 11
+            }
+        ));
+
+        conflict.min_length = Some(10);
+        assert!(text_input(&conflict).is_ok());
+
+        let mut placeholder = base_text_input();
+        placeholder.placeholder = Some("a".repeat(101));
+        assert!(matches!(
+            text_input(&placeholder).unwrap_err().kind(),
+            ComponentValidationErrorType::TextInputPlaceholderLength { chars: 101 }
+        ));
+
+        let mut value = base_text_input();
+        value.value = Some("a".repeat(4001));
+        assert!(matches!(
+            text_input(&value).unwrap_err().kind(),
+            ComponentValidationErrorType::TextInputValueLength { chars: 4001 }
+        ));
+    }
+
     #[test]
     fn test_content_limit() {
         assert!(content_limit(""));
@@ -722,6 +1932,15 @@ mod tests {
         assert!(!get_current_user_guilds_limit(101));
     }
 
+    #[test]
+    fn test_get_guild_bans_limit() {
+        assert!(get_guild_bans_limit(1));
+        assert!(get_guild_bans_limit(1000));
+
+        assert!(!get_guild_bans_limit(0));
+        assert!(!get_guild_bans_limit(1001));
+    }
+
     #[test]
     fn test_get_guild_members_limit() {
         assert!(get_guild_members_limit(1));
@@ -0,0 +1,262 @@
+//! Responses to requests, providing access to the status, headers and
+//! ratelimit information alongside the deserialized model.
+//!
+//! Request builders resolve directly to their model when awaited. Call their
+//! `response` method to instead resolve to a [`Response`], which reads the
+//! body only when one of [`Response::bytes`], [`Response::model`] or
+//! [`Response::text`] is called.
+//!
+//! # Examples
+//!
+//! Log the remaining number of requests in the bucket of a request before
+//! deserializing the model:
+//!
+//! ```rust,no_run
+//! use twilight_http::{ratelimiting::RatelimitHeaders, Client};
+//! use twilight_model::id::ChannelId;
+//!
+//! # #[tokio::main]
+//! # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+//! let client = Client::new("my token");
+//!
+//! let response = client.channel(ChannelId(1)).response().await?;
+//!
+//! if let Some(RatelimitHeaders::Present { remaining, .. }) = response.ratelimit() {
+//!     println!("{} requests remaining", remaining);
+//! }
+//!
+//! let channel = response.model().await?;
+//! # Ok(()) }
+//! ```
+
+use crate::{
+    client::Client,
+    error::{Error, ErrorType},
+    ratelimiting::RatelimitHeaders,
+    request::Request,
+};
+use hyper::{
+    body::{self, Bytes},
+    header::HeaderMap,
+    Body, Response as HyperResponse, StatusCode,
+};
+use serde::de::DeserializeOwned;
+use std::{
+    convert::TryFrom,
+    future::Future,
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Response to a request, with a body deserializing to `T`.
+///
+/// The body isn't read until it is requested via [`bytes`], [`model`] or
+/// [`text`], each of which consumes the response.
+///
+/// [`bytes`]: Self::bytes
+/// [`model`]: Self::model
+/// [`text`]: Self::text
+#[derive(Debug)]
+pub struct Response<T> {
+    inner: HyperResponse<Body>,
+    phantom: PhantomData<fn() -> T>,
+}
+
+impl<T> Response<T> {
+    pub(crate) const fn new(inner: HyperResponse<Body>) -> Self {
+        Self {
+            inner,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Status code of the response.
+    pub fn status(&self) -> StatusCode {
+        self.inner.status()
+    }
+
+    /// Headers of the response.
+    pub fn headers(&self) -> &HeaderMap {
+        self.inner.headers()
+    }
+
+    /// Parse the ratelimit headers of the response, containing the state of
+    /// the ratelimit bucket of the request.
+    ///
+    /// Returns `None` if the headers are malformed.
+    pub fn ratelimit(&self) -> Option<RatelimitHeaders> {
+        RatelimitHeaders::try_from(self.inner.headers()).ok()
+    }
+
+    /// Consume the response, returning the underlying hyper response.
+    pub fn into_inner(self) -> HyperResponse<Body> {
+        self.inner
+    }
+
+    /// Read the body of the response.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ErrorType::ChunkingResponse`] error type if the body
+    /// couldn't be read.
+    pub async fn bytes(self) -> Result<Bytes, Error> {
+        body::to_bytes(self.inner.into_body())
+            .await
+            .map_err(|source| Error {
+                kind: ErrorType::ChunkingResponse,
+                source: Some(Box::new(source)),
+            })
+    }
+
+    /// Read the body of the response as UTF-8 text.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ErrorType::ChunkingResponse`] error type if the body
+    /// couldn't be read.
+    ///
+    /// Returns an [`ErrorType::Parsing`] error type if the body isn't valid
+    /// UTF-8.
+    pub async fn text(self) -> Result<String, Error> {
+        let bytes = self.bytes().await?;
+
+        String::from_utf8(bytes.to_vec()).map_err(|source| Error {
+            kind: ErrorType::Parsing {
+                body: source.as_bytes().to_vec(),
+            },
+            source: Some(Box::new(source)),
+        })
+    }
+}
+
+impl<T: DeserializeOwned> Response<T> {
+    /// Read and deserialize the body of the response into the model.
+    ///
+    /// An empty body is deserialized as `null`, so requests without a
+    /// response body can deserialize to `()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ErrorType::ChunkingResponse`] error type if the body
+    /// couldn't be read.
+    ///
+    /// Returns an [`ErrorType::Parsing`] error type if the body couldn't be
+    /// deserialized.
+    pub async fn model(self) -> Result<T, Error> {
+        let bytes = self.bytes().await?;
+
+        if bytes.is_empty() {
+            return crate::json::parse_bytes(&Bytes::from_static(b"null"));
+        }
+
+        crate::json::parse_bytes(&bytes)
+    }
+}
+
+/// Future resolving to the [`Response`] of a request.
+///
+/// Returned by the `response` method of request builders.
+pub struct ResponseFuture<'a, T> {
+    inner: Pin<Box<dyn Future<Output = Result<Response<T>, Error>> + Send + 'a>>,
+}
+
+impl<'a, T: 'a> ResponseFuture<'a, T> {
+    pub(crate) fn new(http: &'a Client, request: Result<Request, Error>) -> Self {
+        Self {
+            inner: Box::pin(async move { http.response(request?).await }),
+        }
+    }
+}
+
+impl<T> Future for ResponseFuture<'_, T> {
+    type Output = Result<Response<T>, Error>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.inner.as_mut().poll(cx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Response, ResponseFuture};
+    use crate::{error::ErrorType, ratelimiting::RatelimitHeaders, test_server, Client};
+    use hyper::{Body, Response as HyperResponse, StatusCode};
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug, future::Future};
+    use twilight_model::{channel::Message, id::ChannelId};
+
+    assert_impl_all!(Response<Message>: Debug, Send, Sync);
+    assert_impl_all!(ResponseFuture<'_, Message>: Future, Send);
+
+    fn response<T>(body: impl Into<Body>) -> Response<T> {
+        Response::new(
+            HyperResponse::builder()
+                .status(StatusCode::OK)
+                .header("x-ratelimit-bucket", "abcd")
+                .header("x-ratelimit-limit", "5")
+                .header("x-ratelimit-remaining", "4")
+                .header("x-ratelimit-reset", "1625000000.5")
+                .header("x-ratelimit-reset-after", "1.5")
+                .body(body.into())
+                .unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_model() -> Result<(), Box<dyn Error>> {
+        let response = response::<Vec<u64>>("[1, 2]");
+        assert_eq!(StatusCode::OK, response.status());
+        assert_eq!("abcd", response.headers()["x-ratelimit-bucket"]);
+        assert!(matches!(
+            response.ratelimit(),
+            Some(RatelimitHeaders::Present {
+                limit: 5,
+                remaining: 4,
+                ..
+            })
+        ));
+
+        assert_eq!(vec![1, 2], response.model().await?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_request_response() -> Result<(), Box<dyn Error>> {
+        let addr = test_server::start(|_| async {
+            HyperResponse::builder()
+                .header("x-ratelimit-bucket", "abcd")
+                .body(Body::from("[]"))
+        });
+
+        let client = Client::builder().proxy(addr.to_string(), true).build();
+        let response = client.pins(ChannelId(1)).response().await?;
+
+        assert_eq!(StatusCode::OK, response.status());
+        assert_eq!("abcd", response.headers()["x-ratelimit-bucket"]);
+        assert!(response.model().await?.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_model_empty() -> Result<(), Box<dyn Error>> {
+        response::<()>("").model().await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_text() -> Result<(), Box<dyn Error>> {
+        assert_eq!("[1, 2]", response::<()>("[1, 2]").text().await?);
+
+        let result = response::<()>(vec![0xff, 0xfe]).text().await;
+        assert!(matches!(
+            result.map_err(crate::Error::into_parts),
+            Err((ErrorType::Parsing { .. }, Some(_)))
+        ));
+
+        Ok(())
+    }
+}
@@ -3,7 +3,9 @@ mod route;
 mod route_display;
 
 pub use self::{
-    path::{Path, PathParseError, PathParseErrorType},
+    path::{api_relative_path, Path, PathParseError, PathParseErrorType},
     route::Route,
     route_display::RouteDisplay,
 };
+
+pub(crate) use self::path::redact_token;
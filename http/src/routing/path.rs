@@ -1,5 +1,6 @@
 use crate::request::Method;
 use std::{
+    borrow::Cow,
     convert::TryFrom,
     error::Error,
     fmt::{Display, Formatter, Result as FmtResult},
@@ -94,6 +95,8 @@ pub enum Path {
     ChannelsIdMessagesId(Method, u64),
     /// Crossposting an individual channel's message.
     ChannelsIdMessagesIdCrosspost(u64),
+    /// Operating on the threads started from a channel's message.
+    ChannelsIdMessagesIdThreads(u64),
     /// Operating on an individual channel's message's reactions.
     ChannelsIdMessagesIdReactions(u64),
     /// Operating on an individual channel's message's reactions while
@@ -107,8 +110,17 @@ pub enum Path {
     ChannelsIdPinsMessageId(u64),
     /// Operating on a group DM's recipients.
     ChannelsIdRecipients(u64),
+    /// Operating on the members of a thread.
+    ChannelsIdThreadMembers(u64),
+    /// Operating on an individual member of a thread.
+    ChannelsIdThreadMembersId(u64),
+    /// Operating on a channel's threads.
+    ChannelsIdThreads(u64),
     /// Operating on a channel's typing indicator.
     ChannelsIdTyping(u64),
+    /// Operating on the threads of a channel that the current user has
+    /// joined.
+    ChannelsIdUsersIdThreads(u64),
     /// Operating on a channel's webhooks.
     ChannelsIdWebhooks(u64),
     /// Operating on a channel's followers.
@@ -145,6 +157,8 @@ pub enum Path {
     GuildsIdRolesId(u64),
     GuildsIdTemplates(u64),
     GuildsIdTemplatesCode(u64),
+    /// Operating on a guild's threads.
+    GuildsIdThreads(u64),
     GuildsIdVanityUrl(u64),
     GuildsIdVoiceStates(u64),
     GuildsIdWelcomeScreen(u64),
@@ -155,6 +169,12 @@ pub enum Path {
     StageInstances,
     UsersId,
     OauthApplicationsMe,
+    /// Operating on the authorization of an `OAuth2` access token.
+    OauthMe,
+    /// Operating on `OAuth2` access tokens.
+    OauthToken,
+    /// Operating on the revocation of `OAuth2` access tokens.
+    OauthTokenRevoke,
     UsersIdConnections,
     UsersIdChannels,
     /// Operating on the state of a guild that the user is in.
@@ -169,6 +189,99 @@ pub enum Path {
     WebhooksId(u64),
 }
 
+impl Path {
+    /// Major parameter of the path, if any.
+    ///
+    /// The API scopes buckets shared by multiple paths to the path's major
+    /// parameter, which is the ID of the channel, guild, webhook, or other
+    /// resource being operated on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use twilight_http::routing::Path;
+    ///
+    /// assert_eq!(Some(123), Path::ChannelsIdPins(123).major_parameter());
+    /// assert_eq!(None, Path::Gateway.major_parameter());
+    /// ```
+    pub const fn major_parameter(&self) -> Option<u64> {
+        match self {
+            Self::ApplicationCommand(id)
+            | Self::ApplicationCommandId(id)
+            | Self::ApplicationGuildCommand(id)
+            | Self::ApplicationGuildCommandId(id)
+            | Self::ChannelsId(id)
+            | Self::ChannelsIdInvites(id)
+            | Self::ChannelsIdMessages(id)
+            | Self::ChannelsIdMessagesBulkDelete(id)
+            | Self::ChannelsIdMessagesIdCrosspost(id)
+            | Self::ChannelsIdMessagesIdThreads(id)
+            | Self::ChannelsIdMessagesIdReactions(id)
+            | Self::ChannelsIdMessagesIdReactionsUserIdType(id)
+            | Self::ChannelsIdPermissionsOverwriteId(id)
+            | Self::ChannelsIdPins(id)
+            | Self::ChannelsIdPinsMessageId(id)
+            | Self::ChannelsIdRecipients(id)
+            | Self::ChannelsIdThreadMembers(id)
+            | Self::ChannelsIdThreadMembersId(id)
+            | Self::ChannelsIdThreads(id)
+            | Self::ChannelsIdTyping(id)
+            | Self::ChannelsIdUsersIdThreads(id)
+            | Self::ChannelsIdWebhooks(id)
+            | Self::ChannelsIdFollowers(id)
+            | Self::GuildsId(id)
+            | Self::GuildsIdBans(id)
+            | Self::GuildsIdBansId(id)
+            | Self::GuildsIdAuditLogs(id)
+            | Self::GuildsIdBansUserId(id)
+            | Self::GuildsIdChannels(id)
+            | Self::GuildsIdWidget(id)
+            | Self::GuildsIdEmojis(id)
+            | Self::GuildsIdEmojisId(id)
+            | Self::GuildsIdIntegrations(id)
+            | Self::GuildsIdIntegrationsId(id)
+            | Self::GuildsIdIntegrationsIdSync(id)
+            | Self::GuildsIdInvites(id)
+            | Self::GuildsIdMembers(id)
+            | Self::GuildsIdMembersId(id)
+            | Self::GuildsIdMembersIdRolesId(id)
+            | Self::GuildsIdMembersMeNick(id)
+            | Self::GuildsIdMembersSearch(id)
+            | Self::GuildsIdPreview(id)
+            | Self::GuildsIdPrune(id)
+            | Self::GuildsIdRegions(id)
+            | Self::GuildsIdRoles(id)
+            | Self::GuildsIdRolesId(id)
+            | Self::GuildsIdTemplates(id)
+            | Self::GuildsIdTemplatesCode(id)
+            | Self::GuildsIdThreads(id)
+            | Self::GuildsIdVanityUrl(id)
+            | Self::GuildsIdVoiceStates(id)
+            | Self::GuildsIdWelcomeScreen(id)
+            | Self::GuildsIdWebhooks(id)
+            | Self::InteractionCallback(id)
+            | Self::WebhooksIdTokenMessagesId(id)
+            | Self::WebhooksId(id)
+            | Self::ChannelsIdMessagesId(_, id) => Some(*id),
+            Self::Gateway
+            | Self::GatewayBot
+            | Self::Guilds
+            | Self::InvitesCode
+            | Self::StageInstances
+            | Self::UsersId
+            | Self::OauthApplicationsMe
+            | Self::OauthMe
+            | Self::OauthToken
+            | Self::OauthTokenRevoke
+            | Self::UsersIdConnections
+            | Self::UsersIdChannels
+            | Self::UsersIdGuilds
+            | Self::UsersIdGuildsId
+            | Self::VoiceRegions => None,
+        }
+    }
+}
+
 impl FromStr for Path {
     type Err = PathParseError;
 
@@ -236,6 +349,9 @@ impl FromStr for Path {
             ["channels", id, "messages", _, "crosspost"] => {
                 ChannelsIdMessagesIdCrosspost(parse_id(id)?)
             }
+            ["channels", id, "messages", _, "threads"] => {
+                ChannelsIdMessagesIdThreads(parse_id(id)?)
+            }
             ["channels", id, "messages", _, "reactions"]
             | ["channels", id, "messages", _, "reactions", _] => {
                 ChannelsIdMessagesIdReactions(parse_id(id)?)
@@ -249,7 +365,15 @@ impl FromStr for Path {
             ["channels", id, "recipients"] | ["channels", id, "recipients", _] => {
                 ChannelsIdRecipients(parse_id(id)?)
             }
+            ["channels", id, "thread-members"] => ChannelsIdThreadMembers(parse_id(id)?),
+            ["channels", id, "thread-members", _] => ChannelsIdThreadMembersId(parse_id(id)?),
+            ["channels", id, "threads"] | ["channels", id, "threads", "archived", _] => {
+                ChannelsIdThreads(parse_id(id)?)
+            }
             ["channels", id, "typing"] => ChannelsIdTyping(parse_id(id)?),
+            ["channels", id, "users", _, "threads", "archived", "private"] => {
+                ChannelsIdUsersIdThreads(parse_id(id)?)
+            }
             ["channels", id, "webhooks"] | ["channels", id, "webhooks", _] => {
                 ChannelsIdWebhooks(parse_id(id)?)
             }
@@ -282,6 +406,7 @@ impl FromStr for Path {
             ["guilds", id, "roles", _] => GuildsIdRolesId(parse_id(id)?),
             ["guilds", id, "templates"] => GuildsIdTemplates(parse_id(id)?),
             ["guilds", id, "templates", _] => GuildsIdTemplatesCode(parse_id(id)?),
+            ["guilds", id, "threads", "active"] => GuildsIdThreads(parse_id(id)?),
             ["guilds", id, "vanity-url"] => GuildsIdVanityUrl(parse_id(id)?),
             ["guilds", id, "voice-states", _] => GuildsIdVoiceStates(parse_id(id)?),
             ["guilds", id, "welcome-screen"] => GuildsIdWelcomeScreen(parse_id(id)?),
@@ -290,6 +415,9 @@ impl FromStr for Path {
             ["interactions", id, _, "callback"] => InteractionCallback(parse_id(id)?),
             ["stage-instances", _] => StageInstances,
             ["oauth2", "applications", "@me"] => OauthApplicationsMe,
+            ["oauth2", "@me"] => OauthMe,
+            ["oauth2", "token"] => OauthToken,
+            ["oauth2", "token", "revoke"] => OauthTokenRevoke,
             ["users", _] => UsersId,
             ["users", _, "connections"] => UsersIdConnections,
             ["users", _, "channels"] => UsersIdChannels,
@@ -308,6 +436,72 @@ impl FromStr for Path {
     }
 }
 
+/// Strip the API prefix and optional version from the path of a request URI,
+/// returning the path relative to the API.
+///
+/// Returns `None` if the path doesn't start with `/api/`. Any query is kept.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_http::routing::{self, Path};
+/// use std::str::FromStr;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let path = routing::api_relative_path("/api/v9/channels/123/pins");
+/// assert_eq!(Some("channels/123/pins"), path);
+/// assert_eq!(Some("channels/123/pins"), routing::api_relative_path("/api/channels/123/pins"));
+/// assert_eq!(None, routing::api_relative_path("/health"));
+///
+/// assert_eq!(Path::ChannelsIdPins(123), Path::from_str("channels/123/pins")?);
+/// # Ok(()) }
+/// ```
+pub fn api_relative_path(uri_path: &str) -> Option<&str> {
+    let path = uri_path.strip_prefix("/api/")?;
+    let mut segments = path.splitn(2, '/');
+
+    match (segments.next(), segments.next()) {
+        (Some(version), Some(rest)) if is_version(version) => Some(rest),
+        _ => Some(path),
+    }
+}
+
+/// Replace the token in a webhook or interaction path relative to the API
+/// version, such as `webhooks/1/token?wait=true`, with `[redacted]`.
+///
+/// Other paths are returned unchanged.
+pub(crate) fn redact_token(path: &str) -> Cow<'_, str> {
+    let (route, query) = path.split_at(path.find('?').unwrap_or(path.len()));
+    let mut segments = route.splitn(4, '/');
+
+    let (kind, id) = match (segments.next(), segments.next(), segments.next()) {
+        (Some(kind), Some(id), Some(token))
+            if (kind == "webhooks" || kind == "interactions") && !token.is_empty() =>
+        {
+            (kind, id)
+        }
+        _ => return Cow::Borrowed(path),
+    };
+
+    let mut redacted = format!("{}/{}/[redacted]", kind, id);
+
+    if let Some(rest) = segments.next() {
+        redacted.push('/');
+        redacted.push_str(rest);
+    }
+
+    redacted.push_str(query);
+
+    Cow::Owned(redacted)
+}
+
+/// Whether a path segment is an API version, such as `v9`.
+fn is_version(segment: &str) -> bool {
+    segment.len() > 1
+        && segment.starts_with('v')
+        && segment.bytes().skip(1).all(|byte| byte.is_ascii_digit())
+}
+
 impl TryFrom<(Method, &str)> for Path {
     type Error = PathParseError;
 
@@ -327,7 +521,7 @@ impl TryFrom<(Method, &str)> for Path {
 
 #[cfg(test)]
 mod tests {
-    use super::{Path, PathParseError, PathParseErrorType};
+    use super::{api_relative_path, redact_token, Path, PathParseError, PathParseErrorType};
     use crate::request::Method;
     use static_assertions::{assert_fields, assert_impl_all};
     use std::{convert::TryFrom, error::Error, fmt::Debug, hash::Hash, str::FromStr};
@@ -350,10 +544,64 @@ mod tests {
         assert_eq!(Path::ChannelsId(123), Path::from_str("/channels/123")?);
         assert_eq!(Path::WebhooksId(123), Path::from_str("/webhooks/123")?);
         assert_eq!(Path::InvitesCode, Path::from_str("/invites/abc")?);
+        assert_eq!(Path::OauthMe, Path::from_str("oauth2/@me")?);
+        assert_eq!(
+            Path::OauthTokenRevoke,
+            Path::from_str("oauth2/token/revoke")?
+        );
+        assert_eq!(
+            Path::ChannelsIdThreads(123),
+            Path::from_str("channels/123/threads/archived/public")?
+        );
+        assert_eq!(
+            Path::ChannelsIdThreadMembersId(123),
+            Path::from_str("channels/123/thread-members/@me")?
+        );
 
         Ok(())
     }
 
+    #[test]
+    fn test_api_relative_path() {
+        assert_eq!(Some("channels/1"), api_relative_path("/api/v9/channels/1"));
+        assert_eq!(
+            Some("channels/1/messages?limit=5"),
+            api_relative_path("/api/v8/channels/1/messages?limit=5")
+        );
+        assert_eq!(Some("channels/1"), api_relative_path("/api/channels/1"));
+        assert_eq!(Some("vanity/1"), api_relative_path("/api/vanity/1"));
+        assert_eq!(Some("v9"), api_relative_path("/api/v9"));
+        assert_eq!(None, api_relative_path("/channels/1"));
+    }
+
+    #[test]
+    fn test_redact_token() {
+        assert_eq!(
+            "webhooks/1/[redacted]?wait=true",
+            redact_token("webhooks/1/token?wait=true")
+        );
+        assert_eq!(
+            "webhooks/1/[redacted]/messages/2",
+            redact_token("webhooks/1/token/messages/2")
+        );
+        assert_eq!(
+            "interactions/1/[redacted]/callback",
+            redact_token("interactions/1/token/callback")
+        );
+        assert_eq!("webhooks/1", redact_token("webhooks/1"));
+        assert_eq!("channels/1/webhooks", redact_token("channels/1/webhooks"));
+    }
+
+    #[test]
+    fn test_major_parameter() {
+        assert_eq!(Some(1), Path::GuildsIdMembersId(1).major_parameter());
+        assert_eq!(
+            Some(2),
+            Path::ChannelsIdMessagesId(Method::Delete, 2).major_parameter()
+        );
+        assert_eq!(None, Path::UsersIdGuilds.major_parameter());
+    }
+
     #[test]
     fn test_message_id() -> Result<(), Box<dyn Error>> {
         assert!(matches!(
@@ -16,6 +16,15 @@ pub enum Route {
         /// The ID of the user.
         user_id: u64,
     },
+    /// Route information to add a member to a thread.
+    AddThreadMember {
+        /// ID of the thread.
+        channel_id: u64,
+        /// ID of the member.
+        user_id: u64,
+    },
+    /// Route information to exchange an `OAuth2` grant for an access token.
+    CreateAccessToken,
     /// Route information to create a ban on a user in a guild.
     CreateBan {
         /// The number of days' worth of the user's messages to delete in the
@@ -110,6 +119,18 @@ pub enum Route {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to create a thread in a channel.
+    CreateThread {
+        /// ID of the parent channel.
+        channel_id: u64,
+    },
+    /// Route information to create a thread from a message.
+    CreateThreadFromMessage {
+        /// ID of the channel.
+        channel_id: u64,
+        /// ID of the message.
+        message_id: u64,
+    },
     /// Route information to create a typing trigger in a channel.
     CreateTypingTrigger {
         /// The ID of the channel.
@@ -280,6 +301,11 @@ pub enum Route {
         /// The ID of the channel to follow.
         channel_id: u64,
     },
+    /// Route information to get the active threads in a guild.
+    GetActiveThreads {
+        /// ID of the guild.
+        guild_id: u64,
+    },
     /// Route information to get a paginated list of audit logs in a guild.
     GetAuditLogs {
         /// The type of action to get audit logs for.
@@ -302,8 +328,12 @@ pub enum Route {
     },
     /// Route information to get a guild's bans.
     GetBans {
+        /// The user ID after which to retrieve bans.
+        after: Option<u64>,
         /// The ID of the guild.
         guild_id: u64,
+        /// The maximum number of bans to retrieve.
+        limit: Option<u64>,
     },
     /// Route information to get a channel.
     GetChannel {
@@ -334,6 +364,9 @@ pub enum Route {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to get the authorization information of the current
+    /// `OAuth2` access token.
+    GetCurrentAuthorizationInformation,
     /// Route information to get info about application the current bot user belongs to
     GetCurrentUserApplicationInfo,
     /// Route information to get an emoji by ID within a guild.
@@ -481,6 +514,16 @@ pub enum Route {
         /// Whether to retrieve the expiration date of the invite.
         with_expiration: bool,
     },
+    /// Route information to get the archived private threads in a channel
+    /// that the current user has joined.
+    GetJoinedPrivateArchivedThreads {
+        /// Optional ID of a thread to get the threads before.
+        before: Option<u64>,
+        /// ID of the channel.
+        channel_id: u64,
+        /// Optional maximum number of threads to get.
+        limit: Option<u64>,
+    },
     /// Route information to get a member.
     GetMember {
         /// The ID of the guild.
@@ -513,6 +556,24 @@ pub enum Route {
         /// The ID of the channel.
         channel_id: u64,
     },
+    /// Route information to get the archived private threads in a channel.
+    GetPrivateArchivedThreads {
+        /// Optional timestamp to get the threads archived before.
+        before: Option<String>,
+        /// ID of the channel.
+        channel_id: u64,
+        /// Optional maximum number of threads to get.
+        limit: Option<u64>,
+    },
+    /// Route information to get the archived public threads in a channel.
+    GetPublicArchivedThreads {
+        /// Optional timestamp to get the threads archived before.
+        before: Option<String>,
+        /// ID of the channel.
+        channel_id: u64,
+        /// Optional maximum number of threads to get.
+        limit: Option<u64>,
+    },
     /// Route information to get the users who reacted to a message with a
     /// specified emoji.
     GetReactionUsers {
@@ -542,6 +603,11 @@ pub enum Route {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to get the members of a thread.
+    GetThreadMembers {
+        /// ID of the thread.
+        channel_id: u64,
+    },
     /// Route information to get the current user.
     GetUser {
         /// The ID of the target user. This can be `@me` to specify the current
@@ -578,11 +644,21 @@ pub enum Route {
         /// The token for the interaction.
         interaction_token: String,
     },
+    /// Route information to add the current user to a thread.
+    JoinThread {
+        /// ID of the thread.
+        channel_id: u64,
+    },
     /// Route information to leave the guild.
     LeaveGuild {
         /// The ID of the guild.
         guild_id: u64,
     },
+    /// Route information to remove the current user from a thread.
+    LeaveThread {
+        /// ID of the thread.
+        channel_id: u64,
+    },
     /// Route information to pin a message to a channel.
     PinMessage {
         /// The ID of the channel.
@@ -606,6 +682,15 @@ pub enum Route {
         /// The ID of the user.
         user_id: u64,
     },
+    /// Route information to remove a member from a thread.
+    RemoveThreadMember {
+        /// ID of the thread.
+        channel_id: u64,
+        /// ID of the member.
+        user_id: u64,
+    },
+    /// Route information to revoke an `OAuth2` access or refresh token.
+    RevokeAccessToken,
     /// Route information to search for members in a guild.
     SearchGuildMembers {
         /// ID of the guild to search in.
@@ -904,10 +989,13 @@ impl Route {
             | Self::DeleteWebhookMessage { .. }
             | Self::DeleteWebhook { .. }
             | Self::LeaveGuild { .. }
+            | Self::LeaveThread { .. }
             | Self::RemoveMember { .. }
             | Self::RemoveMemberRole { .. }
+            | Self::RemoveThreadMember { .. }
             | Self::UnpinMessage { .. } => Method::Delete,
-            Self::GetAuditLogs { .. }
+            Self::GetActiveThreads { .. }
+            | Self::GetAuditLogs { .. }
             | Self::GetBan { .. }
             | Self::GetBans { .. }
             | Self::GetGatewayBot
@@ -916,6 +1004,7 @@ impl Route {
             | Self::GetChannelWebhooks { .. }
             | Self::GetChannels { .. }
             | Self::GetCommandPermissions { .. }
+            | Self::GetCurrentAuthorizationInformation
             | Self::GetCurrentUserApplicationInfo
             | Self::GetEmoji { .. }
             | Self::GetEmojis { .. }
@@ -939,14 +1028,18 @@ impl Route {
             | Self::GetInteractionOriginal { .. }
             | Self::GetInvite { .. }
             | Self::GetInviteWithExpiration { .. }
+            | Self::GetJoinedPrivateArchivedThreads { .. }
             | Self::GetMember { .. }
             | Self::GetMessage { .. }
             | Self::GetMessages { .. }
             | Self::GetPins { .. }
+            | Self::GetPrivateArchivedThreads { .. }
+            | Self::GetPublicArchivedThreads { .. }
             | Self::GetReactionUsers { .. }
             | Self::GetStageInstance { .. }
             | Self::GetTemplate { .. }
             | Self::GetTemplates { .. }
+            | Self::GetThreadMembers { .. }
             | Self::GetUserConnections
             | Self::GetUserPrivateChannels
             | Self::GetUser { .. }
@@ -976,7 +1069,8 @@ impl Route {
             | Self::UpdateUserVoiceState { .. }
             | Self::UpdateWebhookMessage { .. }
             | Self::UpdateWebhook { .. } => Method::Patch,
-            Self::CreateChannel { .. }
+            Self::CreateAccessToken
+            | Self::CreateChannel { .. }
             | Self::CreateGlobalCommand { .. }
             | Self::CreateGuildCommand { .. }
             | Self::CreateEmoji { .. }
@@ -990,6 +1084,8 @@ impl Route {
             | Self::CreateRole { .. }
             | Self::CreateStageInstance { .. }
             | Self::CreateTemplate { .. }
+            | Self::CreateThread { .. }
+            | Self::CreateThreadFromMessage { .. }
             | Self::CreateTypingTrigger { .. }
             | Self::CreateWebhook { .. }
             | Self::CrosspostMessage { .. }
@@ -997,11 +1093,14 @@ impl Route {
             | Self::ExecuteWebhook { .. }
             | Self::FollowNewsChannel { .. }
             | Self::InteractionCallback { .. }
+            | Self::RevokeAccessToken
             | Self::SyncGuildIntegration { .. } => Method::Post,
             Self::AddGuildMember { .. }
             | Self::AddMemberRole { .. }
+            | Self::AddThreadMember { .. }
             | Self::CreateBan { .. }
             | Self::CreateReaction { .. }
+            | Self::JoinThread { .. }
             | Self::PinMessage { .. }
             | Self::SetCommandPermissions { .. }
             | Self::SetGlobalCommands { .. }
@@ -1022,19 +1121,22 @@ impl Route {
     ///
     /// ```
     /// # #[tokio::main]
-    /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    /// use twilight_http::{ratelimiting::Ratelimiter, routing::Route};
+    /// # async fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    /// use twilight_http::{
+    ///     ratelimiting::{InMemoryRatelimiter, Ratelimiter},
+    ///     routing::Route,
+    /// };
     ///
-    /// let ratelimiter = Ratelimiter::new();
+    /// let ratelimiter = InMemoryRatelimiter::new();
     /// let route = Route::CreateMessage {
     ///     channel_id: 123,
     ///  };
     ///
     /// // Take a ticket from the ratelimiter.
-    /// let rx = ratelimiter.get(route.path()).await;
+    /// let rx = ratelimiter.ticket(route.path()).await?;
     ///
     /// // Wait to be told that a request can be made...
-    /// let _tx = rx.await;
+    /// let _tx = rx.await?;
     ///
     /// // The request can now be made.
     /// # Ok(()) }
@@ -1051,6 +1153,12 @@ impl Route {
             Self::AddMemberRole { guild_id, .. } | Self::RemoveMemberRole { guild_id, .. } => {
                 Path::GuildsIdMembersIdRolesId(*guild_id)
             }
+            Self::AddThreadMember { channel_id, .. }
+            | Self::JoinThread { channel_id }
+            | Self::LeaveThread { channel_id }
+            | Self::RemoveThreadMember { channel_id, .. } => {
+                Path::ChannelsIdThreadMembersId(*channel_id)
+            }
             Self::CreateBan { guild_id, .. } | Self::DeleteBan { guild_id, .. } => {
                 Path::GuildsIdBansUserId(*guild_id)
             }
@@ -1099,6 +1207,14 @@ impl Route {
             Self::CreateTemplate { guild_id } | Self::GetTemplates { guild_id } => {
                 Path::GuildsIdTemplates(*guild_id)
             }
+            Self::CreateThread { channel_id }
+            | Self::GetPrivateArchivedThreads { channel_id, .. }
+            | Self::GetPublicArchivedThreads { channel_id, .. } => {
+                Path::ChannelsIdThreads(*channel_id)
+            }
+            Self::CreateThreadFromMessage { channel_id, .. } => {
+                Path::ChannelsIdMessagesIdThreads(*channel_id)
+            }
             Self::CreateTypingTrigger { channel_id } => Path::ChannelsIdTyping(*channel_id),
             Self::CreateWebhook { channel_id } | Self::GetChannelWebhooks { channel_id } => {
                 Path::ChannelsIdWebhooks(*channel_id)
@@ -1154,9 +1270,10 @@ impl Route {
             | Self::GetWebhook { webhook_id, .. }
             | Self::UpdateWebhook { webhook_id, .. } => (Path::WebhooksId(*webhook_id)),
             Self::FollowNewsChannel { channel_id } => Path::ChannelsIdFollowers(*channel_id),
+            Self::GetActiveThreads { guild_id } => Path::GuildsIdThreads(*guild_id),
             Self::GetAuditLogs { guild_id, .. } => Path::GuildsIdAuditLogs(*guild_id),
             Self::GetBan { guild_id, .. } => Path::GuildsIdBansId(*guild_id),
-            Self::GetBans { guild_id } => Path::GuildsIdBans(*guild_id),
+            Self::GetBans { guild_id, .. } => Path::GuildsIdBans(*guild_id),
             Self::GetGatewayBot => Path::GatewayBot,
             Self::GetChannel { channel_id } | Self::UpdateChannel { channel_id } => {
                 Path::ChannelsId(*channel_id)
@@ -1168,7 +1285,10 @@ impl Route {
             | Self::UpdateCommandPermissions { application_id, .. } => {
                 Path::ApplicationGuildCommandId(*application_id)
             }
+            Self::CreateAccessToken => Path::OauthToken,
+            Self::GetCurrentAuthorizationInformation => Path::OauthMe,
             Self::GetCurrentUserApplicationInfo => Path::OauthApplicationsMe,
+            Self::RevokeAccessToken => Path::OauthTokenRevoke,
             Self::GetUser { .. } | Self::UpdateCurrentUser => Path::UsersId,
             Self::GetEmoji { guild_id, .. } | Self::UpdateEmoji { guild_id, .. } => {
                 Path::GuildsIdEmojisId(*guild_id)
@@ -1190,12 +1310,16 @@ impl Route {
             | Self::UpdateGuildWelcomeScreen { guild_id } => Path::GuildsIdWelcomeScreen(*guild_id),
             Self::GetGuildWebhooks { guild_id } => Path::GuildsIdWebhooks(*guild_id),
             Self::GetGuilds { .. } => Path::UsersIdGuilds,
+            Self::GetJoinedPrivateArchivedThreads { channel_id, .. } => {
+                Path::ChannelsIdUsersIdThreads(*channel_id)
+            }
             Self::GetMessage { channel_id, .. } => {
                 Path::ChannelsIdMessagesId(Method::Get, *channel_id)
             }
             Self::GetPins { channel_id } | Self::PinMessage { channel_id, .. } => {
                 Path::ChannelsIdPins(*channel_id)
             }
+            Self::GetThreadMembers { channel_id } => Path::ChannelsIdThreadMembers(*channel_id),
             Self::GetUserConnections => Path::UsersIdConnections,
             Self::GetVoiceRegions => Path::VoiceRegions,
             Self::InteractionCallback { interaction_id, .. } => {
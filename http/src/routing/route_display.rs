@@ -69,6 +69,20 @@ impl Display for RouteDisplay<'_> {
 
                 Display::fmt(role_id, f)
             }
+            Route::AddThreadMember {
+                channel_id,
+                user_id,
+            }
+            | Route::RemoveThreadMember {
+                channel_id,
+                user_id,
+            } => {
+                f.write_str("channels/")?;
+                Display::fmt(channel_id, f)?;
+                f.write_str("/thread-members/")?;
+
+                Display::fmt(user_id, f)
+            }
             Route::CreateBan {
                 guild_id,
                 delete_message_days,
@@ -234,6 +248,23 @@ impl Display for RouteDisplay<'_> {
 
                 f.write_str("/templates")
             }
+            Route::CreateThread { channel_id } => {
+                f.write_str("channels/")?;
+                Display::fmt(channel_id, f)?;
+
+                f.write_str("/threads")
+            }
+            Route::CreateThreadFromMessage {
+                channel_id,
+                message_id,
+            } => {
+                f.write_str("channels/")?;
+                Display::fmt(channel_id, f)?;
+                f.write_str("/messages/")?;
+                Display::fmt(message_id, f)?;
+
+                f.write_str("/threads")
+            }
             Route::CreateTypingTrigger { channel_id } => {
                 f.write_str("channels/")?;
                 Display::fmt(channel_id, f)?;
@@ -524,6 +555,12 @@ impl Display for RouteDisplay<'_> {
 
                 f.write_str("/followers")
             }
+            Route::GetActiveThreads { guild_id } => {
+                f.write_str("guilds/")?;
+                Display::fmt(guild_id, f)?;
+
+                f.write_str("/threads/active")
+            }
             Route::GetAuditLogs {
                 action_type,
                 before,
@@ -557,11 +594,26 @@ impl Display for RouteDisplay<'_> {
 
                 Ok(())
             }
-            Route::GetBans { guild_id } => {
+            Route::GetBans {
+                after,
+                guild_id,
+                limit,
+            } => {
                 f.write_str("guilds/")?;
                 Display::fmt(guild_id, f)?;
+                f.write_str("/bans?")?;
+
+                if let Some(after) = after {
+                    f.write_str("after=")?;
+                    Display::fmt(after, f)?;
+                }
 
-                f.write_str("/bans")
+                if let Some(limit) = limit {
+                    f.write_str("&limit=")?;
+                    Display::fmt(limit, f)?;
+                }
+
+                Ok(())
             }
             Route::GetGatewayBot => f.write_str("gateway/bot"),
             Route::GetCommandPermissions {
@@ -583,7 +635,10 @@ impl Display for RouteDisplay<'_> {
 
                 f.write_str("/permissions")
             }
+            Route::CreateAccessToken => f.write_str("oauth2/token"),
+            Route::GetCurrentAuthorizationInformation => f.write_str("oauth2/@me"),
             Route::GetCurrentUserApplicationInfo => f.write_str("/oauth2/applications/@me"),
+            Route::RevokeAccessToken => f.write_str("oauth2/token/revoke"),
             Route::UpdateCurrentUser => f.write_str("users/@me"),
             Route::GetGateway => f.write_str("gateway"),
             Route::GetGuild {
@@ -628,7 +683,7 @@ impl Display for RouteDisplay<'_> {
             } => {
                 f.write_str("guilds/")?;
                 Display::fmt(guild_id, f)?;
-                f.write_str("members?")?;
+                f.write_str("/members?")?;
 
                 if let Some(after) = after {
                     f.write_str("after=")?;
@@ -806,6 +861,64 @@ impl Display for RouteDisplay<'_> {
 
                 f.write_str("/pins")
             }
+            Route::GetJoinedPrivateArchivedThreads {
+                before,
+                channel_id,
+                limit,
+            } => {
+                f.write_str("channels/")?;
+                Display::fmt(channel_id, f)?;
+                f.write_str("/users/@me/threads/archived/private?")?;
+
+                if let Some(before) = before {
+                    f.write_str("before=")?;
+                    Display::fmt(before, f)?;
+                }
+
+                if let Some(limit) = limit {
+                    f.write_str("&limit=")?;
+                    Display::fmt(limit, f)?;
+                }
+
+                Ok(())
+            }
+            Route::GetPrivateArchivedThreads {
+                before,
+                channel_id,
+                limit,
+            }
+            | Route::GetPublicArchivedThreads {
+                before,
+                channel_id,
+                limit,
+            } => {
+                f.write_str("channels/")?;
+                Display::fmt(channel_id, f)?;
+
+                if matches!(self.0, Route::GetPrivateArchivedThreads { .. }) {
+                    f.write_str("/threads/archived/private?")?;
+                } else {
+                    f.write_str("/threads/archived/public?")?;
+                }
+
+                if let Some(before) = before {
+                    f.write_str("before=")?;
+                    Display::fmt(&utf8_percent_encode(before, NON_ALPHANUMERIC), f)?;
+                }
+
+                if let Some(limit) = limit {
+                    f.write_str("&limit=")?;
+                    Display::fmt(limit, f)?;
+                }
+
+                Ok(())
+            }
+            Route::GetThreadMembers { channel_id } => {
+                f.write_str("channels/")?;
+                Display::fmt(channel_id, f)?;
+
+                f.write_str("/thread-members")
+            }
             Route::GetReactionUsers {
                 after,
                 channel_id,
@@ -851,6 +964,12 @@ impl Display for RouteDisplay<'_> {
 
                 f.write_str("/callback")
             }
+            Route::JoinThread { channel_id } | Route::LeaveThread { channel_id } => {
+                f.write_str("channels/")?;
+                Display::fmt(channel_id, f)?;
+
+                f.write_str("/thread-members/@me")
+            }
             Route::LeaveGuild { guild_id } => {
                 f.write_str("users/@me/guilds/")?;
 
@@ -932,6 +1051,46 @@ mod tests {
 
     assert_impl_all!(RouteDisplay<'_>: Clone, Debug, Display, Eq, Hash, PartialEq, Send, Sync);
 
+    #[test]
+    fn test_get_bans() {
+        let route = Route::GetBans {
+            after: Some(2),
+            guild_id: 1,
+            limit: Some(1000),
+        };
+
+        assert_eq!(
+            "guilds/1/bans?after=2&limit=1000",
+            route.display().to_string()
+        );
+    }
+
+    #[test]
+    fn test_get_guild_members() {
+        let route = Route::GetGuildMembers {
+            after: None,
+            guild_id: 1,
+            limit: Some(1000),
+            presences: None,
+        };
+
+        assert_eq!("guilds/1/members?&limit=1000", route.display().to_string());
+    }
+
+    #[test]
+    fn test_get_public_archived_threads() {
+        let route = Route::GetPublicArchivedThreads {
+            before: Some("2021-09-19T14:17:32+00:00".to_owned()),
+            channel_id: 1,
+            limit: Some(10),
+        };
+
+        assert_eq!(
+            "channels/1/threads/archived/public?before=2021%2D09%2D19T14%3A17%3A32%2B00%3A00&limit=10",
+            route.display().to_string()
+        );
+    }
+
     #[test]
     fn test_set_guild_commands() {
         let route = Route::SetGuildCommands {
@@ -0,0 +1,33 @@
+//! Local HTTP server standing in for the Discord API in tests.
+
+use hyper::{
+    service::{make_service_fn, service_fn},
+    Body, Request, Response, Server,
+};
+use std::{convert::Infallible, error::Error, future::Future, net::SocketAddr};
+
+/// Start a server on a random local port, responding to every request with
+/// the given handler, and return its address.
+///
+/// The server runs until the runtime of the test shuts down. Point a client
+/// at it with [`ClientBuilder::proxy`].
+///
+/// [`ClientBuilder::proxy`]: crate::client::ClientBuilder::proxy
+pub(crate) fn start<F, Fut, E>(handler: F) -> SocketAddr
+where
+    F: Fn(Request<Body>) -> Fut + Clone + Send + 'static,
+    Fut: Future<Output = Result<Response<Body>, E>> + Send + 'static,
+    E: Into<Box<dyn Error + Send + Sync>> + 'static,
+{
+    let service = make_service_fn(move |_| {
+        let handler = handler.clone();
+
+        async move { Ok::<_, Infallible>(service_fn(handler)) }
+    });
+
+    let server = Server::bind(&([127, 0, 0, 1], 0).into()).serve(service);
+    let addr = server.local_addr();
+    tokio::spawn(server);
+
+    addr
+}
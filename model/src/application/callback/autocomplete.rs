@@ -0,0 +1,16 @@
+use crate::application::command::CommandOptionChoice;
+use serde::{Deserialize, Serialize};
+
+/// Data sent when responding to an [`Interaction`] of type
+/// [`ApplicationCommandAutocomplete`].
+///
+/// Refer to [the discord docs] for more information.
+///
+/// [`Interaction`]: crate::application::interaction::Interaction
+/// [`ApplicationCommandAutocomplete`]: crate::application::interaction::Interaction::ApplicationCommandAutocomplete
+/// [the discord docs]: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object-autocomplete
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Autocomplete {
+    /// Choices suggested to the user, up to 25.
+    pub choices: Vec<CommandOptionChoice>,
+}
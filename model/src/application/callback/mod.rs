@@ -1,15 +1,21 @@
 //! Used when responding to interactions.
 
+mod autocomplete;
 mod callback_data;
+mod modal;
 mod response_type;
 
-pub use self::{callback_data::CallbackData, response_type::ResponseType};
+pub use self::{
+    autocomplete::Autocomplete, callback_data::CallbackData, modal::Modal,
+    response_type::ResponseType,
+};
 
 use serde::{
     de::{Deserializer, Error as DeError, IgnoredAny, MapAccess, Visitor},
     ser::{SerializeStruct, Serializer},
     Deserialize, Serialize,
 };
+use serde_value::Value;
 use std::fmt::{Formatter, Result as FmtResult};
 
 /// Payload used for responding to an interaction.
@@ -25,6 +31,16 @@ pub enum InteractionResponse {
     ChannelMessageWithSource(CallbackData),
     /// Acknowledges an interaction, showing a loading state.
     DeferredChannelMessageWithSource(CallbackData),
+    /// Acknowledges a component interaction, updating the message of the
+    /// component later.
+    DeferredUpdateMessage,
+    /// Responds to a component interaction by updating the message of the
+    /// component.
+    UpdateMessage(CallbackData),
+    /// Responds to an autocomplete interaction with suggested choices.
+    ApplicationCommandAutocompleteResult(Autocomplete),
+    /// Responds to an interaction with a popup form.
+    Modal(Modal),
 }
 
 impl InteractionResponse {
@@ -35,6 +51,12 @@ impl InteractionResponse {
             Self::DeferredChannelMessageWithSource(_) => {
                 ResponseType::DeferredChannelMessageWithSource
             }
+            Self::DeferredUpdateMessage => ResponseType::DeferredUpdateMessage,
+            Self::UpdateMessage(_) => ResponseType::UpdateMessage,
+            Self::ApplicationCommandAutocompleteResult(_) => {
+                ResponseType::ApplicationCommandAutocompleteResult
+            }
+            Self::Modal(_) => ResponseType::Modal,
         }
     }
 }
@@ -62,7 +84,9 @@ impl<'de> Visitor<'de> for ResponseVisitor {
     }
 
     fn visit_map<V: MapAccess<'de>>(self, mut map: V) -> Result<Self::Value, V::Error> {
-        let mut data: Option<CallbackData> = None;
+        // The type of the data depends on the type of the response, which may
+        // come after it.
+        let mut data: Option<Value> = None;
         let mut kind: Option<ResponseType> = None;
 
         let span = tracing::trace_span!("deserializing interaction response");
@@ -111,15 +135,46 @@ impl<'de> Visitor<'de> for ResponseVisitor {
         Ok(match kind {
             ResponseType::Pong => Self::Value::Pong,
             ResponseType::ChannelMessageWithSource => {
-                let data = data.ok_or_else(|| DeError::missing_field("data"))?;
+                let data = data
+                    .ok_or_else(|| DeError::missing_field("data"))?
+                    .deserialize_into()
+                    .map_err(DeError::custom)?;
 
                 Self::Value::ChannelMessageWithSource(data)
             }
             ResponseType::DeferredChannelMessageWithSource => {
-                let data = data.ok_or_else(|| DeError::missing_field("data"))?;
+                let data = data
+                    .ok_or_else(|| DeError::missing_field("data"))?
+                    .deserialize_into()
+                    .map_err(DeError::custom)?;
 
                 Self::Value::DeferredChannelMessageWithSource(data)
             }
+            ResponseType::DeferredUpdateMessage => Self::Value::DeferredUpdateMessage,
+            ResponseType::UpdateMessage => {
+                let data = data
+                    .ok_or_else(|| DeError::missing_field("data"))?
+                    .deserialize_into()
+                    .map_err(DeError::custom)?;
+
+                Self::Value::UpdateMessage(data)
+            }
+            ResponseType::ApplicationCommandAutocompleteResult => {
+                let data = data
+                    .ok_or_else(|| DeError::missing_field("data"))?
+                    .deserialize_into()
+                    .map_err(DeError::custom)?;
+
+                Self::Value::ApplicationCommandAutocompleteResult(data)
+            }
+            ResponseType::Modal => {
+                let data = data
+                    .ok_or_else(|| DeError::missing_field("data"))?
+                    .deserialize_into()
+                    .map_err(DeError::custom)?;
+
+                Self::Value::Modal(data)
+            }
         })
     }
 }
@@ -127,14 +182,32 @@ impl<'de> Visitor<'de> for ResponseVisitor {
 impl Serialize for InteractionResponse {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         match self {
-            Self::Pong => {
+            Self::Pong | Self::DeferredUpdateMessage => {
                 let mut state = serializer.serialize_struct("InteractionResponse", 1)?;
 
                 state.serialize_field("type", &self.kind())?;
 
                 state.end()
             }
-            Self::ChannelMessageWithSource(data) | Self::DeferredChannelMessageWithSource(data) => {
+            Self::ChannelMessageWithSource(data)
+            | Self::DeferredChannelMessageWithSource(data)
+            | Self::UpdateMessage(data) => {
+                let mut state = serializer.serialize_struct("InteractionResponse", 2)?;
+
+                state.serialize_field("type", &self.kind())?;
+                state.serialize_field("data", &data)?;
+
+                state.end()
+            }
+            Self::ApplicationCommandAutocompleteResult(data) => {
+                let mut state = serializer.serialize_struct("InteractionResponse", 2)?;
+
+                state.serialize_field("type", &self.kind())?;
+                state.serialize_field("data", &data)?;
+
+                state.end()
+            }
+            Self::Modal(data) => {
                 let mut state = serializer.serialize_struct("InteractionResponse", 2)?;
 
                 state.serialize_field("type", &self.kind())?;
@@ -148,14 +221,21 @@ impl Serialize for InteractionResponse {
 
 #[cfg(test)]
 mod tests {
-    use super::{CallbackData, InteractionResponse};
-    use crate::channel::message::MessageFlags;
+    use super::{Autocomplete, CallbackData, InteractionResponse, Modal};
+    use crate::{
+        application::{
+            command::CommandOptionChoice,
+            component::{ActionRow, Button, ButtonStyle, Component, TextInput, TextInputStyle},
+        },
+        channel::message::MessageFlags,
+    };
     use serde_test::Token;
 
     #[test]
     fn test_response() {
         let value = InteractionResponse::ChannelMessageWithSource(CallbackData {
             allowed_mentions: None,
+            components: None,
             content: Some("test".into()),
             embeds: Vec::new(),
             flags: Some(MessageFlags::EPHEMERAL),
@@ -187,4 +267,201 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn test_update_message() {
+        let value = InteractionResponse::UpdateMessage(CallbackData {
+            allowed_mentions: None,
+            components: Some(vec![Component::ActionRow(ActionRow {
+                components: vec![Component::Button(Button {
+                    custom_id: Some("refresh".into()),
+                    disabled: false,
+                    emoji: None,
+                    label: Some("Refresh".into()),
+                    style: ButtonStyle::Secondary,
+                    url: None,
+                })],
+            })]),
+            content: None,
+            embeds: Vec::new(),
+            flags: None,
+            tts: None,
+        });
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "InteractionResponse",
+                    len: 2,
+                },
+                Token::Str("type"),
+                Token::U8(7),
+                Token::Str("data"),
+                Token::Struct {
+                    name: "CallbackData",
+                    len: 1,
+                },
+                Token::Str("components"),
+                Token::Some,
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "Component",
+                    len: 2,
+                },
+                Token::Str("type"),
+                Token::U8(1),
+                Token::Str("components"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "Component",
+                    len: 5,
+                },
+                Token::Str("type"),
+                Token::U8(2),
+                Token::Str("custom_id"),
+                Token::Str("refresh"),
+                Token::Str("disabled"),
+                Token::Bool(false),
+                Token::Str("label"),
+                Token::Str("Refresh"),
+                Token::Str("style"),
+                Token::U8(2),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::StructEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_deferred_update_message() {
+        serde_test::assert_tokens(
+            &InteractionResponse::DeferredUpdateMessage,
+            &[
+                Token::Struct {
+                    name: "InteractionResponse",
+                    len: 1,
+                },
+                Token::Str("type"),
+                Token::U8(6),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_autocomplete_result() {
+        let value = InteractionResponse::ApplicationCommandAutocompleteResult(Autocomplete {
+            choices: vec![CommandOptionChoice::String {
+                name: "Never Gonna Give You Up".into(),
+                value: "dQw4w9WgXcQ".into(),
+            }],
+        });
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "InteractionResponse",
+                    len: 2,
+                },
+                Token::Str("type"),
+                Token::U8(8),
+                Token::Str("data"),
+                Token::Struct {
+                    name: "Autocomplete",
+                    len: 1,
+                },
+                Token::Str("choices"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "CommandOptionChoice",
+                    len: 2,
+                },
+                Token::Str("name"),
+                Token::Str("Never Gonna Give You Up"),
+                Token::Str("value"),
+                Token::Str("dQw4w9WgXcQ"),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::StructEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_modal() {
+        let value = InteractionResponse::Modal(Modal {
+            components: vec![Component::ActionRow(ActionRow {
+                components: vec![Component::TextInput(TextInput {
+                    custom_id: "name".into(),
+                    label: "Name".into(),
+                    max_length: None,
+                    min_length: Some(2),
+                    placeholder: None,
+                    required: None,
+                    style: TextInputStyle::Short,
+                    value: None,
+                })],
+            })],
+            custom_id: "signup".into(),
+            title: "Sign up".into(),
+        });
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "InteractionResponse",
+                    len: 2,
+                },
+                Token::Str("type"),
+                Token::U8(9),
+                Token::Str("data"),
+                Token::Struct {
+                    name: "Modal",
+                    len: 3,
+                },
+                Token::Str("components"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "Component",
+                    len: 2,
+                },
+                Token::Str("type"),
+                Token::U8(1),
+                Token::Str("components"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "Component",
+                    len: 5,
+                },
+                Token::Str("type"),
+                Token::U8(4),
+                Token::Str("custom_id"),
+                Token::Str("name"),
+                Token::Str("label"),
+                Token::Str("Name"),
+                Token::Str("min_length"),
+                Token::U16(2),
+                Token::Str("style"),
+                Token::U8(1),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("custom_id"),
+                Token::Str("signup"),
+                Token::Str("title"),
+                Token::Str("Sign up"),
+                Token::StructEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
 }
@@ -0,0 +1,24 @@
+use crate::application::component::Component;
+use serde::{Deserialize, Serialize};
+
+/// Popup form sent when responding to an [`Interaction`].
+///
+/// Submitting the modal sends a [`ModalSubmit`] interaction with the
+/// entered text.
+///
+/// Refer to [the discord docs] for more information.
+///
+/// [`Interaction`]: crate::application::interaction::Interaction
+/// [`ModalSubmit`]: crate::application::interaction::Interaction::ModalSubmit
+/// [the discord docs]: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-response-object-modal
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct Modal {
+    /// Action rows containing a single [`TextInput`] each.
+    ///
+    /// [`TextInput`]: crate::application::component::TextInput
+    pub components: Vec<Component>,
+    /// Developer defined identifier, sent with the submitted interaction.
+    pub custom_id: String,
+    /// Title displayed at the top of the modal.
+    pub title: String,
+}
@@ -9,6 +9,10 @@ pub enum ResponseType {
     Pong = 1,
     ChannelMessageWithSource = 4,
     DeferredChannelMessageWithSource = 5,
+    DeferredUpdateMessage = 6,
+    UpdateMessage = 7,
+    ApplicationCommandAutocompleteResult = 8,
+    Modal = 9,
 }
 
 impl ResponseType {
@@ -17,6 +21,10 @@ impl ResponseType {
             Self::Pong => "Pong",
             Self::ChannelMessageWithSource => "ChannelMessageWithSource",
             Self::DeferredChannelMessageWithSource => "DeferredChannelMessageWithSource",
+            Self::DeferredUpdateMessage => "DeferredUpdateMessage",
+            Self::UpdateMessage => "UpdateMessage",
+            Self::ApplicationCommandAutocompleteResult => "ApplicationCommandAutocompleteResult",
+            Self::Modal => "Modal",
         }
     }
 }
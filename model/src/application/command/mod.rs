@@ -2,11 +2,15 @@
 
 pub mod permissions;
 
+mod command_type;
 mod option;
 
-pub use self::option::{
-    BaseCommandOptionData, ChoiceCommandOptionData, CommandOption, CommandOptionChoice,
-    CommandOptionType, OptionsCommandOptionData,
+pub use self::{
+    command_type::CommandType,
+    option::{
+        BaseCommandOptionData, ChoiceCommandOptionData, CommandOption, CommandOptionChoice,
+        CommandOptionType, OptionsCommandOptionData,
+    },
 };
 
 use crate::id::{ApplicationId, CommandId, GuildId};
@@ -15,8 +19,10 @@ use serde::{Deserialize, Serialize};
 /// Data sent to discord to create a command.
 ///
 /// [`CommandOption`]s that are required must be listed before optional ones.
-/// Command names must be lower case, matching the Regex `^[\w-]{1,32}$`. Refer
-/// to [the discord docs] for more information.
+/// Command names must be lower case, matching the Regex `^[\w-]{1,32}$`.
+/// Context menu commands don't have a description or options, and their names
+/// may contain spaces and upper case letters. Refer to [the discord docs] for
+/// more information.
 ///
 /// [the discord docs]: https://discord.com/developers/docs/interactions/slash-commands#applicationcommand
 #[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
@@ -29,9 +35,65 @@ pub struct Command {
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub default_permission: Option<bool>,
+    /// Description of the command.
+    ///
+    /// Empty for context menu commands.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub description: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<CommandId>,
+    /// Type of the command.
+    #[serde(default, rename = "type")]
+    pub kind: CommandType,
     #[serde(default)]
     pub options: Vec<CommandOption>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Command, CommandType};
+    use crate::id::{ApplicationId, CommandId};
+    use serde_test::Token;
+
+    #[test]
+    fn test_context_menu_command() {
+        let value = Command {
+            application_id: Some(ApplicationId(1)),
+            guild_id: None,
+            name: "Report message".into(),
+            default_permission: None,
+            description: String::new(),
+            id: Some(CommandId(2)),
+            kind: CommandType::Message,
+            options: Vec::new(),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Command",
+                    len: 5,
+                },
+                Token::Str("application_id"),
+                Token::Some,
+                Token::NewtypeStruct {
+                    name: "ApplicationId",
+                },
+                Token::Str("1"),
+                Token::Str("name"),
+                Token::Str("Report message"),
+                Token::Str("id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "CommandId" },
+                Token::Str("2"),
+                Token::Str("type"),
+                Token::U8(3),
+                Token::Str("options"),
+                Token::Seq { len: Some(0) },
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -66,6 +66,8 @@ impl<'de> Deserialize<'de> for CommandOption {
 
 #[derive(Serialize)]
 struct CommandOptionEnvelope<'ser> {
+    #[serde(skip_serializing_if = "is_false")]
+    autocomplete: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     choices: Option<&'ser [CommandOptionChoice]>,
     description: &'ser str,
@@ -82,6 +84,7 @@ impl Serialize for CommandOption {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         let envelope = match self {
             Self::SubCommand(data) | Self::SubCommandGroup(data) => CommandOptionEnvelope {
+                autocomplete: false,
                 choices: None,
                 description: data.description.as_ref(),
                 name: data.name.as_ref(),
@@ -90,7 +93,13 @@ impl Serialize for CommandOption {
                 kind: self.kind(),
             },
             Self::String(data) | Self::Integer(data) => CommandOptionEnvelope {
-                choices: Some(data.choices.as_ref()),
+                autocomplete: data.autocomplete,
+                // Autocompleted options are sent without choices.
+                choices: if data.autocomplete && data.choices.is_empty() {
+                    None
+                } else {
+                    Some(data.choices.as_ref())
+                },
                 description: data.description.as_ref(),
                 name: data.name.as_ref(),
                 options: None,
@@ -102,6 +111,7 @@ impl Serialize for CommandOption {
             | Self::Channel(data)
             | Self::Role(data)
             | Self::Mentionable(data) => CommandOptionEnvelope {
+                autocomplete: false,
                 choices: None,
                 description: data.description.as_ref(),
                 name: data.name.as_ref(),
@@ -118,6 +128,7 @@ impl Serialize for CommandOption {
 #[derive(Debug, Deserialize)]
 #[serde(field_identifier, rename_all = "snake_case")]
 enum OptionField {
+    Autocomplete,
     Choices,
     Description,
     Name,
@@ -137,6 +148,7 @@ impl<'de> Visitor<'de> for OptionVisitor {
 
     #[allow(clippy::too_many_lines)]
     fn visit_map<V: MapAccess<'de>>(self, mut map: V) -> Result<Self::Value, V::Error> {
+        let mut autocomplete: Option<bool> = None;
         let mut choices: Option<Option<Vec<CommandOptionChoice>>> = None;
         let mut description: Option<String> = None;
         let mut kind: Option<CommandOptionType> = None;
@@ -168,6 +180,13 @@ impl<'de> Visitor<'de> for OptionVisitor {
             };
 
             match key {
+                OptionField::Autocomplete => {
+                    if autocomplete.is_some() {
+                        return Err(DeError::duplicate_field("autocomplete"));
+                    }
+
+                    autocomplete = Some(map.next_value()?);
+                }
                 OptionField::Choices => {
                     if choices.is_some() {
                         return Err(DeError::duplicate_field("choices"));
@@ -224,6 +243,7 @@ impl<'de> Visitor<'de> for OptionVisitor {
             "common fields of all variants exist"
         );
 
+        let autocomplete = autocomplete.unwrap_or_default();
         let required = required.unwrap_or_default();
 
         Ok(match kind {
@@ -252,11 +272,14 @@ impl<'de> Visitor<'de> for OptionVisitor {
                 })
             }
             CommandOptionType::String => {
-                let choices = choices
-                    .flatten()
-                    .ok_or_else(|| DeError::missing_field("choices"))?;
+                let choices = match choices.flatten() {
+                    Some(choices) => choices,
+                    None if autocomplete => Vec::new(),
+                    None => return Err(DeError::missing_field("choices")),
+                };
 
                 CommandOption::String(ChoiceCommandOptionData {
+                    autocomplete,
                     choices,
                     description,
                     name,
@@ -264,11 +287,14 @@ impl<'de> Visitor<'de> for OptionVisitor {
                 })
             }
             CommandOptionType::Integer => {
-                let choices = choices
-                    .flatten()
-                    .ok_or_else(|| DeError::missing_field("choices"))?;
+                let choices = match choices.flatten() {
+                    Some(choices) => choices,
+                    None if autocomplete => Vec::new(),
+                    None => return Err(DeError::missing_field("choices")),
+                };
 
                 CommandOption::Integer(ChoiceCommandOptionData {
+                    autocomplete,
                     choices,
                     description,
                     name,
@@ -352,6 +378,19 @@ pub struct OptionsCommandOptionData {
 /// [`Integer`]: CommandOption::Integer
 #[derive(Clone, Debug, Default, Deserialize, Eq, Hash, PartialEq, Serialize)]
 pub struct ChoiceCommandOptionData {
+    /// Whether the option's values are suggested by the application while the
+    /// user is typing.
+    ///
+    /// Users completing an autocompleted option send
+    /// [`ApplicationCommandAutocomplete`] interactions, which are responded to
+    /// with [`ApplicationCommandAutocompleteResult`]. Autocompleted options
+    /// can't have predetermined [`choices`].
+    ///
+    /// [`ApplicationCommandAutocomplete`]: crate::application::interaction::Interaction::ApplicationCommandAutocomplete
+    /// [`ApplicationCommandAutocompleteResult`]: crate::application::callback::InteractionResponse::ApplicationCommandAutocompleteResult
+    /// [`choices`]: Self::choices
+    #[serde(default)]
+    pub autocomplete: bool,
     /// Predetermined choices may be defined for a user to select.
     ///
     /// When completing this option, the user is prompted with a selector of all
@@ -415,8 +454,9 @@ impl CommandOptionType {
 #[cfg(test)]
 mod tests {
     use super::{
-        super::Command, BaseCommandOptionData, ChoiceCommandOptionData, CommandOption,
-        CommandOptionChoice, OptionsCommandOptionData,
+        super::{Command, CommandType},
+        BaseCommandOptionData, ChoiceCommandOptionData, CommandOption, CommandOptionChoice,
+        OptionsCommandOptionData,
     };
     use crate::id::{ApplicationId, CommandId, GuildId};
     use serde_test::Token;
@@ -431,6 +471,7 @@ mod tests {
             default_permission: Some(true),
             description: "this command is a test".into(),
             id: Some(CommandId(200)),
+            kind: CommandType::ChatInput,
             options: vec![CommandOption::SubCommandGroup(OptionsCommandOptionData {
                 description: "sub group desc".into(),
                 name: "sub group name".into(),
@@ -439,6 +480,7 @@ mod tests {
                     name: "sub command name".into(),
                     options: vec![
                         CommandOption::String(ChoiceCommandOptionData {
+                            autocomplete: false,
                             choices: vec![CommandOptionChoice::String {
                                 name: "choicea".into(),
                                 value: "choice_a".into(),
@@ -448,6 +490,7 @@ mod tests {
                             required: false,
                         }),
                         CommandOption::Integer(ChoiceCommandOptionData {
+                            autocomplete: false,
                             choices: vec![CommandOptionChoice::Int {
                                 name: "choice2".into(),
                                 value: 2,
@@ -493,7 +536,7 @@ mod tests {
             &[
                 Token::Struct {
                     name: "Command",
-                    len: 7,
+                    len: 8,
                 },
                 Token::Str("application_id"),
                 Token::Some,
@@ -516,6 +559,8 @@ mod tests {
                 Token::Some,
                 Token::NewtypeStruct { name: "CommandId" },
                 Token::Str("200"),
+                Token::Str("type"),
+                Token::U8(1),
                 Token::Str("options"),
                 Token::Seq { len: Some(1) },
                 Token::Struct {
@@ -658,4 +703,36 @@ mod tests {
             ],
         );
     }
+
+    #[test]
+    fn test_command_option_autocomplete() {
+        let value = CommandOption::String(ChoiceCommandOptionData {
+            autocomplete: true,
+            choices: Vec::new(),
+            description: "track to play".into(),
+            name: "track".into(),
+            required: true,
+        });
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "CommandOptionEnvelope",
+                    len: 5,
+                },
+                Token::Str("autocomplete"),
+                Token::Bool(true),
+                Token::Str("description"),
+                Token::Str("track to play"),
+                Token::Str("name"),
+                Token::Str("track"),
+                Token::Str("required"),
+                Token::Bool(true),
+                Token::Str("type"),
+                Token::U8(3),
+                Token::StructEnd,
+            ],
+        );
+    }
 }
@@ -0,0 +1,16 @@
+use super::Component;
+
+/// Container of other components.
+///
+/// Action rows are the top-level components of a message and can't contain
+/// other action rows. A row can contain up to 5 buttons or a single select
+/// menu.
+///
+/// Refer to [the discord docs] for more information.
+///
+/// [the discord docs]: https://discord.com/developers/docs/interactions/message-components#action-rows
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ActionRow {
+    /// Components in the row.
+    pub components: Vec<Component>,
+}
@@ -0,0 +1,73 @@
+use crate::channel::ReactionType;
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Clickable button.
+///
+/// Buttons of the [`Link`] style navigate to their [`url`], while buttons of
+/// the other styles send a [`MessageComponent`] interaction with their
+/// [`custom_id`].
+///
+/// Refer to [the discord docs] for more information.
+///
+/// [`Link`]: ButtonStyle::Link
+/// [`MessageComponent`]: crate::application::interaction::Interaction::MessageComponent
+/// [`custom_id`]: Self::custom_id
+/// [`url`]: Self::url
+/// [the discord docs]: https://discord.com/developers/docs/interactions/message-components#buttons
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Button {
+    /// Developer defined identifier, sent with the interaction when the button
+    /// is clicked.
+    ///
+    /// Required unless the style is [`ButtonStyle::Link`].
+    pub custom_id: Option<String>,
+    /// Whether the button is disabled.
+    pub disabled: bool,
+    /// Emoji displayed on the button.
+    pub emoji: Option<ReactionType>,
+    /// Text displayed on the button.
+    pub label: Option<String>,
+    /// Style of the button.
+    pub style: ButtonStyle,
+    /// URL navigated to when the button is clicked.
+    ///
+    /// Required if the style is [`ButtonStyle::Link`].
+    pub url: Option<String>,
+}
+
+/// Style of a [`Button`].
+///
+/// Refer to [the discord docs] for more information.
+///
+/// [the discord docs]: https://discord.com/developers/docs/interactions/message-components#button-object-button-styles
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum ButtonStyle {
+    /// Blurple button.
+    Primary = 1,
+    /// Grey button.
+    Secondary = 2,
+    /// Green button.
+    Success = 3,
+    /// Red button.
+    Danger = 4,
+    /// Grey button navigating to a URL.
+    Link = 5,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ButtonStyle;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&ButtonStyle::Primary, &[Token::U8(1)]);
+        serde_test::assert_tokens(&ButtonStyle::Secondary, &[Token::U8(2)]);
+        serde_test::assert_tokens(&ButtonStyle::Success, &[Token::U8(3)]);
+        serde_test::assert_tokens(&ButtonStyle::Danger, &[Token::U8(4)]);
+        serde_test::assert_tokens(&ButtonStyle::Link, &[Token::U8(5)]);
+    }
+}
@@ -0,0 +1,55 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Type of a [`Component`].
+///
+/// Refer to [the discord docs] for more information.
+///
+/// [`Component`]: super::Component
+/// [the discord docs]: https://discord.com/developers/docs/interactions/message-components#component-object-component-types
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum ComponentType {
+    /// Container for other components.
+    ActionRow = 1,
+    /// Clickable button.
+    Button = 2,
+    /// Dropdown menu of options.
+    SelectMenu = 3,
+    /// Field for users to enter text into.
+    TextInput = 4,
+}
+
+impl ComponentType {
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::ActionRow => "ActionRow",
+            Self::Button => "Button",
+            Self::SelectMenu => "SelectMenu",
+            Self::TextInput => "TextInput",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ComponentType;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&ComponentType::ActionRow, &[Token::U8(1)]);
+        serde_test::assert_tokens(&ComponentType::Button, &[Token::U8(2)]);
+        serde_test::assert_tokens(&ComponentType::SelectMenu, &[Token::U8(3)]);
+        serde_test::assert_tokens(&ComponentType::TextInput, &[Token::U8(4)]);
+    }
+
+    #[test]
+    fn test_names() {
+        assert_eq!("ActionRow", ComponentType::ActionRow.name());
+        assert_eq!("Button", ComponentType::Button.name());
+        assert_eq!("SelectMenu", ComponentType::SelectMenu.name());
+        assert_eq!("TextInput", ComponentType::TextInput.name());
+    }
+}
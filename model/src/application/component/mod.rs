@@ -0,0 +1,650 @@
+//! Interactive message components, such as buttons and select menus, and the
+//! text inputs of modals.
+//!
+//! Refer to [the discord docs] for more information.
+//!
+//! [the discord docs]: https://discord.com/developers/docs/interactions/message-components
+
+mod action_row;
+mod button;
+mod kind;
+mod select_menu;
+mod text_input;
+
+pub use self::{
+    action_row::ActionRow,
+    button::{Button, ButtonStyle},
+    kind::ComponentType,
+    select_menu::{SelectMenu, SelectMenuOption},
+    text_input::{TextInput, TextInputStyle},
+};
+
+use crate::channel::ReactionType;
+use serde::{
+    de::{Deserializer, Error as DeError, IgnoredAny, MapAccess, Visitor},
+    ser::{SerializeStruct, Serializer},
+    Deserialize, Serialize,
+};
+use serde_value::Value;
+use std::fmt::{Formatter, Result as FmtResult};
+
+/// Interactive component of a message.
+///
+/// Messages and interaction responses contain [`ActionRow`]s at the top level,
+/// which in turn contain the other components. Modals contain action rows of
+/// [`TextInput`]s.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum Component {
+    /// Container of other components.
+    ActionRow(ActionRow),
+    /// Clickable button.
+    Button(Button),
+    /// Dropdown menu of options.
+    SelectMenu(SelectMenu),
+    /// Field for users to enter text into.
+    TextInput(TextInput),
+}
+
+impl Component {
+    /// Type of the component.
+    pub const fn kind(&self) -> ComponentType {
+        match self {
+            Self::ActionRow(_) => ComponentType::ActionRow,
+            Self::Button(_) => ComponentType::Button,
+            Self::SelectMenu(_) => ComponentType::SelectMenu,
+            Self::TextInput(_) => ComponentType::TextInput,
+        }
+    }
+}
+
+impl From<ActionRow> for Component {
+    fn from(action_row: ActionRow) -> Self {
+        Self::ActionRow(action_row)
+    }
+}
+
+impl From<Button> for Component {
+    fn from(button: Button) -> Self {
+        Self::Button(button)
+    }
+}
+
+impl From<SelectMenu> for Component {
+    fn from(select_menu: SelectMenu) -> Self {
+        Self::SelectMenu(select_menu)
+    }
+}
+
+impl From<TextInput> for Component {
+    fn from(text_input: TextInput) -> Self {
+        Self::TextInput(text_input)
+    }
+}
+
+impl<'de> Deserialize<'de> for Component {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(ComponentVisitor)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(field_identifier, rename_all = "snake_case")]
+enum ComponentField {
+    Components,
+    CustomId,
+    Disabled,
+    Emoji,
+    Label,
+    MaxLength,
+    MaxValues,
+    MinLength,
+    MinValues,
+    Options,
+    Placeholder,
+    Required,
+    Style,
+    Type,
+    Url,
+    Value,
+}
+
+struct ComponentVisitor;
+
+impl<'de> Visitor<'de> for ComponentVisitor {
+    type Value = Component;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("struct Component")
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn visit_map<V: MapAccess<'de>>(self, mut map: V) -> Result<Self::Value, V::Error> {
+        let mut components: Option<Vec<Component>> = None;
+        let mut custom_id: Option<String> = None;
+        let mut disabled: Option<bool> = None;
+        let mut emoji: Option<ReactionType> = None;
+        let mut kind: Option<ComponentType> = None;
+        let mut label: Option<String> = None;
+        let mut max_length: Option<u16> = None;
+        let mut max_values: Option<u8> = None;
+        let mut min_length: Option<u16> = None;
+        let mut min_values: Option<u8> = None;
+        let mut options: Option<Vec<SelectMenuOption>> = None;
+        let mut placeholder: Option<String> = None;
+        let mut required: Option<bool> = None;
+        // The type of the style depends on the type of the component, which
+        // may come after it.
+        let mut style: Option<Value> = None;
+        let mut url: Option<String> = None;
+        let mut value: Option<String> = None;
+
+        let span = tracing::trace_span!("deserializing component");
+        let _span_enter = span.enter();
+
+        loop {
+            let span_child = tracing::trace_span!("iterating over component");
+            let _span_child_enter = span_child.enter();
+
+            let key = match map.next_key() {
+                Ok(Some(key)) => {
+                    tracing::trace!(?key, "found key");
+
+                    key
+                }
+                Ok(None) => break,
+                Err(why) => {
+                    // Encountered when we run into an unknown key.
+                    map.next_value::<IgnoredAny>()?;
+
+                    tracing::trace!("ran into an unknown key: {:?}", why);
+
+                    continue;
+                }
+            };
+
+            match key {
+                ComponentField::Components => {
+                    if components.is_some() {
+                        return Err(DeError::duplicate_field("components"));
+                    }
+
+                    components = Some(map.next_value()?);
+                }
+                ComponentField::CustomId => {
+                    if custom_id.is_some() {
+                        return Err(DeError::duplicate_field("custom_id"));
+                    }
+
+                    custom_id = Some(map.next_value()?);
+                }
+                ComponentField::Disabled => {
+                    if disabled.is_some() {
+                        return Err(DeError::duplicate_field("disabled"));
+                    }
+
+                    disabled = Some(map.next_value()?);
+                }
+                ComponentField::Emoji => {
+                    if emoji.is_some() {
+                        return Err(DeError::duplicate_field("emoji"));
+                    }
+
+                    emoji = Some(map.next_value()?);
+                }
+                ComponentField::Label => {
+                    if label.is_some() {
+                        return Err(DeError::duplicate_field("label"));
+                    }
+
+                    label = Some(map.next_value()?);
+                }
+                ComponentField::MaxLength => {
+                    if max_length.is_some() {
+                        return Err(DeError::duplicate_field("max_length"));
+                    }
+
+                    max_length = Some(map.next_value()?);
+                }
+                ComponentField::MaxValues => {
+                    if max_values.is_some() {
+                        return Err(DeError::duplicate_field("max_values"));
+                    }
+
+                    max_values = Some(map.next_value()?);
+                }
+                ComponentField::MinLength => {
+                    if min_length.is_some() {
+                        return Err(DeError::duplicate_field("min_length"));
+                    }
+
+                    min_length = Some(map.next_value()?);
+                }
+                ComponentField::MinValues => {
+                    if min_values.is_some() {
+                        return Err(DeError::duplicate_field("min_values"));
+                    }
+
+                    min_values = Some(map.next_value()?);
+                }
+                ComponentField::Options => {
+                    if options.is_some() {
+                        return Err(DeError::duplicate_field("options"));
+                    }
+
+                    options = Some(map.next_value()?);
+                }
+                ComponentField::Placeholder => {
+                    if placeholder.is_some() {
+                        return Err(DeError::duplicate_field("placeholder"));
+                    }
+
+                    placeholder = Some(map.next_value()?);
+                }
+                ComponentField::Required => {
+                    if required.is_some() {
+                        return Err(DeError::duplicate_field("required"));
+                    }
+
+                    required = Some(map.next_value()?);
+                }
+                ComponentField::Style => {
+                    if style.is_some() {
+                        return Err(DeError::duplicate_field("style"));
+                    }
+
+                    style = Some(map.next_value()?);
+                }
+                ComponentField::Type => {
+                    if kind.is_some() {
+                        return Err(DeError::duplicate_field("type"));
+                    }
+
+                    kind = Some(map.next_value()?);
+                }
+                ComponentField::Url => {
+                    if url.is_some() {
+                        return Err(DeError::duplicate_field("url"));
+                    }
+
+                    url = Some(map.next_value()?);
+                }
+                ComponentField::Value => {
+                    if value.is_some() {
+                        return Err(DeError::duplicate_field("value"));
+                    }
+
+                    value = Some(map.next_value()?);
+                }
+            }
+        }
+
+        let kind = kind.ok_or_else(|| DeError::missing_field("type"))?;
+        let disabled = disabled.unwrap_or_default();
+
+        tracing::trace!(?kind, "common fields of all variants exist");
+
+        Ok(match kind {
+            ComponentType::ActionRow => {
+                let components = components.ok_or_else(|| DeError::missing_field("components"))?;
+
+                Self::Value::ActionRow(ActionRow { components })
+            }
+            ComponentType::Button => {
+                let style = style
+                    .ok_or_else(|| DeError::missing_field("style"))?
+                    .deserialize_into()
+                    .map_err(DeError::custom)?;
+
+                Self::Value::Button(Button {
+                    custom_id,
+                    disabled,
+                    emoji,
+                    label,
+                    style,
+                    url,
+                })
+            }
+            ComponentType::SelectMenu => {
+                let custom_id = custom_id.ok_or_else(|| DeError::missing_field("custom_id"))?;
+                let options = options.ok_or_else(|| DeError::missing_field("options"))?;
+
+                Self::Value::SelectMenu(SelectMenu {
+                    custom_id,
+                    disabled,
+                    max_values,
+                    min_values,
+                    options,
+                    placeholder,
+                })
+            }
+            ComponentType::TextInput => {
+                let custom_id = custom_id.ok_or_else(|| DeError::missing_field("custom_id"))?;
+                let label = label.ok_or_else(|| DeError::missing_field("label"))?;
+                let style = style
+                    .ok_or_else(|| DeError::missing_field("style"))?
+                    .deserialize_into()
+                    .map_err(DeError::custom)?;
+
+                Self::Value::TextInput(TextInput {
+                    custom_id,
+                    label,
+                    max_length,
+                    min_length,
+                    placeholder,
+                    required,
+                    style,
+                    value,
+                })
+            }
+        })
+    }
+}
+
+impl Serialize for Component {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::ActionRow(action_row) => {
+                let mut state = serializer.serialize_struct("Component", 2)?;
+
+                state.serialize_field("type", &self.kind())?;
+                state.serialize_field("components", &action_row.components)?;
+
+                state.end()
+            }
+            Self::Button(button) => {
+                let len = 3
+                    + usize::from(button.custom_id.is_some())
+                    + usize::from(button.emoji.is_some())
+                    + usize::from(button.label.is_some())
+                    + usize::from(button.url.is_some());
+
+                let mut state = serializer.serialize_struct("Component", len)?;
+
+                state.serialize_field("type", &self.kind())?;
+
+                if let Some(custom_id) = &button.custom_id {
+                    state.serialize_field("custom_id", custom_id)?;
+                }
+
+                state.serialize_field("disabled", &button.disabled)?;
+
+                if let Some(emoji) = &button.emoji {
+                    state.serialize_field("emoji", emoji)?;
+                }
+
+                if let Some(label) = &button.label {
+                    state.serialize_field("label", label)?;
+                }
+
+                state.serialize_field("style", &button.style)?;
+
+                if let Some(url) = &button.url {
+                    state.serialize_field("url", url)?;
+                }
+
+                state.end()
+            }
+            Self::SelectMenu(select_menu) => {
+                let len = 4
+                    + usize::from(select_menu.max_values.is_some())
+                    + usize::from(select_menu.min_values.is_some())
+                    + usize::from(select_menu.placeholder.is_some());
+
+                let mut state = serializer.serialize_struct("Component", len)?;
+
+                state.serialize_field("type", &self.kind())?;
+                state.serialize_field("custom_id", &select_menu.custom_id)?;
+                state.serialize_field("disabled", &select_menu.disabled)?;
+
+                if let Some(max_values) = select_menu.max_values {
+                    state.serialize_field("max_values", &max_values)?;
+                }
+
+                if let Some(min_values) = select_menu.min_values {
+                    state.serialize_field("min_values", &min_values)?;
+                }
+
+                state.serialize_field("options", &select_menu.options)?;
+
+                if let Some(placeholder) = &select_menu.placeholder {
+                    state.serialize_field("placeholder", placeholder)?;
+                }
+
+                state.end()
+            }
+            Self::TextInput(text_input) => {
+                let len = 4
+                    + usize::from(text_input.max_length.is_some())
+                    + usize::from(text_input.min_length.is_some())
+                    + usize::from(text_input.placeholder.is_some())
+                    + usize::from(text_input.required.is_some())
+                    + usize::from(text_input.value.is_some());
+
+                let mut state = serializer.serialize_struct("Component", len)?;
+
+                state.serialize_field("type", &self.kind())?;
+                state.serialize_field("custom_id", &text_input.custom_id)?;
+                state.serialize_field("label", &text_input.label)?;
+
+                if let Some(max_length) = text_input.max_length {
+                    state.serialize_field("max_length", &max_length)?;
+                }
+
+                if let Some(min_length) = text_input.min_length {
+                    state.serialize_field("min_length", &min_length)?;
+                }
+
+                if let Some(placeholder) = &text_input.placeholder {
+                    state.serialize_field("placeholder", placeholder)?;
+                }
+
+                if let Some(required) = text_input.required {
+                    state.serialize_field("required", &required)?;
+                }
+
+                state.serialize_field("style", &text_input.style)?;
+
+                if let Some(value) = &text_input.value {
+                    state.serialize_field("value", value)?;
+                }
+
+                state.end()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        ActionRow, Button, ButtonStyle, Component, ComponentType, SelectMenu, SelectMenuOption,
+        TextInput, TextInputStyle,
+    };
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::assert_impl_all;
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_impl_all!(
+        Component: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        From<ActionRow>,
+        From<Button>,
+        From<SelectMenu>,
+        From<TextInput>,
+        Hash,
+        PartialEq,
+        Send,
+        Serialize,
+        Sync
+    );
+
+    #[test]
+    fn test_action_row_buttons() {
+        let value = Component::ActionRow(ActionRow {
+            components: vec![
+                Component::Button(Button {
+                    custom_id: Some("next".to_owned()),
+                    disabled: false,
+                    emoji: None,
+                    label: Some("Next".to_owned()),
+                    style: ButtonStyle::Primary,
+                    url: None,
+                }),
+                Component::Button(Button {
+                    custom_id: None,
+                    disabled: true,
+                    emoji: None,
+                    label: None,
+                    style: ButtonStyle::Link,
+                    url: Some("https://twilight.rs".to_owned()),
+                }),
+            ],
+        });
+
+        assert_eq!(ComponentType::ActionRow, value.kind());
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Component",
+                    len: 2,
+                },
+                Token::Str("type"),
+                Token::U8(1),
+                Token::Str("components"),
+                Token::Seq { len: Some(2) },
+                Token::Struct {
+                    name: "Component",
+                    len: 5,
+                },
+                Token::Str("type"),
+                Token::U8(2),
+                Token::Str("custom_id"),
+                Token::Str("next"),
+                Token::Str("disabled"),
+                Token::Bool(false),
+                Token::Str("label"),
+                Token::Str("Next"),
+                Token::Str("style"),
+                Token::U8(1),
+                Token::StructEnd,
+                Token::Struct {
+                    name: "Component",
+                    len: 4,
+                },
+                Token::Str("type"),
+                Token::U8(2),
+                Token::Str("disabled"),
+                Token::Bool(true),
+                Token::Str("style"),
+                Token::U8(5),
+                Token::Str("url"),
+                Token::Str("https://twilight.rs"),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_select_menu() {
+        let value = Component::SelectMenu(SelectMenu {
+            custom_id: "tracks".to_owned(),
+            disabled: false,
+            max_values: Some(2),
+            min_values: None,
+            options: vec![SelectMenuOption {
+                default: false,
+                description: Some("first track".to_owned()),
+                emoji: None,
+                label: "track 1".to_owned(),
+                value: "1".to_owned(),
+            }],
+            placeholder: Some("choose tracks".to_owned()),
+        });
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Component",
+                    len: 6,
+                },
+                Token::Str("type"),
+                Token::U8(3),
+                Token::Str("custom_id"),
+                Token::Str("tracks"),
+                Token::Str("disabled"),
+                Token::Bool(false),
+                Token::Str("max_values"),
+                Token::U8(2),
+                Token::Str("options"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "SelectMenuOption",
+                    len: 4,
+                },
+                Token::Str("default"),
+                Token::Bool(false),
+                Token::Str("description"),
+                Token::Some,
+                Token::Str("first track"),
+                Token::Str("label"),
+                Token::Str("track 1"),
+                Token::Str("value"),
+                Token::Str("1"),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("placeholder"),
+                Token::Str("choose tracks"),
+                Token::StructEnd,
+            ],
+        );
+    }
+
+    #[test]
+    fn test_text_input() {
+        let value = Component::TextInput(TextInput {
+            custom_id: "feedback".to_owned(),
+            label: "Feedback".to_owned(),
+            max_length: Some(1000),
+            min_length: None,
+            placeholder: Some("What could be improved?".to_owned()),
+            required: Some(false),
+            style: TextInputStyle::Paragraph,
+            value: None,
+        });
+
+        assert_eq!(ComponentType::TextInput, value.kind());
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Component",
+                    len: 7,
+                },
+                Token::Str("type"),
+                Token::U8(4),
+                Token::Str("custom_id"),
+                Token::Str("feedback"),
+                Token::Str("label"),
+                Token::Str("Feedback"),
+                Token::Str("max_length"),
+                Token::U16(1000),
+                Token::Str("placeholder"),
+                Token::Str("What could be improved?"),
+                Token::Str("required"),
+                Token::Bool(false),
+                Token::Str("style"),
+                Token::U8(2),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
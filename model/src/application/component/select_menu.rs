@@ -0,0 +1,97 @@
+use crate::channel::ReactionType;
+use serde::{Deserialize, Serialize};
+
+/// Dropdown menu of options.
+///
+/// Selecting options sends a [`MessageComponent`] interaction with the
+/// [`value`]s of the selected options.
+///
+/// Refer to [the discord docs] for more information.
+///
+/// [`MessageComponent`]: crate::application::interaction::Interaction::MessageComponent
+/// [`value`]: SelectMenuOption::value
+/// [the discord docs]: https://discord.com/developers/docs/interactions/message-components#select-menus
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct SelectMenu {
+    /// Developer defined identifier, sent with the interaction when options
+    /// are selected.
+    pub custom_id: String,
+    /// Whether the menu is disabled.
+    pub disabled: bool,
+    /// Maximum number of options that may be selected.
+    pub max_values: Option<u8>,
+    /// Minimum number of options that must be selected.
+    pub min_values: Option<u8>,
+    /// Options of the menu.
+    pub options: Vec<SelectMenuOption>,
+    /// Text displayed when no option is selected.
+    pub placeholder: Option<String>,
+}
+
+/// Option of a [`SelectMenu`].
+///
+/// Refer to [the discord docs] for more information.
+///
+/// [the discord docs]: https://discord.com/developers/docs/interactions/message-components#select-menu-object-select-option-structure
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct SelectMenuOption {
+    /// Whether the option is selected by default.
+    #[serde(default)]
+    pub default: bool,
+    /// Additional description of the option.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Emoji displayed next to the option.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<ReactionType>,
+    /// Text displayed for the option.
+    pub label: String,
+    /// Developer defined value of the option, sent with the interaction.
+    pub value: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SelectMenuOption;
+    use crate::channel::ReactionType;
+    use serde_test::Token;
+
+    #[test]
+    fn test_select_menu_option() {
+        let value = SelectMenuOption {
+            default: true,
+            description: None,
+            emoji: Some(ReactionType::Unicode {
+                name: "\u{1f3b5}".to_owned(),
+            }),
+            label: "music".to_owned(),
+            value: "1".to_owned(),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "SelectMenuOption",
+                    len: 4,
+                },
+                Token::Str("default"),
+                Token::Bool(true),
+                Token::Str("emoji"),
+                Token::Some,
+                Token::Struct {
+                    name: "ReactionType",
+                    len: 1,
+                },
+                Token::Str("name"),
+                Token::Str("\u{1f3b5}"),
+                Token::StructEnd,
+                Token::Str("label"),
+                Token::Str("music"),
+                Token::Str("value"),
+                Token::Str("1"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Field for users to enter text into.
+///
+/// Text inputs can only be used in [`Modal`]s, which send a [`ModalSubmit`]
+/// interaction with the entered text once submitted.
+///
+/// Refer to [the discord docs] for more information.
+///
+/// [`Modal`]: crate::application::callback::Modal
+/// [`ModalSubmit`]: crate::application::interaction::Interaction::ModalSubmit
+/// [the discord docs]: https://discord.com/developers/docs/interactions/message-components#text-inputs
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct TextInput {
+    /// Developer defined identifier, sent with the submitted text.
+    pub custom_id: String,
+    /// Text displayed above the input.
+    pub label: String,
+    /// Maximum length of the input.
+    pub max_length: Option<u16>,
+    /// Minimum length of the input.
+    pub min_length: Option<u16>,
+    /// Text displayed when the input is empty.
+    pub placeholder: Option<String>,
+    /// Whether the input must be filled in.
+    ///
+    /// Defaults to `true`.
+    pub required: Option<bool>,
+    /// Style of the input.
+    pub style: TextInputStyle,
+    /// Text the input is pre-filled with.
+    pub value: Option<String>,
+}
+
+/// Style of a [`TextInput`].
+///
+/// Refer to [the discord docs] for more information.
+///
+/// [the discord docs]: https://discord.com/developers/docs/interactions/message-components#text-inputs-text-input-styles
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u8)]
+pub enum TextInputStyle {
+    /// Single line input.
+    Short = 1,
+    /// Multi line input.
+    Paragraph = 2,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TextInputStyle;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&TextInputStyle::Short, &[Token::U8(1)]);
+        serde_test::assert_tokens(&TextInputStyle::Paragraph, &[Token::U8(2)]);
+    }
+}
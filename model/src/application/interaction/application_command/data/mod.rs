@@ -2,7 +2,11 @@ mod resolved;
 
 pub use self::resolved::{CommandInteractionDataResolved, InteractionChannel, InteractionMember};
 
-use crate::id::CommandId;
+use crate::{
+    channel::Message,
+    id::{CommandId, GenericId, MessageId, UserId},
+    user::User,
+};
 use serde::{Deserialize, Serialize};
 
 /// Data received when an [`ApplicationCommand`] interaction is executed.
@@ -21,7 +25,60 @@ pub struct CommandData {
     #[serde(default)]
     pub options: Vec<CommandDataOption>,
     /// Data sent if any of the options are discord types.
+    ///
+    /// Also contains the target of context menu commands.
     pub resolved: Option<CommandInteractionDataResolved>,
+    /// ID of the user or message targeted by a context menu command.
+    ///
+    /// Use [`target_user`], [`target_member`] or [`target_message`] to get the
+    /// resolved target.
+    ///
+    /// [`target_user`]: Self::target_user
+    /// [`target_member`]: Self::target_member
+    /// [`target_message`]: Self::target_message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_id: Option<GenericId>,
+}
+
+impl CommandData {
+    /// User targeted by a [`User`] context menu command.
+    ///
+    /// [`User`]: crate::application::command::CommandType::User
+    pub fn target_user(&self) -> Option<&User> {
+        let target_id = UserId(self.target_id?.0);
+
+        self.resolved
+            .as_ref()?
+            .users
+            .iter()
+            .find(|user| user.id == target_id)
+    }
+
+    /// Member targeted by a [`User`] context menu command used in a guild.
+    ///
+    /// [`User`]: crate::application::command::CommandType::User
+    pub fn target_member(&self) -> Option<&InteractionMember> {
+        let target_id = UserId(self.target_id?.0);
+
+        self.resolved
+            .as_ref()?
+            .members
+            .iter()
+            .find(|member| member.id == target_id)
+    }
+
+    /// Message targeted by a [`Message`] context menu command.
+    ///
+    /// [`Message`]: crate::application::command::CommandType::Message
+    pub fn target_message(&self) -> Option<&Message> {
+        let target_id = MessageId(self.target_id?.0);
+
+        self.resolved
+            .as_ref()?
+            .messages
+            .iter()
+            .find(|message| message.id == target_id)
+    }
 }
 
 /// Data received when a user fills in a command option.
@@ -1,7 +1,7 @@
 use crate::{
-    channel::ChannelType,
+    channel::{ChannelType, Message},
     guild::{Permissions, Role},
-    id::{ChannelId, RoleId, UserId},
+    id::{ChannelId, MessageId, RoleId, UserId},
     user::User,
 };
 use serde::{
@@ -18,6 +18,7 @@ use std::{
 pub struct CommandInteractionDataResolved {
     pub channels: Vec<InteractionChannel>,
     pub members: Vec<InteractionMember>,
+    pub messages: Vec<Message>,
     pub roles: Vec<Role>,
     pub users: Vec<User>,
 }
@@ -33,6 +34,7 @@ impl Serialize for CommandInteractionDataResolved {
         let len = vec![
             self.channels.is_empty(),
             self.members.is_empty(),
+            self.messages.is_empty(),
             self.roles.is_empty(),
             self.users.is_empty(),
         ]
@@ -64,6 +66,17 @@ impl Serialize for CommandInteractionDataResolved {
             state.serialize_field("members", &map)?;
         }
 
+        if !self.messages.is_empty() {
+            let map: HashMap<MessageId, &Message, RandomState> = self
+                .messages
+                .iter()
+                .map(|m| m.id)
+                .zip(self.messages.iter())
+                .collect();
+
+            state.serialize_field("messages", &map)?;
+        }
+
         if !self.roles.is_empty() {
             let map: HashMap<RoleId, &Role, RandomState> = self
                 .roles
@@ -95,6 +108,7 @@ impl Serialize for CommandInteractionDataResolved {
 enum ResolvedField {
     Channels,
     Members,
+    Messages,
     Roles,
     Users,
 }
@@ -111,6 +125,7 @@ impl<'de> Visitor<'de> for ResolvedVisitor {
     fn visit_map<V: MapAccess<'de>>(self, mut map: V) -> Result<Self::Value, V::Error> {
         let mut channels: Option<Vec<InteractionChannel>> = None;
         let mut members: Option<Vec<InteractionMember>> = None;
+        let mut messages: Option<Vec<Message>> = None;
         let mut roles: Option<Vec<Role>> = None;
         let mut users: Option<Vec<User>> = None;
 
@@ -154,6 +169,15 @@ impl<'de> Visitor<'de> for ResolvedVisitor {
                             .collect(),
                     );
                 }
+                ResolvedField::Messages => {
+                    if messages.is_some() {
+                        return Err(DeError::duplicate_field("messages"));
+                    }
+
+                    let map_messages: HashMap<MessageId, Message> = map.next_value()?;
+
+                    messages = Some(map_messages.into_iter().map(|(_, v)| v).collect());
+                }
                 ResolvedField::Roles => {
                     if roles.is_some() {
                         return Err(DeError::duplicate_field("roles"));
@@ -178,6 +202,7 @@ impl<'de> Visitor<'de> for ResolvedVisitor {
         Ok(CommandInteractionDataResolved {
             channels: channels.unwrap_or_default(),
             members: members.unwrap_or_default(),
+            messages: messages.unwrap_or_default(),
             roles: roles.unwrap_or_default(),
             users: users.unwrap_or_default(),
         })
@@ -249,6 +274,7 @@ mod tests {
                 premium_since: None,
                 roles: Vec::new(),
             }],
+            messages: Vec::new(),
             roles: vec![Role {
                 color: 0,
                 hoist: true,
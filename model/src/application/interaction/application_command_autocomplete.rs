@@ -0,0 +1,238 @@
+use super::InteractionType;
+use crate::{
+    application::command::CommandOptionType,
+    guild::PartialMember,
+    id::{ApplicationId, ChannelId, CommandId, GuildId, InteractionId},
+    user::User,
+};
+use serde::{
+    de::{Deserializer, Error as DeError, Visitor},
+    Deserialize, Serialize,
+};
+use std::fmt::{Formatter, Result as FmtResult};
+
+/// Data present in an [`Interaction`] of type [`ApplicationCommandAutocomplete`].
+///
+/// [`Interaction`]: super::Interaction
+/// [`ApplicationCommandAutocomplete`]: super::Interaction::ApplicationCommandAutocomplete
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename(serialize = "Interaction"))]
+pub struct ApplicationCommandAutocomplete {
+    /// ID of the associated application.
+    pub application_id: ApplicationId,
+    /// The channel the interaction was triggered from.
+    pub channel_id: ChannelId,
+    /// Data from the command being completed.
+    pub data: ApplicationCommandAutocompleteData,
+    /// ID of the guild the interaction was triggered from.
+    pub guild_id: Option<GuildId>,
+    /// ID of the interaction.
+    pub id: InteractionId,
+    /// Kind of the interaction.
+    #[serde(rename = "type")]
+    pub kind: InteractionType,
+    /// Member that triggered the interaction.
+    ///
+    /// Present when the command is used in a guild.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member: Option<PartialMember>,
+    /// Token of the interaction.
+    pub token: String,
+    /// User that triggered the interaction.
+    ///
+    /// Present when the command is used in a direct message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+}
+
+/// Data received when an [`ApplicationCommandAutocomplete`] interaction is
+/// executed.
+///
+/// Refer to [the discord docs] for more information.
+///
+/// [`ApplicationCommandAutocomplete`]: crate::application::interaction::Interaction::ApplicationCommandAutocomplete
+/// [the discord docs]: https://discord.com/developers/docs/interactions/application-commands#autocomplete
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ApplicationCommandAutocompleteData {
+    /// ID of the command.
+    pub id: CommandId,
+    /// Name of the command.
+    pub name: String,
+    /// Options the user has filled in so far.
+    #[serde(default)]
+    pub options: Vec<ApplicationCommandAutocompleteDataOption>,
+}
+
+impl ApplicationCommandAutocompleteData {
+    /// Option the user is currently typing in, searching through
+    /// subcommands and subcommand groups.
+    ///
+    /// Its [`value`] is the partial input to complete.
+    ///
+    /// [`value`]: ApplicationCommandAutocompleteDataOption::value
+    pub fn focused(&self) -> Option<&ApplicationCommandAutocompleteDataOption> {
+        find_focused(&self.options)
+    }
+}
+
+fn find_focused(
+    options: &[ApplicationCommandAutocompleteDataOption],
+) -> Option<&ApplicationCommandAutocompleteDataOption> {
+    options.iter().find_map(|option| {
+        if option.focused {
+            Some(option)
+        } else {
+            find_focused(&option.options)
+        }
+    })
+}
+
+/// Option filled in by a user while completing a command.
+///
+/// Unlike [`CommandDataOption`], values are received as they were typed and
+/// may be incomplete, so they're always provided as strings.
+///
+/// [`CommandDataOption`]: super::application_command::CommandDataOption
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ApplicationCommandAutocompleteDataOption {
+    /// Whether the option is the one the user is currently typing in.
+    #[serde(default)]
+    pub focused: bool,
+    /// Type of the option.
+    #[serde(rename = "type")]
+    pub kind: CommandOptionType,
+    /// Name of the option.
+    pub name: String,
+    /// Options of a subcommand or subcommand group.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<ApplicationCommandAutocompleteDataOption>,
+    /// Input of the option.
+    ///
+    /// Not present for subcommands and subcommand groups.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_value",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub value: Option<String>,
+}
+
+/// Deserialize an option value of any primitive type as a string.
+fn deserialize_value<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<String>, D::Error> {
+    deserializer.deserialize_any(OptionValueVisitor)
+}
+
+struct OptionValueVisitor;
+
+impl<'de> Visitor<'de> for OptionValueVisitor {
+    type Value = Option<String>;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("a string, number, or boolean option value")
+    }
+
+    fn visit_bool<E: DeError>(self, value: bool) -> Result<Self::Value, E> {
+        Ok(Some(value.to_string()))
+    }
+
+    fn visit_i64<E: DeError>(self, value: i64) -> Result<Self::Value, E> {
+        Ok(Some(value.to_string()))
+    }
+
+    fn visit_u64<E: DeError>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(Some(value.to_string()))
+    }
+
+    fn visit_f64<E: DeError>(self, value: f64) -> Result<Self::Value, E> {
+        Ok(Some(value.to_string()))
+    }
+
+    fn visit_str<E: DeError>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(Some(value.to_owned()))
+    }
+
+    fn visit_string<E: DeError>(self, value: String) -> Result<Self::Value, E> {
+        Ok(Some(value))
+    }
+
+    fn visit_none<E: DeError>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_unit<E: DeError>(self) -> Result<Self::Value, E> {
+        Ok(None)
+    }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_any(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ApplicationCommandAutocompleteData, ApplicationCommandAutocompleteDataOption};
+    use crate::{application::command::CommandOptionType, id::CommandId};
+    use serde_test::Token;
+
+    #[test]
+    fn test_focused() {
+        let value = ApplicationCommandAutocompleteData {
+            id: CommandId(1),
+            name: "music".to_owned(),
+            options: vec![ApplicationCommandAutocompleteDataOption {
+                focused: false,
+                kind: CommandOptionType::SubCommand,
+                name: "play".to_owned(),
+                options: vec![
+                    ApplicationCommandAutocompleteDataOption {
+                        focused: false,
+                        kind: CommandOptionType::Integer,
+                        name: "volume".to_owned(),
+                        options: Vec::new(),
+                        value: Some("50".to_owned()),
+                    },
+                    ApplicationCommandAutocompleteDataOption {
+                        focused: true,
+                        kind: CommandOptionType::String,
+                        name: "track".to_owned(),
+                        options: Vec::new(),
+                        value: Some("never gonna".to_owned()),
+                    },
+                ],
+                value: None,
+            }],
+        };
+
+        let focused = value.focused().unwrap();
+        assert_eq!("track", focused.name);
+        assert_eq!(Some("never gonna"), focused.value.as_deref());
+    }
+
+    #[test]
+    fn test_option_value_number() {
+        serde_test::assert_de_tokens(
+            &ApplicationCommandAutocompleteDataOption {
+                focused: false,
+                kind: CommandOptionType::Integer,
+                name: "volume".to_owned(),
+                options: Vec::new(),
+                value: Some("50".to_owned()),
+            },
+            &[
+                Token::Struct {
+                    name: "ApplicationCommandAutocompleteDataOption",
+                    len: 3,
+                },
+                Token::Str("type"),
+                Token::U8(4),
+                Token::Str("name"),
+                Token::Str("volume"),
+                Token::Str("value"),
+                Token::I64(50),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -15,6 +15,9 @@ use std::fmt::{Display, Formatter, Result as FmtResult};
 pub enum InteractionType {
     Ping = 1,
     ApplicationCommand = 2,
+    MessageComponent = 3,
+    ApplicationCommandAutocomplete = 4,
+    ModalSubmit = 5,
 }
 
 impl InteractionType {
@@ -22,6 +25,9 @@ impl InteractionType {
         match self {
             Self::Ping => "Ping",
             Self::ApplicationCommand => "ApplicationCommand",
+            Self::MessageComponent => "MessageComponent",
+            Self::ApplicationCommandAutocomplete => "ApplicationCommandAutocomplete",
+            Self::ModalSubmit => "ModalSubmit",
         }
     }
 }
@@ -46,6 +52,9 @@ impl TryFrom<u8> for InteractionType {
         match i {
             1 => Ok(Self::Ping),
             2 => Ok(Self::ApplicationCommand),
+            3 => Ok(Self::MessageComponent),
+            4 => Ok(Self::ApplicationCommandAutocomplete),
+            5 => Ok(Self::ModalSubmit),
             other => Err(UnknownInteractionTypeError { value: other }),
         }
     }
@@ -0,0 +1,62 @@
+use super::InteractionType;
+use crate::{
+    application::component::ComponentType,
+    channel::Message,
+    guild::PartialMember,
+    id::{ApplicationId, ChannelId, GuildId, InteractionId},
+    user::User,
+};
+use serde::{Deserialize, Serialize};
+
+/// Data present in an [`Interaction`] of type [`MessageComponent`].
+///
+/// [`Interaction`]: super::Interaction
+/// [`MessageComponent`]: super::Interaction::MessageComponent
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename(serialize = "Interaction"))]
+pub struct MessageComponentInteraction {
+    /// ID of the associated application.
+    pub application_id: ApplicationId,
+    /// The channel the interaction was triggered from.
+    pub channel_id: ChannelId,
+    /// Data from the interacted component.
+    pub data: MessageComponentInteractionData,
+    /// ID of the guild the interaction was triggered from.
+    pub guild_id: Option<GuildId>,
+    /// ID of the interaction.
+    pub id: InteractionId,
+    /// Kind of the interaction.
+    #[serde(rename = "type")]
+    pub kind: InteractionType,
+    /// Member that triggered the interaction.
+    ///
+    /// Present when the component is used in a guild.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member: Option<PartialMember>,
+    /// Message the interacted component is attached to.
+    pub message: Message,
+    /// Token of the interaction.
+    pub token: String,
+    /// User that triggered the interaction.
+    ///
+    /// Present when the component is used in a direct message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+}
+
+/// Data received when a [`MessageComponent`] interaction is executed.
+///
+/// Refer to [the discord docs] for more information.
+///
+/// [`MessageComponent`]: crate::application::interaction::Interaction::MessageComponent
+/// [the discord docs]: https://discord.com/developers/docs/interactions/slash-commands#interaction-object-interaction-data-structure
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct MessageComponentInteractionData {
+    /// Developer defined identifier of the interacted component.
+    pub custom_id: String,
+    /// Type of the interacted component.
+    pub component_type: ComponentType,
+    /// Values of the selected options, if the component is a select menu.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub values: Vec<String>,
+}
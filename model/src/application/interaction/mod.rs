@@ -1,15 +1,22 @@
 //! Used when recieving interactions through gateway or webhooks.
 
 pub mod application_command;
+pub mod application_command_autocomplete;
+pub mod message_component;
+pub mod modal_submit;
+
 mod interaction_type;
 mod ping;
 
 pub use self::{
-    application_command::ApplicationCommand, interaction_type::InteractionType, ping::Ping,
+    application_command::ApplicationCommand,
+    application_command_autocomplete::ApplicationCommandAutocomplete,
+    interaction_type::InteractionType, message_component::MessageComponentInteraction,
+    modal_submit::ModalSubmitInteraction, ping::Ping,
 };
 
 use crate::{
-    application::interaction::application_command::CommandData,
+    channel::Message,
     guild::PartialMember,
     id::{ApplicationId, ChannelId, GuildId, InteractionId},
     user::User,
@@ -18,6 +25,7 @@ use serde::{
     de::{Deserializer, Error as DeError, IgnoredAny, MapAccess, Visitor},
     Deserialize, Serialize,
 };
+use serde_value::Value;
 use std::fmt::{Formatter, Result as FmtResult};
 
 /// Payload received when a user executes an interaction.
@@ -34,6 +42,12 @@ pub enum Interaction {
     Ping(Box<Ping>),
     /// Application command variant.
     ApplicationCommand(Box<ApplicationCommand>),
+    /// Message component variant.
+    MessageComponent(Box<MessageComponentInteraction>),
+    /// Application command autocomplete variant.
+    ApplicationCommandAutocomplete(Box<ApplicationCommandAutocomplete>),
+    /// Modal submit variant.
+    ModalSubmit(Box<ModalSubmitInteraction>),
 }
 
 impl Interaction {
@@ -41,6 +55,9 @@ impl Interaction {
         match self {
             Self::Ping(_) => None,
             Self::ApplicationCommand(inner) => inner.guild_id,
+            Self::MessageComponent(inner) => inner.guild_id,
+            Self::ApplicationCommandAutocomplete(inner) => inner.guild_id,
+            Self::ModalSubmit(inner) => inner.guild_id,
         }
     }
 }
@@ -60,6 +77,7 @@ enum InteractionField {
     GuildId,
     Id,
     Member,
+    Message,
     Token,
     Type,
     User,
@@ -78,10 +96,13 @@ impl<'de> Visitor<'de> for InteractionVisitor {
     fn visit_map<V: MapAccess<'de>>(self, mut map: V) -> Result<Self::Value, V::Error> {
         let mut application_id: Option<ApplicationId> = None;
         let mut channel_id: Option<ChannelId> = None;
-        let mut data: Option<CommandData> = None;
+        // The type of the data depends on the type of the interaction, which
+        // may come after it.
+        let mut data: Option<Value> = None;
         let mut guild_id: Option<Option<GuildId>> = None;
         let mut id: Option<InteractionId> = None;
         let mut member: Option<Option<PartialMember>> = None;
+        let mut message: Option<Message> = None;
         let mut token: Option<String> = None;
         let mut kind: Option<InteractionType> = None;
         let mut user: Option<Option<User>> = None;
@@ -153,6 +174,13 @@ impl<'de> Visitor<'de> for InteractionVisitor {
 
                     member = Some(map.next_value()?);
                 }
+                InteractionField::Message => {
+                    if message.is_some() {
+                        return Err(DeError::duplicate_field("message"));
+                    }
+
+                    message = Some(map.next_value()?);
+                }
                 InteractionField::Token => {
                     if token.is_some() {
                         return Err(DeError::duplicate_field("token"));
@@ -204,7 +232,10 @@ impl<'de> Visitor<'de> for InteractionVisitor {
             }
             InteractionType::ApplicationCommand => {
                 let channel_id = channel_id.ok_or_else(|| DeError::missing_field("channel_id"))?;
-                let data = data.ok_or_else(|| DeError::missing_field("data"))?;
+                let data = data
+                    .ok_or_else(|| DeError::missing_field("data"))?
+                    .deserialize_into()
+                    .map_err(DeError::custom)?;
 
                 let guild_id = guild_id.unwrap_or_default();
                 let member = member.unwrap_or_default();
@@ -224,6 +255,86 @@ impl<'de> Visitor<'de> for InteractionVisitor {
                     user,
                 }))
             }
+            InteractionType::MessageComponent => {
+                let channel_id = channel_id.ok_or_else(|| DeError::missing_field("channel_id"))?;
+                let data = data
+                    .ok_or_else(|| DeError::missing_field("data"))?
+                    .deserialize_into()
+                    .map_err(DeError::custom)?;
+                let message = message.ok_or_else(|| DeError::missing_field("message"))?;
+
+                let guild_id = guild_id.unwrap_or_default();
+                let member = member.unwrap_or_default();
+                let user = user.unwrap_or_default();
+
+                tracing::trace!(%channel_id, "handling message component");
+
+                Self::Value::MessageComponent(Box::new(MessageComponentInteraction {
+                    application_id,
+                    channel_id,
+                    data,
+                    guild_id,
+                    id,
+                    kind,
+                    member,
+                    message,
+                    token,
+                    user,
+                }))
+            }
+            InteractionType::ApplicationCommandAutocomplete => {
+                let channel_id = channel_id.ok_or_else(|| DeError::missing_field("channel_id"))?;
+                let data = data
+                    .ok_or_else(|| DeError::missing_field("data"))?
+                    .deserialize_into()
+                    .map_err(DeError::custom)?;
+
+                let guild_id = guild_id.unwrap_or_default();
+                let member = member.unwrap_or_default();
+                let user = user.unwrap_or_default();
+
+                tracing::trace!(%channel_id, "handling application command autocomplete");
+
+                Self::Value::ApplicationCommandAutocomplete(Box::new(
+                    ApplicationCommandAutocomplete {
+                        application_id,
+                        channel_id,
+                        data,
+                        guild_id,
+                        id,
+                        kind,
+                        member,
+                        token,
+                        user,
+                    },
+                ))
+            }
+            InteractionType::ModalSubmit => {
+                let channel_id = channel_id.ok_or_else(|| DeError::missing_field("channel_id"))?;
+                let data = data
+                    .ok_or_else(|| DeError::missing_field("data"))?
+                    .deserialize_into()
+                    .map_err(DeError::custom)?;
+
+                let guild_id = guild_id.unwrap_or_default();
+                let member = member.unwrap_or_default();
+                let user = user.unwrap_or_default();
+
+                tracing::trace!(%channel_id, "handling modal submit");
+
+                Self::Value::ModalSubmit(Box::new(ModalSubmitInteraction {
+                    application_id,
+                    channel_id,
+                    data,
+                    guild_id,
+                    id,
+                    kind,
+                    member,
+                    message,
+                    token,
+                    user,
+                }))
+            }
         })
     }
 }
@@ -231,14 +342,31 @@ impl<'de> Visitor<'de> for InteractionVisitor {
 #[cfg(test)]
 mod test {
     use crate::{
-        application::interaction::{
-            application_command::{
-                ApplicationCommand, CommandData, CommandDataOption, CommandInteractionDataResolved,
+        application::{
+            command::CommandOptionType,
+            component::{ActionRow, Component, ComponentType, SelectMenu, SelectMenuOption},
+            interaction::{
+                application_command::{
+                    ApplicationCommand, CommandData, CommandDataOption,
+                    CommandInteractionDataResolved,
+                },
+                application_command_autocomplete::{
+                    ApplicationCommandAutocomplete, ApplicationCommandAutocompleteData,
+                    ApplicationCommandAutocompleteDataOption,
+                },
+                message_component::MessageComponentInteractionData,
+                modal_submit::{
+                    ModalSubmitActionRow, ModalSubmitComponent, ModalSubmitInteraction,
+                    ModalSubmitInteractionData,
+                },
+                Interaction, InteractionType,
             },
-            Interaction, InteractionType,
         },
         guild::{PartialMember, Permissions},
-        id::{ApplicationId, ChannelId, CommandId, GuildId, InteractionId, UserId},
+        id::{
+            ApplicationId, ChannelId, CommandId, GenericId, GuildId, InteractionId, MessageId,
+            UserId,
+        },
         user::User,
     };
     use serde_test::Token;
@@ -259,6 +387,7 @@ mod test {
                 resolved: Some(CommandInteractionDataResolved {
                     channels: Vec::new(),
                     members: Vec::new(),
+                    messages: Vec::new(),
                     roles: Vec::new(),
                     users: vec![User {
                         avatar: Some("avatar string".into()),
@@ -276,6 +405,7 @@ mod test {
                         verified: None,
                     }],
                 }),
+                target_id: None,
             },
             guild_id: Some(GuildId(400)),
             id: InteractionId(500),
@@ -434,4 +564,365 @@ mod test {
             ],
         );
     }
+
+    #[test]
+    fn test_message_component() {
+        let input = r#"{
+            "application_id": "1",
+            "channel_id": "2",
+            "data": {
+                "component_type": 3,
+                "custom_id": "tracks",
+                "values": ["first", "second"]
+            },
+            "guild_id": "3",
+            "id": "4",
+            "message": {
+                "attachments": [],
+                "author": {
+                    "avatar": null,
+                    "discriminator": "0001",
+                    "id": "5",
+                    "username": "bot"
+                },
+                "channel_id": "2",
+                "components": [{
+                    "type": 1,
+                    "components": [{
+                        "type": 3,
+                        "custom_id": "tracks",
+                        "options": [{
+                            "label": "first track",
+                            "value": "first"
+                        }]
+                    }]
+                }],
+                "content": "choose tracks",
+                "edited_timestamp": null,
+                "embeds": [],
+                "id": "6",
+                "mention_everyone": false,
+                "mention_roles": [],
+                "mentions": [],
+                "pinned": false,
+                "timestamp": "2021-09-19T14:17:32.000000+00:00",
+                "tts": false,
+                "type": 0
+            },
+            "token": "interaction token",
+            "type": 3
+        }"#;
+
+        let interaction = serde_json::from_str::<Interaction>(input).unwrap();
+        assert_eq!(Some(GuildId(3)), interaction.guild_id());
+
+        let component = match interaction {
+            Interaction::MessageComponent(component) => component,
+            other => panic!("expected a message component interaction: {:?}", other),
+        };
+
+        assert_eq!(
+            MessageComponentInteractionData {
+                custom_id: "tracks".to_owned(),
+                component_type: ComponentType::SelectMenu,
+                values: vec!["first".to_owned(), "second".to_owned()],
+            },
+            component.data
+        );
+        assert_eq!(InteractionType::MessageComponent, component.kind);
+        assert_eq!(MessageId(6), component.message.id);
+        assert_eq!(
+            vec![Component::ActionRow(ActionRow {
+                components: vec![Component::SelectMenu(SelectMenu {
+                    custom_id: "tracks".to_owned(),
+                    disabled: false,
+                    max_values: None,
+                    min_values: None,
+                    options: vec![SelectMenuOption {
+                        default: false,
+                        description: None,
+                        emoji: None,
+                        label: "first track".to_owned(),
+                        value: "first".to_owned(),
+                    }],
+                    placeholder: None,
+                })],
+            })],
+            component.message.components
+        );
+    }
+
+    #[test]
+    #[allow(clippy::too_many_lines)]
+    fn test_application_command_autocomplete() {
+        let value =
+            Interaction::ApplicationCommandAutocomplete(Box::new(ApplicationCommandAutocomplete {
+                application_id: ApplicationId(1),
+                channel_id: ChannelId(2),
+                data: ApplicationCommandAutocompleteData {
+                    id: CommandId(3),
+                    name: "play".into(),
+                    options: vec![ApplicationCommandAutocompleteDataOption {
+                        focused: true,
+                        kind: CommandOptionType::String,
+                        name: "track".into(),
+                        options: Vec::new(),
+                        value: Some("never gonna".into()),
+                    }],
+                },
+                guild_id: None,
+                id: InteractionId(4),
+                kind: InteractionType::ApplicationCommandAutocomplete,
+                member: None,
+                token: "interaction token".into(),
+                user: Some(User {
+                    avatar: None,
+                    bot: false,
+                    discriminator: "0001".into(),
+                    email: None,
+                    flags: None,
+                    id: UserId(5),
+                    locale: None,
+                    mfa_enabled: None,
+                    name: "username".into(),
+                    premium_type: None,
+                    public_flags: None,
+                    system: None,
+                    verified: None,
+                }),
+            }));
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Interaction",
+                    len: 8,
+                },
+                Token::Str("application_id"),
+                Token::NewtypeStruct {
+                    name: "ApplicationId",
+                },
+                Token::Str("1"),
+                Token::Str("channel_id"),
+                Token::NewtypeStruct { name: "ChannelId" },
+                Token::Str("2"),
+                Token::Str("data"),
+                Token::Struct {
+                    name: "ApplicationCommandAutocompleteData",
+                    len: 3,
+                },
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "CommandId" },
+                Token::Str("3"),
+                Token::Str("name"),
+                Token::Str("play"),
+                Token::Str("options"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "ApplicationCommandAutocompleteDataOption",
+                    len: 4,
+                },
+                Token::Str("focused"),
+                Token::Bool(true),
+                Token::Str("type"),
+                Token::U8(3),
+                Token::Str("name"),
+                Token::Str("track"),
+                Token::Str("value"),
+                Token::Some,
+                Token::Str("never gonna"),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::StructEnd,
+                Token::Str("guild_id"),
+                Token::None,
+                Token::Str("id"),
+                Token::NewtypeStruct {
+                    name: "InteractionId",
+                },
+                Token::Str("4"),
+                Token::Str("type"),
+                Token::U8(4),
+                Token::Str("token"),
+                Token::Str("interaction token"),
+                Token::Str("user"),
+                Token::Some,
+                Token::Struct {
+                    name: "User",
+                    len: 5,
+                },
+                Token::Str("avatar"),
+                Token::None,
+                Token::Str("bot"),
+                Token::Bool(false),
+                Token::Str("discriminator"),
+                Token::Str("0001"),
+                Token::Str("id"),
+                Token::NewtypeStruct { name: "UserId" },
+                Token::Str("5"),
+                Token::Str("username"),
+                Token::Str("username"),
+                Token::StructEnd,
+                Token::StructEnd,
+            ],
+        );
+
+        let data = match value {
+            Interaction::ApplicationCommandAutocomplete(autocomplete) => autocomplete.data,
+            other => panic!("expected an autocomplete interaction: {:?}", other),
+        };
+        assert_eq!(
+            Some("never gonna"),
+            data.focused().and_then(|o| o.value.as_deref())
+        );
+    }
+
+    #[test]
+    #[allow(clippy::too_many_lines)]
+    fn test_modal_submit() {
+        let value = Interaction::ModalSubmit(Box::new(ModalSubmitInteraction {
+            application_id: ApplicationId(1),
+            channel_id: ChannelId(2),
+            data: ModalSubmitInteractionData {
+                components: vec![ModalSubmitActionRow {
+                    components: vec![ModalSubmitComponent {
+                        custom_id: "name".into(),
+                        kind: ComponentType::TextInput,
+                        value: "twilight".into(),
+                    }],
+                    kind: ComponentType::ActionRow,
+                }],
+                custom_id: "signup".into(),
+            },
+            guild_id: Some(GuildId(3)),
+            id: InteractionId(4),
+            kind: InteractionType::ModalSubmit,
+            member: None,
+            message: None,
+            token: "interaction token".into(),
+            user: None,
+        }));
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "Interaction",
+                    len: 7,
+                },
+                Token::Str("application_id"),
+                Token::NewtypeStruct {
+                    name: "ApplicationId",
+                },
+                Token::Str("1"),
+                Token::Str("channel_id"),
+                Token::NewtypeStruct { name: "ChannelId" },
+                Token::Str("2"),
+                Token::Str("data"),
+                Token::Struct {
+                    name: "ModalSubmitInteractionData",
+                    len: 2,
+                },
+                Token::Str("components"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "ModalSubmitActionRow",
+                    len: 2,
+                },
+                Token::Str("components"),
+                Token::Seq { len: Some(1) },
+                Token::Struct {
+                    name: "ModalSubmitComponent",
+                    len: 3,
+                },
+                Token::Str("custom_id"),
+                Token::Str("name"),
+                Token::Str("type"),
+                Token::U8(4),
+                Token::Str("value"),
+                Token::Str("twilight"),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("type"),
+                Token::U8(1),
+                Token::StructEnd,
+                Token::SeqEnd,
+                Token::Str("custom_id"),
+                Token::Str("signup"),
+                Token::StructEnd,
+                Token::Str("guild_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "GuildId" },
+                Token::Str("3"),
+                Token::Str("id"),
+                Token::NewtypeStruct {
+                    name: "InteractionId",
+                },
+                Token::Str("4"),
+                Token::Str("type"),
+                Token::U8(5),
+                Token::Str("token"),
+                Token::Str("interaction token"),
+                Token::StructEnd,
+            ],
+        );
+
+        let data = match value {
+            Interaction::ModalSubmit(modal) => modal.data,
+            other => panic!("expected a modal submit interaction: {:?}", other),
+        };
+        assert_eq!(Some("twilight"), data.value("name"));
+        assert_eq!(None, data.value("email"));
+    }
+
+    #[test]
+    fn test_user_context_menu() {
+        let input = r#"{
+            "application_id": "1",
+            "channel_id": "2",
+            "data": {
+                "id": "3",
+                "name": "User info",
+                "resolved": {
+                    "members": {
+                        "4": {
+                            "joined_at": "2021-09-19T14:17:32.000000+00:00",
+                            "roles": []
+                        }
+                    },
+                    "users": {
+                        "4": {
+                            "avatar": null,
+                            "discriminator": "0001",
+                            "id": "4",
+                            "username": "target"
+                        }
+                    }
+                },
+                "target_id": "4",
+                "type": 2
+            },
+            "guild_id": "5",
+            "id": "6",
+            "token": "interaction token",
+            "type": 2
+        }"#;
+
+        let command = match serde_json::from_str::<Interaction>(input).unwrap() {
+            Interaction::ApplicationCommand(command) => command,
+            other => panic!("expected an application command interaction: {:?}", other),
+        };
+
+        assert_eq!(Some(GenericId(4)), command.data.target_id);
+        assert_eq!(
+            Some("target"),
+            command.data.target_user().map(|user| user.name.as_str())
+        );
+        assert_eq!(
+            Some(UserId(4)),
+            command.data.target_member().map(|member| member.id)
+        );
+        assert!(command.data.target_message().is_none());
+    }
 }
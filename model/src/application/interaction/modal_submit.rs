@@ -0,0 +1,98 @@
+use super::InteractionType;
+use crate::{
+    application::component::ComponentType,
+    channel::Message,
+    guild::PartialMember,
+    id::{ApplicationId, ChannelId, GuildId, InteractionId},
+    user::User,
+};
+use serde::{Deserialize, Serialize};
+
+/// Data present in an [`Interaction`] of type [`ModalSubmit`].
+///
+/// [`Interaction`]: super::Interaction
+/// [`ModalSubmit`]: super::Interaction::ModalSubmit
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename(serialize = "Interaction"))]
+pub struct ModalSubmitInteraction {
+    /// ID of the associated application.
+    pub application_id: ApplicationId,
+    /// The channel the interaction was triggered from.
+    pub channel_id: ChannelId,
+    /// Data from the submitted modal.
+    pub data: ModalSubmitInteractionData,
+    /// ID of the guild the interaction was triggered from.
+    pub guild_id: Option<GuildId>,
+    /// ID of the interaction.
+    pub id: InteractionId,
+    /// Kind of the interaction.
+    #[serde(rename = "type")]
+    pub kind: InteractionType,
+    /// Member that triggered the interaction.
+    ///
+    /// Present when the modal is submitted in a guild.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub member: Option<PartialMember>,
+    /// Message of the component the modal was opened from.
+    ///
+    /// Present when the modal was sent in response to a
+    /// [`MessageComponent`] interaction.
+    ///
+    /// [`MessageComponent`]: super::Interaction::MessageComponent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<Message>,
+    /// Token of the interaction.
+    pub token: String,
+    /// User that triggered the interaction.
+    ///
+    /// Present when the modal is submitted in a direct message.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+}
+
+/// Data received when a [`ModalSubmit`] interaction is executed.
+///
+/// Refer to [the discord docs] for more information.
+///
+/// [`ModalSubmit`]: crate::application::interaction::Interaction::ModalSubmit
+/// [the discord docs]: https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object-modal-submit-data-structure
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ModalSubmitInteractionData {
+    /// Action rows of the submitted text inputs.
+    pub components: Vec<ModalSubmitActionRow>,
+    /// Developer defined identifier of the modal.
+    pub custom_id: String,
+}
+
+impl ModalSubmitInteractionData {
+    /// Submitted text of the input with the given custom ID.
+    pub fn value(&self, custom_id: &str) -> Option<&str> {
+        self.components
+            .iter()
+            .flat_map(|row| &row.components)
+            .find(|component| component.custom_id == custom_id)
+            .map(|component| component.value.as_str())
+    }
+}
+
+/// Action row of a submitted modal.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ModalSubmitActionRow {
+    /// Submitted components in the row.
+    pub components: Vec<ModalSubmitComponent>,
+    /// Type of the component, always [`ComponentType::ActionRow`].
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+}
+
+/// Text input of a submitted modal.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ModalSubmitComponent {
+    /// Developer defined identifier of the input.
+    pub custom_id: String,
+    /// Type of the component, always [`ComponentType::TextInput`].
+    #[serde(rename = "type")]
+    pub kind: ComponentType,
+    /// Text entered by the user.
+    pub value: String,
+}
@@ -2,6 +2,7 @@ pub mod embed;
 pub mod message;
 pub mod permission_overwrite;
 pub mod stage_instance;
+pub mod thread;
 pub mod webhook;
 
 mod attachment;
@@ -14,6 +15,7 @@ mod private_channel;
 mod reaction;
 mod reaction_type;
 mod text_channel;
+mod thread_channel;
 mod video_quality_mode;
 mod voice_channel;
 
@@ -30,12 +32,13 @@ pub use self::{
     reaction_type::ReactionType,
     stage_instance::StageInstance,
     text_channel::TextChannel,
+    thread_channel::ThreadChannel,
     video_quality_mode::VideoQualityMode,
     voice_channel::VoiceChannel,
     webhook::{Webhook, WebhookType},
 };
 
-use crate::id::{ChannelId, GuildId, MessageId};
+use crate::id::{ChannelId, GuildId, MessageId, UserId};
 use serde::{
     de::{Deserializer, Error as DeError, IgnoredAny, MapAccess, Visitor},
     Deserialize, Serialize,
@@ -108,6 +111,9 @@ pub enum GuildChannel {
     Text(TextChannel),
     Voice(VoiceChannel),
     Stage(VoiceChannel),
+    NewsThread(ThreadChannel),
+    PrivateThread(ThreadChannel),
+    PublicThread(ThreadChannel),
 }
 
 impl GuildChannel {
@@ -118,6 +124,9 @@ impl GuildChannel {
             Self::Text(text) => text.guild_id,
             Self::Voice(voice) => voice.guild_id,
             Self::Stage(stage) => stage.guild_id,
+            Self::NewsThread(thread) | Self::PrivateThread(thread) | Self::PublicThread(thread) => {
+                thread.guild_id
+            }
         }
     }
 
@@ -128,6 +137,9 @@ impl GuildChannel {
             Self::Text(text) => text.id,
             Self::Voice(voice) => voice.id,
             Self::Stage(stage) => stage.id,
+            Self::NewsThread(thread) | Self::PrivateThread(thread) | Self::PublicThread(thread) => {
+                thread.id
+            }
         }
     }
 
@@ -137,6 +149,7 @@ impl GuildChannel {
             Self::Category(c) => c.kind,
             Self::Text(c) => c.kind,
             Self::Stage(c) | Self::Voice(c) => c.kind,
+            Self::NewsThread(c) | Self::PrivateThread(c) | Self::PublicThread(c) => c.kind,
         }
     }
 
@@ -147,6 +160,32 @@ impl GuildChannel {
             Self::Text(text) => text.name.as_ref(),
             Self::Voice(voice) => voice.name.as_ref(),
             Self::Stage(stage) => stage.name.as_ref(),
+            Self::NewsThread(thread) | Self::PrivateThread(thread) | Self::PublicThread(thread) => {
+                thread.name.as_ref()
+            }
+        }
+    }
+
+    /// Return an immutable reference to the inner thread, if the guild
+    /// channel is a thread.
+    pub const fn thread(&self) -> Option<&ThreadChannel> {
+        match self {
+            Self::NewsThread(thread) | Self::PrivateThread(thread) | Self::PublicThread(thread) => {
+                Some(thread)
+            }
+            _ => None,
+        }
+    }
+
+    /// Return a mutable reference to the inner thread, if the guild channel
+    /// is a thread.
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn thread_mut(&mut self) -> Option<&mut ThreadChannel> {
+        match self {
+            Self::NewsThread(thread) | Self::PrivateThread(thread) | Self::PublicThread(thread) => {
+                Some(thread)
+            }
+            _ => None,
         }
     }
 }
@@ -159,13 +198,18 @@ enum GuildChannelField {
     Id,
     LastMessageId,
     LastPinTimestamp,
+    Member,
+    MemberCount,
+    MessageCount,
     Name,
     Nsfw,
+    OwnerId,
     ParentId,
     PermissionOverwrites,
     Position,
     RateLimitPerUser,
     RtcRegion,
+    ThreadMetadata,
     Topic,
     Type,
     UserLimit,
@@ -186,6 +230,10 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
         const VARIANTS: &[&str] = &[
             "GuildCategory",
             "GuildNews",
+            "GuildNewsThread",
+            "GuildPrivateThread",
+            "GuildPublicThread",
+            "GuildStageVoice",
             "GuildStore",
             "GuildText",
             "GuildVoice",
@@ -194,16 +242,21 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
         let mut bitrate = None;
         let mut guild_id = None;
         let mut id = None;
-        let mut kind = None;
+        let mut kind: Option<ChannelType> = None;
         let mut last_message_id: Option<Option<MessageId>> = None;
         let mut last_pin_timestamp: Option<Option<String>> = None;
+        let mut member = None;
+        let mut member_count = None;
+        let mut message_count = None;
         let mut name = None;
         let mut nsfw = None;
+        let mut owner_id: Option<Option<UserId>> = None;
         let mut parent_id: Option<Option<ChannelId>> = None;
         let mut permission_overwrites = None;
         let mut position = None;
         let mut rate_limit_per_user = None;
         let mut rtc_region: Option<Option<String>> = None;
+        let mut thread_metadata = None;
         let mut topic: Option<Option<String>> = None;
         let mut user_limit = None;
         let mut video_quality_mode = None;
@@ -275,6 +328,27 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
 
                     last_pin_timestamp = Some(map.next_value()?);
                 }
+                GuildChannelField::Member => {
+                    if member.is_some() {
+                        return Err(DeError::duplicate_field("member"));
+                    }
+
+                    member = Some(map.next_value()?);
+                }
+                GuildChannelField::MemberCount => {
+                    if member_count.is_some() {
+                        return Err(DeError::duplicate_field("member_count"));
+                    }
+
+                    member_count = Some(map.next_value()?);
+                }
+                GuildChannelField::MessageCount => {
+                    if message_count.is_some() {
+                        return Err(DeError::duplicate_field("message_count"));
+                    }
+
+                    message_count = Some(map.next_value()?);
+                }
                 GuildChannelField::Name => {
                     if name.is_some() {
                         return Err(DeError::duplicate_field("name"));
@@ -289,6 +363,13 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
 
                     nsfw = Some(map.next_value()?);
                 }
+                GuildChannelField::OwnerId => {
+                    if owner_id.is_some() {
+                        return Err(DeError::duplicate_field("owner_id"));
+                    }
+
+                    owner_id = Some(map.next_value()?);
+                }
                 GuildChannelField::ParentId => {
                     if parent_id.is_some() {
                         return Err(DeError::duplicate_field("parent_id"));
@@ -324,6 +405,13 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
 
                     rtc_region = Some(map.next_value()?);
                 }
+                GuildChannelField::ThreadMetadata => {
+                    if thread_metadata.is_some() {
+                        return Err(DeError::duplicate_field("thread_metadata"));
+                    }
+
+                    thread_metadata = Some(map.next_value()?);
+                }
                 GuildChannelField::Topic => {
                     if topic.is_some() {
                         return Err(DeError::duplicate_field("topic"));
@@ -353,9 +441,6 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
         let id = id.ok_or_else(|| DeError::missing_field("id"))?;
         let kind = kind.ok_or_else(|| DeError::missing_field("type"))?;
         let name = name.ok_or_else(|| DeError::missing_field("name"))?;
-        let permission_overwrites =
-            permission_overwrites.ok_or_else(|| DeError::missing_field("permission_overwrites"))?;
-        let position = position.ok_or_else(|| DeError::missing_field("position"))?;
 
         let nsfw = nsfw.unwrap_or_default();
         let parent_id = parent_id.unwrap_or_default();
@@ -366,9 +451,59 @@ impl<'de> Visitor<'de> for GuildChannelVisitor {
             %name,
             %nsfw,
             ?parent_id,
+            "common fields of all variants exist"
+        );
+
+        if kind.is_thread() {
+            let last_message_id = last_message_id.unwrap_or_default();
+            let member = member.unwrap_or_default();
+            let member_count =
+                member_count.ok_or_else(|| DeError::missing_field("member_count"))?;
+            let message_count =
+                message_count.ok_or_else(|| DeError::missing_field("message_count"))?;
+            let owner_id = owner_id.unwrap_or_default();
+            let thread_metadata =
+                thread_metadata.ok_or_else(|| DeError::missing_field("thread_metadata"))?;
+
+            tracing::trace!(
+                ?last_message_id,
+                %member_count,
+                %message_count,
+                ?owner_id,
+                ?thread_metadata,
+                "handling thread channel"
+            );
+
+            let thread = ThreadChannel {
+                guild_id,
+                id,
+                kind,
+                last_message_id,
+                member,
+                member_count,
+                message_count,
+                name,
+                owner_id,
+                parent_id,
+                rate_limit_per_user,
+                thread_metadata,
+            };
+
+            return Ok(match kind {
+                ChannelType::GuildNewsThread => GuildChannel::NewsThread(thread),
+                ChannelType::GuildPrivateThread => GuildChannel::PrivateThread(thread),
+                _ => GuildChannel::PublicThread(thread),
+            });
+        }
+
+        let permission_overwrites =
+            permission_overwrites.ok_or_else(|| DeError::missing_field("permission_overwrites"))?;
+        let position = position.ok_or_else(|| DeError::missing_field("position"))?;
+
+        tracing::trace!(
             ?permission_overwrites,
             %position,
-            "common fields of all variants exist"
+            "common fields of non-thread variants exist"
         );
 
         Ok(match kind {
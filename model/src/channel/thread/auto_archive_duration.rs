@@ -0,0 +1,40 @@
+use serde_repr::{Deserialize_repr, Serialize_repr};
+
+/// Duration of inactivity after which a thread is automatically archived.
+#[derive(
+    Clone, Copy, Debug, Deserialize_repr, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize_repr,
+)]
+#[repr(u16)]
+pub enum AutoArchiveDuration {
+    Hour = 60,
+    Day = 1440,
+    ThreeDays = 4320,
+    Week = 10080,
+}
+
+impl AutoArchiveDuration {
+    /// Number of minutes of the duration.
+    pub const fn minutes(self) -> u16 {
+        self as u16
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AutoArchiveDuration;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        serde_test::assert_tokens(&AutoArchiveDuration::Hour, &[Token::U16(60)]);
+        serde_test::assert_tokens(&AutoArchiveDuration::Day, &[Token::U16(1440)]);
+        serde_test::assert_tokens(&AutoArchiveDuration::ThreeDays, &[Token::U16(4320)]);
+        serde_test::assert_tokens(&AutoArchiveDuration::Week, &[Token::U16(10080)]);
+    }
+
+    #[test]
+    fn test_minutes() {
+        assert_eq!(60, AutoArchiveDuration::Hour.minutes());
+        assert_eq!(10080, AutoArchiveDuration::Week.minutes());
+    }
+}
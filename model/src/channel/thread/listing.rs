@@ -0,0 +1,14 @@
+use crate::channel::{thread::ThreadMember, Channel};
+use serde::{Deserialize, Serialize};
+
+/// List of threads and the current user's thread members of the threads it
+/// has joined.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ThreadsListing {
+    /// Whether there are more threads that could be returned by another
+    /// request.
+    #[serde(default)]
+    pub has_more: bool,
+    pub members: Vec<ThreadMember>,
+    pub threads: Vec<Channel>,
+}
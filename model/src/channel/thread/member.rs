@@ -0,0 +1,59 @@
+use crate::id::{ChannelId, UserId};
+use serde::{Deserialize, Serialize};
+
+/// Member of a thread.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ThreadMember {
+    /// Flags of the member, used for notification settings.
+    pub flags: u64,
+    /// ID of the thread.
+    ///
+    /// Not present when the member is included in a thread channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<ChannelId>,
+    pub join_timestamp: String,
+    /// ID of the user.
+    ///
+    /// Not present when the member is included in a thread channel.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_id: Option<UserId>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ChannelId, ThreadMember, UserId};
+    use serde_test::Token;
+
+    #[test]
+    fn test_thread_member() {
+        let value = ThreadMember {
+            flags: 1,
+            id: Some(ChannelId(2)),
+            join_timestamp: "2021-09-19T14:17:32.000000+00:00".to_owned(),
+            user_id: Some(UserId(3)),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "ThreadMember",
+                    len: 4,
+                },
+                Token::Str("flags"),
+                Token::U64(1),
+                Token::Str("id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "ChannelId" },
+                Token::Str("2"),
+                Token::Str("join_timestamp"),
+                Token::Str("2021-09-19T14:17:32.000000+00:00"),
+                Token::Str("user_id"),
+                Token::Some,
+                Token::NewtypeStruct { name: "UserId" },
+                Token::Str("3"),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
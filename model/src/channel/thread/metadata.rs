@@ -0,0 +1,58 @@
+use crate::channel::thread::AutoArchiveDuration;
+use serde::{Deserialize, Serialize};
+
+/// Thread-specific fields of a thread channel.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ThreadMetadata {
+    /// Timestamp of when the thread's archive status was last changed.
+    pub archive_timestamp: String,
+    pub archived: bool,
+    pub auto_archive_duration: AutoArchiveDuration,
+    /// Whether non-moderators can add other non-moderators to the thread.
+    ///
+    /// Only present on private threads.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub invitable: Option<bool>,
+    /// Whether only moderators can unarchive the thread.
+    #[serde(default)]
+    pub locked: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AutoArchiveDuration, ThreadMetadata};
+    use serde_test::Token;
+
+    #[test]
+    fn test_thread_metadata() {
+        let value = ThreadMetadata {
+            archive_timestamp: "2021-09-19T14:17:32.000000+00:00".to_owned(),
+            archived: true,
+            auto_archive_duration: AutoArchiveDuration::Day,
+            invitable: Some(false),
+            locked: false,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "ThreadMetadata",
+                    len: 5,
+                },
+                Token::Str("archive_timestamp"),
+                Token::Str("2021-09-19T14:17:32.000000+00:00"),
+                Token::Str("archived"),
+                Token::Bool(true),
+                Token::Str("auto_archive_duration"),
+                Token::U16(1440),
+                Token::Str("invitable"),
+                Token::Some,
+                Token::Bool(false),
+                Token::Str("locked"),
+                Token::Bool(false),
+                Token::StructEnd,
+            ],
+        );
+    }
+}
@@ -1,8 +1,14 @@
 mod channel;
 mod guild;
 mod kind;
+mod url;
 
-pub use self::{channel::WebhookChannel, guild::WebhookGuild, kind::WebhookType};
+pub use self::{
+    channel::WebhookChannel,
+    guild::WebhookGuild,
+    kind::WebhookType,
+    url::{parse_url, parse_url_with_token, WebhookParseError, WebhookParseErrorType},
+};
 
 use crate::{
     id::{ApplicationId, ChannelId, GuildId, WebhookId},
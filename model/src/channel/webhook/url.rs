@@ -0,0 +1,254 @@
+use crate::id::WebhookId;
+use std::{
+    error::Error,
+    fmt::{Display, Formatter, Result as FmtResult},
+};
+
+/// Error when [parsing] a webhook URL.
+///
+/// [parsing]: parse_url
+#[derive(Debug)]
+pub struct WebhookParseError {
+    kind: WebhookParseErrorType,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl WebhookParseError {
+    /// Immutable reference to the type of error that occurred.
+    #[must_use = "retrieving the type has no effect if left unused"]
+    pub const fn kind(&self) -> &WebhookParseErrorType {
+        &self.kind
+    }
+
+    /// Consume the error, returning the source error if there is any.
+    #[must_use = "consuming the error and retrieving the source has no effect if left unused"]
+    pub fn into_source(self) -> Option<Box<dyn Error + Send + Sync>> {
+        self.source
+    }
+
+    /// Consume the error, returning the owned error type and the source error.
+    #[must_use = "consuming the error into its parts has no effect if left unused"]
+    pub fn into_parts(self) -> (WebhookParseErrorType, Option<Box<dyn Error + Send + Sync>>) {
+        (self.kind, self.source)
+    }
+
+    const fn segment_missing() -> Self {
+        Self {
+            kind: WebhookParseErrorType::SegmentMissing,
+            source: None,
+        }
+    }
+}
+
+impl Display for WebhookParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.kind {
+            WebhookParseErrorType::IdInvalid => f.write_str("url path segment isn't a valid ID"),
+            WebhookParseErrorType::SegmentMissing => {
+                f.write_str("url is missing a required path segment")
+            }
+            WebhookParseErrorType::TokenMissing => f.write_str("url is missing the webhook token"),
+        }
+    }
+}
+
+impl Error for WebhookParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        self.source
+            .as_ref()
+            .map(|source| &**source as &(dyn Error + 'static))
+    }
+}
+
+/// Type of [`WebhookParseError`] that occurred.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum WebhookParseErrorType {
+    /// ID segment in the URL path is not an integer.
+    IdInvalid,
+    /// Required segment of the URL path is missing.
+    SegmentMissing,
+    /// Token segment of the URL path is missing.
+    ///
+    /// Only returned by [`parse_url_with_token`].
+    TokenMissing,
+}
+
+/// Parse the webhook ID and token from a webhook URL, if it exists in the
+/// string.
+///
+/// The path may contain an API version, such as in
+/// `https://discord.com/api/v9/webhooks/1/token`. The host isn't checked and
+/// any query is ignored.
+///
+/// # Examples
+///
+/// Parse a webhook URL with a token:
+///
+/// ```
+/// use twilight_model::{channel::webhook, id::WebhookId};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let url = "https://canary.discord.com/api/webhooks/794590023369752587/tjxHaPHLKp9aEdSwJuLeHhHHGEqIxt1aay4I67FOP9uzsYEWmj0eJmDn-2ZvCYLyOb_K";
+///
+/// let (id, token) = webhook::parse_url(url)?;
+/// assert_eq!(WebhookId(794590023369752587), id);
+/// assert_eq!(
+///     Some("tjxHaPHLKp9aEdSwJuLeHhHHGEqIxt1aay4I67FOP9uzsYEWmj0eJmDn-2ZvCYLyOb_K"),
+///     token,
+/// );
+/// # Ok(()) }
+/// ```
+///
+/// Parse a webhook URL without a token:
+///
+/// ```
+/// use twilight_model::{channel::webhook, id::WebhookId};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let url = "https://canary.discord.com/api/webhooks/794590023369752587";
+///
+/// let (id, token) = webhook::parse_url(url)?;
+/// assert_eq!(WebhookId(794590023369752587), id);
+/// assert!(token.is_none());
+/// # Ok(()) }
+/// ```
+///
+/// # Errors
+///
+/// Returns [`WebhookParseErrorType::IdInvalid`] error type if the ID segment of
+/// the URL is not a valid integer.
+///
+/// Returns [`WebhookParseErrorType::SegmentMissing`] error type if one of the
+/// required segments is missing. This can be the "api" or "webhooks" standard
+/// segment of the URL or the segment containing the webhook ID.
+pub fn parse_url(url: &str) -> Result<(WebhookId, Option<&str>), WebhookParseError> {
+    const API_SEGMENT: &str = "/api/";
+
+    let path = url.split('?').next().unwrap_or_default();
+
+    let mut segments = path
+        .find(API_SEGMENT)
+        .map(|index| &path[index + API_SEGMENT.len()..])
+        .ok_or_else(WebhookParseError::segment_missing)?
+        .split('/')
+        .skip_while(|segment| segment.starts_with('v') && segment[1..].parse::<u8>().is_ok());
+
+    if segments.next() != Some("webhooks") {
+        return Err(WebhookParseError::segment_missing());
+    }
+
+    let id_segment = segments.next().unwrap_or_default();
+
+    // If we don't have this check it'll return `IdInvalid`, which isn't right.
+    if id_segment.is_empty() {
+        return Err(WebhookParseError::segment_missing());
+    }
+
+    let id = id_segment.parse().map_err(|source| WebhookParseError {
+        kind: WebhookParseErrorType::IdInvalid,
+        source: Some(Box::new(source)),
+    })?;
+
+    // Don't return an empty token if the segment is empty.
+    let token = segments.next().filter(|token| !token.is_empty());
+
+    Ok((WebhookId(id), token))
+}
+
+/// Parse the webhook ID and token from a webhook URL, requiring the token to
+/// be present.
+///
+/// # Examples
+///
+/// ```
+/// use twilight_model::{channel::webhook, id::WebhookId};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// let url = "https://discord.com/api/v9/webhooks/1/token?wait=true";
+///
+/// assert_eq!((WebhookId(1), "token"), webhook::parse_url_with_token(url)?);
+/// # Ok(()) }
+/// ```
+///
+/// # Errors
+///
+/// Returns the same errors as [`parse_url`].
+///
+/// Returns [`WebhookParseErrorType::TokenMissing`] error type if the URL
+/// doesn't contain the token of the webhook.
+pub fn parse_url_with_token(url: &str) -> Result<(WebhookId, &str), WebhookParseError> {
+    match parse_url(url)? {
+        (id, Some(token)) => Ok((id, token)),
+        (_, None) => Err(WebhookParseError {
+            kind: WebhookParseErrorType::TokenMissing,
+            source: None,
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_url, parse_url_with_token, WebhookParseError, WebhookParseErrorType};
+    use crate::id::WebhookId;
+    use static_assertions::assert_impl_all;
+    use std::{error::Error, fmt::Debug};
+
+    assert_impl_all!(WebhookParseErrorType: Debug, Send, Sync);
+    assert_impl_all!(WebhookParseError: Debug, Error, Send, Sync);
+
+    #[test]
+    fn test_parse_url() {
+        assert_eq!(
+            (WebhookId(1), Some("token")),
+            parse_url("https://discord.com/api/webhooks/1/token").unwrap()
+        );
+        assert_eq!(
+            (WebhookId(2), Some("token")),
+            parse_url("https://canary.discord.com/api/v8/webhooks/2/token/slack?wait=true")
+                .unwrap()
+        );
+        assert_eq!(
+            (WebhookId(3), None),
+            parse_url("https://discord.com/api/webhooks/3/?wait=true").unwrap()
+        );
+        assert!(matches!(
+            parse_url("https://discord.com/api/webhooks/abc/token")
+                .unwrap_err()
+                .kind(),
+            WebhookParseErrorType::IdInvalid
+        ));
+        assert!(matches!(
+            parse_url("https://discord.com/webhooks/1/token")
+                .unwrap_err()
+                .kind(),
+            WebhookParseErrorType::SegmentMissing
+        ));
+        assert!(matches!(
+            parse_url("https://discord.com/api/v9/channels/1")
+                .unwrap_err()
+                .kind(),
+            WebhookParseErrorType::SegmentMissing
+        ));
+    }
+
+    #[test]
+    fn test_parse_url_with_token() {
+        assert_eq!(
+            (WebhookId(1), "token"),
+            parse_url_with_token("https://discord.com/api/webhooks/1/token").unwrap()
+        );
+        assert!(matches!(
+            parse_url_with_token("https://discord.com/api/webhooks/1")
+                .unwrap_err()
+                .kind(),
+            WebhookParseErrorType::TokenMissing
+        ));
+        assert!(matches!(
+            parse_url_with_token("https://discord.com/api/webhooks/")
+                .unwrap_err()
+                .kind(),
+            WebhookParseErrorType::SegmentMissing
+        ));
+    }
+}
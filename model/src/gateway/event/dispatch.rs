@@ -55,6 +55,12 @@ pub enum DispatchEvent {
     StageInstanceCreate(StageInstanceCreate),
     StageInstanceDelete(StageInstanceDelete),
     StageInstanceUpdate(StageInstanceUpdate),
+    ThreadCreate(ThreadCreate),
+    ThreadDelete(ThreadDelete),
+    ThreadListSync(ThreadListSync),
+    ThreadMemberUpdate(ThreadMemberUpdate),
+    ThreadMembersUpdate(ThreadMembersUpdate),
+    ThreadUpdate(ThreadUpdate),
     TypingStart(Box<TypingStart>),
     UnavailableGuild(UnavailableGuild),
     UserUpdate(UserUpdate),
@@ -107,6 +113,12 @@ impl DispatchEvent {
             Self::StageInstanceCreate(_) => EventType::StageInstanceCreate,
             Self::StageInstanceDelete(_) => EventType::StageInstanceDelete,
             Self::StageInstanceUpdate(_) => EventType::StageInstanceUpdate,
+            Self::ThreadCreate(_) => EventType::ThreadCreate,
+            Self::ThreadDelete(_) => EventType::ThreadDelete,
+            Self::ThreadListSync(_) => EventType::ThreadListSync,
+            Self::ThreadMemberUpdate(_) => EventType::ThreadMemberUpdate,
+            Self::ThreadMembersUpdate(_) => EventType::ThreadMembersUpdate,
+            Self::ThreadUpdate(_) => EventType::ThreadUpdate,
             Self::TypingStart(_) => EventType::TypingStart,
             Self::UnavailableGuild(_) => EventType::UnavailableGuild,
             Self::UserUpdate(_) => EventType::UserUpdate,
@@ -162,6 +174,12 @@ impl TryFrom<Event> for DispatchEvent {
             Event::StageInstanceCreate(v) => Self::StageInstanceCreate(v),
             Event::StageInstanceDelete(v) => Self::StageInstanceDelete(v),
             Event::StageInstanceUpdate(v) => Self::StageInstanceUpdate(v),
+            Event::ThreadCreate(v) => Self::ThreadCreate(v),
+            Event::ThreadDelete(v) => Self::ThreadDelete(v),
+            Event::ThreadListSync(v) => Self::ThreadListSync(v),
+            Event::ThreadMemberUpdate(v) => Self::ThreadMemberUpdate(v),
+            Event::ThreadMembersUpdate(v) => Self::ThreadMembersUpdate(v),
+            Event::ThreadUpdate(v) => Self::ThreadUpdate(v),
             Event::TypingStart(v) => Self::TypingStart(v),
             Event::UnavailableGuild(v) => Self::UnavailableGuild(v),
             Event::UserUpdate(v) => Self::UserUpdate(v),
@@ -313,6 +331,24 @@ impl<'de, 'a> DeserializeSeed<'de> for DispatchEventWithTypeDeserializer<'a> {
             "STAGE_INSTANCE_UPDATE" => {
                 DispatchEvent::StageInstanceUpdate(StageInstanceUpdate::deserialize(deserializer)?)
             }
+            "THREAD_CREATE" => {
+                DispatchEvent::ThreadCreate(ThreadCreate::deserialize(deserializer)?)
+            }
+            "THREAD_DELETE" => {
+                DispatchEvent::ThreadDelete(ThreadDelete::deserialize(deserializer)?)
+            }
+            "THREAD_LIST_SYNC" => {
+                DispatchEvent::ThreadListSync(ThreadListSync::deserialize(deserializer)?)
+            }
+            "THREAD_MEMBER_UPDATE" => {
+                DispatchEvent::ThreadMemberUpdate(ThreadMemberUpdate::deserialize(deserializer)?)
+            }
+            "THREAD_MEMBERS_UPDATE" => {
+                DispatchEvent::ThreadMembersUpdate(ThreadMembersUpdate::deserialize(deserializer)?)
+            }
+            "THREAD_UPDATE" => {
+                DispatchEvent::ThreadUpdate(ThreadUpdate::deserialize(deserializer)?)
+            }
             "TYPING_START" => {
                 DispatchEvent::TypingStart(Box::new(TypingStart::deserialize(deserializer)?))
             }
@@ -334,6 +370,14 @@ impl<'de, 'a> DeserializeSeed<'de> for DispatchEventWithTypeDeserializer<'a> {
 #[cfg(test)]
 mod tests {
     use super::{DispatchEvent, DispatchEventWithTypeDeserializer};
+    use crate::{
+        channel::{
+            thread::{AutoArchiveDuration, ThreadMetadata},
+            Channel, ChannelType, GuildChannel, ThreadChannel,
+        },
+        gateway::payload::ThreadCreate,
+        id::{ChannelId, GuildId, UserId},
+    };
     use serde::de::DeserializeSeed;
     use serde_json::Deserializer;
 
@@ -350,4 +394,57 @@ mod tests {
 
         assert_eq!(event, DispatchEvent::GiftCodeUpdate);
     }
+
+    #[test]
+    fn test_thread_create() {
+        let input = r#"{
+            "guild_id": "1",
+            "id": "2",
+            "member_count": 1,
+            "message_count": 0,
+            "name": "a thread",
+            "owner_id": "3",
+            "parent_id": "4",
+            "rate_limit_per_user": 0,
+            "thread_metadata": {
+                "archive_timestamp": "2021-09-19T14:17:32.000000+00:00",
+                "archived": false,
+                "auto_archive_duration": 1440,
+                "locked": false
+            },
+            "type": 11
+        }"#;
+
+        let deserializer = DispatchEventWithTypeDeserializer::new("THREAD_CREATE");
+        let mut json_deserializer = Deserializer::from_str(input);
+        let event = deserializer.deserialize(&mut json_deserializer).unwrap();
+
+        let thread = ThreadChannel {
+            guild_id: Some(GuildId(1)),
+            id: ChannelId(2),
+            kind: ChannelType::GuildPublicThread,
+            last_message_id: None,
+            member: None,
+            member_count: 1,
+            message_count: 0,
+            name: "a thread".to_owned(),
+            owner_id: Some(UserId(3)),
+            parent_id: Some(ChannelId(4)),
+            rate_limit_per_user: Some(0),
+            thread_metadata: ThreadMetadata {
+                archive_timestamp: "2021-09-19T14:17:32.000000+00:00".to_owned(),
+                archived: false,
+                auto_archive_duration: AutoArchiveDuration::Day,
+                invitable: None,
+                locked: false,
+            },
+        };
+
+        assert_eq!(
+            event,
+            DispatchEvent::ThreadCreate(ThreadCreate(Channel::Guild(GuildChannel::PublicThread(
+                thread
+            ))))
+        );
+    }
 }
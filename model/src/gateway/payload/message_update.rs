@@ -1,4 +1,5 @@
 use crate::{
+    application::component::Component,
     channel::{
         embed::Embed,
         message::{Mention, MessageType},
@@ -16,6 +17,8 @@ pub struct MessageUpdate {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub author: Option<User>,
     pub channel_id: ChannelId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub components: Option<Vec<Component>>,
     pub content: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub edited_timestamp: Option<String>,
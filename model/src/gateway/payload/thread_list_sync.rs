@@ -0,0 +1,20 @@
+use crate::{
+    channel::{thread::ThreadMember, Channel},
+    id::{ChannelId, GuildId},
+};
+use serde::{Deserialize, Serialize};
+
+/// Active threads the current user can see, sent when gaining access to a
+/// channel.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ThreadListSync {
+    /// IDs of the parent channels whose threads are being synced.
+    ///
+    /// If empty, all of the guild's active threads are synced.
+    #[serde(default)]
+    pub channel_ids: Vec<ChannelId>,
+    pub guild_id: GuildId,
+    /// Thread members of the current user for the threads it has joined.
+    pub members: Vec<ThreadMember>,
+    pub threads: Vec<Channel>,
+}
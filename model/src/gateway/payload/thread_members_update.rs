@@ -0,0 +1,54 @@
+use crate::{
+    channel::thread::ThreadMember,
+    id::{ChannelId, GuildId, UserId},
+};
+use serde::{Deserialize, Serialize};
+
+/// Members were added to or removed from a thread.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct ThreadMembersUpdate {
+    #[serde(default)]
+    pub added_members: Vec<ThreadMember>,
+    pub guild_id: GuildId,
+    /// ID of the thread.
+    pub id: ChannelId,
+    /// Approximate number of members in the thread, stopping at 50.
+    pub member_count: u8,
+    #[serde(default)]
+    pub removed_member_ids: Vec<UserId>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ChannelId, GuildId, ThreadMember, ThreadMembersUpdate, UserId};
+
+    #[test]
+    fn test_thread_members_update() {
+        let value = ThreadMembersUpdate {
+            added_members: vec![ThreadMember {
+                flags: 0,
+                id: Some(ChannelId(2)),
+                join_timestamp: "2021-09-19T14:17:32.000000+00:00".to_owned(),
+                user_id: Some(UserId(3)),
+            }],
+            guild_id: GuildId(1),
+            id: ChannelId(2),
+            member_count: 1,
+            removed_member_ids: Vec::new(),
+        };
+
+        let input = serde_json::json!({
+            "added_members": [{
+                "flags": 0,
+                "id": "2",
+                "join_timestamp": "2021-09-19T14:17:32.000000+00:00",
+                "user_id": "3",
+            }],
+            "guild_id": "1",
+            "id": "2",
+            "member_count": 1,
+        });
+
+        assert_eq!(value, serde_json::from_value(input).unwrap());
+    }
+}
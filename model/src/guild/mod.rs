@@ -106,6 +106,13 @@ pub struct Guild {
     pub stage_instances: Vec<StageInstance>,
     pub system_channel_flags: SystemChannelFlags,
     pub system_channel_id: Option<ChannelId>,
+    /// Active threads in the guild that the current user can see.
+    ///
+    /// Only present in [`GuildCreate`] events.
+    ///
+    /// [`GuildCreate`]: crate::gateway::payload::GuildCreate
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub threads: Vec<GuildChannel>,
     #[serde(default)]
     pub unavailable: bool,
     pub vanity_url_code: Option<String>,
@@ -162,6 +169,7 @@ impl<'de> Deserialize<'de> for Guild {
             SystemChannelFlags,
             SystemChannelId,
             RulesChannelId,
+            Threads,
             Unavailable,
             VerificationLevel,
             VoiceStates,
@@ -219,6 +227,7 @@ impl<'de> Deserialize<'de> for Guild {
                 let mut system_channel_id = None::<Option<_>>;
                 let mut system_channel_flags = None;
                 let mut rules_channel_id = None::<Option<_>>;
+                let mut threads = None::<Vec<GuildChannel>>;
                 let mut unavailable = None;
                 let mut verification_level = None;
                 let mut voice_states = None::<Vec<VoiceState>>;
@@ -502,6 +511,13 @@ impl<'de> Deserialize<'de> for Guild {
 
                             stage_instances = Some(map.next_value()?);
                         }
+                        Field::Threads => {
+                            if threads.is_some() {
+                                return Err(DeError::duplicate_field("threads"));
+                            }
+
+                            threads = Some(map.next_value()?);
+                        }
                         Field::SystemChannelId => {
                             if system_channel_id.is_some() {
                                 return Err(DeError::duplicate_field("system_channel_id"));
@@ -614,6 +630,7 @@ impl<'de> Deserialize<'de> for Guild {
                 let splash = splash.unwrap_or_default();
                 let stage_instances = stage_instances.unwrap_or_default();
                 let system_channel_id = system_channel_id.unwrap_or_default();
+                let mut threads = threads.unwrap_or_default();
                 let unavailable = unavailable.unwrap_or_default();
                 let vanity_url_code = vanity_url_code.unwrap_or_default();
                 let mut voice_states = voice_states.unwrap_or_default();
@@ -662,6 +679,7 @@ impl<'de> Deserialize<'de> for Guild {
                     ?stage_instances,
                     ?system_channel_flags,
                     ?system_channel_id,
+                    ?threads,
                     ?unavailable,
                     ?vanity_url_code,
                     ?voice_states,
@@ -681,6 +699,17 @@ impl<'de> Deserialize<'de> for Guild {
                         GuildChannel::Voice(c) | GuildChannel::Stage(c) => {
                             c.guild_id.replace(id);
                         }
+                        GuildChannel::NewsThread(c)
+                        | GuildChannel::PrivateThread(c)
+                        | GuildChannel::PublicThread(c) => {
+                            c.guild_id.replace(id);
+                        }
+                    }
+                }
+
+                for thread in &mut threads {
+                    if let Some(c) = thread.thread_mut() {
+                        c.guild_id.replace(id);
                     }
                 }
 
@@ -735,6 +764,7 @@ impl<'de> Deserialize<'de> for Guild {
                     stage_instances,
                     system_channel_flags,
                     system_channel_id,
+                    threads,
                     unavailable,
                     vanity_url_code,
                     verification_level,
@@ -783,6 +813,7 @@ impl<'de> Deserialize<'de> for Guild {
             "system_channel_id",
             "system_channel_flags",
             "rules_channel_id",
+            "threads",
             "unavailable",
             "verification_level",
             "voice_states",
@@ -846,6 +877,7 @@ mod tests {
             stage_instances: Vec::new(),
             system_channel_flags: SystemChannelFlags::SUPPRESS_PREMIUM_SUBSCRIPTIONS,
             system_channel_id: Some(ChannelId(7)),
+            threads: Vec::new(),
             unavailable: false,
             vanity_url_code: Some("twilight".to_owned()),
             verification_level: VerificationLevel::Medium,
@@ -0,0 +1,174 @@
+use super::Scope;
+use serde::{Deserialize, Serialize};
+
+/// Access token granted to an `OAuth2` application.
+///
+/// Refer to [the discord docs] for more information.
+///
+/// [the discord docs]: https://discord.com/developers/docs/topics/oauth2#authorization-code-grant-access-token-response
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct AccessToken {
+    /// Token to authorize requests on behalf of the user with.
+    pub access_token: String,
+    /// Number of seconds until the access token expires.
+    pub expires_in: u64,
+    /// Token to request a new access token with once the access token
+    /// expires.
+    ///
+    /// Not present for tokens granted via the client credentials grant.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_token: Option<String>,
+    /// Scopes the access token was granted.
+    ///
+    /// # serde
+    ///
+    /// The API uses a space-separated string of scopes, such as
+    /// `"identify guilds.join"`, which this field is both deserialized from
+    /// and serialized to.
+    #[serde(rename = "scope", with = "scopes")]
+    pub scopes: Vec<Scope>,
+    /// Type of the token, such as `Bearer`.
+    pub token_type: String,
+}
+
+impl AccessToken {
+    /// Value of the `Authorization` header to make requests with the token,
+    /// such as `Bearer abcd`.
+    pub fn authorization(&self) -> String {
+        format!("{} {}", self.token_type, self.access_token)
+    }
+}
+
+pub(crate) mod scopes {
+    use super::Scope;
+    use serde::{
+        de::{value::StrDeserializer, Deserializer, Error as DeError, Visitor},
+        ser::Serializer,
+        Deserialize,
+    };
+    use std::fmt::{Formatter, Result as FmtResult};
+
+    struct ScopesVisitor;
+
+    impl<'de> Visitor<'de> for ScopesVisitor {
+        type Value = Vec<Scope>;
+
+        fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+            f.write_str("space-separated string of scopes")
+        }
+
+        fn visit_str<E: DeError>(self, value: &str) -> Result<Self::Value, E> {
+            value
+                .split_whitespace()
+                .map(|scope| Scope::deserialize(StrDeserializer::<E>::new(scope)))
+                .collect()
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<Scope>, D::Error> {
+        deserializer.deserialize_str(ScopesVisitor)
+    }
+
+    #[allow(clippy::ptr_arg)]
+    pub fn serialize<S: Serializer>(value: &Vec<Scope>, serializer: S) -> Result<S::Ok, S::Error> {
+        let names = value
+            .iter()
+            .map(|scope| scope.name())
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        serializer.serialize_str(&names)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AccessToken, Scope};
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_fields!(
+        AccessToken: access_token,
+        expires_in,
+        refresh_token,
+        scopes,
+        token_type
+    );
+
+    assert_impl_all!(
+        AccessToken: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Serialize
+    );
+
+    #[test]
+    fn test_access_token() {
+        let value = AccessToken {
+            access_token: "6qrZcUqja7812RVdnEKjpzOL4CvHBFG".to_owned(),
+            expires_in: 604_800,
+            refresh_token: Some("D43f5y0ahjqew82jZ4NViEr2YafMKhue".to_owned()),
+            scopes: vec![Scope::Identify, Scope::GuildsJoin],
+            token_type: "Bearer".to_owned(),
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "AccessToken",
+                    len: 5,
+                },
+                Token::Str("access_token"),
+                Token::Str("6qrZcUqja7812RVdnEKjpzOL4CvHBFG"),
+                Token::Str("expires_in"),
+                Token::U64(604_800),
+                Token::Str("refresh_token"),
+                Token::Some,
+                Token::Str("D43f5y0ahjqew82jZ4NViEr2YafMKhue"),
+                Token::Str("scope"),
+                Token::Str("identify guilds.join"),
+                Token::Str("token_type"),
+                Token::Str("Bearer"),
+                Token::StructEnd,
+            ],
+        );
+
+        assert_eq!(
+            "Bearer 6qrZcUqja7812RVdnEKjpzOL4CvHBFG",
+            value.authorization()
+        );
+    }
+
+    #[test]
+    fn test_unknown_scope() {
+        serde_test::assert_de_tokens_error::<AccessToken>(
+            &[
+                Token::Struct {
+                    name: "AccessToken",
+                    len: 4,
+                },
+                Token::Str("access_token"),
+                Token::Str("token"),
+                Token::Str("expires_in"),
+                Token::U64(604_800),
+                Token::Str("scope"),
+                Token::Str("identify unknown"),
+            ],
+            "unknown variant `unknown`, expected one of `activities.read`, \
+             `activities.write`, `applications.builds.read`, \
+             `applications.builds.upload`, `applications.commands`, \
+             `applications.commands.update`, `applications.entitlements`, \
+             `applications.store.update`, `bot`, `connections`, `email`, \
+             `gdm.join`, `guilds`, `guilds.join`, `identify`, `messages.read`, \
+             `relationships.read`, `rpc`, `rpc.activities.write`, \
+             `rpc.notifications.read`, `rpc.voice.read`, `rpc.voice.write`, \
+             `webhook.incoming`",
+        );
+    }
+}
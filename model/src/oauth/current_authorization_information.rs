@@ -0,0 +1,140 @@
+use super::Scope;
+use crate::{id::ApplicationId, user::User};
+use serde::{Deserialize, Serialize};
+
+/// Application an `OAuth2` access token was granted to.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct CurrentAuthorizationApplication {
+    pub bot_public: bool,
+    pub bot_require_code_grant: bool,
+    pub description: String,
+    pub icon: Option<String>,
+    pub id: ApplicationId,
+    pub name: String,
+    pub summary: String,
+    pub verify_key: String,
+}
+
+/// Information about the authorization of the current `OAuth2` access token.
+///
+/// Refer to [the discord docs] for more information.
+///
+/// [the discord docs]: https://discord.com/developers/docs/topics/oauth2#get-current-authorization-information
+#[derive(Clone, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub struct CurrentAuthorizationInformation {
+    /// Application the access token was granted to.
+    pub application: CurrentAuthorizationApplication,
+    /// ISO 8601 timestamp of when the access token expires.
+    pub expires: String,
+    /// Scopes the access token was granted.
+    pub scopes: Vec<Scope>,
+    /// User who granted the access token, if it was granted the
+    /// [`Scope::Identify`] scope.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CurrentAuthorizationApplication, CurrentAuthorizationInformation, Scope};
+    use crate::id::ApplicationId;
+    use serde::{Deserialize, Serialize};
+    use serde_test::Token;
+    use static_assertions::{assert_fields, assert_impl_all};
+    use std::{fmt::Debug, hash::Hash};
+
+    assert_fields!(
+        CurrentAuthorizationApplication: bot_public,
+        bot_require_code_grant,
+        description,
+        icon,
+        id,
+        name,
+        summary,
+        verify_key
+    );
+    assert_fields!(CurrentAuthorizationInformation: application, expires, scopes, user);
+
+    assert_impl_all!(
+        CurrentAuthorizationApplication: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Serialize
+    );
+    assert_impl_all!(
+        CurrentAuthorizationInformation: Clone,
+        Debug,
+        Deserialize<'static>,
+        Eq,
+        Hash,
+        PartialEq,
+        Serialize
+    );
+
+    #[test]
+    fn test_current_authorization_information() {
+        let value = CurrentAuthorizationInformation {
+            application: CurrentAuthorizationApplication {
+                bot_public: true,
+                bot_require_code_grant: false,
+                description: "a pretty cool application".to_owned(),
+                icon: None,
+                id: ApplicationId(1),
+                name: "cool application".to_owned(),
+                summary: "a summary".to_owned(),
+                verify_key: "key".to_owned(),
+            },
+            expires: "2021-07-01T00:00:00.000000+00:00".to_owned(),
+            scopes: vec![Scope::Guilds],
+            user: None,
+        };
+
+        serde_test::assert_tokens(
+            &value,
+            &[
+                Token::Struct {
+                    name: "CurrentAuthorizationInformation",
+                    len: 3,
+                },
+                Token::Str("application"),
+                Token::Struct {
+                    name: "CurrentAuthorizationApplication",
+                    len: 8,
+                },
+                Token::Str("bot_public"),
+                Token::Bool(true),
+                Token::Str("bot_require_code_grant"),
+                Token::Bool(false),
+                Token::Str("description"),
+                Token::Str("a pretty cool application"),
+                Token::Str("icon"),
+                Token::None,
+                Token::Str("id"),
+                Token::NewtypeStruct {
+                    name: "ApplicationId",
+                },
+                Token::Str("1"),
+                Token::Str("name"),
+                Token::Str("cool application"),
+                Token::Str("summary"),
+                Token::Str("a summary"),
+                Token::Str("verify_key"),
+                Token::Str("key"),
+                Token::StructEnd,
+                Token::Str("expires"),
+                Token::Str("2021-07-01T00:00:00.000000+00:00"),
+                Token::Str("scopes"),
+                Token::Seq { len: Some(1) },
+                Token::UnitVariant {
+                    name: "Scope",
+                    variant: "guilds",
+                },
+                Token::SeqEnd,
+                Token::StructEnd,
+            ],
+        );
+    }
+}
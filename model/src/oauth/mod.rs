@@ -2,8 +2,17 @@ pub mod current_application_info;
 pub mod id;
 pub mod team;
 
+mod access_token;
+mod current_authorization_information;
 mod partial_application;
+mod scope;
 
 pub use self::{
-    current_application_info::CurrentApplicationInfo, partial_application::PartialApplication,
+    access_token::AccessToken,
+    current_application_info::CurrentApplicationInfo,
+    current_authorization_information::{
+        CurrentAuthorizationApplication, CurrentAuthorizationInformation,
+    },
+    partial_application::PartialApplication,
+    scope::Scope,
 };
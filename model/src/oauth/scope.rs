@@ -0,0 +1,162 @@
+use serde::{Deserialize, Serialize};
+
+/// Permission an `OAuth2` application requests from a user.
+///
+/// Refer to [the discord docs] for more information.
+///
+/// [the discord docs]: https://discord.com/developers/docs/topics/oauth2#shared-resources-oauth2-scopes
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
+pub enum Scope {
+    /// Read the activities of the user.
+    #[serde(rename = "activities.read")]
+    ActivitiesRead,
+    /// Update the activity of the user.
+    #[serde(rename = "activities.write")]
+    ActivitiesWrite,
+    /// Read the build data of the user's applications.
+    #[serde(rename = "applications.builds.read")]
+    ApplicationsBuildsRead,
+    /// Upload and update builds of the user's applications.
+    #[serde(rename = "applications.builds.upload")]
+    ApplicationsBuildsUpload,
+    /// Use commands of the application in a guild.
+    #[serde(rename = "applications.commands")]
+    ApplicationsCommands,
+    /// Update commands of the application via a bearer token.
+    #[serde(rename = "applications.commands.update")]
+    ApplicationsCommandsUpdate,
+    /// Read the entitlements of the user's applications.
+    #[serde(rename = "applications.entitlements")]
+    ApplicationsEntitlements,
+    /// Manage the store listings and SKUs of the user's applications.
+    #[serde(rename = "applications.store.update")]
+    ApplicationsStoreUpdate,
+    /// Add a bot to a guild.
+    #[serde(rename = "bot")]
+    Bot,
+    /// Read the third-party connections of the user.
+    #[serde(rename = "connections")]
+    Connections,
+    /// Read the email address of the user.
+    #[serde(rename = "email")]
+    Email,
+    /// Join the user to a group DM.
+    #[serde(rename = "gdm.join")]
+    GdmJoin,
+    /// Read the guilds the user is in.
+    #[serde(rename = "guilds")]
+    Guilds,
+    /// Join the user to a guild.
+    #[serde(rename = "guilds.join")]
+    GuildsJoin,
+    /// Read the user, without their email address.
+    #[serde(rename = "identify")]
+    Identify,
+    /// Read the messages of the user over RPC.
+    #[serde(rename = "messages.read")]
+    MessagesRead,
+    /// Read the friends of the user.
+    #[serde(rename = "relationships.read")]
+    RelationshipsRead,
+    /// Control the user's local Discord client over RPC.
+    #[serde(rename = "rpc")]
+    Rpc,
+    /// Update the activity of the user over RPC.
+    #[serde(rename = "rpc.activities.write")]
+    RpcActivitiesWrite,
+    /// Receive notifications sent to the user over RPC.
+    #[serde(rename = "rpc.notifications.read")]
+    RpcNotificationsRead,
+    /// Read the voice settings of the user over RPC.
+    #[serde(rename = "rpc.voice.read")]
+    RpcVoiceRead,
+    /// Update the voice settings of the user over RPC.
+    #[serde(rename = "rpc.voice.write")]
+    RpcVoiceWrite,
+    /// Create a webhook in a channel chosen by the user.
+    #[serde(rename = "webhook.incoming")]
+    WebhookIncoming,
+}
+
+impl Scope {
+    /// Name of the scope as used by the API, such as `guilds.join`.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::ActivitiesRead => "activities.read",
+            Self::ActivitiesWrite => "activities.write",
+            Self::ApplicationsBuildsRead => "applications.builds.read",
+            Self::ApplicationsBuildsUpload => "applications.builds.upload",
+            Self::ApplicationsCommands => "applications.commands",
+            Self::ApplicationsCommandsUpdate => "applications.commands.update",
+            Self::ApplicationsEntitlements => "applications.entitlements",
+            Self::ApplicationsStoreUpdate => "applications.store.update",
+            Self::Bot => "bot",
+            Self::Connections => "connections",
+            Self::Email => "email",
+            Self::GdmJoin => "gdm.join",
+            Self::Guilds => "guilds",
+            Self::GuildsJoin => "guilds.join",
+            Self::Identify => "identify",
+            Self::MessagesRead => "messages.read",
+            Self::RelationshipsRead => "relationships.read",
+            Self::Rpc => "rpc",
+            Self::RpcActivitiesWrite => "rpc.activities.write",
+            Self::RpcNotificationsRead => "rpc.notifications.read",
+            Self::RpcVoiceRead => "rpc.voice.read",
+            Self::RpcVoiceWrite => "rpc.voice.write",
+            Self::WebhookIncoming => "webhook.incoming",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Scope;
+    use serde_test::Token;
+
+    #[test]
+    fn test_variants() {
+        let scopes = [
+            (Scope::ActivitiesRead, "activities.read"),
+            (Scope::ActivitiesWrite, "activities.write"),
+            (Scope::ApplicationsBuildsRead, "applications.builds.read"),
+            (
+                Scope::ApplicationsBuildsUpload,
+                "applications.builds.upload",
+            ),
+            (Scope::ApplicationsCommands, "applications.commands"),
+            (
+                Scope::ApplicationsCommandsUpdate,
+                "applications.commands.update",
+            ),
+            (Scope::ApplicationsEntitlements, "applications.entitlements"),
+            (Scope::ApplicationsStoreUpdate, "applications.store.update"),
+            (Scope::Bot, "bot"),
+            (Scope::Connections, "connections"),
+            (Scope::Email, "email"),
+            (Scope::GdmJoin, "gdm.join"),
+            (Scope::Guilds, "guilds"),
+            (Scope::GuildsJoin, "guilds.join"),
+            (Scope::Identify, "identify"),
+            (Scope::MessagesRead, "messages.read"),
+            (Scope::RelationshipsRead, "relationships.read"),
+            (Scope::Rpc, "rpc"),
+            (Scope::RpcActivitiesWrite, "rpc.activities.write"),
+            (Scope::RpcNotificationsRead, "rpc.notifications.read"),
+            (Scope::RpcVoiceRead, "rpc.voice.read"),
+            (Scope::RpcVoiceWrite, "rpc.voice.write"),
+            (Scope::WebhookIncoming, "webhook.incoming"),
+        ];
+
+        for (scope, name) in &scopes {
+            assert_eq!(*name, scope.name());
+            serde_test::assert_tokens(
+                scope,
+                &[Token::UnitVariant {
+                    name: "Scope",
+                    variant: name,
+                }],
+            );
+        }
+    }
+}
@@ -399,7 +399,13 @@ impl<'a> PermissionCalculator<'a> {
         // type.
         if matches!(channel_type, ChannelType::GuildStageVoice) {
             permissions = bitops::remove(permissions, PERMISSIONS_STAGE_OMIT);
-        } else if matches!(channel_type, ChannelType::GuildText) {
+        } else if matches!(
+            channel_type,
+            ChannelType::GuildText
+                | ChannelType::GuildNewsThread
+                | ChannelType::GuildPrivateThread
+                | ChannelType::GuildPublicThread
+        ) {
             permissions = bitops::remove(permissions, PERMISSIONS_TEXT_OMIT);
         } else if matches!(channel_type, ChannelType::GuildVoice) {
             permissions = bitops::remove(permissions, PERMISSIONS_VOICE_OMIT);